@@ -0,0 +1,211 @@
+//! ANSI escape handling for pipe mode.
+//!
+//! Lines piped from log colorization scripts carry escape codes. They are
+//! stripped before tokenizing (see [`formatorbit_core::tokenize`]), and their
+//! positions are kept so highlighted output can restore the original colors.
+
+/// An escape sequence removed by [`strip_ansi`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnsiSpan {
+    /// Byte offset in the stripped string where the sequence was
+    pub clean_offset: usize,
+    /// Byte offset of the sequence in the original string (start)
+    pub start: usize,
+    /// Byte offset of the sequence in the original string (end, exclusive)
+    pub end: usize,
+}
+
+impl AnsiSpan {
+    /// The escape sequence text within the original string.
+    pub fn text<'a>(&self, original: &'a str) -> &'a str {
+        &original[self.start..self.end]
+    }
+}
+
+/// Strip ANSI escape codes from a string, recording where each one was.
+///
+/// Handles:
+/// - CSI sequences: `\x1b[...m` (colors, styles)
+/// - OSC sequences: `\x1b]...ST` (terminal titles, links)
+/// - Simple escapes: `\x1b[A-Z]` (cursor movement)
+///
+/// The spans let positions in the stripped text be mapped back to the
+/// original (see [`original_start`] and [`original_end`]).
+pub fn strip_ansi(input: &str) -> (String, Vec<AnsiSpan>) {
+    let mut clean = String::with_capacity(input.len());
+    let mut spans = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        if c != '\x1b' {
+            clean.push(c);
+            continue;
+        }
+
+        // Start of escape sequence
+        match chars.peek().map(|&(_, next)| next) {
+            Some('[') => {
+                // CSI sequence: \x1b[...m
+                chars.next(); // consume '['
+                              // Skip until we hit a letter (the final byte)
+                for (_, next) in chars.by_ref() {
+                    if next.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            Some(']') => {
+                // OSC sequence: \x1b]...ST (where ST is \x1b\ or \x07)
+                chars.next(); // consume ']'
+                while let Some((_, next)) = chars.next() {
+                    if next == '\x07' {
+                        break;
+                    }
+                    if next == '\x1b' && chars.peek().map(|&(_, c)| c) == Some('\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            Some(next) if next.is_ascii_alphabetic() => {
+                // Simple escape: \x1b followed by a letter
+                chars.next();
+            }
+            _ => {
+                // Unknown escape, skip just the ESC
+            }
+        }
+
+        let end = chars.peek().map_or(input.len(), |&(i, _)| i);
+        spans.push(AnsiSpan {
+            clean_offset: clean.len(),
+            start,
+            end,
+        });
+    }
+
+    (clean, spans)
+}
+
+/// Map the start of a range in stripped text back to the original.
+///
+/// Escape sequences right at the offset stay before the range, so a token
+/// wrapped in a color code starts after the code.
+pub fn original_start(spans: &[AnsiSpan], clean_offset: usize) -> usize {
+    clean_offset
+        + spans
+            .iter()
+            .filter(|s| s.clean_offset <= clean_offset)
+            .map(|s| s.end - s.start)
+            .sum::<usize>()
+}
+
+/// Map the end of a range in stripped text back to the original.
+///
+/// Escape sequences right at the offset stay after the range, so a token
+/// wrapped in a color code ends before the reset.
+pub fn original_end(spans: &[AnsiSpan], clean_offset: usize) -> usize {
+    clean_offset
+        + spans
+            .iter()
+            .filter(|s| s.clean_offset < clean_offset)
+            .map(|s| s.end - s.start)
+            .sum::<usize>()
+}
+
+#[cfg(test)]
+mod tests {
+    use formatorbit_core::tokenize::split_tokens;
+
+    use super::*;
+
+    fn strip_ansi_codes(input: &str) -> String {
+        strip_ansi(input).0
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_colors() {
+        // Red text: \x1b[31m...\x1b[0m
+        assert_eq!(strip_ansi_codes("\x1b[31mERROR\x1b[0m"), "ERROR");
+
+        // Yellow text
+        assert_eq!(strip_ansi_codes("\x1b[33mWARNING\x1b[0m"), "WARNING");
+
+        // Bold text: \x1b[1m...\x1b[0m
+        assert_eq!(strip_ansi_codes("\x1b[1mbold\x1b[0m"), "bold");
+
+        // Combined: bold red
+        assert_eq!(strip_ansi_codes("\x1b[1;31mERROR\x1b[0m"), "ERROR");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_full_line() {
+        // Simulates colorlogJack.py output for an error line
+        let colored = "\x1b[31m[2024-01-15 10:30:00] E Some error occurred\x1b[0m";
+        let stripped = strip_ansi_codes(colored);
+        assert_eq!(stripped, "[2024-01-15 10:30:00] E Some error occurred");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_no_codes() {
+        // Plain text should pass through unchanged
+        assert_eq!(strip_ansi_codes("hello world"), "hello world");
+        assert_eq!(strip_ansi_codes("192.168.1.1"), "192.168.1.1");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_multiple() {
+        // Multiple color changes in one line
+        let colored = "\x1b[32mOK\x1b[0m: \x1b[33muser123\x1b[0m logged in";
+        assert_eq!(strip_ansi_codes(colored), "OK: user123 logged in");
+    }
+
+    #[test]
+    fn test_strip_ansi_codes_preserves_content() {
+        // Ensure hex values and UUIDs are preserved
+        let colored = "\x1b[36m550e8400-e29b-41d4-a716-446655440000\x1b[0m";
+        assert_eq!(
+            strip_ansi_codes(colored),
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+
+        let colored = "\x1b[33m691E01B8\x1b[0m";
+        assert_eq!(strip_ansi_codes(colored), "691E01B8");
+    }
+
+    #[test]
+    fn test_strip_ansi_records_spans() {
+        let colored = "id=\x1b[31m550e8400-e29b-41d4-a716-446655440000\x1b[0m done";
+        let (clean, spans) = strip_ansi(colored);
+        assert_eq!(clean, "id=550e8400-e29b-41d4-a716-446655440000 done");
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].text(colored), "\x1b[31m");
+        assert_eq!(spans[0].clean_offset, 3);
+        assert_eq!(spans[1].text(colored), "\x1b[0m");
+        assert_eq!(spans[1].clean_offset, 39);
+    }
+
+    #[test]
+    fn test_original_offsets_skip_wrapping_codes() {
+        let colored = "id=\x1b[31m550e8400-e29b-41d4-a716-446655440000\x1b[0m done";
+        let (clean, spans) = strip_ansi(colored);
+        let token = split_tokens(&clean)
+            .into_iter()
+            .find(|t| t.text.starts_with("id="))
+            .unwrap();
+
+        // The token maps to the colored text without its color codes
+        let start = original_start(&spans, token.start);
+        let end = original_end(&spans, token.end);
+        assert_eq!(
+            &colored[start..end],
+            "id=\x1b[31m550e8400-e29b-41d4-a716-446655440000"
+        );
+
+        let uuid_start = original_start(&spans, 3);
+        assert_eq!(
+            &colored[uuid_start..end],
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! Precedence: CLI args > Environment vars > Config file > Defaults
 
-use formatorbit_core::{BlockingConfig, ConversionConfig, PriorityAdjustment, PriorityConfig};
+use formatorbit_core::{
+    BlockingConfig, ColorAlphaOrder, ConversionConfig, PriorityAdjustment, PriorityConfig,
+    RelativeTimeStyle,
+};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
@@ -30,6 +33,13 @@ url_timeout = 30
 # Maximum response size for URL fetches (K, M, G suffixes)
 url_max_size = "10M"
 
+# Channel order of 8-digit hex colors: "rgba-first" (#RRGGBBAA, CSS) or
+# "argb-first" (#AARRGGBB, Android)
+# color_alpha_order = "rgba-first"
+
+# Wording of relative times: "verbose" (3 days ago) or "terse" (3d ago)
+# relative_time_style = "verbose"
+
 # ============================================================================
 # Priority Configuration (optional)
 # ============================================================================
@@ -62,6 +72,17 @@ url_max_size = "10M"
 # # Block specific conversion paths (source:target or full path)
 # paths = ["hex:msgpack", "uuid:epoch-seconds"]
 
+# ============================================================================
+# Confidence Overrides (optional)
+# ============================================================================
+# Scale how confident a format is about its matches. Interpretations are
+# ranked by confidence, so this promotes or demotes formats everywhere.
+# Adjusted confidences are clamped to 0.0-1.0.
+
+# [confidence]
+# hex = 1.5            # Prefer hex readings
+# text = 0.5           # Demote plain text
+
 # ============================================================================
 # Analytics Configuration (optional)
 # ============================================================================
@@ -116,6 +137,12 @@ url_max_size = "10M"
 # target = "EUR"
 #
 # # Can also be set via: FORB_TARGET_CURRENCY=EUR
+#
+# # Pin exchange rates (value of one unit in USD) instead of fetching them.
+# # Useful for reproducible output, e.g. in CI.
+# [currency.rates]
+# EUR = 1.08
+# SEK = 0.095
 "#;
 
 /// Priority configuration as stored in TOML.
@@ -205,6 +232,8 @@ pub struct CliCurrencyConfig {
     /// Target currency code for expression functions.
     /// If not set, auto-detected from system locale or defaults to USD.
     pub target: Option<String>,
+    /// Pinned exchange rates: currency code -> value of one unit in USD.
+    pub rates: HashMap<String, f64>,
 }
 
 /// Configuration loaded from file and environment.
@@ -217,10 +246,16 @@ pub struct Config {
     pub url_timeout: Option<u64>,
     pub url_max_size: Option<String>,
     pub max_tokens: Option<usize>,
+    /// Channel order of 8-digit hex colors.
+    pub color_alpha_order: Option<ColorAlphaOrder>,
+    /// Wording of relative times.
+    pub relative_time_style: Option<RelativeTimeStyle>,
     /// Priority configuration.
     pub priority: Option<CliPriorityConfig>,
     /// Blocking configuration.
     pub blocking: Option<CliBlockingConfig>,
+    /// Confidence multipliers by format id.
+    pub confidence: HashMap<String, f32>,
     /// Analytics configuration.
     #[serde(default)]
     pub analytics: CliAnalyticsConfig,
@@ -340,6 +375,18 @@ impl Config {
         self.updates.check
     }
 
+    /// Check whether offline mode is requested via FORB_OFFLINE.
+    pub fn offline_env() -> bool {
+        std::env::var("FORB_OFFLINE")
+            .map(|val| {
+                !matches!(
+                    val.to_lowercase().as_str(),
+                    "" | "0" | "false" | "no" | "off"
+                )
+            })
+            .unwrap_or(false)
+    }
+
     /// Get plugins enabled with precedence: env > config > default (true).
     #[allow(dead_code)]
     pub fn plugins_enabled(&self) -> bool {
@@ -376,7 +423,8 @@ impl Config {
 
     /// Convert CLI config to core ConversionConfig.
     ///
-    /// Returns `Some(config)` if there's any priority or blocking customization,
+    /// Returns `Some(config)` if there's any priority, blocking, confidence, rate
+    /// or color customization,
     /// otherwise `None` (to use defaults).
     #[must_use]
     pub fn conversion_config(&self) -> Option<ConversionConfig> {
@@ -408,11 +456,26 @@ impl Config {
 
         // Only return Some if there's actual customization
         match (&priority, &blocking) {
-            (None, None) => None,
+            (None, None)
+                if self.currency.rates.is_empty()
+                    && self.confidence.is_empty()
+                    && self.color_alpha_order.is_none()
+                    && self.relative_time_style.is_none() =>
+            {
+                None
+            }
             _ => Some(ConversionConfig {
                 priority: priority.unwrap_or_default(),
                 blocking: blocking.unwrap_or_default(),
                 reinterpret_threshold: 0.0, // Use default (0.7) - will be overridden by CLI if set
+                custom_rates: self.currency.rates.clone(),
+                offline: false, // Set from --offline / FORB_OFFLINE by the CLI
+                max_depth: None,
+                display_timezone: None, // Set from --tz by the CLI
+                confidence_overrides: self.confidence.clone(),
+                color_alpha_order: self.color_alpha_order.unwrap_or_default(),
+                output_radix: None, // Set from --base by the CLI
+                relative_time_style: self.relative_time_style.unwrap_or_default(),
             }),
         }
     }
@@ -472,4 +535,53 @@ limit = 10
         assert_eq!(config.threshold(), 0.8);
         assert!(!config.no_color());
     }
+
+    #[test]
+    fn test_currency_rates_config() {
+        let toml = r#"
+[currency.rates]
+EUR = 1.25
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let conv_config = config
+            .conversion_config()
+            .expect("rates are a customization");
+        assert_eq!(conv_config.custom_rates.get("EUR"), Some(&1.25));
+        assert!(conv_config.is_customized());
+    }
+
+    #[test]
+    fn test_confidence_config() {
+        let toml = r#"
+[confidence]
+hex = 2.0
+text = 0.5
+"#;
+        let config: Config = toml::from_str(toml).unwrap();
+        let conv_config = config
+            .conversion_config()
+            .expect("confidence overrides are a customization");
+        assert_eq!(conv_config.confidence_override("hex"), Some(2.0));
+        assert_eq!(conv_config.confidence_override("text"), Some(0.5));
+        assert_eq!(conv_config.confidence_override("uuid"), None);
+    }
+
+    #[test]
+    fn test_color_alpha_order_config() {
+        let config: Config = toml::from_str(r#"color_alpha_order = "argb-first""#).unwrap();
+        let conv_config = config
+            .conversion_config()
+            .expect("alpha order is a customization");
+        assert_eq!(conv_config.color_alpha_order, ColorAlphaOrder::ArgbFirst);
+        assert!(Config::default().conversion_config().is_none());
+    }
+
+    #[test]
+    fn test_relative_time_style_config() {
+        let config: Config = toml::from_str(r#"relative_time_style = "terse""#).unwrap();
+        let conv_config = config
+            .conversion_config()
+            .expect("relative time style is a customization");
+        assert_eq!(conv_config.relative_time_style, RelativeTimeStyle::Terse);
+    }
 }
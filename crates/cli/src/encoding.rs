@@ -0,0 +1,73 @@
+//! Decoding of input bytes in a legacy or UTF-16 text encoding.
+//!
+//! `--input-encoding` turns the raw bytes of piped or `@file` input into text
+//! before interpretation, so a UTF-16 or Latin-1 "Hello" is read the same as a
+//! UTF-8 one. Encoding names are WHATWG labels (`utf-16le`, `latin1`,
+//! `shift_jis`, ...), plus the hyphenless `utf8`, `utf16le` and `utf16be`.
+
+use encoding_rs::Encoding;
+
+/// Look up an encoding by name.
+fn encoding_for(label: &str) -> Option<&'static Encoding> {
+    let label = label.trim().to_ascii_lowercase();
+    let label = match label.as_str() {
+        "utf16" => "utf-16",
+        "utf16le" => "utf-16le",
+        "utf16be" => "utf-16be",
+        other => other,
+    };
+    Encoding::for_label(label.as_bytes())
+}
+
+/// Decode input bytes from the named encoding.
+///
+/// A byte order mark for the same encoding is skipped. Fails for unknown
+/// encodings and for bytes that aren't valid in the encoding, rather than
+/// substituting replacement characters.
+pub fn decode_input(bytes: &[u8], label: &str) -> Result<String, String> {
+    let encoding = encoding_for(label).ok_or_else(|| format!("unknown encoding '{}'", label))?;
+
+    let bytes = match Encoding::for_bom(bytes) {
+        Some((bom_encoding, bom_len)) if bom_encoding == encoding => &bytes[bom_len..],
+        _ => bytes,
+    };
+
+    encoding
+        .decode_without_bom_handling_and_without_replacement(bytes)
+        .map(|text| text.into_owned())
+        .ok_or_else(|| format!("input is not valid {}", encoding.name()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_utf16_and_latin1() {
+        let utf16le = [b'H', 0, b'i', 0];
+        assert_eq!(decode_input(&utf16le, "utf16le").unwrap(), "Hi");
+        assert_eq!(
+            decode_input(&[0xFF, 0xFE, b'H', 0], "UTF-16LE").unwrap(),
+            "H"
+        );
+        assert_eq!(decode_input(&[0, b'H', 0, b'i'], "utf16be").unwrap(), "Hi");
+        assert_eq!(
+            decode_input(&[b'c', b'a', b'f', 0xE9], "latin1").unwrap(),
+            "café"
+        );
+    }
+
+    #[test]
+    fn test_decode_errors() {
+        assert_eq!(
+            decode_input(b"Hi", "klingon").unwrap_err(),
+            "unknown encoding 'klingon'"
+        );
+        // Odd byte count
+        assert_eq!(
+            decode_input(&[b'H', 0, b'i'], "utf16le").unwrap_err(),
+            "input is not valid UTF-16LE"
+        );
+        assert!(decode_input(&[0xC3, 0x28], "utf8").is_err());
+    }
+}
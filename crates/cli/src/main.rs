@@ -1,23 +1,32 @@
 mod analytics;
+mod ansi;
 mod config;
+#[cfg(feature = "encodings")]
+mod encoding;
 mod graph;
 mod pipe;
 mod pretty;
-mod tokenizer;
 mod updates;
+#[cfg(feature = "clipboard")]
+mod watch;
 
 use config::Config;
 
+use std::collections::HashMap;
 use std::fs;
 use std::io::{self, IsTerminal, Read, Write};
 use std::path::Path;
 use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
 
 use clap::{CommandFactory, Parser};
 use colored::{control::set_override, Colorize};
 use formatorbit_core::{
-    truncate_str, Conversion, ConversionKind, CoreValue, Formatorbit, RichDisplay,
-    RichDisplayOption,
+    formats::{DateTimeFormat, ImageFormat},
+    split_format_filter, truncate_str, ColorAlphaOrder, Conversion, ConversionKind,
+    ConversionPriority, ConversionResult, CoreValue, FormatExplanation, FormatFilterError,
+    Formatorbit, RichDisplay, RichDisplayOption, UnwrapResult,
 };
 use tracing_subscriber::{filter::LevelFilter, EnvFilter};
 
@@ -31,19 +40,23 @@ UUIDs, colors, math expressions, currencies, units, and more.
 
 SUPPORTED FORMATS:
   Encoding:     hex, base64, binary, octal, url-encoding, escape sequences
-  Numbers:      decimal, data sizes (1MB, 1MiB), temperature (30°C, 86°F)
-  Math:         expressions (2 + 2, 0xFF + 1, 1 << 8)
+  Numbers:      decimal, data sizes (1MB, 1MiB), temperature (30°C, 86°F, 491.67°R)
+  Math:         expressions (2 + 2, 0xFF + 1, 1 << 8), percentages (15% of 240),
+                fractions (3/8, 1 1/2)
   Units:        length, weight, volume, speed, pressure, energy, angle, area
+                musical notes (A4, C#5, 261.63 Hz)
   Currency:     100 USD, $50, 5kEUR, 2.5MSEK (with live exchange rates)
   Time:         Unix epoch (sec/ms), durations (1h30m), ISO 8601, cron (*/5 * * * *)
   Hashing:      MD5, SHA-1, SHA-256, SHA-512, Blake2b, Blake3, CRC32
   Identifiers:  UUID (v1-v8), ULID, NanoID, CUID2, JWT
   Network:      IPv4, IPv6, MAC address (with OUI vendor lookup)
-  Web:          URL parsing (with tracking parameter removal)
+  Web:          URL parsing (with tracking parameter removal), QR payloads
+                (WIFI:, mailto:, tel:, geo:, vCard)
   Coordinates:  DD, DMS, DDM, Geohash, Plus Code, UTM, MGRS
-  Colors:       #RGB, rgb(), hsl(), 0xAARRGGBB (Android)
+  Colors:       #RGB, rgb(), hsl(), 0xAARRGGBB (Android), CSS names,
+                contrast (#777 on #fff)
   Text:         plain text, ASCII codes, UTF-8 detection
-  Data:         JSON, MessagePack, Protobuf, plist
+  Data:         JSON, XML, MessagePack, Protobuf, plist
   Images:       JPEG, PNG, GIF, WebP, BMP, TIFF (with EXIF metadata)
 
 EXAMPLES:
@@ -104,6 +117,7 @@ CONFIGURATION:
   url_timeout  | --url-timeout  | FORB_URL_TIMEOUT   | 30
   url_max_size | --url-max-size | FORB_URL_MAX_SIZE  | 10M
   max_tokens   | --max-tokens   | FORB_MAX_TOKENS    | 50
+  offline      | --offline      | FORB_OFFLINE       | false
 
   Config file location: forb --config-path
   Generate default config: forb --config-init
@@ -141,6 +155,22 @@ struct Cli {
     #[arg(long, short = 'j')]
     json: bool,
 
+    /// Output results as CSV, one row per conversion (for spreadsheets)
+    ///
+    /// Columns: input, source_format, confidence, target_format, kind, value.
+    #[arg(long, conflicts_with = "json")]
+    csv: bool,
+
+    /// Render each conversion with a custom template (for scripting)
+    ///
+    /// Placeholders: {input}, {confidence}, {source_format}, {target_format},
+    /// {kind}, {value} and {path}. Write {{ and }} for literal braces;
+    /// \t and \n are expanded to tab and newline.
+    ///
+    /// Example: --template '{source_format}\t{target_format}\t{value}'
+    #[arg(long, value_name = "TEMPLATE", conflicts_with_all = ["json", "csv", "raw"])]
+    template: Option<String>,
+
     /// List all supported formats
     #[arg(long)]
     formats: bool,
@@ -167,9 +197,10 @@ struct Cli {
 
     /// Only use specific formats (comma-separated, supports aliases)
     ///
-    /// Examples: --only uuid,hex,ts  or  -o b64,ip
+    /// Prefix a name with ! or - to exclude it instead.
+    /// Examples: --only uuid,hex,ts  or  -o b64,ip  or  -o '!coords,!text'
     /// Use --formats to see available format IDs and aliases.
-    #[arg(long, short = 'o', value_delimiter = ',')]
+    #[arg(long, short = 'o', value_delimiter = ',', allow_hyphen_values = true)]
     only: Option<Vec<String>>,
 
     /// Maximum conversions to show per interpretation (0 = unlimited)
@@ -178,6 +209,14 @@ struct Cli {
     #[arg(long, short = 'l')]
     limit: Option<usize>,
 
+    /// Maximum conversions of each kind to show per interpretation
+    ///
+    /// Caps conversions (→), representations (≈) and traits (✓) separately,
+    /// before --limit caps the total.
+    /// Example: --limit-per-kind 2  shows at most two lines of each kind.
+    #[arg(long, value_name = "N", value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..))]
+    limit_per_kind: Option<usize>,
+
     /// Force tee mode even when stdin is a TTY (for testing)
     #[arg(long, hide = true)]
     force_tee: bool,
@@ -186,6 +225,29 @@ struct Cli {
     #[arg(long, hide = true)]
     max_tokens: Option<usize>,
 
+    // === Watch mode options ===
+    /// Watch the clipboard and show interpretations of each new value
+    /// (requires --features clipboard)
+    ///
+    /// Polls the system clipboard and evaluates every change with the other
+    /// options given (--only, --limit, --json, ...). The screen is cleared
+    /// between updates unless --watch-append is set.
+    ///
+    /// Example: forb --watch --only uuid,ts
+    #[cfg(feature = "clipboard")]
+    #[arg(long, conflicts_with_all = ["input", "input_env", "tee"])]
+    watch: bool,
+
+    /// Milliseconds between clipboard polls in watch mode
+    #[cfg(feature = "clipboard")]
+    #[arg(long, value_name = "MS", default_value_t = 500, requires = "watch")]
+    watch_interval: u64,
+
+    /// Append each update in watch mode instead of clearing the screen
+    #[cfg(feature = "clipboard")]
+    #[arg(long, requires = "watch")]
+    watch_append: bool,
+
     /// Disable colored output
     #[arg(long, short = 'C')]
     no_color: bool,
@@ -205,6 +267,137 @@ struct Cli {
     #[arg(long, short = '1')]
     first: bool,
 
+    /// Group conversions under Conversions/Representations/Traits headers
+    ///
+    /// Sections each interpretation's output by conversion kind instead of
+    /// interleaving them. Makes long conversion lists easier to navigate.
+    #[arg(long)]
+    group_by_kind: bool,
+
+    /// Show only primary conversions for each interpretation
+    ///
+    /// Keeps conversions a format marks as its most essential result (e.g.,
+    /// hex bytes → decimal, 5km → meters). Stricter than --limit because it
+    /// filters by meaning rather than count.
+    #[arg(long)]
+    primary: bool,
+
+    /// Show only conversions of the given kinds (comma-separated)
+    ///
+    /// Kinds are conversion (→), representation (≈) and trait (✓).
+    /// Example: --kinds conversion  shows only true transformations.
+    #[arg(long, value_delimiter = ',', value_name = "KINDS")]
+    kinds: Option<Vec<String>>,
+
+    /// Hide ✓ trait lines (same as --kinds conversion,representation)
+    #[arg(long)]
+    no_traits: bool,
+
+    /// Hide ≈ representation lines (same as --kinds conversion,trait)
+    #[arg(long)]
+    no_representations: bool,
+
+    /// Order conversions by priority (default), name or kind
+    ///
+    /// name sorts by target format; kind lists conversions (→), then
+    /// representations (≈), then traits (✓), keeping priority order within each.
+    #[arg(long, value_name = "ORDER")]
+    sort: Option<String>,
+
+    /// Peel encoding layers and show the chain found
+    ///
+    /// Repeatedly decodes (base64, hex, URL encoding) and decompresses
+    /// (gzip, zlib) until JSON, text, or an image remains, then reports the
+    /// chain, e.g. "base64 → gzip → json", and the decoded value.
+    #[arg(long)]
+    unwrap: bool,
+
+    /// Show why each format matched or rejected the input
+    ///
+    /// Prints a table of every format with the confidence it assigned and its
+    /// reasoning, e.g. why 1704067200 reads as an epoch rather than an ISBN.
+    #[arg(long)]
+    explain: bool,
+
+    /// Interpret one field of JSON input, selected by a JSON pointer
+    ///
+    /// Navigates the parsed JSON and interprets the selected value on its
+    /// own. Strings are used as-is; other values as compact JSON.
+    ///
+    /// Example: forb @big.json --path /users/0/created_at
+    #[arg(long, value_name = "POINTER")]
+    path: Option<String>,
+
+    /// Interpret each line of input on its own
+    ///
+    /// Results are grouped under a header per line. With --json, prints an
+    /// array holding each line's results. Blank lines are skipped.
+    ///
+    /// Example: cat ids.txt | forb --batch
+    #[arg(long, conflicts_with_all = ["tee", "unwrap", "explain", "csv", "template", "dot", "mermaid"])]
+    batch: bool,
+
+    /// Fail if an @path doesn't exist instead of treating it as text
+    ///
+    /// By default, `forb @1.2.3.4` falls back to interpreting "1.2.3.4" with a
+    /// warning when no such file exists.
+    #[arg(long)]
+    strict_at: bool,
+
+    /// Read input from an environment variable
+    ///
+    /// Keeps sensitive values (tokens, keys) out of shell history and `ps`.
+    /// The value is used literally; @path and URL syntax are not expanded.
+    ///
+    /// Example: forb --input-env API_TOKEN
+    #[arg(long, value_name = "VAR", conflicts_with = "input")]
+    input_env: Option<String>,
+
+    /// Decode piped or @file input from this text encoding
+    /// (requires --features encodings)
+    ///
+    /// For text saved as UTF-16 or in a legacy code page. Accepts utf8,
+    /// utf16le, utf16be, latin1 and other WHATWG encoding labels
+    /// (shift_jis, windows-1251, ...). Fails if the input isn't valid in
+    /// the encoding.
+    ///
+    /// Example: forb @notes.txt --input-encoding utf16le
+    #[cfg(feature = "encodings")]
+    #[arg(long, value_name = "ENCODING")]
+    input_encoding: Option<String>,
+
+    /// Mask the original input in all output
+    ///
+    /// Replaces the input with [REDACTED] in descriptions, values, JSON and
+    /// graph labels. Lossless conversions (hex, base64, ...) would give it
+    /// away and are masked too; lossy ones (sizes, relative times) and traits
+    /// are still shown, as is each interpretation's summary. Useful when
+    /// inspecting tokens or keys in shared terminals or logs.
+    #[arg(long)]
+    redact: bool,
+
+    /// Include the normalized source format in conversions
+    ///
+    /// Conversions normally skip the input's own format (no hex → hex).
+    /// This adds its canonical form first, e.g. "de:ad:be:ef" → DEADBEEF.
+    #[arg(long)]
+    include_source: bool,
+
+    /// Collapse conversions repeated across interpretations
+    ///
+    /// Different interpretations often reach the same value (e.g. 0xDEADBEEF
+    /// from both hex readings). Keeps the conversion with the shortest path
+    /// per target format and value (the first displayed on a tie).
+    #[arg(long)]
+    dedupe: bool,
+
+    /// Show a thumbnail preview when the input decodes to an image
+    ///
+    /// Renders the first image found (e.g., base64-encoded PNG) as
+    /// half-block characters, or as ASCII shades with --no-color.
+    #[arg(long)]
+    preview: bool,
+
     /// Force input to be interpreted as a specific format
     ///
     /// Skip auto-detection and treat input as the specified format.
@@ -212,6 +405,16 @@ struct Cli {
     #[arg(long, short = 'f', value_name = "FORMAT")]
     from: Option<String>,
 
+    /// Show this interpretation first when it matches
+    ///
+    /// Softer than --from: every interpretation is still shown, but those from
+    /// the named format (id or alias; epoch covers epoch-seconds) move to the
+    /// top. Combine with -1 to pick it.
+    ///
+    /// Example: forb --prefer epoch 3000000000
+    #[arg(long, value_name = "FORMAT")]
+    prefer: Option<String>,
+
     /// Output conversion graph in Graphviz DOT format
     ///
     /// Pipe to dot to render: forb --dot INPUT | dot -Tpng > graph.png
@@ -237,6 +440,28 @@ struct Cli {
     #[arg(long, value_name = "THRESHOLD")]
     reinterpret_threshold: Option<f32>,
 
+    /// Also show datetimes in this timezone (IANA name)
+    ///
+    /// Adds a local-time line next to each UTC datetime.
+    /// Example: forb --tz Europe/Stockholm 1703456789
+    #[arg(long, value_name = "ZONE")]
+    tz: Option<String>,
+
+    /// Also show integers in this base (2-36)
+    ///
+    /// Adds a base-N line next to each integer result. Bases 2, 8 and 16
+    /// are already covered by the binary, octal and hex outputs.
+    /// Example: forb --base 36 255
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(2..=36))]
+    base: Option<u32>,
+
+    /// Channel order of 8-digit hex colors: rgba-first (CSS) or argb-first (Android)
+    ///
+    /// Overrides color_alpha_order from the config file.
+    /// Example: forb --color-alpha-order argb-first '#80FF0000'
+    #[arg(long, value_name = "ORDER")]
+    color_alpha_order: Option<String>,
+
     /// Show packet layout for binary formats (protobuf, msgpack)
     ///
     /// Displays byte-level structure with offsets, lengths, and decoded values.
@@ -251,6 +476,16 @@ struct Cli {
     #[arg(long, short = 'v', action = clap::ArgAction::Count)]
     verbose: u8,
 
+    /// Give up interpreting an input after this many milliseconds
+    ///
+    /// Bounds pathological inputs (huge or adversarial) that make the
+    /// conversion search slow. Applies to each input (each line with
+    /// --batch) and exits with an error when exceeded. Best-effort: the
+    /// work isn't interrupted, so output ends at the deadline but a single
+    /// slow step (like a regex) still runs in the background until exit.
+    #[arg(long, value_name = "MS", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+
     /// Timeout for URL fetches in seconds
     #[arg(long, value_name = "SECS")]
     url_timeout: Option<u64>,
@@ -259,6 +494,13 @@ struct Cli {
     #[arg(long, value_name = "SIZE")]
     url_max_size: Option<String>,
 
+    /// Never access the network
+    ///
+    /// @https:// fetches fail, currency conversions use pinned and cached
+    /// rates only, and update checks are skipped. Also set by FORB_OFFLINE=1.
+    #[arg(long)]
+    offline: bool,
+
     /// Show config file path
     #[arg(long)]
     config_path: bool,
@@ -470,7 +712,21 @@ enum InputData {
 }
 
 /// Fetch content from a URL with timeout and size limits.
-fn fetch_url(url: &str, timeout_secs: u64, max_size: u64) -> Result<InputData, String> {
+///
+/// Fails without connecting when `offline` is set.
+fn fetch_url(
+    url: &str,
+    timeout_secs: u64,
+    max_size: u64,
+    offline: bool,
+) -> Result<InputData, String> {
+    if offline {
+        return Err(format!(
+            "Not fetching '{}': network access is disabled (--offline / FORB_OFFLINE)",
+            url
+        ));
+    }
+
     let response = ureq::get(url)
         .timeout(std::time::Duration::from_secs(timeout_secs))
         .call()
@@ -537,8 +793,17 @@ fn fetch_url(url: &str, timeout_secs: u64, max_size: u64) -> Result<InputData, S
 
 /// Read input, handling @path syntax for file reading and URL fetching.
 ///
-/// For URL fetching, uses the provided timeout and size limits.
-fn read_input(input: &str, url_timeout: u64, url_max_size: u64) -> Result<InputData, String> {
+/// For URL fetching, uses the provided timeout and size limits, and fails
+/// immediately in offline mode.
+/// If an @path doesn't exist, the text after `@` is used as literal input
+/// (with a warning) unless `strict_at` is set.
+fn read_input(
+    input: &str,
+    url_timeout: u64,
+    url_max_size: u64,
+    strict_at: bool,
+    offline: bool,
+) -> Result<InputData, String> {
     if !input.starts_with('@') {
         return Ok(InputData::Text(input.to_string()));
     }
@@ -547,7 +812,7 @@ fn read_input(input: &str, url_timeout: u64, url_max_size: u64) -> Result<InputD
 
     // Handle URLs (http:// or https://)
     if path.starts_with("http://") || path.starts_with("https://") {
-        return fetch_url(path, url_timeout, url_max_size);
+        return fetch_url(path, url_timeout, url_max_size, offline);
     }
 
     // Handle @- for stdin
@@ -575,7 +840,17 @@ fn read_input(input: &str, url_timeout: u64, url_max_size: u64) -> Result<InputD
     // Check if file exists
     let file_path = Path::new(path);
     if !file_path.exists() {
-        return Err(format!("File not found: {}", path));
+        if strict_at {
+            return Err(format!("File not found: {}", path));
+        }
+        // Likely meant literally (e.g., @1.2.3.4) - interpret the text after @
+        eprintln!(
+            "{}: File not found: {}, interpreting '{}' as text (use --strict-at to fail instead)",
+            "warning".yellow().bold(),
+            path,
+            path
+        );
+        return Ok(InputData::Text(path.to_string()));
     }
 
     // Read file contents
@@ -710,14 +985,24 @@ fn main() {
         return;
     }
 
+    // Offline mode: CLI flag or FORB_OFFLINE env
+    let offline = cli.offline || Config::offline_env();
+
     // Handle --analytics subcommand (early, before config loading for disable)
     if let Some(ref cmd) = cli.analytics {
-        handle_analytics_command(cmd);
+        handle_analytics_command(cmd, offline);
         return;
     }
 
     // Handle --check-updates (explicit update check)
     if cli.check_updates {
+        if offline {
+            eprintln!(
+                "{}: update check needs network access, which --offline disables",
+                "error".red().bold()
+            );
+            std::process::exit(1);
+        }
         handle_check_updates();
         return;
     }
@@ -944,6 +1229,8 @@ fn main() {
     let forb = {
         let mut conv_config = file_config.conversion_config().unwrap_or_default();
 
+        conv_config.offline = offline;
+
         // Apply CLI override for reinterpret threshold
         if let Some(threshold) = cli.reinterpret_threshold {
             conv_config.reinterpret_threshold = threshold;
@@ -952,6 +1239,39 @@ fn main() {
             }
         }
 
+        if let Some(ref tz) = cli.tz {
+            if DateTimeFormat::parse_timezone(tz).is_none() {
+                eprintln!(
+                    "{}: Unknown timezone '{}'. Use an IANA name like Europe/Stockholm.",
+                    "error".red().bold(),
+                    tz.yellow()
+                );
+                std::process::exit(1);
+            }
+            conv_config.display_timezone = Some(tz.clone());
+        }
+
+        conv_config.output_radix = cli.base;
+
+        match cli
+            .color_alpha_order
+            .as_deref()
+            .map(str::to_lowercase)
+            .as_deref()
+        {
+            None => {}
+            Some("rgba-first") => conv_config.color_alpha_order = ColorAlphaOrder::RgbaFirst,
+            Some("argb-first") => conv_config.color_alpha_order = ColorAlphaOrder::ArgbFirst,
+            Some(other) => {
+                eprintln!(
+                    "{}: Unknown color alpha order '{}'. Use 'rgba-first' or 'argb-first'.",
+                    "error".red().bold(),
+                    other.yellow()
+                );
+                std::process::exit(1);
+            }
+        }
+
         #[cfg(feature = "plugins")]
         let base = {
             if file_config.plugins_enabled() {
@@ -990,7 +1310,7 @@ fn main() {
 
         if conv_config.is_customized() || cli.reinterpret_threshold.is_some() {
             if cli.verbose > 0 && conv_config.is_customized() {
-                tracing::debug!("Using custom priority/blocking config from config file");
+                tracing::debug!("Using custom priority/blocking/rates config from config file");
             }
             base.set_config(conv_config)
         } else {
@@ -1066,9 +1386,100 @@ fn main() {
         return;
     }
 
+    // Handle --no-color flag
+    if no_color {
+        set_override(false);
+    }
+
+    // Build pretty config
+    let pretty_config = PrettyConfig {
+        color: !no_color,
+        indent: "  ",
+        compact: cli.compact,
+        packet_mode,
+        show_paths: cli.show_paths,
+        verbose: cli.verbose > 0,
+    };
+
+    // Format filter from --only (also used by --watch)
+    let format_filter = cli.only.clone().unwrap_or_default();
+
+    // Validate format names early
+    for name in [&cli.from, &cli.prefer].into_iter().flatten() {
+        if !forb.is_valid_format(name) {
+            eprintln!(
+                "{}: Unknown format '{}'. Use {} to see available formats.",
+                "error".red().bold(),
+                name.yellow(),
+                "--formats".bold()
+            );
+            std::process::exit(1);
+        }
+    }
+    match forb.validate_format_filter(&format_filter) {
+        Ok(()) => {}
+        Err(FormatFilterError::UnknownFormat(name)) => {
+            eprintln!(
+                "{}: Unknown format '{}'. Use {} to see available formats.",
+                "error".red().bold(),
+                name.yellow(),
+                "--formats".bold()
+            );
+            std::process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{}: {}", "error".red().bold(), e);
+            std::process::exit(1);
+        }
+    }
+
+    // Parse --template before converting anything, so a bad one is reported
+    // even when the input has no interpretations
+    let template = cli.template.as_deref().map(|template| {
+        parse_template(template).unwrap_or_else(|e| {
+            eprintln!("{}: --template: {}", "error".red().bold(), e);
+            std::process::exit(1);
+        })
+    });
+
+    // Handle --watch (evaluate each clipboard change like a single input)
+    #[cfg(feature = "clipboard")]
+    if cli.watch {
+        run_watch(
+            &forb,
+            &cli,
+            template.as_deref(),
+            &mut tracker,
+            &format_filter,
+            limit,
+            &pretty_config,
+        );
+        return;
+    }
+
     // Handle piped input (not tee mode) - read and process as single input
     // We'll set raw_input based on what we read, then let the normal flow handle it
-    let (raw_input, piped_binary_data) = if stdin_is_pipe && cli.input.is_none() {
+    let (raw_input, piped_binary_data) = if let Some(ref var) = cli.input_env {
+        match std::env::var(var) {
+            Ok(value) if !value.trim().is_empty() => (value.trim().to_string(), None),
+            Ok(_) => {
+                eprintln!(
+                    "{}: Environment variable '{}' is empty",
+                    "error".red().bold(),
+                    var
+                );
+                std::process::exit(1);
+            }
+            Err(_) => {
+                eprintln!(
+                    "{}: Environment variable '{}' is not set",
+                    "error".red().bold(),
+                    var
+                );
+                std::process::exit(1);
+            }
+        }
+    } else if stdin_is_pipe && cli.input.is_none() {
         let mut buffer = Vec::new();
         if let Err(e) = io::stdin().read_to_end(&mut buffer) {
             eprintln!("{}: Failed to read stdin: {}", "error".red().bold(), e);
@@ -1077,6 +1488,9 @@ fn main() {
 
         // Detect if binary or text
         let is_binary = buffer.contains(&0) || std::str::from_utf8(&buffer).is_err();
+        // Bytes in another encoding are decoded (and trimmed) below
+        #[cfg(feature = "encodings")]
+        let is_binary = is_binary || cli.input_encoding.is_some();
 
         if is_binary {
             // Binary data - will be processed via convert_bytes
@@ -1091,8 +1505,8 @@ fn main() {
             }
             (trimmed, None)
         }
-    } else if let Some(input) = cli.input {
-        (input, None)
+    } else if let Some(ref input) = cli.input {
+        (input.clone(), None)
     } else {
         // No input provided
         eprintln!("{}: No input provided", "error".red().bold());
@@ -1129,21 +1543,42 @@ fn main() {
 
     // Process input (handle @path syntax for file reading, or use piped binary)
     // Track file/URL input for analytics
-    if raw_input.starts_with("@http://") || raw_input.starts_with("@https://") {
+    // (--input-env values are always literal)
+    let expand_input = cli.input_env.is_none();
+    if expand_input && (raw_input.starts_with("@http://") || raw_input.starts_with("@https://")) {
         tracker.record_url_fetch();
-    } else if raw_input.starts_with('@') {
+    } else if expand_input && raw_input.starts_with('@') {
         tracker.record_file_input();
     }
 
+    // Only bytes read from stdin or a file can be in another encoding, and a
+    // missing @file must not fall back to decoding the literal path
+    #[cfg(feature = "encodings")]
+    let strict_at = if cli.input_encoding.is_some() {
+        if piped_binary_data.is_none() && !(expand_input && raw_input.starts_with('@')) {
+            eprintln!(
+                "{}: --input-encoding only applies to piped or @file input",
+                "error".red().bold()
+            );
+            std::process::exit(1);
+        }
+        true
+    } else {
+        cli.strict_at
+    };
+    #[cfg(not(feature = "encodings"))]
+    let strict_at = cli.strict_at;
+
     let (input, binary_data, file_path) = if let Some(data) = piped_binary_data {
         // Piped binary data - already read
         (String::new(), Some(data), Some("(stdin)".to_string()))
-    } else if raw_input.starts_with('@')
-        || raw_input.starts_with("http://")
-        || raw_input.starts_with("https://")
+    } else if expand_input
+        && (raw_input.starts_with('@')
+            || raw_input.starts_with("http://")
+            || raw_input.starts_with("https://"))
     {
         // File or URL input - use read_input
-        match read_input(&raw_input, url_timeout, url_max_size) {
+        match read_input(&raw_input, url_timeout, url_max_size, strict_at, offline) {
             Ok(InputData::Text(text)) => (text, None, None),
             Ok(InputData::Binary { data, path }) => (String::new(), Some(data), Some(path)),
             Err(e) => {
@@ -1156,74 +1591,108 @@ fn main() {
         (raw_input.clone(), None, None)
     };
 
-    // Handle --no-color flag
-    if no_color {
-        set_override(false);
-    }
-
-    // Build pretty config
-    let pretty_config = PrettyConfig {
-        color: !no_color,
-        indent: "  ",
-        compact: cli.compact,
-        packet_mode,
-        show_paths: cli.show_paths,
-        verbose: cli.verbose > 0,
+    // Decode the stdin or file bytes given in another text encoding (text
+    // read from a file is still its raw bytes)
+    #[cfg(feature = "encodings")]
+    let (input, binary_data) = match cli.input_encoding {
+        Some(ref label) => {
+            let bytes = binary_data.unwrap_or_else(|| input.into_bytes());
+            match encoding::decode_input(&bytes, label) {
+                Ok(text) if !text.trim().is_empty() => (text.trim().to_string(), None),
+                Ok(_) => {
+                    eprintln!("{}: Empty input", "error".red().bold());
+                    std::process::exit(1);
+                }
+                Err(e) => {
+                    eprintln!("{}: --input-encoding: {}", "error".red().bold(), e);
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => (input, binary_data),
     };
 
-    // Get results - either forced format or auto-detect
-    let format_filter = cli.only.unwrap_or_default();
-
-    // Validate format names early
-    if let Some(ref from_format) = cli.from {
-        if !forb.is_valid_format(from_format) {
+    // Narrow JSON input to the field selected by --path
+    let input = match cli.path {
+        Some(_) if binary_data.is_some() => {
             eprintln!(
-                "{}: Unknown format '{}'. Use {} to see available formats.",
-                "error".red().bold(),
-                from_format.yellow(),
-                "--formats".bold()
+                "{}: --path needs JSON text input, got binary data",
+                "error".red().bold()
             );
             std::process::exit(1);
         }
-    }
-    for name in &format_filter {
-        if !forb.is_valid_format(name) {
+        Some(ref pointer) => match select_json_pointer(&forb, &input, pointer) {
+            Ok(selected) => selected,
+            Err(e) => {
+                eprintln!("{}: {}", "error".red().bold(), e);
+                std::process::exit(1);
+            }
+        },
+        None => input,
+    };
+
+    // Handle --unwrap (encoding chain explainer)
+    if cli.unwrap {
+        if binary_data.is_some() {
             eprintln!(
-                "{}: Unknown format '{}'. Use {} to see available formats.",
-                "error".red().bold(),
-                name.yellow(),
-                "--formats".bold()
+                "{}: --unwrap needs text input (e.g., a base64 string)",
+                "error".red().bold()
             );
             std::process::exit(1);
         }
+        match forb.unwrap_layers(&input) {
+            Some(result) if cli.json => {
+                println!("{}", serde_json::to_string_pretty(&result).unwrap());
+            }
+            Some(result) => print_unwrap_result(&result, &pretty_config),
+            None => {
+                println!("No encoding layers found for: {input}");
+            }
+        }
+        return;
     }
 
-    let results = if let Some(ref data) = binary_data {
-        // Binary data - use convert_bytes
-        if let Some(ref from_format) = cli.from {
-            forb.convert_bytes_filtered(data, std::slice::from_ref(from_format))
+    // Handle --explain (per-format match diagnostics)
+    if cli.explain {
+        if binary_data.is_some() {
+            eprintln!("{}: --explain needs text input", "error".red().bold());
+            std::process::exit(1);
+        }
+        let explanations = forb.explain(&input);
+        if cli.json {
+            println!("{}", serde_json::to_string_pretty(&explanations).unwrap());
         } else {
-            forb.convert_bytes_filtered(data, &format_filter)
+            print_explanations(&explanations);
         }
-    } else if let Some(ref from_format) = cli.from {
-        // Force specific format interpretation
-        forb.convert_all_filtered(&input, std::slice::from_ref(from_format))
-    } else {
-        forb.convert_all_filtered(&input, &format_filter)
-    };
+        return;
+    }
 
-    // Track format usage, conversion targets, and paths for analytics
-    for result in &results {
-        tracker.record_format_usage(&result.interpretation.source_format);
-        for conv in &result.conversions {
-            tracker.record_conversion_target(&conv.target_format);
-            // Track full conversion paths (e.g., ["hex", "int-be", "epoch-seconds"])
-            if conv.path.len() >= 2 {
-                tracker.record_conversion_path(&conv.path);
-            }
+    if cli.batch {
+        if binary_data.is_some() {
+            eprintln!("{}: --batch needs text input", "error".red().bold());
+            std::process::exit(1);
         }
+        run_batch(
+            &forb,
+            &cli,
+            &mut tracker,
+            &input,
+            &format_filter,
+            limit,
+            &pretty_config,
+        );
+        return;
     }
 
+    let results = collect_results(
+        &forb,
+        &cli,
+        &mut tracker,
+        &input,
+        binary_data.as_deref(),
+        &format_filter,
+    );
+
     if results.is_empty() {
         if cli.raw {
             // Silent failure for raw mode
@@ -1233,6 +1702,8 @@ fn main() {
             path.as_str()
         } else if binary_data.is_some() {
             "(binary data)"
+        } else if cli.redact {
+            REDACTED
         } else {
             input.as_str()
         };
@@ -1250,13 +1721,13 @@ fn main() {
                     );
                     std::process::exit(1);
                 }
-            } else if format_filter.len() == 1 {
-                // Single format in --only filter
-                if let Some(error) = forb.validate(&input, &format_filter[0]) {
+            } else if let [name] = split_format_filter(&format_filter).0[..] {
+                // Single included format in --only filter
+                if let Some(error) = forb.validate(&input, name) {
                     eprintln!(
                         "{}: Cannot parse as {}: {}",
                         "error".red().bold(),
-                        format_filter[0].yellow(),
+                        name.yellow(),
                         error
                     );
                     std::process::exit(1);
@@ -1272,41 +1743,61 @@ fn main() {
         return;
     }
 
-    // Filter to show only high-confidence interpretations (skip utf8 fallback for hex-like input)
-    let meaningful_results: Vec<_> = results
-        .iter()
-        .filter(|r| r.interpretation.confidence > 0.2)
-        .collect();
-
-    let results_to_show: Vec<_> = if meaningful_results.is_empty() {
-        results.iter().collect()
-    } else {
-        meaningful_results
-    };
-
-    // Apply --first flag
-    let results_to_show: Vec<_> = if cli.first {
-        results_to_show.into_iter().take(1).collect()
-    } else {
-        results_to_show
-    };
+    let results_to_show = select_results(&results, cli.first);
 
     // For graph display, use file path if binary, otherwise input text
     let graph_label = if let Some(ref path) = file_path {
         path.clone()
+    } else if cli.redact {
+        REDACTED.to_string()
     } else {
         input.clone()
     };
 
+    print_results(
+        &forb,
+        &cli,
+        template.as_deref(),
+        &graph_label,
+        results_to_show,
+        limit,
+        &pretty_config,
+    );
+
+    // Background update check (after output, to stderr)
+    // Skip for JSON output, raw output, pipe mode, offline, or when updates are disabled
+    if file_config.updates_enabled() && !offline && !cli.json && !cli.raw {
+        if let Some(new_version) = check_for_updates_background() {
+            use updates::{InstallMethod, VERSION};
+            let hint = InstallMethod::detect().upgrade_hint();
+            eprintln!(
+                "Update available: v{} → v{} ({})",
+                VERSION, new_version, hint
+            );
+        }
+    }
+}
+
+/// Print the selected results in the output format chosen on the command line
+/// (--dot, --mermaid, --json, --csv, --template, --raw or human-readable).
+fn print_results(
+    forb: &Formatorbit,
+    cli: &Cli,
+    template: Option<&[TemplatePart]>,
+    label: &str,
+    results_to_show: Vec<&ConversionResult>,
+    limit: usize,
+    pretty_config: &PrettyConfig,
+) {
     // Handle --dot output
     if cli.dot {
-        print_dot_graph(&graph_label, &results_to_show);
+        print_dot_graph(forb, label, &results_to_show);
         return;
     }
 
     // Handle --mermaid output
     if cli.mermaid {
-        print_mermaid_graph(&graph_label, &results_to_show);
+        print_mermaid_graph(label, &results_to_show);
         return;
     }
 
@@ -1317,30 +1808,384 @@ fn main() {
         return;
     }
 
-    // Handle --raw output
-    if cli.raw {
-        for result in &results_to_show {
-            // Print conversion values only
-            let conversions_to_show: Vec<_> = if limit == 0 {
-                result.conversions.iter().collect()
-            } else {
-                result.conversions.iter().take(limit).collect()
-            };
+    // Handle --csv output
+    if cli.csv {
+        print_csv(label, &results_to_show, limit);
+        return;
+    }
+
+    // Handle --template output
+    if let Some(template) = template {
+        print_template(template, label, &results_to_show, limit);
+        return;
+    }
+
+    // Handle --raw output
+    if cli.raw {
+        print_raw_results(&results_to_show, limit, pretty_config);
+        return;
+    }
+
+    // Standard human-readable output
+    print_human_results(results_to_show, cli, limit, pretty_config);
+}
+
+/// Interpret each line of `input` independently (--batch).
+///
+/// Human output groups each line's results under a header; --json prints an
+/// array holding one results array per line.
+fn run_batch(
+    forb: &Formatorbit,
+    cli: &Cli,
+    tracker: &mut analytics::AnalyticsTracker,
+    input: &str,
+    format_filter: &[String],
+    limit: usize,
+    pretty_config: &PrettyConfig,
+) {
+    let lines = input.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    if cli.json {
+        let output: Vec<Vec<ConversionResult>> = lines
+            .map(|line| {
+                let results = collect_results(forb, cli, tracker, line, None, format_filter);
+                select_results(&results, cli.first)
+                    .into_iter()
+                    .cloned()
+                    .collect()
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&output).unwrap());
+        return;
+    }
+
+    for (index, line) in lines.enumerate() {
+        let results = collect_results(forb, cli, tracker, line, None, format_filter);
+        let results_to_show = select_results(&results, cli.first);
+
+        if cli.raw {
+            print_raw_results(&results_to_show, limit, pretty_config);
+            continue;
+        }
+
+        let label = if cli.redact { REDACTED } else { line };
+        println!(
+            "{} {} {}",
+            "──".dimmed(),
+            format!("line {}:", index + 1).dimmed(),
+            label.bold()
+        );
+        if results_to_show.is_empty() {
+            println!("  {}", "(no interpretations found)".dimmed());
+            println!();
+        } else {
+            print_human_results(results_to_show, cli, limit, pretty_config);
+        }
+    }
+}
+
+/// Interpret each new clipboard value like a single input (--watch).
+#[cfg(feature = "clipboard")]
+fn run_watch(
+    forb: &Formatorbit,
+    cli: &Cli,
+    template: Option<&[TemplatePart]>,
+    tracker: &mut analytics::AnalyticsTracker,
+    format_filter: &[String],
+    limit: usize,
+    pretty_config: &PrettyConfig,
+) {
+    let watch_config = watch::WatchModeConfig {
+        interval: Duration::from_millis(cli.watch_interval),
+        append: cli.watch_append,
+    };
+
+    let result = watch::run_watch_mode(&watch_config, |input| {
+        let results = collect_results(forb, cli, tracker, input, None, format_filter);
+        let label = if cli.redact { REDACTED } else { input };
+        if results.is_empty() {
+            if !cli.raw {
+                println!("No interpretations found for: {label}");
+            }
+            return;
+        }
+        let results_to_show = select_results(&results, cli.first);
+        print_results(
+            forb,
+            cli,
+            template,
+            label,
+            results_to_show,
+            limit,
+            pretty_config,
+        );
+    });
+
+    if let Err(e) = result {
+        eprintln!(
+            "{}: Failed to watch the clipboard: {}",
+            "error".red().bold(),
+            e
+        );
+        std::process::exit(1);
+    }
+}
 
-            for conv in conversions_to_show {
-                let display = format_conversion_display(
-                    &conv.value,
-                    &conv.display,
-                    &conv.rich_display,
-                    &pretty_config,
+/// Interpret the input and apply the result filters (--include-source,
+/// --primary, --kinds, --limit-per-kind, --sort, --redact).
+fn collect_results(
+    forb: &Formatorbit,
+    cli: &Cli,
+    tracker: &mut analytics::AnalyticsTracker,
+    input: &str,
+    binary_data: Option<&[u8]>,
+    format_filter: &[String],
+) -> Vec<ConversionResult> {
+    let timeout = cli.timeout.map(Duration::from_millis);
+    let mut results = with_timeout(timeout, || {
+        if let Some(data) = binary_data {
+            // Binary data - use convert_bytes
+            if let Some(ref from_format) = cli.from {
+                forb.convert_bytes_filtered(data, std::slice::from_ref(from_format))
+            } else {
+                forb.convert_bytes_filtered(data, format_filter)
+            }
+        } else if let Some(ref from_format) = cli.from {
+            // Force specific format interpretation
+            forb.convert_all_filtered(input, std::slice::from_ref(from_format))
+        } else if format_filter.is_empty() {
+            // Skip the conversion search for noise, unless nothing else matched
+            let results = forb.convert_all_above(input, MIN_INTERPRETATION_CONFIDENCE);
+            if results.is_empty() {
+                forb.convert_all(input)
+            } else {
+                results
+            }
+        } else {
+            forb.convert_all_filtered(input, format_filter)
+        }
+    });
+
+    // Track format usage, conversion targets, and paths for analytics
+    for result in &results {
+        tracker.record_format_usage(&result.interpretation.source_format);
+        for conv in &result.conversions {
+            tracker.record_conversion_target(&conv.target_format);
+            // Track full conversion paths (e.g., ["hex", "int-be", "epoch-seconds"])
+            if conv.path.len() >= 2 {
+                tracker.record_conversion_path(&conv.path);
+            }
+        }
+    }
+
+    // Apply --prefer (stable, so the other results keep their order)
+    if let Some(info) = cli
+        .prefer
+        .as_deref()
+        .and_then(|name| forb.format_info(name))
+    {
+        let is_preferred = |source: &str| {
+            // Formats may name interpretations "<id>-<variant>" (epoch-seconds)
+            source == info.id
+                || source
+                    .strip_prefix(info.id)
+                    .is_some_and(|rest| rest.starts_with('-'))
+        };
+        let (mut preferred_results, others): (Vec<_>, Vec<_>) = results
+            .into_iter()
+            .partition(|r| is_preferred(&r.interpretation.source_format));
+        preferred_results.extend(others);
+        results = preferred_results;
+    }
+
+    // Apply --include-source (prepend the normalized self-representation)
+    if cli.include_source {
+        for result in &mut results {
+            if let Some(source) = forb.source_representation(&result.interpretation) {
+                result.conversions.retain(|c| {
+                    c.target_format != source.target_format || c.display != source.display
+                });
+                result.conversions.insert(0, source);
+            }
+        }
+    }
+
+    // Apply --primary filter (after tracking, so analytics see the full result)
+    if cli.primary {
+        for result in &mut results {
+            result
+                .conversions
+                .retain(|c| c.priority == ConversionPriority::Primary);
+        }
+    }
+
+    // Apply --kinds / --no-traits / --no-representations
+    let mut kinds = match cli.kinds.as_deref() {
+        Some(names) => match parse_conversion_kinds(names) {
+            Ok(kinds) => kinds,
+            Err(name) => {
+                eprintln!(
+                    "{}: Unknown conversion kind '{}'. Expected conversion, representation or trait.",
+                    "error".red().bold(),
+                    name.yellow()
                 );
-                println!("{}", display);
+                std::process::exit(1);
             }
+        },
+        None => vec![
+            ConversionKind::Conversion,
+            ConversionKind::Representation,
+            ConversionKind::Trait,
+        ],
+    };
+    if cli.no_traits {
+        kinds.retain(|k| *k != ConversionKind::Trait);
+    }
+    if cli.no_representations {
+        kinds.retain(|k| *k != ConversionKind::Representation);
+    }
+    for result in &mut results {
+        result.conversions.retain(|c| kinds.contains(&c.kind));
+    }
+
+    // Apply --dedupe (before the limits, so they count distinct conversions)
+    if cli.dedupe {
+        dedupe_conversions(&mut results, cli.first);
+    }
+
+    // Apply --limit-per-kind (before --limit, which caps the total at display)
+    if let Some(per_kind) = cli.limit_per_kind {
+        for result in &mut results {
+            limit_per_kind(&mut result.conversions, per_kind);
         }
-        return;
     }
 
-    // Standard human-readable output
+    // Apply --sort (display-only; conversions arrive in priority order)
+    match cli.sort.as_deref().map(str::to_lowercase).as_deref() {
+        None | Some("priority") => {}
+        Some("name") => {
+            for result in &mut results {
+                result
+                    .conversions
+                    .sort_by(|a, b| a.target_format.cmp(&b.target_format));
+            }
+        }
+        Some("kind") => {
+            for result in &mut results {
+                result.conversions.sort_by_key(|c| kind_order(c.kind));
+            }
+        }
+        Some(other) => {
+            eprintln!(
+                "{}: Unknown sort order '{}'. Use 'priority', 'name' or 'kind'.",
+                "error".red().bold(),
+                other.yellow()
+            );
+            std::process::exit(1);
+        }
+    }
+
+    // Apply --redact (mask the input everywhere it would be echoed)
+    if cli.redact && binary_data.is_none() {
+        for result in &mut results {
+            redact_result(result, input);
+        }
+    }
+
+    results
+}
+
+/// Run `work` on a worker thread, exiting with an error if it takes longer
+/// than `timeout` (--timeout).
+///
+/// Best-effort: the worker can't be interrupted, so it keeps running until
+/// the process exits.
+fn with_timeout<T: Send>(timeout: Option<Duration>, work: impl FnOnce() -> T + Send) -> T {
+    let Some(timeout) = timeout else {
+        return work();
+    };
+
+    std::thread::scope(|scope| {
+        let (tx, rx) = mpsc::channel();
+        scope.spawn(move || {
+            let _ = tx.send(work());
+        });
+        match rx.recv_timeout(timeout) {
+            Ok(value) => value,
+            Err(RecvTimeoutError::Timeout) => {
+                eprintln!(
+                    "{}: Timed out after {} ms (see --timeout)",
+                    "error".red().bold(),
+                    timeout.as_millis()
+                );
+                // Exit without joining the worker
+                std::process::exit(1);
+            }
+            Err(RecvTimeoutError::Disconnected) => panic!("conversion worker panicked"),
+        }
+    })
+}
+
+/// Pick the interpretations to display, honoring --first.
+fn select_results(results: &[ConversionResult], first: bool) -> Vec<&ConversionResult> {
+    shown_indices(results, first)
+        .into_iter()
+        .map(|i| &results[i])
+        .collect()
+}
+
+/// Indices of the results `select_results` shows, in display order.
+fn shown_indices(results: &[ConversionResult], first: bool) -> Vec<usize> {
+    // Filter to show only high-confidence interpretations (skip utf8 fallback for hex-like input).
+    // Auto-detected input is already filtered; this covers --only, --from and binary input.
+    let meaningful: Vec<_> = (0..results.len())
+        .filter(|&i| results[i].interpretation.confidence > MIN_INTERPRETATION_CONFIDENCE)
+        .collect();
+
+    let indices = if meaningful.is_empty() {
+        (0..results.len()).collect()
+    } else {
+        meaningful
+    };
+
+    // Apply --first flag
+    if first {
+        indices.into_iter().take(1).collect()
+    } else {
+        indices
+    }
+}
+
+/// Print only the conversion values, one per line (--raw).
+fn print_raw_results(results: &[&ConversionResult], limit: usize, pretty_config: &PrettyConfig) {
+    for result in results {
+        // Print conversion values only
+        let conversions_to_show: Vec<_> = if limit == 0 {
+            result.conversions.iter().collect()
+        } else {
+            result.conversions.iter().take(limit).collect()
+        };
+
+        for conv in conversions_to_show {
+            let display = format_conversion_display(
+                &conv.value,
+                &conv.display,
+                &conv.rich_display,
+                pretty_config,
+            );
+            println!("{}", display);
+        }
+    }
+}
+
+/// Print interpretations and their conversions in the standard layout.
+fn print_human_results(
+    results_to_show: Vec<&ConversionResult>,
+    cli: &Cli,
+    limit: usize,
+    pretty_config: &PrettyConfig,
+) {
+    let mut previewed = false;
     for result in results_to_show {
         let conf = (result.interpretation.confidence * 100.0) as u32;
         println!(
@@ -1351,6 +2196,29 @@ fn main() {
         );
         println!("  {}", result.interpretation.description.dimmed());
 
+        // Verbose: show tabular details (e.g., epoch precision comparison)
+        if pretty_config.verbose {
+            for opt in &result.interpretation.rich_display {
+                if let RichDisplay::Table { headers, rows } = &opt.preferred {
+                    for line in pretty::pretty_table(headers, rows, pretty_config).lines() {
+                        println!("    {}", line);
+                    }
+                }
+            }
+        }
+
+        // Thumbnail for the first interpretation that decodes as an image
+        if cli.preview && !previewed {
+            if let CoreValue::Bytes(bytes) = &result.interpretation.value {
+                if let Some(thumb) = ImageFormat::thumbnail(bytes, PREVIEW_WIDTH, PREVIEW_HEIGHT) {
+                    for line in pretty::pretty_thumbnail(&thumb, pretty_config).lines() {
+                        println!("    {}", line);
+                    }
+                    previewed = true;
+                }
+            }
+        }
+
         if result.conversions.is_empty() {
             println!("  {}", "(no conversions available)".dimmed());
         } else {
@@ -1358,76 +2226,17 @@ fn main() {
             let displayable_conversions: Vec<_> =
                 result.conversions.iter().filter(|c| !c.hidden).collect();
 
-            // Hash format IDs - shown at the bottom
-            const HASH_FORMATS: &[&str] = &[
-                "crc32",
-                "md5",
-                "sha1",
-                "sha256",
-                "sha512",
-                "blake2b-256",
-                "blake3",
-            ];
-
-            // Partition into: traits, hashes, and primary conversions
-            let (traits, non_traits): (Vec<_>, Vec<_>) = displayable_conversions
-                .iter()
-                .partition(|c| c.kind == ConversionKind::Trait);
-
-            let (hashes, primary): (Vec<&&Conversion>, Vec<&&Conversion>) = non_traits
-                .into_iter()
-                .partition(|c: &&&Conversion| HASH_FORMATS.contains(&c.target_format.as_str()));
-
-            // Helper to display a single conversion
-            let display_conversion = |conv: &Conversion| {
-                let path_str = if conv.path.len() > 1 {
-                    format!(" (via {})", conv.path.join(" → "))
-                } else {
-                    String::new()
-                };
-
-                let block_path_str = if pretty_config.show_paths && !conv.path.is_empty() {
-                    format!(" {}", format!("[{}]", conv.path.join(":")).dimmed())
-                } else {
-                    String::new()
-                };
-
-                let display = format_conversion_display(
-                    &conv.value,
-                    &conv.display,
-                    &conv.rich_display,
-                    &pretty_config,
-                );
-
-                let kind_symbol = match conv.kind {
-                    ConversionKind::Conversion => "→".cyan(),
-                    ConversionKind::Representation => "≈".blue(),
-                    ConversionKind::Trait => "✓".magenta(),
-                };
+            if cli.group_by_kind {
+                print_grouped_conversions(&displayable_conversions, limit, pretty_config);
+                println!();
+                continue;
+            }
 
-                let display_lines: Vec<&str> = display.lines().collect();
-                if display_lines.len() > 1 {
-                    println!(
-                        "  {} {}:{}{}",
-                        kind_symbol,
-                        conv.target_format.yellow(),
-                        path_str.dimmed(),
-                        block_path_str
-                    );
-                    for line in display_lines {
-                        println!("    {}", line);
-                    }
-                } else {
-                    println!(
-                        "  {} {}: {}{}{}",
-                        kind_symbol,
-                        conv.target_format.yellow(),
-                        display,
-                        path_str.dimmed(),
-                        block_path_str
-                    );
-                }
-            };
+            // Partition into: traits and everything else
+            let (traits, non_traits): (Vec<&Conversion>, Vec<&Conversion>) =
+                displayable_conversions
+                    .iter()
+                    .partition(|c| c.kind == ConversionKind::Trait);
 
             // 1. Display traits first - grouped on one line unless verbose
             if !traits.is_empty() {
@@ -1459,50 +2268,14 @@ fn main() {
                 }
             }
 
-            // 2. Display primary conversions (non-traits, non-hashes)
-            let primary_to_show: Vec<_> = if limit == 0 {
-                primary
-            } else {
-                // Reserve some slots for hashes if limit is applied
-                let primary_limit = if limit > 3 { limit - 3 } else { limit };
-                primary.into_iter().take(primary_limit).collect()
-            };
-
-            for conv in &primary_to_show {
-                display_conversion(conv);
-            }
-
-            // 3. Display hashes last
-            let hashes_to_show: Vec<_> = if limit == 0 {
-                hashes
-            } else {
-                // Show remaining slots for hashes
-                let used = primary_to_show.len();
-                let remaining = limit.saturating_sub(used);
-                hashes.into_iter().take(remaining).collect()
-            };
-
-            for conv in &hashes_to_show {
-                display_conversion(conv);
+            // 2. Display primary conversions, then hashes
+            let shown = limit_hashes_last(&non_traits, limit);
+            for conv in &shown {
+                print_conversion(conv, "  ", pretty_config);
             }
 
             // Show how many more are hidden (use hidden field, not hardcoded format names)
-            let total_primary = result
-                .conversions
-                .iter()
-                .filter(|c| {
-                    !c.hidden
-                        && c.kind != ConversionKind::Trait
-                        && !HASH_FORMATS.contains(&c.target_format.as_str())
-                })
-                .count();
-            let total_hashes = result
-                .conversions
-                .iter()
-                .filter(|c| !c.hidden && HASH_FORMATS.contains(&c.target_format.as_str()))
-                .count();
-            let shown = primary_to_show.len() + hashes_to_show.len();
-            let hidden_count = (total_primary + total_hashes).saturating_sub(shown);
+            let hidden_count = non_traits.len() - shown.len();
             if hidden_count > 0 {
                 println!(
                     "  {} {}",
@@ -1513,21 +2286,279 @@ fn main() {
         }
         println!();
     }
+}
 
-    // Background update check (after output, to stderr)
-    // Skip for JSON output, raw output, pipe mode, or when updates are disabled
-    if file_config.updates_enabled() && !cli.json && !cli.raw {
-        if let Some(new_version) = check_for_updates_background() {
-            use updates::{InstallMethod, VERSION};
-            let hint = InstallMethod::detect().upgrade_hint();
-            eprintln!(
-                "Update available: v{} → v{} ({})",
-                VERSION, new_version, hint
-            );
+/// Print a single conversion line (multi-line displays are indented below the label).
+fn print_conversion(conv: &Conversion, indent: &str, config: &PrettyConfig) {
+    let path_str = if conv.path.len() > 1 {
+        format!(" (via {})", conv.path.join(" → "))
+    } else {
+        String::new()
+    };
+
+    let block_path_str = if config.show_paths && !conv.path.is_empty() {
+        format!(" {}", format!("[{}]", conv.path.join(":")).dimmed())
+    } else {
+        String::new()
+    };
+
+    let display = format_conversion_display(&conv.value, &conv.display, &conv.rich_display, config);
+
+    let kind_symbol = match conv.kind {
+        ConversionKind::Conversion => "→".cyan(),
+        ConversionKind::Representation => "≈".blue(),
+        ConversionKind::Trait => "✓".magenta(),
+    };
+
+    let display_lines: Vec<&str> = display.lines().collect();
+    if display_lines.len() > 1 {
+        println!(
+            "{}{} {}:{}{}",
+            indent,
+            kind_symbol,
+            conv.target_format.yellow(),
+            path_str.dimmed(),
+            block_path_str
+        );
+        for line in display_lines {
+            println!("{}  {}", indent, line);
+        }
+    } else {
+        println!(
+            "{}{} {}: {}{}{}",
+            indent,
+            kind_symbol,
+            conv.target_format.yellow(),
+            display,
+            path_str.dimmed(),
+            block_path_str
+        );
+    }
+}
+
+/// Print conversions sectioned by kind (`--group-by-kind`).
+///
+/// Sections appear in the order Conversions, Representations, Traits. The limit
+/// applies to conversions and representations combined (in priority order,
+/// hashes last); traits are always shown, as in the default view.
+fn print_grouped_conversions(conversions: &[&Conversion], limit: usize, config: &PrettyConfig) {
+    let (traits, others): (Vec<&Conversion>, Vec<&Conversion>) = conversions
+        .iter()
+        .partition(|c| c.kind == ConversionKind::Trait);
+
+    let shown = limit_hashes_last(&others, limit);
+
+    let sections = [
+        ("Conversions", ConversionKind::Conversion, shown.as_slice()),
+        (
+            "Representations",
+            ConversionKind::Representation,
+            shown.as_slice(),
+        ),
+        ("Traits", ConversionKind::Trait, traits.as_slice()),
+    ];
+
+    for (title, kind, candidates) in sections {
+        let in_section: Vec<_> = candidates.iter().filter(|c| c.kind == kind).collect();
+        if in_section.is_empty() {
+            continue;
+        }
+        println!("  {}", title.bold());
+        for conv in in_section {
+            print_conversion(conv, "    ", config);
+        }
+    }
+
+    let hidden_count = others.len() - shown.len();
+    if hidden_count > 0 {
+        println!(
+            "  {} {}",
+            "…".dimmed(),
+            format!("({} more, use -l 0 to show all)", hidden_count).dimmed()
+        );
+    }
+}
+
+/// Hash format IDs - shown at the bottom.
+const HASH_FORMATS: &[&str] = &[
+    "crc32",
+    "md5",
+    "sha1",
+    "sha256",
+    "sha512",
+    "blake2b-256",
+    "blake3",
+];
+
+/// Apply the display limit to non-trait conversions, moving hashes to the end.
+///
+/// With a limit, primary conversions get all but three slots and hashes fill
+/// whatever is left.
+fn limit_hashes_last<'a>(conversions: &[&'a Conversion], limit: usize) -> Vec<&'a Conversion> {
+    let (hashes, primary): (Vec<&Conversion>, Vec<&Conversion>) = conversions
+        .iter()
+        .partition(|c| HASH_FORMATS.contains(&c.target_format.as_str()));
+
+    if limit == 0 {
+        return primary.into_iter().chain(hashes).collect();
+    }
+
+    // Reserve some slots for hashes if limit is applied
+    let primary_limit = if limit > 3 { limit - 3 } else { limit };
+    let mut shown: Vec<_> = primary.into_iter().take(primary_limit).collect();
+    let remaining = limit.saturating_sub(shown.len());
+    shown.extend(hashes.into_iter().take(remaining));
+    shown
+}
+
+/// Interpretations at or below this confidence are hidden unless nothing else matched.
+const MIN_INTERPRETATION_CONFIDENCE: f32 = 0.2;
+
+/// Placeholder shown instead of the input in --redact mode.
+const REDACTED: &str = "[REDACTED]";
+
+/// Thumbnail bounds for --preview (in pixels; two pixel rows per line).
+const PREVIEW_WIDTH: u32 = 48;
+const PREVIEW_HEIGHT: u32 = 32;
+
+/// Mask the secret in a result's rendered strings (--redact).
+///
+/// Lossless conversions (hex, base64, a UUID as an integer, ...) can be
+/// turned back into the secret, so they're masked whole, as are the
+/// intermediate steps of every path. Lossy conversions and traits only have
+/// literal occurrences of the secret masked.
+fn redact_result(result: &mut ConversionResult, secret: &str) {
+    if secret.is_empty() {
+        return;
+    }
+    redact_str(&mut result.input, secret);
+    redact_str(&mut result.interpretation.description, secret);
+    redact_value(&mut result.interpretation.value, secret);
+    redact_rich_display(&mut result.interpretation.rich_display, secret);
+
+    for conv in &mut result.conversions {
+        if !conv.is_lossy && conv.kind != ConversionKind::Trait {
+            conv.display = REDACTED.to_string();
+            conv.value = CoreValue::String(REDACTED.to_string());
+            conv.rich_display.clear();
+        } else {
+            redact_str(&mut conv.display, secret);
+            redact_value(&mut conv.value, secret);
+            redact_rich_display(&mut conv.rich_display, secret);
+        }
+        let last = conv.steps.len().saturating_sub(1);
+        for (i, step) in conv.steps.iter_mut().enumerate() {
+            if i == last {
+                step.display.clone_from(&conv.display);
+                step.value = conv.value.clone();
+            } else {
+                step.display = REDACTED.to_string();
+                step.value = CoreValue::String(REDACTED.to_string());
+            }
         }
     }
 }
 
+fn redact_str(s: &mut String, secret: &str) {
+    if s.contains(secret) {
+        *s = s.replace(secret, REDACTED);
+    }
+}
+
+fn redact_value(value: &mut CoreValue, secret: &str) {
+    match value {
+        CoreValue::String(s) => redact_str(s, secret),
+        CoreValue::Json(json) => redact_json(json, secret),
+        _ => {}
+    }
+}
+
+fn redact_json(json: &mut serde_json::Value, secret: &str) {
+    match json {
+        serde_json::Value::String(s) => redact_str(s, secret),
+        serde_json::Value::Array(items) => items.iter_mut().for_each(|v| redact_json(v, secret)),
+        serde_json::Value::Object(map) => map.values_mut().for_each(|v| redact_json(v, secret)),
+        _ => {}
+    }
+}
+
+/// Rich displays have many shapes; redact them through their JSON form.
+fn redact_rich_display(options: &mut Vec<RichDisplayOption>, secret: &str) {
+    if options.is_empty() {
+        return;
+    }
+    let Ok(mut json) = serde_json::to_value(&*options) else {
+        return;
+    };
+    redact_json(&mut json, secret);
+    if let Ok(redacted) = serde_json::from_value(json) {
+        *options = redacted;
+    }
+}
+
+/// Print the chain and decoded value found by --unwrap.
+fn print_unwrap_result(result: &UnwrapResult, config: &PrettyConfig) {
+    println!("{} {}", "▶".blue(), result.chain().green().bold());
+
+    let display = match &result.value {
+        CoreValue::String(s) => s.clone(),
+        CoreValue::Bytes(bytes) => format!("{} bytes", bytes.len()),
+        other => format_conversion_display(other, "", &[], config),
+    };
+    for line in display.lines() {
+        println!("  {}", line);
+    }
+}
+
+/// Print the --explain table: matches by confidence, then rejections.
+fn print_explanations(explanations: &[FormatExplanation]) {
+    let mut sorted: Vec<&FormatExplanation> = explanations.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.confidence
+            .unwrap_or(-1.0)
+            .total_cmp(&a.confidence.unwrap_or(-1.0))
+    });
+
+    let width = sorted.iter().map(|e| e.format.len()).max().unwrap_or(0);
+    for explanation in sorted {
+        let status = match explanation.confidence {
+            Some(confidence) => format!("{:>3.0}%", confidence * 100.0).green(),
+            None => " -- ".dimmed(),
+        };
+        println!(
+            "{}  {}  {}",
+            format!("{:width$}", explanation.format).bold(),
+            status,
+            explanation.reason.as_deref().unwrap_or("").dimmed()
+        );
+    }
+}
+
+/// Select a value from JSON input with a JSON pointer (RFC 6901).
+///
+/// Returns the selected value as text to interpret: strings without quotes,
+/// everything else as compact JSON.
+fn select_json_pointer(forb: &Formatorbit, input: &str, pointer: &str) -> Result<String, String> {
+    let json = forb
+        .interpret_filtered(input, &["json".to_string()])
+        .into_iter()
+        .find_map(|interp| match interp.value {
+            CoreValue::Json(json) => Some(json),
+            _ => None,
+        })
+        .ok_or("--path needs JSON input")?;
+
+    if !pointer.is_empty() && !pointer.starts_with('/') {
+        return Err(format!("JSON pointer must start with '/': {}", pointer));
+    }
+
+    match json.pointer(pointer) {
+        Some(serde_json::Value::String(s)) => Ok(s.clone()),
+        Some(value) => Ok(value.to_string()),
+        None => Err(format!("JSON pointer {} does not match any value", pointer)),
+    }
+}
+
 /// Format a conversion's display string, applying pretty-printing for structured data.
 fn format_conversion_display(
     value: &CoreValue,
@@ -1557,6 +2588,7 @@ fn format_conversion_display(
             // Pretty-print protobuf with colors
             pretty::pretty_protobuf(fields, config)
         }
+        CoreValue::Xml(root) => pretty::pretty_xml(root, config),
         _ => {
             // For other types, use the original display
             original_display.to_string()
@@ -1564,8 +2596,282 @@ fn format_conversion_display(
     }
 }
 
+/// Parse `--kinds` names (singular or plural) into conversion kinds.
+///
+/// Returns the first unrecognized name as the error.
+fn parse_conversion_kinds(names: &[String]) -> Result<Vec<ConversionKind>, String> {
+    names
+        .iter()
+        .map(|name| match name.trim().to_lowercase().as_str() {
+            "conversion" | "conversions" => Ok(ConversionKind::Conversion),
+            "representation" | "representations" => Ok(ConversionKind::Representation),
+            "trait" | "traits" => Ok(ConversionKind::Trait),
+            _ => Err(name.clone()),
+        })
+        .collect()
+}
+
+/// Lowercase name of a conversion kind, as accepted by `--kinds`.
+fn conversion_kind_name(kind: ConversionKind) -> &'static str {
+    match kind {
+        ConversionKind::Conversion => "conversion",
+        ConversionKind::Representation => "representation",
+        ConversionKind::Trait => "trait",
+    }
+}
+
+/// Quote a CSV field (RFC 4180) if it contains a comma, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Print results as CSV, one row per visible conversion (honoring --limit).
+fn print_csv(input: &str, results: &[&formatorbit_core::ConversionResult], limit: usize) {
+    println!("input,source_format,confidence,target_format,kind,value");
+    for result in results {
+        let visible = result.conversions.iter().filter(|c| !c.hidden);
+        let conversions: Vec<_> = if limit == 0 {
+            visible.collect()
+        } else {
+            visible.take(limit).collect()
+        };
+        for conv in conversions {
+            let row = [
+                input,
+                &result.interpretation.source_format,
+                &format!("{:.2}", result.interpretation.confidence),
+                &conv.target_format,
+                conversion_kind_name(conv.kind),
+                &conv.display,
+            ];
+            let fields: Vec<_> = row.iter().map(|f| csv_field(f)).collect();
+            println!("{}", fields.join(","));
+        }
+    }
+}
+
+/// A placeholder available in --template.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TemplateField {
+    Input,
+    Confidence,
+    SourceFormat,
+    TargetFormat,
+    Kind,
+    Value,
+    Path,
+}
+
+impl TemplateField {
+    const ALL: [Self; 7] = [
+        Self::Input,
+        Self::Confidence,
+        Self::SourceFormat,
+        Self::TargetFormat,
+        Self::Kind,
+        Self::Value,
+        Self::Path,
+    ];
+
+    /// The name written between braces, e.g. `source_format`.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Input => "input",
+            Self::Confidence => "confidence",
+            Self::SourceFormat => "source_format",
+            Self::TargetFormat => "target_format",
+            Self::Kind => "kind",
+            Self::Value => "value",
+            Self::Path => "path",
+        }
+    }
+}
+
+/// A parsed --template: literal text and placeholders, in order.
+#[derive(Debug, PartialEq)]
+enum TemplatePart {
+    Literal(String),
+    Field(TemplateField),
+}
+
+/// Parse a --template string.
+///
+/// `{name}` is a [`TemplateField`] placeholder, `{{` and `}}` are
+/// literal braces, and `\t`, `\n` and `\\` are expanded.
+fn parse_template(template: &str) -> Result<Vec<TemplatePart>, String> {
+    let mut parts = Vec::new();
+    let mut literal = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(format!("unclosed placeholder '{{{name}'")),
+                    }
+                }
+                let Some(field) = TemplateField::ALL.into_iter().find(|f| f.name() == name) else {
+                    let names: Vec<_> = TemplateField::ALL.iter().map(|f| f.name()).collect();
+                    return Err(format!(
+                        "unknown placeholder '{{{name}}}' (expected one of {})",
+                        names.join(", ")
+                    ));
+                };
+                if !literal.is_empty() {
+                    parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(TemplatePart::Field(field));
+            }
+            '}' => return Err("unmatched '}' (write '}}' for a literal brace)".to_string()),
+            '\\' => match chars.peek() {
+                Some('t') => {
+                    chars.next();
+                    literal.push('\t');
+                }
+                Some('n') => {
+                    chars.next();
+                    literal.push('\n');
+                }
+                Some('\\') => {
+                    chars.next();
+                    literal.push('\\');
+                }
+                _ => literal.push('\\'),
+            },
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() {
+        parts.push(TemplatePart::Literal(literal));
+    }
+    Ok(parts)
+}
+
+/// Print one rendered template line per visible conversion (honoring --limit).
+fn print_template(
+    template: &[TemplatePart],
+    input: &str,
+    results: &[&ConversionResult],
+    limit: usize,
+) {
+    for result in results {
+        let visible = result.conversions.iter().filter(|c| !c.hidden);
+        let conversions: Vec<_> = if limit == 0 {
+            visible.collect()
+        } else {
+            visible.take(limit).collect()
+        };
+        for conv in conversions {
+            let mut line = String::new();
+            for part in template {
+                match part {
+                    TemplatePart::Literal(text) => line.push_str(text),
+                    TemplatePart::Field(field) => match field {
+                        TemplateField::Input => line.push_str(input),
+                        TemplateField::Confidence => {
+                            line.push_str(&format!("{:.2}", result.interpretation.confidence));
+                        }
+                        TemplateField::SourceFormat => {
+                            line.push_str(&result.interpretation.source_format);
+                        }
+                        TemplateField::TargetFormat => line.push_str(&conv.target_format),
+                        TemplateField::Kind => line.push_str(conversion_kind_name(conv.kind)),
+                        TemplateField::Value => line.push_str(&conv.display),
+                        TemplateField::Path => line.push_str(&conv.path.join(":")),
+                    },
+                }
+            }
+            println!("{line}");
+        }
+    }
+}
+
+/// Drop conversions whose target format and value appear more than once in the
+/// displayed results, keeping the copy with the shortest conversion path (the
+/// first in display order on a tie).
+///
+/// Only the results `select_results` shows take part, so an interpretation
+/// that isn't displayed can't take conversions away from one that is.
+fn dedupe_conversions(results: &mut [ConversionResult], first: bool) {
+    let shown = shown_indices(results, first);
+
+    // Copy to keep per target format and value: (path length, result, conversion)
+    let mut keep: HashMap<(String, String), (usize, usize, usize)> = HashMap::new();
+    for &i in &shown {
+        for (j, conv) in results[i].conversions.iter().enumerate() {
+            let candidate = (conv.path.len(), i, j);
+            keep.entry((conv.target_format.clone(), conv.display.clone()))
+                .and_modify(|best| {
+                    if candidate.0 < best.0 {
+                        *best = candidate;
+                    }
+                })
+                .or_insert(candidate);
+        }
+    }
+
+    for &i in &shown {
+        let mut j = 0;
+        results[i].conversions.retain(|conv| {
+            let key = (conv.target_format.clone(), conv.display.clone());
+            let kept = keep
+                .get(&key)
+                .is_some_and(|&(_, ki, kj)| (ki, kj) == (i, j));
+            j += 1;
+            kept
+        });
+    }
+}
+
+/// Keep at most `per_kind` visible conversions of each kind, in their current order.
+///
+/// Hidden conversions aren't displayed, so they don't count towards the cap.
+fn limit_per_kind(conversions: &mut Vec<Conversion>, per_kind: usize) {
+    let mut buckets = [0usize; 3];
+    conversions.retain(|c| {
+        if c.hidden {
+            return true;
+        }
+        let count = &mut buckets[kind_order(c.kind) as usize];
+        *count += 1;
+        *count <= per_kind
+    });
+}
+
+/// Position of a conversion kind in `--sort kind` output.
+fn kind_order(kind: ConversionKind) -> u8 {
+    match kind {
+        ConversionKind::Conversion => 0,
+        ConversionKind::Representation => 1,
+        ConversionKind::Trait => 2,
+    }
+}
+
 /// Output conversion graph in Graphviz DOT format.
-fn print_dot_graph(input: &str, results: &[&formatorbit_core::ConversionResult]) {
+///
+/// Edges to notations the interpreted value can be recovered from (see
+/// [`Formatorbit::reverse_interpret`]) are drawn in both directions.
+fn print_dot_graph(
+    forb: &Formatorbit,
+    input: &str,
+    results: &[&formatorbit_core::ConversionResult],
+) {
     println!("digraph conversions {{");
     println!("  rankdir=LR;");
     println!("  node [shape=box, fontname=\"Helvetica\"];");
@@ -1594,6 +2900,7 @@ fn print_dot_graph(input: &str, results: &[&formatorbit_core::ConversionResult])
             interp_node, interp_label
         );
         println!("  input -> {} [label=\"{}%\"];", interp_node, conf);
+        let reversible = forb.reverse_interpret(&interp.value);
 
         // Conversion nodes
         for conv in &result.conversions {
@@ -1613,15 +2920,18 @@ fn print_dot_graph(input: &str, results: &[&formatorbit_core::ConversionResult])
                 String::new()
             };
 
-            if edge_label.is_empty() {
+            let mut attrs = Vec::new();
+            if !edge_label.is_empty() {
+                attrs.push(format!("label=\"{}\"", escape_dot_label(&edge_label)));
+            }
+            if conv.path.len() <= 2 && reversible.contains(&conv.target_format) {
+                attrs.push("dir=both".to_string());
+            }
+
+            if attrs.is_empty() {
                 println!("  {} -> {};", interp_node, conv_node);
             } else {
-                println!(
-                    "  {} -> {} [label=\"{}\"];",
-                    interp_node,
-                    conv_node,
-                    escape_dot_label(&edge_label)
-                );
+                println!("  {} -> {} [{}];", interp_node, conv_node, attrs.join(", "));
             }
         }
         println!();
@@ -1705,7 +3015,7 @@ fn escape_mermaid_label(s: &str) -> String {
 }
 
 /// Handle analytics subcommand.
-fn handle_analytics_command(cmd: &str) {
+fn handle_analytics_command(cmd: &str, offline: bool) {
     use colored::Colorize;
 
     match cmd {
@@ -1766,6 +3076,13 @@ fn handle_analytics_command(cmd: &str) {
             println!("{}", payload.format_preview());
         }
         "contribute" => {
+            if offline {
+                eprintln!(
+                    "{}: contributing needs network access, which --offline disables",
+                    "error".red().bold()
+                );
+                std::process::exit(1);
+            }
             let data = analytics::AnalyticsData::load();
 
             if data.session_stats.total_invocations == 0 {
@@ -1945,7 +3262,6 @@ fn handle_graph_command(forb: &Formatorbit, mode: &str, use_dot: bool) {
 fn handle_plugins_command(cmd: &str) {
     use colored::Colorize;
     use formatorbit_core::plugin::{discovery, PluginRegistry, PythonRuntime};
-    use std::collections::HashMap;
 
     match cmd {
         "list" | "" => {
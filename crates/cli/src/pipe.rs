@@ -6,10 +6,11 @@
 use std::io::{self, BufRead, Write};
 
 use colored::Colorize;
+use formatorbit_core::tokenize::{tokenize, Token, TokenHint};
 use formatorbit_core::{ConversionResult, Formatorbit, RichDisplay};
 
+use crate::ansi::{original_end, original_start, strip_ansi, AnsiSpan};
 use crate::pretty::{self, PacketMode, PrettyConfig};
-use crate::tokenizer::{is_interesting_candidate, strip_ansi_codes, tokenize, Token};
 
 /// Configuration for pipe mode.
 pub struct PipeModeConfig {
@@ -34,6 +35,9 @@ struct AnnotatedToken {
 }
 
 /// Run pipe mode, processing stdin line by line.
+///
+/// Each line is flushed as soon as it's annotated, so live tails
+/// (`tail -f app.log | forb --tee`) show output as it arrives.
 pub fn run_pipe_mode(forb: &Formatorbit, config: &PipeModeConfig) -> io::Result<()> {
     let stdin = io::stdin();
     let stdout = io::stdout();
@@ -42,10 +46,12 @@ pub fn run_pipe_mode(forb: &Formatorbit, config: &PipeModeConfig) -> io::Result<
 
     for line_result in handle.lines() {
         let line = line_result?;
-        // Strip ANSI escape codes from colorized output (e.g., from log colorization scripts)
-        let clean_line = strip_ansi_codes(&line);
+        // Strip ANSI escape codes from colorized output (e.g., from log colorization scripts),
+        // keeping their positions so the line can be echoed with its colors intact
+        let (clean_line, spans) = strip_ansi(&line);
         let annotations = process_line(forb, &clean_line, config);
-        print_line_result(&mut out, &clean_line, &annotations, config)?;
+        print_line_result(&mut out, &line, &clean_line, &spans, &annotations, config)?;
+        out.flush()?;
     }
 
     Ok(())
@@ -53,19 +59,12 @@ pub fn run_pipe_mode(forb: &Formatorbit, config: &PipeModeConfig) -> io::Result<
 
 /// Process a single line and find interesting tokens.
 fn process_line(forb: &Formatorbit, line: &str, config: &PipeModeConfig) -> Vec<AnnotatedToken> {
-    let tokens = tokenize(line);
-
-    // Filter to interesting candidates and limit count
-    let candidates: Vec<_> = tokens
-        .into_iter()
-        .filter(|t| is_interesting_candidate(&t.text))
-        .take(config.max_tokens)
-        .collect();
+    let candidates = tokenize(line, config.max_tokens);
 
     let mut annotated = Vec::new();
 
     for token in candidates {
-        let results = forb.convert_all_filtered(&token.text, &config.format_filter);
+        let results = convert_token(forb, &token, config);
 
         // Find best interpretation above threshold
         let best = results
@@ -85,20 +84,43 @@ fn process_line(forb: &Formatorbit, line: &str, config: &PipeModeConfig) -> Vec<
     annotated
 }
 
+/// Interpret a token, using its hint when the format filter allows it.
+fn convert_token(
+    forb: &Formatorbit,
+    token: &Token,
+    config: &PipeModeConfig,
+) -> Vec<ConversionResult> {
+    match token.hint {
+        Some(TokenHint::FileSize)
+            if forb.format_filter_allows("datasize", &config.format_filter) =>
+        {
+            // A bare byte count only reads as a data size with its unit
+            forb.convert_all_filtered(&format!("{}B", token.text), &["datasize".to_string()])
+        }
+        _ => forb.convert_all_filtered(&token.text, &config.format_filter),
+    }
+}
+
 /// Print a line with its annotations.
+///
+/// `line` is the original input, `clean_line` the same line with ANSI
+/// codes removed (`spans` records where they were). Token positions refer
+/// to `clean_line`.
 fn print_line_result(
     out: &mut impl Write,
     line: &str,
+    clean_line: &str,
+    spans: &[AnsiSpan],
     annotations: &[AnnotatedToken],
     config: &PipeModeConfig,
 ) -> io::Result<()> {
     if config.json {
-        return print_json_line(out, line, annotations);
+        return print_json_line(out, clean_line, annotations);
     }
 
     // Print the original line (with optional highlighting)
     if config.highlight && !annotations.is_empty() {
-        print_highlighted_line(out, line, annotations)?;
+        print_highlighted_line(out, line, spans, annotations)?;
     } else {
         writeln!(out, "{}", line)?;
     }
@@ -112,9 +134,13 @@ fn print_line_result(
 }
 
 /// Print the line with matched tokens highlighted.
+///
+/// Token positions are mapped back into the original (possibly colored)
+/// line, and the line's own colors are restored after each highlight.
 fn print_highlighted_line(
     out: &mut impl Write,
     line: &str,
+    spans: &[AnsiSpan],
     annotations: &[AnnotatedToken],
 ) -> io::Result<()> {
     // Sort annotations by position
@@ -125,17 +151,19 @@ fn print_highlighted_line(
 
     for annotated in sorted {
         let token = &annotated.token;
+        let start = original_start(spans, token.start);
+        let end = original_end(spans, token.end);
 
         // Print text before this token
-        if token.start > last_end {
-            write!(out, "{}", &line[last_end..token.start])?;
+        if start > last_end {
+            write!(out, "{}", &line[last_end..start])?;
         }
 
-        // Print highlighted token
-        let highlighted = line[token.start..token.end].on_bright_yellow().black();
-        write!(out, "{}", highlighted)?;
+        // Print highlighted token (the highlight ends with a reset)
+        let highlighted = line[start..end].on_bright_yellow().black();
+        write!(out, "{}{}", highlighted, active_sgr(line, spans, end))?;
 
-        last_end = token.end;
+        last_end = end;
     }
 
     // Print remaining text
@@ -147,6 +175,28 @@ fn print_highlighted_line(
     Ok(())
 }
 
+/// SGR (color/style) sequences still in effect at `offset` in the original line.
+///
+/// Re-emitted after a highlight so its reset doesn't drop the line's colors.
+fn active_sgr(line: &str, spans: &[AnsiSpan], offset: usize) -> String {
+    let mut active = String::new();
+    for span in spans.iter().filter(|s| s.end <= offset) {
+        let seq = span.text(line);
+        let Some(params) = seq
+            .strip_prefix("\x1b[")
+            .and_then(|rest| rest.strip_suffix('m'))
+        else {
+            continue;
+        };
+        if params.is_empty() || params == "0" {
+            active.clear();
+        } else {
+            active.push_str(seq);
+        }
+    }
+    active
+}
+
 /// Print annotation for a token.
 fn print_annotation(
     out: &mut impl Write,
@@ -263,3 +313,137 @@ fn print_json_line(
     writeln!(out, "{}", serde_json::to_string(&output).unwrap())?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UUID: &str = "550e8400-e29b-41d4-a716-446655440000";
+
+    fn config(highlight: bool) -> PipeModeConfig {
+        PipeModeConfig {
+            threshold: 0.8,
+            highlight,
+            max_tokens: 50,
+            json: false,
+            format_filter: vec![],
+            packet_mode: PacketMode::None,
+        }
+    }
+
+    fn render(line: &str, config: &PipeModeConfig) -> (Vec<AnnotatedToken>, String) {
+        let forb = Formatorbit::new();
+        let (clean, spans) = strip_ansi(line);
+        let annotations = process_line(&forb, &clean, config);
+        let mut out = Vec::new();
+        print_line_result(&mut out, line, &clean, &spans, &annotations, config).unwrap();
+        (annotations, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_colored_uuid_detected_and_colors_kept() {
+        let line = format!("request \x1b[31m{}\x1b[0m failed", UUID);
+
+        for highlight in [false, true] {
+            let (annotations, output) = render(&line, &config(highlight));
+            assert!(
+                annotations.iter().any(
+                    |a| a.token.text == UUID && a.result.interpretation.source_format == "uuid"
+                ),
+                "UUID not detected"
+            );
+            let first_line = output.lines().next().unwrap();
+            assert!(
+                first_line.starts_with("request \x1b[31m"),
+                "{:?}",
+                first_line
+            );
+            assert!(first_line.contains(UUID), "{:?}", first_line);
+            assert!(first_line.contains("\x1b[0m failed"), "{:?}", first_line);
+        }
+    }
+
+    #[test]
+    fn test_ls_long_line_annotations() {
+        let line = "-rw-r--r--  1 user group 4096 Jan 15 10:30 file.txt";
+        let (annotations, _) = render(line, &config(false));
+        let formats: Vec<_> = annotations
+            .iter()
+            .map(|a| {
+                (
+                    a.token.text.as_str(),
+                    a.result.interpretation.source_format.as_str(),
+                )
+            })
+            .collect();
+
+        assert!(
+            formats.contains(&("-rw-r--r--", "permissions")),
+            "{:?}",
+            formats
+        );
+        assert!(formats.contains(&("4096", "datasize")), "{:?}", formats);
+        assert!(
+            formats.contains(&("Jan 15 10:30", "datetime")),
+            "{:?}",
+            formats
+        );
+    }
+
+    #[test]
+    fn test_file_size_hint_honors_filter_aliases() {
+        let line = "-rw-r--r--  1 user group 4096 Jan 15 10:30 file.txt";
+        let source_of_size = |filter: &[&str]| {
+            let config = PipeModeConfig {
+                format_filter: filter.iter().map(|f| (*f).to_string()).collect(),
+                ..config(false)
+            };
+            render(line, &config)
+                .0
+                .iter()
+                .find(|a| a.token.text == "4096")
+                .map(|a| a.result.interpretation.source_format.clone())
+        };
+
+        assert_eq!(source_of_size(&["size"]).as_deref(), Some("datasize"));
+        assert_ne!(source_of_size(&["!size"]).as_deref(), Some("datasize"));
+    }
+
+    #[test]
+    fn test_env_file_values_annotated() {
+        let env = "# Service settings\nexport CREATED_AT=\"1703456789\"\nLOG_LEVEL=debug";
+        let lines: Vec<_> = env
+            .lines()
+            .map(|line| render(line, &config(false)))
+            .collect();
+
+        assert!(lines[0].0.is_empty());
+        assert!(lines[2].0.is_empty());
+
+        let (annotations, output) = &lines[1];
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].token.text, "1703456789");
+        assert_eq!(
+            annotations[0].result.interpretation.source_format,
+            "epoch-seconds"
+        );
+        // The key stays visible, and the annotation points at the value
+        let mut output_lines = output.lines();
+        assert_eq!(
+            output_lines.next(),
+            Some("export CREATED_AT=\"1703456789\"")
+        );
+        assert_eq!(annotations[0].token.display_col, 19);
+        assert!(output_lines.next().unwrap().starts_with(&" ".repeat(19)));
+    }
+
+    #[test]
+    fn test_active_sgr_restores_color_after_token() {
+        // Red runs across the token and past it
+        let line = format!("\x1b[1m\x1b[31mid {} tail\x1b[0m", UUID);
+        let (_, spans) = strip_ansi(&line);
+        let after_token = line.find(" tail").unwrap();
+        assert_eq!(active_sgr(&line, &spans, after_token), "\x1b[1m\x1b[31m");
+        assert_eq!(active_sgr(&line, &spans, line.len()), "");
+    }
+}
@@ -9,7 +9,8 @@
 //! - Punctuation: white/default
 
 use colored::{Color, Colorize};
-use formatorbit_core::{truncate_str, PacketSegment, ProtoField, ProtoValue};
+use formatorbit_core::formats::Thumbnail;
+use formatorbit_core::{truncate_str, PacketSegment, ProtoField, ProtoValue, XmlElement, XmlNode};
 
 /// Packet layout display mode.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -387,6 +388,87 @@ fn decode_zigzag(n: u64) -> i64 {
     ((n >> 1) as i64) ^ (-((n & 1) as i64))
 }
 
+/// Pretty-print an XML element tree with colors.
+///
+/// Tags are blue, attribute names cyan, attribute values green and
+/// comments dimmed. Elements whose only content is text stay on one line.
+pub fn pretty_xml(root: &XmlElement, config: &PrettyConfig) -> String {
+    let mut output = String::new();
+    format_xml_element(root, config, 0, &mut output);
+    output
+}
+
+fn format_xml_element(
+    element: &XmlElement,
+    config: &PrettyConfig,
+    depth: usize,
+    output: &mut String,
+) {
+    output.push_str(&colorize(
+        &format!("<{}", element.name),
+        Color::Blue,
+        config.color,
+    ));
+    for (key, value) in &element.attributes {
+        output.push(' ');
+        output.push_str(&colorize(key, Color::Cyan, config.color));
+        output.push('=');
+        output.push_str(&colorize(
+            &format!("\"{}\"", escape_xml(value, true)),
+            Color::Green,
+            config.color,
+        ));
+    }
+
+    let close = colorize(&format!("</{}>", element.name), Color::Blue, config.color);
+    match element.children.as_slice() {
+        [] => output.push_str(&colorize("/>", Color::Blue, config.color)),
+        [XmlNode::Text(text)] => {
+            output.push_str(&colorize(">", Color::Blue, config.color));
+            output.push_str(&escape_xml(text, false));
+            output.push_str(&close);
+        }
+        children => {
+            output.push_str(&colorize(">", Color::Blue, config.color));
+            for child in children {
+                if !config.compact {
+                    output.push('\n');
+                    output.push_str(&config.indent.repeat(depth + 1));
+                }
+                match child {
+                    XmlNode::Element(e) => format_xml_element(e, config, depth + 1, output),
+                    XmlNode::Text(text) => output.push_str(&escape_xml(text, false)),
+                    XmlNode::Comment(comment) => output.push_str(&colorize(
+                        &format!("<!-- {} -->", comment),
+                        Color::BrightBlack,
+                        config.color,
+                    )),
+                }
+            }
+            if !config.compact {
+                output.push('\n');
+                output.push_str(&config.indent.repeat(depth));
+            }
+            output.push_str(&close);
+        }
+    }
+}
+
+/// Escape XML special characters (quotes only inside attribute values).
+fn escape_xml(s: &str, attribute: bool) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' if attribute => result.push_str("&quot;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
 /// Pretty-print packet layout in compact mode.
 pub fn pretty_packet_compact(segments: &[PacketSegment], config: &PrettyConfig) -> String {
     let mut output = String::new();
@@ -440,6 +522,81 @@ pub fn pretty_packet_detailed(segments: &[PacketSegment], config: &PrettyConfig)
     lines.join("\n")
 }
 
+/// Pretty-print a table with aligned columns and a dimmed header.
+pub fn pretty_table(headers: &[String], rows: &[Vec<String>], config: &PrettyConfig) -> String {
+    let columns = headers.len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|i| {
+            rows.iter()
+                .filter_map(|row| row.get(i))
+                .chain(std::iter::once(&headers[i]))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut lines = vec![colorize(
+        &format_row(headers),
+        Color::BrightBlack,
+        config.color,
+    )];
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    lines.join("\n")
+}
+
+/// Render an RGB thumbnail using half-block characters.
+///
+/// Each output character covers two pixel rows (foreground = top, background =
+/// bottom). Without color, falls back to an ASCII brightness ramp.
+pub fn pretty_thumbnail(thumb: &Thumbnail, config: &PrettyConfig) -> String {
+    const RAMP: &[u8] = b" .:-=+*#%@";
+    let (width, height) = (thumb.width as usize, thumb.height as usize);
+    let pixel = |x: usize, y: usize| thumb.pixels.get(y * width + x).copied();
+
+    let mut lines = Vec::with_capacity(height.div_ceil(2));
+    for y in (0..height).step_by(2) {
+        let mut line = String::new();
+        for x in 0..width {
+            let Some(top) = pixel(x, y) else { break };
+            let bottom = if y + 1 < height {
+                pixel(x, y + 1)
+            } else {
+                None
+            };
+
+            if config.color {
+                let (r, g, b) = top;
+                let cell = "▀".truecolor(r, g, b);
+                line.push_str(&match bottom {
+                    Some((r, g, b)) => cell.on_truecolor(r, g, b).to_string(),
+                    None => cell.to_string(),
+                });
+            } else {
+                let luma = |(r, g, b): (u8, u8, u8)| {
+                    (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000
+                };
+                let level = (luma(top) + luma(bottom.unwrap_or(top))) / 2;
+                let index = level as usize * (RAMP.len() - 1) / 255;
+                line.push(RAMP[index] as char);
+            }
+        }
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
 fn format_packet_detailed_recursive(
     segments: &[PacketSegment],
     config: &PrettyConfig,
@@ -503,6 +660,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_pretty_thumbnail_ascii() {
+        let config = no_color_config();
+        // 2x2: white column, black column
+        let thumb = Thumbnail {
+            width: 2,
+            height: 2,
+            pixels: vec![(255, 255, 255), (0, 0, 0), (255, 255, 255), (0, 0, 0)],
+        };
+        assert_eq!(pretty_thumbnail(&thumb, &config), "@ ");
+    }
+
     #[test]
     fn test_pretty_json_simple() {
         let config = no_color_config();
@@ -535,10 +704,52 @@ mod tests {
         assert!(!output.contains('\n'));
     }
 
+    #[test]
+    fn test_pretty_xml() {
+        let root = XmlElement {
+            name: "book".to_string(),
+            attributes: vec![("id".to_string(), "a&b".to_string())],
+            children: vec![
+                XmlNode::Element(XmlElement {
+                    name: "title".to_string(),
+                    attributes: vec![],
+                    children: vec![XmlNode::Text("Dune".to_string())],
+                }),
+                XmlNode::Comment("draft".to_string()),
+            ],
+        };
+        assert_eq!(
+            pretty_xml(&root, &no_color_config()),
+            "<book id=\"a&amp;b\">\n  <title>Dune</title>\n  <!-- draft -->\n</book>"
+        );
+        let compact = PrettyConfig {
+            color: false,
+            compact: true,
+            ..Default::default()
+        };
+        assert!(!pretty_xml(&root, &compact).contains('\n'));
+    }
+
     #[test]
     fn test_escape_json_string() {
         assert_eq!(escape_json_string("hello"), "hello");
         assert_eq!(escape_json_string("hello\nworld"), "hello\\nworld");
         assert_eq!(escape_json_string("say \"hi\""), "say \\\"hi\\\"");
     }
+
+    #[test]
+    fn test_pretty_table_aligns_columns() {
+        let config = no_color_config();
+        let headers = vec!["Unit".to_string(), "Date".to_string()];
+        let rows = vec![
+            vec!["milliseconds".to_string(), "2024-12-28".to_string()],
+            vec!["seconds".to_string(), "out of range".to_string()],
+        ];
+        let output = pretty_table(&headers, &rows, &config);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "Unit          Date");
+        assert_eq!(lines[1], "milliseconds  2024-12-28");
+        assert_eq!(lines[2], "seconds       out of range");
+    }
 }
@@ -0,0 +1,80 @@
+//! Watch mode for re-evaluating the clipboard whenever it changes.
+//!
+//! Polls the system clipboard and hands each new value to the caller, which
+//! interprets and prints it like a single input, so every output option
+//! (`--only`, `--limit`, `--json`, ...) behaves as usual.
+
+use std::io::{self, IsTerminal, Write};
+use std::thread;
+use std::time::Duration;
+
+use arboard::Clipboard;
+use colored::Colorize;
+use formatorbit_core::watch::watch_events;
+
+/// Configuration for watch mode.
+pub struct WatchModeConfig {
+    /// Time between clipboard polls
+    pub interval: Duration,
+    /// Append each update instead of clearing the screen
+    pub append: bool,
+}
+
+/// Read the clipboard as text, or `None` when it holds no text.
+fn read_clipboard(clipboard: &mut Clipboard) -> io::Result<Option<String>> {
+    match clipboard.get_text() {
+        Ok(text) => Ok(Some(text)),
+        Err(arboard::Error::ContentNotAvailable) => Ok(None),
+        Err(e) => Err(io::Error::other(e)),
+    }
+}
+
+/// Run watch mode until interrupted or the clipboard can't be read.
+///
+/// `evaluate` is called with each new clipboard value and prints its results.
+pub fn run_watch_mode(config: &WatchModeConfig, mut evaluate: impl FnMut(&str)) -> io::Result<()> {
+    let mut clipboard = Clipboard::new().map_err(io::Error::other)?;
+    // Clearing only makes sense on a terminal
+    let clear = !config.append && io::stdout().is_terminal();
+
+    eprintln!(
+        "{}",
+        "Watching the clipboard for changes (Ctrl+C to stop)".dimmed()
+    );
+
+    let mut read_error = None;
+    let mut first_poll = true;
+    let snapshots = std::iter::from_fn(|| {
+        if !std::mem::take(&mut first_poll) {
+            thread::sleep(config.interval);
+        }
+        read_clipboard(&mut clipboard)
+            .map_err(|e| read_error = Some(e))
+            .ok()
+    });
+
+    for input in watch_events(snapshots) {
+        let mut out = io::stdout().lock();
+        if clear {
+            write!(out, "\x1b[2J\x1b[H")?;
+        }
+        writeln!(
+            out,
+            "{}",
+            format!(
+                "── clipboard changed at {} ──",
+                chrono::Local::now().format("%H:%M:%S")
+            )
+            .dimmed()
+        )?;
+        drop(out);
+
+        evaluate(&input);
+        io::stdout().flush()?;
+    }
+
+    match read_error {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
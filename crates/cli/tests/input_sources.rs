@@ -0,0 +1,245 @@
+//! Integration tests for input resolution (@path, stdin, etc.).
+//!
+//! Run with: cargo test -p formatorbit-cli --test input_sources
+
+use std::process::{Command, Output, Stdio};
+
+/// Run forb with the given args (stdin closed) and return the raw output.
+fn run_forb(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_forb"))
+        .arg("--no-color")
+        .args(args)
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to run forb")
+}
+
+#[test]
+fn test_missing_at_path_falls_back_to_literal() {
+    let output = run_forb(&["-1", "@1.2.3.4"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "stderr: {}", stderr);
+    assert!(stderr.contains("warning"), "expected a warning: {}", stderr);
+    assert!(
+        stdout.contains("ipv4"),
+        "expected IP interpretation: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_strict_at_keeps_file_not_found_error() {
+    let output = run_forb(&["--strict-at", "@1.2.3.4"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("File not found: 1.2.3.4"),
+        "stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_input_env_reads_variable() {
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "-1", "--input-env", "FORB_TEST_SECRET"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .env("FORB_TEST_SECRET", "550e8400-e29b-41d4-a716-446655440000")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to run forb");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.contains("uuid"), "expected UUID: {}", stdout);
+}
+
+#[test]
+fn test_input_env_unset_variable_fails() {
+    let output = run_forb(&["--input-env", "FORB_TEST_DEFINITELY_UNSET"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("is not set"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_input_env_conflicts_with_positional_input() {
+    let output = run_forb(&["--input-env", "HOME", "691E01B8"]);
+    assert!(!output.status.success());
+}
+
+const NESTED_JSON: &str = r#"{"event": {"timestamp": 1703456789}, "users": [{"id": "550e8400-e29b-41d4-a716-446655440000"}]}"#;
+
+#[test]
+fn test_path_selects_nested_field() {
+    let output = run_forb(&["-1", "--path", "/event/timestamp", NESTED_JSON]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("▶ epoch"), "{}", stdout);
+    assert!(stdout.contains("2023-12-24"), "{}", stdout);
+}
+
+#[test]
+fn test_path_selects_array_element() {
+    let output = run_forb(&["-1", "--path", "/users/0/id", NESTED_JSON]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success());
+    assert!(stdout.starts_with("▶ uuid"), "{}", stdout);
+}
+
+#[test]
+fn test_path_unresolved_pointer_fails() {
+    let output = run_forb(&["--path", "/users/5/id", NESTED_JSON]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("does not match"), "stderr: {}", stderr);
+}
+
+#[test]
+fn test_offline_rejects_url_without_connecting() {
+    // A non-routable address: a connection attempt would hang until the timeout
+    let start = std::time::Instant::now();
+    let output = run_forb(&["--offline", "--url-timeout", "20", "@https://10.255.255.1/"]);
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--offline"), "{}", stderr);
+    assert!(start.elapsed() < std::time::Duration::from_secs(10));
+}
+
+#[test]
+fn test_offline_env_rejects_url() {
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "@https://10.255.255.1/"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .env("FORB_OFFLINE", "1")
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to run forb");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("network access is disabled"));
+}
+
+#[test]
+fn test_tee_outputs_each_line_before_stdin_closes() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--tee"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run forb");
+
+    // Keep stdin open, like `tail -f`
+    let mut stdin = child.stdin.take().unwrap();
+    writeln!(stdin, "request 550e8400-e29b-41d4-a716-446655440000 failed").unwrap();
+    stdin.flush().unwrap();
+
+    let stdout = child.stdout.take().unwrap();
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut line = String::new();
+        BufReader::new(stdout).read_line(&mut line).unwrap();
+        tx.send(line).ok();
+    });
+
+    let first_line = rx.recv_timeout(Duration::from_secs(10));
+    drop(stdin);
+    child.wait().unwrap();
+
+    let first_line = first_line.expect("no output before stdin was closed");
+    assert!(
+        first_line.starts_with("request 550e8400"),
+        "{:?}",
+        first_line
+    );
+}
+
+/// Run forb with the given args and bytes on stdin, returning stdout.
+#[cfg(feature = "encodings")]
+fn run_forb_with_stdin(args: &[&str], stdin: &[u8]) -> String {
+    use std::io::Write;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .arg("--no-color")
+        .args(args)
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run forb");
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[cfg(feature = "encodings")]
+#[test]
+fn test_input_encoding_utf16le_matches_utf8() {
+    let utf16le: Vec<u8> = "Hello".encode_utf16().flat_map(u16::to_le_bytes).collect();
+    let decoded = run_forb_with_stdin(&["--json", "--input-encoding", "utf16le"], &utf16le);
+    let utf8 = run_forb_with_stdin(&["--json"], b"Hello");
+
+    assert!(decoded.contains("\"text\""), "expected text: {}", decoded);
+    assert!(
+        decoded.contains("48656C6C6F"),
+        "expected UTF-8 hex: {}",
+        decoded
+    );
+    assert_eq!(decoded, utf8);
+}
+
+#[cfg(feature = "encodings")]
+#[test]
+fn test_input_encoding_invalid_bytes_fail() {
+    use std::io::Write;
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--input-encoding", "utf16le"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to run forb");
+    // Odd byte count: a truncated UTF-16 code unit
+    child.stdin.take().unwrap().write_all(b"H\0i").unwrap();
+    let output = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("--input-encoding"), "stderr: {}", stderr);
+}
+
+#[cfg(feature = "encodings")]
+#[test]
+fn test_input_encoding_rejects_positional_input() {
+    let output = run_forb(&["--input-encoding", "latin1", "caf\u{e9}"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("--input-encoding only applies to piped or @file input"),
+        "stderr: {}",
+        stderr
+    );
+}
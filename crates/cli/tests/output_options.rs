@@ -0,0 +1,651 @@
+//! Integration tests for CLI output options.
+//!
+//! These run the `forb` binary and check the rendered (uncolored) output.
+//!
+//! Run with: cargo test -p formatorbit-cli --test output_options
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Run forb with the given args and return stdout.
+fn run_forb(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .arg("--no-color")
+        .args(args)
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .output()
+        .expect("Failed to run forb");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// Run forb with the given args and stdin, and return stdout.
+fn run_forb_with_stdin(args: &[&str], stdin: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .arg("--no-color")
+        .args(args)
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("Failed to run forb");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().expect("Failed to run forb");
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_group_by_kind_layout() {
+    let stdout = run_forb(&["--group-by-kind", "-l", "0", "-f", "decimal", "256"]);
+
+    // Section headers and the kind symbol of every entry below them, in order
+    let mut layout: Vec<String> = Vec::new();
+    for line in stdout.lines() {
+        if let Some(entry) = line.strip_prefix("    ") {
+            let symbol = entry.chars().next().unwrap_or(' ');
+            if layout.last().map(String::as_str) != Some(&symbol.to_string()) {
+                layout.push(symbol.to_string());
+            }
+        } else if let Some(header) = line.strip_prefix("  ") {
+            if !header.starts_with(' ') {
+                layout.push(header.to_string());
+            }
+        }
+    }
+
+    assert_eq!(
+        layout,
+        vec![
+            "Integer: 256",
+            "Conversions",
+            "→",
+            "Representations",
+            "≈",
+            "Traits",
+            "✓",
+        ],
+        "unexpected grouped layout:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_default_layout_has_no_group_headers() {
+    let stdout = run_forb(&["-l", "0", "-f", "decimal", "256"]);
+
+    for header in ["Conversions", "Representations", "Traits"] {
+        assert!(
+            !stdout.lines().any(|l| l.trim() == header),
+            "default view should not contain '{}' header:\n{}",
+            header,
+            stdout
+        );
+    }
+}
+
+#[test]
+fn test_primary_keeps_only_primary_conversions() {
+    let stdout = run_forb(&["--primary", "--json", "-f", "hex", "691E01B8"]);
+    let results: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    let priorities: Vec<&str> = results
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|r| r["conversions"].as_array().unwrap())
+        .map(|c| c["priority"].as_str().unwrap())
+        .collect();
+
+    assert!(
+        !priorities.is_empty(),
+        "hex should have a primary conversion"
+    );
+    assert!(
+        priorities.iter().all(|p| *p == "Primary"),
+        "expected only primary conversions, got {:?}",
+        priorities
+    );
+}
+
+#[test]
+fn test_kinds_conversion_hides_traits_and_representations() {
+    let stdout = run_forb(&["--kinds", "conversion", "-l", "0", "256"]);
+    assert!(stdout.contains("→ "), "expected conversions:\n{}", stdout);
+    assert!(
+        !stdout.lines().any(|l| l.trim_start().starts_with('✓')),
+        "trait lines should be filtered out:\n{}",
+        stdout
+    );
+    assert!(
+        !stdout.lines().any(|l| l.trim_start().starts_with('≈')),
+        "representation lines should be filtered out:\n{}",
+        stdout
+    );
+
+    let stdout = run_forb(&["--no-traits", "--json", "256"]);
+    let results: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    assert!(results
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|r| r["conversions"].as_array().unwrap())
+        .all(|c| c["kind"] != "Trait"));
+
+    // Repeating a kind doesn't bring the others back
+    let stdout = run_forb(&[
+        "--kinds",
+        "conversion,conversion,conversion",
+        "-l",
+        "0",
+        "256",
+    ]);
+    assert!(stdout.contains("→ "), "expected conversions:\n{}", stdout);
+    assert!(
+        !stdout
+            .lines()
+            .any(|l| l.trim_start().starts_with(['✓', '≈'])),
+        "only conversions expected:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_limit_per_kind_caps_each_kind() {
+    let trait_lines = |stdout: &str| {
+        stdout
+            .lines()
+            .filter(|l| l.trim_start().starts_with('✓'))
+            .count()
+    };
+
+    // Verbose puts each trait on its own line
+    let all = run_forb(&["-v", "-l", "0", "-f", "decimal", "6"]);
+    assert!(
+        trait_lines(&all) > 2,
+        "6 should have several traits:\n{}",
+        all
+    );
+
+    let capped = run_forb(&[
+        "--limit-per-kind",
+        "2",
+        "-v",
+        "-l",
+        "0",
+        "-f",
+        "decimal",
+        "6",
+    ]);
+    assert_eq!(
+        trait_lines(&capped),
+        2,
+        "expected 2 trait lines:\n{}",
+        capped
+    );
+    for symbol in ['→', '≈'] {
+        let count = capped
+            .lines()
+            .filter(|l| l.trim_start().starts_with(symbol))
+            .count();
+        assert!(count <= 2, "too many {} lines:\n{}", symbol, capped);
+    }
+}
+
+#[test]
+fn test_unwrap_reports_chain() {
+    // base64(hex("Hello"))
+    let stdout = run_forb(&["--unwrap", "NDg2NTZjNmM2Zg=="]);
+    let mut lines = stdout.lines();
+
+    assert_eq!(lines.next(), Some("▶ base64 → hex → text"));
+    assert_eq!(lines.next(), Some("  Hello"));
+}
+
+#[test]
+fn test_redact_masks_input() {
+    let secret = "https://example.com/reset?token=s3cr3tT0kenValue42";
+
+    for mode in [
+        &["-l", "0"][..],
+        &["--json"],
+        &["--dot"],
+        &["-v", "-l", "0"],
+    ] {
+        let mut args = mode.to_vec();
+        args.extend(["--redact", secret]);
+        let stdout = run_forb(&args);
+
+        assert!(stdout.contains("[REDACTED]"), "{:?}: {}", mode, stdout);
+        assert!(
+            !stdout.contains(secret),
+            "{:?} leaked the input:\n{}",
+            mode,
+            stdout
+        );
+    }
+}
+
+#[test]
+fn test_redact_masks_encodings() {
+    for mode in [&["-l", "0"][..], &["--json"], &["--dot"]] {
+        let mut args = mode.to_vec();
+        args.extend(["--redact", "hunter2"]);
+        let stdout = run_forb(&args);
+
+        // hex, base64, binary and ASCII codes of the input
+        for encoded in ["68756E74657232", "aHVudGVyMg", "01101000", "104 117 110"] {
+            assert!(
+                !stdout.contains(encoded),
+                "{:?} leaked {}:\n{}",
+                mode,
+                encoded,
+                stdout
+            );
+        }
+    }
+
+    // Lossy conversions don't reveal the input and are still shown
+    let stdout = run_forb(&["--redact", "-l", "0", "-f", "decimal", "1703456789"]);
+    assert!(stdout.contains("datasize-iec: 1.59 GiB"), "{}", stdout);
+    assert!(!stdout.contains("0x6588B015"), "{}", stdout);
+}
+
+#[test]
+fn test_include_source_shows_normalized_hex() {
+    let stdout = run_forb(&["--include-source", "-f", "hex", "de:ad:be:ef"]);
+    let first_conversion = stdout
+        .lines()
+        .find(|l| l.starts_with("  →") || l.starts_with("  ≈"))
+        .unwrap_or_default();
+
+    assert!(
+        first_conversion.contains("hex: DEADBEEF"),
+        "expected normalized hex first:\n{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_preview_renders_base64_image() {
+    // 1x1 PNG
+    let png = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+    let stdout = run_forb(&["--preview", "-f", "image", png]);
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert!(lines[1].contains("PNG 1×1"), "{}", stdout);
+    // One ASCII shade character for the single pixel, right below the description
+    assert_eq!(lines[2].trim_start().chars().count(), 1, "{}", stdout);
+    assert!(lines[2].starts_with("    "), "{}", stdout);
+}
+
+#[test]
+fn test_only_negation_excludes_formats() {
+    let stdout = run_forb(&["-o", "-text,!decimal", "12345"]);
+    let sources: Vec<&str> = stdout
+        .lines()
+        .filter_map(|l| l.strip_prefix("▶ "))
+        .collect();
+
+    assert!(!sources.is_empty(), "{}", stdout);
+    assert!(
+        sources
+            .iter()
+            .all(|s| !s.starts_with("text") && !s.starts_with("decimal")),
+        "{}",
+        stdout
+    );
+}
+
+#[test]
+fn test_explain_lists_every_format() {
+    let stdout = run_forb(&["--explain", "1704067200"]);
+
+    let first = stdout.lines().next().unwrap();
+    assert!(first.starts_with("epoch"), "{}", stdout);
+    assert!(
+        stdout
+            .lines()
+            .any(|l| l.starts_with("coords") && l.contains("geohash")),
+        "{}",
+        stdout
+    );
+    assert!(stdout.lines().any(|l| l.starts_with("isbn")), "{}", stdout);
+}
+
+#[test]
+fn test_tz_adds_local_time_line() {
+    let stdout = run_forb(&["--tz", "Europe/Stockholm", "-f", "epoch", "1703456789"]);
+    assert!(
+        stdout.contains("timezone: 2023-12-24T23:26:29+01:00 (Europe/Stockholm)"),
+        "{}",
+        stdout
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--tz", "Mars/Olympus_Mons", "1703456789"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .output()
+        .expect("Failed to run forb");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown timezone"));
+}
+
+#[test]
+fn test_color_alpha_order_flag() {
+    let stdout = run_forb(&[
+        "--color-alpha-order",
+        "argb-first",
+        "-f",
+        "color",
+        "#80FF0000",
+    ]);
+    assert!(stdout.contains("ARGB: RGBA(255, 0, 0, 128)"), "{}", stdout);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--color-alpha-order", "bgra", "#80FF0000"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .output()
+        .expect("Failed to run forb");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Unknown color alpha order"));
+}
+
+#[test]
+fn test_sort_name_orders_by_target_format() {
+    let stdout = run_forb(&[
+        "--json", "--sort", "name", "-l", "0", "-f", "decimal", "256",
+    ]);
+    let results: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+
+    let targets: Vec<&str> = results[0]["conversions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["target_format"].as_str().unwrap())
+        .collect();
+    assert!(targets.len() > 3, "expected conversions: {:?}", targets);
+
+    let mut sorted = targets.clone();
+    sorted.sort();
+    assert_eq!(targets, sorted);
+}
+
+#[test]
+fn test_csv_output_quotes_fields() {
+    let stdout = run_forb(&["--csv", "-f", "json", r#"{"a":1,"b":"x,y"}"#]);
+    let mut lines = stdout.lines();
+
+    assert_eq!(
+        lines.next(),
+        Some("input,source_format,confidence,target_format,kind,value")
+    );
+    // Fields with commas or quotes are quoted, with inner quotes doubled
+    let row = lines.next().expect("a data row");
+    assert!(
+        row.starts_with(r#""{""a"":1,""b"":""x,y""}",json,0.95,"#),
+        "row: {row}"
+    );
+    assert!(stdout.contains(r#"""b"": ""x,y"""#), "stdout: {stdout}");
+}
+
+const BATCH_INPUT: &str = "550e8400-e29b-41d4-a716-446655440000\n1703456789\n\n0xDEADBEEF\n";
+
+#[test]
+fn test_batch_groups_results_per_line() {
+    let stdout = run_forb_with_stdin(&["--batch", "-1"], BATCH_INPUT);
+
+    let headers: Vec<&str> = stdout.lines().filter(|l| l.starts_with("──")).collect();
+    assert_eq!(
+        headers,
+        [
+            "── line 1: 550e8400-e29b-41d4-a716-446655440000",
+            "── line 2: 1703456789",
+            "── line 3: 0xDEADBEEF",
+        ]
+    );
+    assert_eq!(
+        shown_sources(&stdout),
+        ["uuid", "epoch-seconds", "color-hex"]
+    );
+}
+
+#[test]
+fn test_batch_json_is_array_per_line() {
+    let stdout = run_forb_with_stdin(&["--batch", "--json"], BATCH_INPUT);
+    let batches: serde_json::Value = serde_json::from_str(&stdout).expect("valid JSON");
+    let batches = batches.as_array().unwrap();
+
+    assert_eq!(batches.len(), 3);
+    let top: Vec<&str> = batches
+        .iter()
+        .map(|results| {
+            results[0]["interpretation"]["source_format"]
+                .as_str()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(top, ["uuid", "epoch-seconds", "color-hex"]);
+}
+
+#[test]
+fn test_timeout_aborts_slow_input() {
+    // A large input keeps the conversion search busy well past 1 ms
+    let mut child = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--timeout", "1"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to run forb");
+    let input = "ab".repeat(100_000);
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().expect("Failed to run forb");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Timed out after 1 ms"), "stderr: {stderr}");
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn test_timeout_allows_fast_input() {
+    let stdout = run_forb(&["--timeout", "10000", "-1", "1703456789"]);
+    assert!(stdout.starts_with("▶ epoch-seconds"), "stdout: {stdout}");
+}
+
+#[test]
+fn test_template_renders_each_conversion() {
+    let stdout = run_forb(&[
+        "--template",
+        r"{source_format}\t{target_format}\t{value} {{{kind}}}",
+        "-f",
+        "decimal",
+        "-l",
+        "1",
+        "256",
+    ]);
+    assert_eq!(stdout, "decimal\thex-int\t0x100 {representation}\n");
+}
+
+#[test]
+fn test_template_rejected_before_conversion() {
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--template", "{nope}", "256"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .output()
+        .expect("Failed to run forb");
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("unknown placeholder '{nope}'"),
+        "stderr: {stderr}"
+    );
+}
+
+#[test]
+fn test_spaced_card_number_shows_masked_network() {
+    for input in ["4111 1111 1111 1111", "4111-1111-1111-1111"] {
+        let stdout = run_forb(&[input]);
+        assert!(
+            stdout.contains("Visa card number ************1111"),
+            "{input}: {stdout}"
+        );
+    }
+}
+
+/// Source formats of the interpretations shown, in order.
+fn shown_sources(stdout: &str) -> Vec<&str> {
+    stdout
+        .lines()
+        .filter_map(|l| l.strip_prefix("▶ "))
+        .map(|l| l.split_whitespace().next().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_prefer_moves_interpretation_first() {
+    // Far enough in the future that decimal outranks epoch by default
+    let default = run_forb(&["3000000000"]);
+    assert_eq!(shown_sources(&default)[..2], ["decimal", "epoch-seconds"]);
+
+    let preferred = run_forb(&["--prefer", "epoch", "3000000000"]);
+    assert_eq!(shown_sources(&preferred)[..2], ["epoch-seconds", "decimal"]);
+
+    // Format aliases work too, and other interpretations keep their order
+    let by_alias = run_forb(&["--prefer", "unix", "3000000000"]);
+    assert_eq!(shown_sources(&by_alias), shown_sources(&preferred));
+}
+
+#[test]
+fn test_prefer_rejects_unknown_format() {
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--prefer", "nope", "3000000000"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .output()
+        .expect("Failed to run forb");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown format 'nope'"), "stderr: {stderr}");
+}
+
+#[test]
+fn test_limit_per_kind_rejects_zero() {
+    let output = Command::new(env!("CARGO_BIN_EXE_forb"))
+        .args(["--no-color", "--limit-per-kind", "0", "255"])
+        .env("FORB_ANALYTICS", "0")
+        .env("FORB_CHECK_UPDATES", "0")
+        .output()
+        .expect("Failed to run forb");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--limit-per-kind"), "stderr: {stderr}");
+}
+
+/// (target_format, display) of every conversion in `forb --json` output.
+fn json_conversion_keys(stdout: &str) -> Vec<(String, String)> {
+    let results: serde_json::Value = serde_json::from_str(stdout).expect("valid JSON");
+    results
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|r| r["conversions"].as_array().unwrap())
+        .map(|c| {
+            (
+                c["target_format"].as_str().unwrap().to_string(),
+                c["display"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_unlimited_output_has_no_more_hint() {
+    // Checksum traits must not be counted as hidden hash conversions
+    for input in ["DEADBEEF", "hello", "313233343536373839"] {
+        let stdout = run_forb(&["-l", "0", input]);
+        assert!(!stdout.contains("more, use -l 0"), "{input}: {stdout}");
+    }
+}
+
+#[test]
+fn test_dedupe_collapses_repeated_conversions() {
+    // Both hex interpretations reach decimal 3735928559, 0xDEADBEEF, ...
+    let all = json_conversion_keys(&run_forb(&["--json", "deadbeef"]));
+    let deduped = json_conversion_keys(&run_forb(&["--json", "--dedupe", "deadbeef"]));
+    assert!(deduped.len() < all.len());
+
+    let decimal = ("decimal".to_string(), "3735928559".to_string());
+    assert!(all.iter().filter(|k| **k == decimal).count() > 1);
+    assert_eq!(deduped.iter().filter(|k| **k == decimal).count(), 1);
+
+    let unique: std::collections::HashSet<_> = deduped.iter().collect();
+    assert_eq!(unique.len(), deduped.len());
+}
+
+#[test]
+fn test_dedupe_keeps_shortest_path() {
+    // Shortest path of each target format and value among the displayed results
+    fn path_lengths(stdout: &str) -> std::collections::HashMap<(String, String), Vec<usize>> {
+        let results: serde_json::Value = serde_json::from_str(stdout).expect("valid JSON");
+        let mut lengths = std::collections::HashMap::<_, Vec<usize>>::new();
+        for conv in results
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|r| r["conversions"].as_array().unwrap())
+        {
+            let key = (
+                conv["target_format"].as_str().unwrap().to_string(),
+                conv["display"].as_str().unwrap().to_string(),
+            );
+            lengths
+                .entry(key)
+                .or_default()
+                .push(conv["path"].as_array().unwrap().len());
+        }
+        lengths
+    }
+
+    let all = path_lengths(&run_forb(&["--json", "-l", "0", "deadbeef"]));
+    let deduped = path_lengths(&run_forb(&["--json", "-l", "0", "--dedupe", "deadbeef"]));
+    assert!(all
+        .values()
+        .any(|lengths| lengths.iter().min() != lengths.iter().max()));
+    for (key, lengths) in &deduped {
+        assert_eq!(lengths.len(), 1, "{:?}", key);
+        // Displays relative to now (like "now + 43d5h45m28s = ...") can tick between runs
+        let Some(all_lengths) = all.get(key) else {
+            continue;
+        };
+        assert_eq!(lengths.first(), all_lengths.iter().min(), "{:?}", key);
+    }
+}
+
+#[test]
+fn test_dedupe_keeps_conversions_of_shown_interpretation() {
+    // The top interpretation must keep everything it shares with hidden ones
+    let plain = run_forb(&["-l", "0", "-1", "DEADBEEF"]);
+    let deduped = run_forb(&["-l", "0", "-1", "--dedupe", "DEADBEEF"]);
+    assert!(plain.contains("hex-int: 0xDEADBEEF"));
+    assert!(deduped.contains("hex-int: 0xDEADBEEF"));
+    assert!(deduped.contains("DEADBEEF (debug memory marker)"));
+    assert_eq!(deduped.lines().count(), plain.lines().count());
+}
@@ -132,6 +132,7 @@ fn should_skip(cmd: &str) -> bool {
         || cmd.contains("--config-init")
         || cmd.contains("--analytics")
         || cmd.contains("--plugins")
+        || cmd.contains("--watch")
         || cmd.contains("--currency")
         || cmd.contains("--check-updates")
         || cmd.contains("--graph")
@@ -4,16 +4,29 @@
 //! a graph where nodes are values and edges are format conversions.
 
 use std::collections::VecDeque;
+use std::io::Read;
+
+use chrono_tz::Tz;
 
 use crate::format::Format;
+use crate::formats::{BaseNFormat, DateTimeFormat, ImageFormat};
 use crate::types::{
     BlockingConfig, Conversion, ConversionConfig, ConversionKind, ConversionPriority,
-    ConversionStep, CoreValue, PriorityConfig,
+    ConversionStep, CoreValue, PriorityConfig, UnwrapResult, LOSSY_CONVERSION_CONFIDENCE,
 };
 
 /// Maximum BFS depth to prevent infinite loops in conversion graph traversal.
 const MAX_BFS_DEPTH: usize = 5;
 
+/// Maximum number of encoding layers [`unwrap_layers`] will peel.
+const MAX_UNWRAP_DEPTH: usize = 8;
+
+/// Text decoders tried by [`unwrap_layers`], in order.
+const UNWRAP_TEXT_LAYERS: &[&str] = &["base64", "hex", "url-encoded"];
+
+/// Maximum decompressed size accepted while unwrapping (guards against zip bombs).
+const MAX_UNWRAP_BYTES: u64 = 16 * 1024 * 1024;
+
 /// Unit format IDs that shouldn't cross-convert to each other.
 const UNIT_FORMATS: &[&str] = &[
     "length",
@@ -86,6 +99,9 @@ const UNIT_TARGETS: &[&str] = &[
     "kelvin",
 ];
 
+/// Target format IDs of checksum traits.
+const CHECKSUM_TARGETS: &[&str] = &["checksum-crc32", "checksum-crc16-ccitt", "checksum-adler32"];
+
 /// Root-based blocking: targets that should never be reached from a given root interpretation.
 /// Unlike BLOCKED_PATHS which blocks immediate source→target, this blocks the target
 /// regardless of the path taken (e.g., "text" blocks ipv4 via text→bytes→ipv4).
@@ -98,10 +114,23 @@ const ROOT_BLOCKED_TARGETS: &[(&str, &str)] = &[
     ("text", "color-rgb"),
     ("text", "color-hex"),
     ("text", "color-hsl"),
+    ("text", "color-name"),
     // Text bytes shouldn't be interpreted as integers or timestamps
     // (already blocked via BLOCKED_PATHS for immediate, but this catches all paths)
     ("text", "int-be"),
     ("text", "int-le"),
+    ("text", "u16-be"),
+    ("text", "u16-le"),
+    ("text", "i16-be"),
+    ("text", "i16-le"),
+    ("text", "u32-be"),
+    ("text", "u32-le"),
+    ("text", "i32-be"),
+    ("text", "i32-le"),
+    ("text", "u64-be"),
+    ("text", "u64-le"),
+    ("text", "i64-be"),
+    ("text", "i64-le"),
     ("text", "epoch-seconds"),
     ("text", "epoch-millis"),
     ("text", "apple-cocoa"),
@@ -114,6 +143,10 @@ const ROOT_BLOCKED_TARGETS: &[(&str, &str)] = &[
     // Text bytes shouldn't be interpreted as UUIDs
     // (any 16 bytes can be formatted as UUID, but "🏳️‍🌈oj" isn't a UUID)
     ("text", "uuid"),
+    // Checksums of text bytes are rarely what someone typing text wants
+    ("text", "checksum-crc32"),
+    ("text", "checksum-crc16-ccitt"),
+    ("text", "checksum-adler32"),
     // Hex bytes shouldn't be interpreted as IP addresses
     // (DEADBEEF as bytes isn't an IP like 222.173.190.239)
     ("hex", "ipv4"),
@@ -123,12 +156,14 @@ const ROOT_BLOCKED_TARGETS: &[(&str, &str)] = &[
     // (use #DEADBEEF explicitly for color interpretation)
     ("hex", "color-rgb"),
     ("hex", "color-hsl"),
+    ("hex", "color-name"),
     // MAC address bytes shouldn't be interpreted as IPs or colors
     // (6 bytes of MAC aren't an IPv4/IPv6 address or color)
     ("mac-address", "ipv4"),
     ("mac-address", "ipv6"),
     ("mac-address", "color-rgb"),
     ("mac-address", "color-hsl"),
+    ("mac-address", "color-name"),
 ];
 
 /// Nonsensical source→target combinations to filter out.
@@ -158,6 +193,14 @@ const BLOCKED_PATHS: &[(&str, &str)] = &[
     ("expr", "duration"),
     ("expr", "duration-ms"),
     ("expr", "decimal"),
+    // Fractions are numbers, not msgpack-encoded data
+    ("fraction", "msgpack"),
+    // A contrast ratio is a verdict, not a number to re-encode
+    ("color-contrast", "msgpack"),
+    // Note frequencies are shown as Hz, note and MIDI number
+    ("music-note", "msgpack"),
+    // QR payload fields are shown once, as JSON
+    ("qr-payload", "json-formatted"),
     // Data sizes aren't durations
     ("datasize", "duration"),
     ("datasize", "duration-ms"),
@@ -182,6 +225,11 @@ const BLOCKED_PATHS: &[(&str, &str)] = &[
     ("color-hsl", "datasize"),
     ("color-hsl", "datasize-iec"),
     ("color-hsl", "datasize-si"),
+    ("color-name", "duration"),
+    ("color-name", "duration-ms"),
+    ("color-name", "datasize"),
+    ("color-name", "datasize-iec"),
+    ("color-name", "datasize-si"),
     // Hexdump output is for display only - don't re-encode it
     ("hexdump", "bytes"),
     ("hexdump", "url-encoded"),
@@ -216,6 +264,7 @@ const BLOCKED_PATHS: &[(&str, &str)] = &[
     ("text", "color-rgb"),
     ("text", "color-hex"),
     ("text", "color-hsl"),
+    ("text", "color-name"),
 ];
 
 /// Check if a source→target conversion should be blocked (hardcoded rules only).
@@ -349,6 +398,68 @@ pub fn find_all_conversions(
     source_format: Option<&str>,
     config: Option<&ConversionConfig>,
 ) -> Vec<Conversion> {
+    let (results, stats) =
+        search_conversions(formats, initial, exclude_format, source_format, config);
+    tracing::trace!(
+        source = source_format.unwrap_or(""),
+        results = results.len(),
+        pruned_cycles = stats.pruned_cycles,
+        "conversion search finished"
+    );
+    results
+}
+
+/// Bookkeeping from a conversion search, for diagnostics.
+#[derive(Debug, Default)]
+struct SearchStats {
+    /// Conversions not explored again because their (format, value) was already visited.
+    pruned_cycles: usize,
+}
+
+/// Identity of a value for cycle detection in the conversion search.
+fn value_hash(value: &CoreValue) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    std::mem::discriminant(value).hash(&mut hasher);
+    match value {
+        CoreValue::Empty => {}
+        CoreValue::Bytes(bytes) => bytes.hash(&mut hasher),
+        CoreValue::String(s) => s.hash(&mut hasher),
+        CoreValue::Int {
+            value,
+            original_bytes,
+        } => (value, original_bytes).hash(&mut hasher),
+        CoreValue::Rational { num, den } => (num, den).hash(&mut hasher),
+        CoreValue::Bool(b) => b.hash(&mut hasher),
+        CoreValue::DateTime(dt) => dt.hash(&mut hasher),
+        CoreValue::Json(json) => json.to_string().hash(&mut hasher),
+        CoreValue::Protobuf(_) | CoreValue::Xml(_) => format!("{:?}", value).hash(&mut hasher),
+        CoreValue::Float(n)
+        | CoreValue::Length(n)
+        | CoreValue::Weight(n)
+        | CoreValue::Volume(n)
+        | CoreValue::Speed(n)
+        | CoreValue::Pressure(n)
+        | CoreValue::Energy(n)
+        | CoreValue::Angle(n)
+        | CoreValue::Area(n)
+        | CoreValue::Temperature(n) => n.to_bits().hash(&mut hasher),
+        CoreValue::Currency { amount, code } => (amount.to_bits(), code).hash(&mut hasher),
+        CoreValue::Coordinates { lat, lon } => (lat.to_bits(), lon.to_bits()).hash(&mut hasher),
+    }
+    hasher.finish()
+}
+
+/// [`find_all_conversions`], also returning search statistics.
+fn search_conversions(
+    formats: &[Box<dyn Format>],
+    initial: &CoreValue,
+    exclude_format: Option<&str>,
+    source_format: Option<&str>,
+    config: Option<&ConversionConfig>,
+) -> (Vec<Conversion>, SearchStats) {
+    let mut stats = SearchStats::default();
     let blocking = config.map(|c| &c.blocking);
     let priority = config.map(|c| &c.priority);
     let mut results = Vec::new();
@@ -356,11 +467,16 @@ pub fn find_all_conversions(
     // for the same format (e.g., int-be → epoch vs int-le → epoch with different dates)
     let mut seen_results: std::collections::HashSet<(String, String)> =
         std::collections::HashSet::new();
-    // Track seen formats for BFS exploration (to prevent infinite loops)
+    // Track visited (format, value) nodes for BFS exploration, so chains that lead
+    // back to a value they already passed through (int → hex-int → int) stop there.
     // We use a separate set here because we still want to explore from a format only once
     // per unique value, but we want to show all unique results.
-    let mut seen_for_bfs: std::collections::HashSet<(String, String)> =
+    let mut seen_for_bfs: std::collections::HashSet<(String, u64)> =
         std::collections::HashSet::new();
+    seen_for_bfs.insert((
+        source_format.unwrap_or_default().to_string(),
+        value_hash(initial),
+    ));
 
     // Pre-exclude the source format if specified
     if let Some(excluded) = exclude_format {
@@ -368,21 +484,25 @@ pub fn find_all_conversions(
         seen_results.insert((excluded.to_string(), String::new()));
     }
 
-    // Queue holds (value, path_so_far, steps_so_far)
-    let mut queue: VecDeque<(CoreValue, Vec<String>, Vec<ConversionStep>)> = VecDeque::new();
+    // Queue holds (value, path_so_far, steps_so_far, confidence_so_far)
+    let mut queue: VecDeque<(CoreValue, Vec<String>, Vec<ConversionStep>, f32)> = VecDeque::new();
 
     // Initialize with source format if provided, so paths show the full chain
     let initial_path = source_format
         .map(|s| vec![s.to_string()])
         .unwrap_or_default();
-    queue.push_back((initial.clone(), initial_path, vec![]));
+    queue.push_back((initial.clone(), initial_path, vec![], 1.0));
 
     // Call source_conversions() for the source format only.
     // These are conversions specific to the format that parsed the input,
     // not applicable to values from other sources during BFS.
     if let Some(source_fmt) = source_format {
         if let Some(format) = formats.iter().find(|f| f.id() == source_fmt) {
-            for mut conv in format.source_conversions(initial) {
+            let source_convs = match config {
+                Some(config) => format.source_conversions_with_config(initial, config),
+                None => format.source_conversions(initial),
+            };
+            for mut conv in source_convs {
                 // Build path including source format
                 let mut path = vec![source_fmt.to_string()];
                 path.push(conv.target_format.clone());
@@ -394,6 +514,7 @@ pub fn find_all_conversions(
                     value: conv.value.clone(),
                     display: conv.display.clone(),
                 }];
+                conv.confidence = step_confidence(&conv);
 
                 let result_key = (conv.target_format.clone(), conv.display.clone());
                 if seen_results.insert(result_key) {
@@ -427,10 +548,12 @@ pub fn find_all_conversions(
                             display,
                         }],
                         is_lossy: false,
+                        confidence: 1.0,
                         priority: ConversionPriority::default(),
                         display_only: false,
                         kind: ConversionKind::default(),
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![],
                     });
                 }
@@ -441,14 +564,18 @@ pub fn find_all_conversions(
     // Get reinterpret threshold from config (default 0.7)
     let reinterpret_threshold = config.map(|c| c.reinterpret_threshold()).unwrap_or(0.7);
 
-    // BFS through conversions
+    // BFS through conversions, up to the configured depth (never beyond the built-in cap)
+    let configured_depth = config.and_then(|c| c.max_depth);
+    let max_depth = configured_depth.map_or(MAX_BFS_DEPTH, |d| d.min(MAX_BFS_DEPTH));
     let mut depth = 0;
 
-    while !queue.is_empty() && depth < MAX_BFS_DEPTH {
+    while !queue.is_empty() && depth < max_depth {
         let level_size = queue.len();
 
         for _ in 0..level_size {
-            let Some((current_value, current_path, current_steps)) = queue.pop_front() else {
+            let Some((current_value, current_path, current_steps, current_confidence)) =
+                queue.pop_front()
+            else {
                 break;
             };
 
@@ -474,6 +601,7 @@ pub fn find_all_conversions(
                             }
 
                             let target_format = interp.source_format.clone();
+                            let confidence = current_confidence * interp.confidence;
 
                             // Check blocking - but skip root-based blocking for string reinterpretation
                             // because we're now in a different semantic domain (text content, not raw bytes).
@@ -494,7 +622,7 @@ pub fn find_all_conversions(
                                 .unwrap_or_else(|| interp.description.clone());
 
                             let result_key = (target_format.clone(), display.clone());
-                            let bfs_key = (target_format.clone(), display.clone());
+                            let bfs_key = (target_format.clone(), value_hash(&interp.value));
 
                             // Build path
                             let mut full_path = current_path.clone();
@@ -517,17 +645,21 @@ pub fn find_all_conversions(
                                     path: full_path.clone(),
                                     steps: full_steps.clone(),
                                     is_lossy: false,
+                                    confidence,
                                     priority: ConversionPriority::Structured,
                                     kind: ConversionKind::Conversion,
                                     display_only: false,
                                     hidden: false,
+                                    depth_limited: false,
                                     rich_display: interp.rich_display.clone(),
                                 });
                             }
 
                             // Add to queue for further exploration
                             if seen_for_bfs.insert(bfs_key) {
-                                queue.push_back((interp.value, full_path, full_steps));
+                                queue.push_back((interp.value, full_path, full_steps, confidence));
+                            } else {
+                                stats.pruned_cycles += 1;
                             }
                         }
                     }
@@ -536,7 +668,11 @@ pub fn find_all_conversions(
 
             // Get conversions from all formats
             for format in formats {
-                for conv in format.conversions(&current_value) {
+                let convs = match config {
+                    Some(config) => format.conversions_with_config(&current_value, config),
+                    None => format.conversions(&current_value),
+                };
+                for conv in convs {
                     // Check blocking early (before adding to results or queue)
                     if is_blocked(
                         immediate_source,
@@ -548,8 +684,14 @@ pub fn find_all_conversions(
                         continue;
                     }
 
+                    // Checksums describe the input's own bytes, not bytes reached along
+                    // the way (uuid → uuid → bytes would checksum the UUID's text)
+                    if depth > 0 && CHECKSUM_TARGETS.contains(&conv.target_format.as_str()) {
+                        continue;
+                    }
+
                     let result_key = (conv.target_format.clone(), conv.display.clone());
-                    let bfs_key = (conv.target_format.clone(), conv.display.clone());
+                    let confidence = current_confidence * step_confidence(&conv);
 
                     // Build the full path (format IDs only, for backwards compat)
                     let mut full_path = current_path.clone();
@@ -581,17 +723,24 @@ pub fn find_all_conversions(
                             path: full_path.clone(),
                             steps: full_steps.clone(),
                             is_lossy: conv.is_lossy,
+                            confidence,
                             priority: conv.priority,
                             kind: conv.kind,
                             display_only: conv.display_only,
                             hidden: conv.hidden,
+                            depth_limited: false,
                             rich_display: conv.rich_display.clone(),
                         });
                     }
 
                     // Add to queue for further exploration (unless terminal or already explored)
-                    if !conv.display_only && seen_for_bfs.insert(bfs_key) {
-                        queue.push_back((conv.value, full_path, full_steps));
+                    if !conv.display_only {
+                        let bfs_key = (conv.target_format.clone(), value_hash(&conv.value));
+                        if seen_for_bfs.insert(bfs_key) {
+                            queue.push_back((conv.value, full_path, full_steps, confidence));
+                        } else {
+                            stats.pruned_cycles += 1;
+                        }
                     }
                 }
             }
@@ -600,6 +749,40 @@ pub fn find_all_conversions(
         depth += 1;
     }
 
+    // Whatever is still queued was cut off by the configured depth; flag those results
+    if configured_depth.is_some() && !queue.is_empty() {
+        let unexplored: std::collections::HashSet<&Vec<String>> =
+            queue.iter().map(|(_, path, _, _)| path).collect();
+        for conv in &mut results {
+            if unexplored.contains(&conv.path) {
+                conv.depth_limited = true;
+            }
+        }
+    }
+
+    // Also render datetimes in the configured display timezone
+    if let Some(tz) = config
+        .and_then(|c| c.display_timezone.as_deref())
+        .and_then(DateTimeFormat::parse_timezone)
+    {
+        add_timezone_conversions(&mut results, initial, source_format, tz, &mut seen_results);
+    }
+
+    // Also render integers in the configured output radix, unless a
+    // dedicated format (binary, octal, hex) already shows it
+    if let Some(radix) = config
+        .and_then(|c| c.output_radix)
+        .filter(|r| (2..=36).contains(r) && !matches!(r, 2 | 8 | 16))
+    {
+        add_radix_conversions(
+            &mut results,
+            initial,
+            source_format,
+            radix,
+            &mut seen_results,
+        );
+    }
+
     // Filter out blocked source→target combinations (builtin + user config)
     // This catches any that slipped through (e.g., from initial format() calls)
     if let Some(source) = exclude_format {
@@ -617,7 +800,246 @@ pub fn find_all_conversions(
     // Sort by priority, respecting user configuration
     sort_conversions(&mut results, priority);
 
-    results
+    (results, stats)
+}
+
+/// Add a `timezone` conversion for the initial value and every result that is a datetime.
+fn add_timezone_conversions(
+    results: &mut Vec<Conversion>,
+    initial: &CoreValue,
+    source_format: Option<&str>,
+    tz: Tz,
+    seen_results: &mut std::collections::HashSet<(String, String)>,
+) {
+    let mut datetimes = Vec::new();
+    if let CoreValue::DateTime(dt) = initial {
+        let path = source_format
+            .map(|s| vec![s.to_string()])
+            .unwrap_or_default();
+        // The input itself is a datetime: its local time is the headline result
+        datetimes.push((*dt, path, vec![], 1.0, ConversionPriority::Primary));
+    }
+    for conv in results.iter().filter(|c| !c.hidden) {
+        if let CoreValue::DateTime(dt) = &conv.value {
+            datetimes.push((
+                *dt,
+                conv.path.clone(),
+                conv.steps.clone(),
+                conv.confidence,
+                conv.priority,
+            ));
+        }
+    }
+
+    for (dt, mut path, mut steps, confidence, priority) in datetimes {
+        let display = DateTimeFormat::format_in_timezone(&dt, tz);
+        if !seen_results.insert(("timezone".to_string(), display.clone())) {
+            continue;
+        }
+        path.push("timezone".to_string());
+        steps.push(ConversionStep {
+            format: "timezone".to_string(),
+            value: CoreValue::DateTime(dt),
+            display: display.clone(),
+        });
+        results.push(Conversion {
+            value: CoreValue::DateTime(dt),
+            target_format: "timezone".to_string(),
+            display,
+            path,
+            steps,
+            confidence,
+            priority,
+            kind: ConversionKind::Representation,
+            display_only: true,
+            ..Default::default()
+        });
+    }
+}
+
+/// Add a `base<N>` conversion for the initial value and every result that is an integer.
+fn add_radix_conversions(
+    results: &mut Vec<Conversion>,
+    initial: &CoreValue,
+    source_format: Option<&str>,
+    radix: u32,
+    seen_results: &mut std::collections::HashSet<(String, String)>,
+) {
+    let target = format!("base{}", radix);
+
+    let mut ints = Vec::new();
+    if let CoreValue::Int { value, .. } = initial {
+        let path = source_format
+            .map(|s| vec![s.to_string()])
+            .unwrap_or_default();
+        // The input itself is an integer: the requested radix is the headline result
+        ints.push((*value, path, vec![], 1.0, ConversionPriority::Primary));
+    }
+    for conv in results.iter().filter(|c| !c.hidden) {
+        if let CoreValue::Int { value, .. } = &conv.value {
+            ints.push((
+                *value,
+                conv.path.clone(),
+                conv.steps.clone(),
+                conv.confidence,
+                conv.priority,
+            ));
+        }
+    }
+
+    for (value, mut path, mut steps, confidence, priority) in ints {
+        let Some(display) = BaseNFormat::to_radix(value, radix) else {
+            continue;
+        };
+        if !seen_results.insert((target.clone(), display.clone())) {
+            continue;
+        }
+        path.push(target.clone());
+        steps.push(ConversionStep {
+            format: target.clone(),
+            value: CoreValue::String(display.clone()),
+            display: display.clone(),
+        });
+        results.push(Conversion {
+            value: CoreValue::String(display.clone()),
+            target_format: target.clone(),
+            display,
+            path,
+            steps,
+            confidence,
+            priority,
+            kind: ConversionKind::Representation,
+            display_only: true,
+            ..Default::default()
+        });
+    }
+}
+
+/// Peel encoding layers off an input until a meaningful value remains.
+///
+/// Tries text decoders (base64, hex, URL encoding) and decompressors (gzip,
+/// zlib) depth-first, backtracking out of dead ends, and stops once the value
+/// is JSON, readable text, or an image. Returns `None` when no layer could be
+/// peeled.
+pub fn unwrap_layers(formats: &[Box<dyn Format>], input: &str) -> Option<UnwrapResult> {
+    let mut layers = Vec::new();
+    let (terminal, value) = unwrap_string(formats, input, &mut layers, 0)?;
+    if layers.is_empty() {
+        return None;
+    }
+    Some(UnwrapResult {
+        layers,
+        terminal: terminal.to_string(),
+        value,
+    })
+}
+
+/// Unwrap a text value. Text that can't be decoded further is itself a terminal.
+fn unwrap_string(
+    formats: &[Box<dyn Format>],
+    text: &str,
+    layers: &mut Vec<String>,
+    depth: usize,
+) -> Option<(&'static str, CoreValue)> {
+    let trimmed = text.trim();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        if let Ok(json) = serde_json::from_str(trimmed) {
+            return Some(("json", CoreValue::Json(json)));
+        }
+    }
+
+    if depth < MAX_UNWRAP_DEPTH {
+        for id in UNWRAP_TEXT_LAYERS {
+            let Some(format) = formats.iter().find(|f| f.id() == *id) else {
+                continue;
+            };
+            let Some(interp) = format
+                .parse(trimmed)
+                .into_iter()
+                .max_by(|a, b| a.confidence.total_cmp(&b.confidence))
+            else {
+                continue;
+            };
+
+            layers.push((*id).to_string());
+            let found = match &interp.value {
+                CoreValue::Bytes(bytes) => unwrap_bytes(formats, bytes, layers, depth + 1),
+                CoreValue::String(s) if s != trimmed => {
+                    unwrap_string(formats, s, layers, depth + 1)
+                }
+                _ => None,
+            };
+            if found.is_some() {
+                return found;
+            }
+            layers.pop();
+        }
+    }
+
+    is_readable_text(text).then(|| ("text", CoreValue::String(text.to_string())))
+}
+
+/// Unwrap a binary value. Binary that is neither compressed, an image, nor
+/// text is a dead end.
+fn unwrap_bytes(
+    formats: &[Box<dyn Format>],
+    bytes: &[u8],
+    layers: &mut Vec<String>,
+    depth: usize,
+) -> Option<(&'static str, CoreValue)> {
+    if ImageFormat::detect_format(bytes).is_some() {
+        return Some(("image", CoreValue::Bytes(bytes.to_vec())));
+    }
+
+    if depth < MAX_UNWRAP_DEPTH {
+        if let Some((layer, inflated)) = decompress(bytes) {
+            layers.push(layer.to_string());
+            if let Some(found) = unwrap_bytes(formats, &inflated, layers, depth + 1) {
+                return Some(found);
+            }
+            layers.pop();
+        }
+    }
+
+    let text = std::str::from_utf8(bytes).ok()?;
+    unwrap_string(formats, text, layers, depth)
+}
+
+/// Decompress gzip or zlib data, identified by magic bytes.
+fn decompress(bytes: &[u8]) -> Option<(&'static str, Vec<u8>)> {
+    let mut out = Vec::new();
+    let result = if bytes.starts_with(&[0x1F, 0x8B]) {
+        flate2::read::GzDecoder::new(bytes)
+            .take(MAX_UNWRAP_BYTES)
+            .read_to_end(&mut out)
+            .map(|_| "gzip")
+    } else if bytes.len() >= 2 && bytes[0] == 0x78 && matches!(bytes[1], 0x01 | 0x5E | 0x9C | 0xDA)
+    {
+        flate2::read::ZlibDecoder::new(bytes)
+            .take(MAX_UNWRAP_BYTES)
+            .read_to_end(&mut out)
+            .map(|_| "zlib")
+    } else {
+        return None;
+    };
+    result
+        .ok()
+        .filter(|_| !out.is_empty())
+        .map(|layer| (layer, out))
+}
+
+/// Non-empty text without control characters (other than whitespace).
+fn is_readable_text(text: &str) -> bool {
+    !text.trim().is_empty() && text.chars().all(|c| !c.is_control() || c.is_whitespace())
+}
+
+/// Confidence of a single conversion step, capping lossy steps that don't set their own.
+fn step_confidence(conv: &Conversion) -> f32 {
+    if conv.is_lossy {
+        conv.confidence.min(LOSSY_CONVERSION_CONFIDENCE)
+    } else {
+        conv.confidence
+    }
 }
 
 /// Sort conversions by priority, respecting user configuration.
@@ -636,14 +1058,16 @@ fn sort_conversions(results: &mut [Conversion], priority_config: Option<&Priorit
                 // Higher offset comes first
                 off_b
                     .cmp(&off_a)
+                    .then_with(|| b.confidence.total_cmp(&a.confidence))
                     .then_with(|| a.path.len().cmp(&b.path.len()))
             } else {
                 cat_a.cmp(&cat_b)
             }
         } else {
-            // Default: priority enum order, then path length
+            // Default: priority enum order, then confidence, then path length
             a.priority
                 .cmp(&b.priority)
+                .then_with(|| b.confidence.total_cmp(&a.confidence))
                 .then_with(|| a.path.len().cmp(&b.path.len()))
         }
     });
@@ -652,7 +1076,10 @@ fn sort_conversions(results: &mut [Conversion], priority_config: Option<&Priorit
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::formats::{Base64Format, BytesToIntFormat, DateTimeFormat, HexFormat};
+    use crate::formats::{
+        Base64Format, BytesToIntFormat, DateTimeFormat, HexFormat, UrlEncodingFormat,
+    };
+    use crate::tests::TestFormat;
 
     #[test]
     fn test_bytes_to_multiple_formats() {
@@ -720,4 +1147,135 @@ mod tests {
         assert!(dt.display.contains("2025"));
         assert!(!dt.path.is_empty()); // Has a path
     }
+
+    fn unwrap_formats() -> Vec<Box<dyn Format>> {
+        vec![
+            Box::new(HexFormat),
+            Box::new(Base64Format),
+            Box::new(UrlEncodingFormat),
+        ]
+    }
+
+    #[test]
+    fn test_unwrap_base64_gzip_json() {
+        use base64::Engine;
+        use std::io::Write;
+
+        let mut gz = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gz.write_all(br#"{"user":"alice","id":42}"#).unwrap();
+        let input = base64::engine::general_purpose::STANDARD.encode(gz.finish().unwrap());
+
+        let result = unwrap_layers(&unwrap_formats(), &input).expect("should unwrap");
+
+        assert_eq!(result.layers, vec!["base64", "gzip"]);
+        assert_eq!(result.terminal, "json");
+        assert_eq!(result.chain(), "base64 → gzip → json");
+        let CoreValue::Json(json) = result.value else {
+            panic!("Expected Json");
+        };
+        assert_eq!(json["user"], "alice");
+    }
+
+    #[test]
+    fn test_unwrap_hex_text() {
+        let result = unwrap_layers(&unwrap_formats(), "48656c6c6f20776f726c64").unwrap();
+        assert_eq!(result.layers, vec!["hex"]);
+        assert_eq!(result.terminal, "text");
+        assert!(matches!(result.value, CoreValue::String(ref s) if s == "Hello world"));
+    }
+
+    #[test]
+    fn test_unwrap_nothing_to_peel() {
+        assert!(unwrap_layers(&unwrap_formats(), r#"{"a":1}"#).is_none());
+        assert!(unwrap_layers(&unwrap_formats(), "plain words here").is_none());
+    }
+
+    #[test]
+    fn test_conversion_confidence() {
+        use crate::formats::{DataSizeFormat, Utf8Format};
+
+        // Exact representations keep full confidence
+        let formats: Vec<Box<dyn Format>> = vec![Box::new(HexFormat)];
+        let bytes = CoreValue::Bytes(vec![0xDE, 0xAD]);
+        let conversions = find_all_conversions(&formats, &bytes, None, None, None);
+        assert!(conversions.iter().all(|c| c.confidence == 1.0));
+
+        // Lossy steps are capped
+        let formats: Vec<Box<dyn Format>> = vec![Box::new(DataSizeFormat)];
+        let size = CoreValue::Int {
+            value: 1_500_000,
+            original_bytes: None,
+        };
+        let conversions = find_all_conversions(&formats, &size, None, None, None);
+        let iec = conversions
+            .iter()
+            .find(|c| c.target_format == "datasize-iec")
+            .unwrap();
+        assert!(iec.is_lossy);
+        assert_eq!(iec.confidence, LOSSY_CONVERSION_CONFIDENCE);
+
+        // Heuristic traits set their own
+        let formats: Vec<Box<dyn Format>> = vec![Box::new(Utf8Format)];
+        let text = CoreValue::String("The cat is sleeping on the sofa and it was happy".into());
+        let conversions = find_all_conversions(&formats, &text, None, None, None);
+        let language = conversions
+            .iter()
+            .find(|c| c.target_format == "language")
+            .unwrap();
+        assert!(language.confidence < 1.0);
+    }
+
+    #[test]
+    fn test_sort_prefers_confident_conversions() {
+        let mut conversions = vec![
+            Conversion {
+                target_format: "guess".into(),
+                confidence: 0.5,
+                ..Default::default()
+            },
+            Conversion {
+                target_format: "exact".into(),
+                ..Default::default()
+            },
+        ];
+        sort_conversions(&mut conversions, None);
+        assert_eq!(conversions[0].target_format, "exact");
+    }
+
+    #[test]
+    fn test_search_prunes_cycles() {
+        // Keeps integers cycling: each value maps to itself as "ping" and "pong",
+        // and to the next value mod 3 as "next"
+        let cycling = TestFormat::new("cycling").converting(|value| {
+            let CoreValue::Int { value: n, .. } = value else {
+                return vec![];
+            };
+            [("ping", *n), ("pong", *n), ("next", (n + 1) % 3)]
+                .into_iter()
+                .map(|(target, n)| Conversion {
+                    value: CoreValue::Int {
+                        value: n,
+                        original_bytes: None,
+                    },
+                    target_format: target.to_string(),
+                    display: format!("{} {}", target, n),
+                    path: vec![target.to_string()],
+                    ..Default::default()
+                })
+                .collect()
+        });
+        let formats: Vec<Box<dyn Format>> = vec![Box::new(cycling)];
+        let initial = CoreValue::Int {
+            value: 0,
+            original_bytes: None,
+        };
+
+        let (results, stats) = search_conversions(&formats, &initial, None, None, None);
+
+        // Every (format, value) node is found once...
+        assert_eq!(results.len(), 9);
+        // ...and the search stops revisiting them before the depth limit:
+        // 6 + 6 + 6 + 3 revisits over depths 1-3, nothing left at depth 4
+        assert_eq!(stats.pruned_cycles, 21);
+    }
 }
@@ -4,9 +4,10 @@
 //! with plugin-provided variables and functions, as well as built-in currency
 //! conversion functions.
 
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use crate::formats::currency_expr;
+use crate::types::ConversionConfig;
 
 /// Global expression context for evalexpr.
 ///
@@ -69,20 +70,59 @@ pub struct EvalResult {
 
 /// Evaluate an expression using the global context (if available).
 ///
-/// Falls back to evalexpr::eval() if no context is set.
+/// Currency functions convert with the rates pinned in `config`.
 /// Returns both the result and the result currency if currency functions were used.
-pub fn eval(expr: &str) -> Result<EvalResult, evalexpr::EvalexprError> {
+pub fn eval(expr: &str, config: &ConversionConfig) -> Result<EvalResult, evalexpr::EvalexprError> {
+    // Clear the currency tracking before evaluation
+    currency_expr::clear_currency_flag();
+
+    let value = evalexpr::eval_with_context(expr, &build_context(config))?;
+    let result_currency = currency_expr::get_result_currency();
+
+    Ok(EvalResult {
+        value,
+        result_currency,
+    })
+}
+
+/// Evaluate an already parsed expression (or part of one) in the same context as [`eval`].
+pub fn eval_node(
+    node: &evalexpr::Node,
+    config: &ConversionConfig,
+) -> Result<evalexpr::Value, evalexpr::EvalexprError> {
+    node.eval_with_context(&build_context(config))
+}
+
+/// Call a function from the same context as [`eval`] (or an evalexpr builtin)
+/// with already evaluated arguments.
+pub fn call_function(
+    identifier: &str,
+    arguments: &[evalexpr::Value],
+    config: &ConversionConfig,
+) -> Result<evalexpr::Value, evalexpr::EvalexprError> {
+    use evalexpr::ContextWithMutableVariables;
+
+    // Bind the arguments to variables, since builtins are only reachable by name
+    let mut context = build_context(config);
+    let mut names = Vec::with_capacity(arguments.len());
+    for (i, argument) in arguments.iter().enumerate() {
+        let name = format!("__arg{i}");
+        context.set_value(name.clone(), argument.clone())?;
+        names.push(name);
+    }
+    evalexpr::eval_with_context(&format!("{identifier}({})", names.join(", ")), &context)
+}
+
+/// Build the evaluation context: currency functions plus plugin variables and functions.
+fn build_context(config: &ConversionConfig) -> evalexpr::HashMapContext {
     #[cfg(feature = "python")]
     use evalexpr::ContextWithMutableFunctions;
     use evalexpr::ContextWithMutableVariables;
 
-    // Clear the currency tracking before evaluation
-    currency_expr::clear_currency_flag();
-
     let mut context = evalexpr::HashMapContext::new();
 
     // 1. Add built-in currency functions FIRST (so plugins can override)
-    add_currency_functions(&mut context);
+    add_currency_functions(&mut context, &Arc::new(config.clone()));
 
     // 2. Add plugin context if available
     if let Ok(guard) = EXPR_CONTEXT.read() {
@@ -100,31 +140,26 @@ pub fn eval(expr: &str) -> Result<EvalResult, evalexpr::EvalexprError> {
         }
     }
 
-    let value = evalexpr::eval_with_context(expr, &context)?;
-    let result_currency = currency_expr::get_result_currency();
-
-    Ok(EvalResult {
-        value,
-        result_currency,
-    })
+    context
 }
 
 /// Add currency conversion functions to the context.
 ///
 /// Registers functions like USD(amount), EUR(amount), BTC(amount) that convert
 /// to the target currency, plus toXXX/inXXX functions for explicit target conversion.
-fn add_currency_functions(context: &mut evalexpr::HashMapContext) {
+fn add_currency_functions(context: &mut evalexpr::HashMapContext, config: &Arc<ConversionConfig>) {
     use evalexpr::ContextWithMutableFunctions;
 
     for code in currency_expr::all_currency_codes() {
         // Register uppercase version (USD, EUR, BTC) - converts to user's target currency
         let code_upper = code.to_uppercase();
         let code_for_upper = code_upper.clone();
+        let config_for_upper = Arc::clone(config);
         let _ = context.set_function(
             code_upper,
             evalexpr::Function::new(move |args| {
                 let amount = args.as_number()?;
-                match currency_expr::convert_to_target(amount, &code_for_upper) {
+                match currency_expr::convert_to_target(amount, &code_for_upper, &config_for_upper) {
                     Some(result) => Ok(evalexpr::Value::Float(result)),
                     None => Err(evalexpr::EvalexprError::CustomMessage(format!(
                         "Cannot convert {} to target currency (no exchange rates available)",
@@ -137,11 +172,12 @@ fn add_currency_functions(context: &mut evalexpr::HashMapContext) {
         // Register lowercase version (usd, eur, btc)
         let code_lower = code.to_lowercase();
         let code_for_lower = code.to_uppercase(); // Still use uppercase for conversion
+        let config_for_lower = Arc::clone(config);
         let _ = context.set_function(
             code_lower,
             evalexpr::Function::new(move |args| {
                 let amount = args.as_number()?;
-                match currency_expr::convert_to_target(amount, &code_for_lower) {
+                match currency_expr::convert_to_target(amount, &code_for_lower, &config_for_lower) {
                     Some(result) => Ok(evalexpr::Value::Float(result)),
                     None => Err(evalexpr::EvalexprError::CustomMessage(format!(
                         "Cannot convert {} to target currency (no exchange rates available)",
@@ -153,8 +189,8 @@ fn add_currency_functions(context: &mut evalexpr::HashMapContext) {
 
         // Register toXXX and inXXX functions for explicit target conversion
         // These convert FROM the user's target currency TO the specified currency
-        add_explicit_conversion_function(context, &code, "to");
-        add_explicit_conversion_function(context, &code, "in");
+        add_explicit_conversion_function(context, &code, "to", config);
+        add_explicit_conversion_function(context, &code, "in", config);
     }
 }
 
@@ -166,6 +202,7 @@ fn add_explicit_conversion_function(
     context: &mut evalexpr::HashMapContext,
     currency_code: &str,
     prefix: &str,
+    config: &Arc<ConversionConfig>,
 ) {
     use evalexpr::ContextWithMutableFunctions;
 
@@ -174,11 +211,12 @@ fn add_explicit_conversion_function(
     // Uppercase: toEUR, inEUR
     let func_upper = format!("{}{}", prefix, target);
     let target_for_upper = target.clone();
+    let config_for_upper = Arc::clone(config);
     let _ = context.set_function(
         func_upper,
         evalexpr::Function::new(move |args| {
             let amount = args.as_number()?;
-            match currency_expr::convert_from_target(amount, &target_for_upper) {
+            match currency_expr::convert_from_target(amount, &target_for_upper, &config_for_upper) {
                 Some(result) => Ok(evalexpr::Value::Float(result)),
                 None => Err(evalexpr::EvalexprError::CustomMessage(format!(
                     "Cannot convert to {} (no exchange rates available)",
@@ -191,11 +229,12 @@ fn add_explicit_conversion_function(
     // Lowercase: toeur, ineur
     let func_lower = format!("{}{}", prefix, currency_code.to_lowercase());
     let target_for_lower = target.clone();
+    let config_for_lower = Arc::clone(config);
     let _ = context.set_function(
         func_lower,
         evalexpr::Function::new(move |args| {
             let amount = args.as_number()?;
-            match currency_expr::convert_from_target(amount, &target_for_lower) {
+            match currency_expr::convert_from_target(amount, &target_for_lower, &config_for_lower) {
                 Some(result) => Ok(evalexpr::Value::Float(result)),
                 None => Err(evalexpr::EvalexprError::CustomMessage(format!(
                     "Cannot convert to {} (no exchange rates available)",
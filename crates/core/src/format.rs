@@ -1,6 +1,6 @@
 //! Format trait definition.
 
-use crate::types::{Conversion, CoreValue, Interpretation};
+use crate::types::{Conversion, ConversionConfig, CoreValue, Interpretation};
 use serde::{Serialize, Serializer};
 
 /// Metadata about a format for help/documentation.
@@ -30,6 +30,17 @@ pub struct FormatInfo {
     pub has_validation: bool,
 }
 
+/// Why a single format matched or rejected an input.
+#[derive(Debug, Clone, Serialize)]
+pub struct FormatExplanation {
+    /// Format identifier (e.g., "epoch")
+    pub format: &'static str,
+    /// Highest confidence among the format's interpretations, or `None` if it rejected the input
+    pub confidence: Option<f32>,
+    /// The format's own explanation, falling back to its validation error
+    pub reason: Option<String>,
+}
+
 fn serialize_static_slice<S>(
     slice: &&'static [&'static str],
     serializer: S,
@@ -40,6 +51,19 @@ where
     slice.serialize(serializer)
 }
 
+/// Specificity tiers for [`Format::specificity`].
+///
+/// Formats are ordered by specificity, and at equal confidence the more
+/// specific format's interpretation comes first.
+pub mod specificity {
+    /// Distinctive structure that rules out most other readings (JWT, UUID, IP addresses).
+    pub const HIGH: u32 = 300;
+    /// Identifiers with a recognizable shape but looser rules (IBAN, SemVer, NanoID).
+    pub const IDENTIFIER: u32 = 200;
+    /// Broad formats that match many inputs (hex, base64, decimal, text).
+    pub const DEFAULT: u32 = 100;
+}
+
 /// Trait for built-in formats and Rust plugins.
 ///
 /// This is the "fast path" without FFI overhead.
@@ -67,6 +91,24 @@ pub trait Format: Send + Sync {
     /// Try to parse an input string into interpretations.
     fn parse(&self, input: &str) -> Vec<Interpretation>;
 
+    /// Try to parse raw binary data (e.g. file contents) into interpretations.
+    ///
+    /// Binary formats (images, archives, ...) override this to read the bytes
+    /// directly instead of base64-decoding them in [`Format::parse`]. The
+    /// default doesn't recognize binary input.
+    fn parse_bytes(&self, _bytes: &[u8]) -> Vec<Interpretation> {
+        vec![]
+    }
+
+    /// Parse input honoring user configuration.
+    ///
+    /// Used instead of [`Format::parse`] when the converter has a config.
+    /// Override this for formats with settings of their own (e.g. the
+    /// channel order of 8-digit hex colors); the default ignores the config.
+    fn parse_with_config(&self, input: &str, _config: &ConversionConfig) -> Vec<Interpretation> {
+        self.parse(input)
+    }
+
     /// Check if this format can format the given value type.
     fn can_format(&self, value: &CoreValue) -> bool;
 
@@ -82,6 +124,19 @@ pub trait Format: Send + Sync {
         vec![]
     }
 
+    /// Get conversions honoring user configuration.
+    ///
+    /// Used instead of [`Format::conversions`] when the converter has a config.
+    /// Override this for conversions with settings of their own (e.g. the
+    /// wording of relative times); the default ignores the config.
+    fn conversions_with_config(
+        &self,
+        value: &CoreValue,
+        _config: &ConversionConfig,
+    ) -> Vec<Conversion> {
+        self.conversions(value)
+    }
+
     /// Get conversions that should only appear when this format was the source.
     ///
     /// Called once per interpretation, only for the format that originally parsed
@@ -93,11 +148,45 @@ pub trait Format: Send + Sync {
         vec![]
     }
 
+    /// Get source conversions honoring user configuration.
+    ///
+    /// Used instead of [`Format::source_conversions`] when the converter has
+    /// a config; the default ignores the config.
+    fn source_conversions_with_config(
+        &self,
+        value: &CoreValue,
+        _config: &ConversionConfig,
+    ) -> Vec<Conversion> {
+        self.source_conversions(value)
+    }
+
+    /// Adjust the conversions of an interpretation this format produced.
+    ///
+    /// Called once per interpretation with the original input, for facts the
+    /// parsed value alone doesn't carry (e.g. the operands behind the result
+    /// of `DEADBEEF ^ CAFEBABE`). The default leaves the conversions as they are.
+    fn annotate_conversions(
+        &self,
+        _input: &str,
+        _interpretation: &Interpretation,
+        _conversions: &mut Vec<Conversion>,
+    ) {
+    }
+
     /// Short aliases for this format (e.g., "b64" for "base64").
     fn aliases(&self) -> &'static [&'static str] {
         &[]
     }
 
+    /// How specific this format's matches are, for ordering formats.
+    ///
+    /// Formats are kept sorted by specificity (highest first), so an
+    /// interpretation from a more specific format wins a confidence tie.
+    /// See [`specificity`] for the tiers.
+    fn specificity(&self) -> u32 {
+        specificity::DEFAULT
+    }
+
     /// Check if the given name matches this format's id or any alias.
     fn matches_name(&self, name: &str) -> bool {
         self.id() == name || self.aliases().contains(&name)
@@ -112,4 +201,14 @@ pub trait Format: Send + Sync {
     fn validate(&self, _input: &str) -> Option<String> {
         None
     }
+
+    /// Describe why this format matched or rejected the input, and the confidence assigned.
+    ///
+    /// Unlike [`Format::validate`], this is called for every format (e.g., `--explain`)
+    /// and may explain successful matches too.
+    ///
+    /// Returns `None` if the format has no explanation beyond its parse result.
+    fn explain(&self, _input: &str) -> Option<String> {
+        None
+    }
 }
@@ -44,9 +44,9 @@ impl ArchiveFormat {
         data.len() >= 4 && data.starts_with(&[0x50, 0x4B, 0x03, 0x04])
     }
 
-    /// Check if data is a GZip file.
+    /// Check if data is a GZip file (10-byte header, deflate compression).
     fn is_gzip(data: &[u8]) -> bool {
-        data.len() >= 2 && data.starts_with(&[0x1F, 0x8B])
+        data.len() >= 10 && data.starts_with(&[0x1F, 0x8B, 0x08])
     }
 
     /// Check if data is a TAR archive (check for ustar magic at offset 257).
@@ -59,8 +59,7 @@ impl ArchiveFormat {
     }
 
     /// Detect archive type from magic bytes.
-    #[cfg(test)]
-    fn detect_archive_type(data: &[u8]) -> Option<&'static str> {
+    pub(crate) fn detect_archive_type(data: &[u8]) -> Option<&'static str> {
         if Self::is_zip(data) {
             Some("ZIP")
         } else if Self::is_gzip(data) {
@@ -291,23 +290,24 @@ impl Format for ArchiveFormat {
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         // Try to decode as base64
-        if let Ok(bytes) = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input)
-        {
-            if let Some(meta) = Self::parse_archive(&bytes) {
-                let description = Self::format_description(&meta);
-                let rich_display = Self::build_rich_display(&meta);
-
-                return vec![Interpretation {
-                    value: CoreValue::Bytes(bytes),
-                    source_format: "archive".to_string(),
-                    confidence: 0.95,
-                    description,
-                    rich_display,
-                }];
-            }
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input) {
+            Ok(bytes) => self.parse_bytes(&bytes),
+            Err(_) => vec![],
         }
+    }
 
-        vec![]
+    fn parse_bytes(&self, bytes: &[u8]) -> Vec<Interpretation> {
+        let Some(meta) = Self::parse_archive(bytes) else {
+            return vec![];
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes.to_vec()),
+            source_format: "archive".to_string(),
+            confidence: 0.95,
+            description: Self::format_description(&meta),
+            rich_display: Self::build_rich_display(&meta),
+        }]
     }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
@@ -337,10 +337,12 @@ impl Format for ArchiveFormat {
             path: vec!["archive-info".to_string()],
             steps: vec![],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Structured,
             display_only: true,
             kind: ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display,
         }]
     }
@@ -361,7 +363,7 @@ mod tests {
             Some("ZIP")
         );
         assert_eq!(
-            ArchiveFormat::detect_archive_type(&[0x1F, 0x8B]),
+            ArchiveFormat::detect_archive_type(&[0x1F, 0x8B, 0x08, 0, 0, 0, 0, 0, 0, 0x03]),
             Some("GZIP")
         );
         assert_eq!(ArchiveFormat::detect_archive_type(b"not an archive"), None);
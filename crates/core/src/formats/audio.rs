@@ -49,11 +49,16 @@ impl AudioFormat {
         if data.len() >= 3 && data.starts_with(b"ID3") {
             return true;
         }
-        // MP3 frame sync (0xFF followed by 0xE* or 0xF*)
-        if data.len() >= 2 && data[0] == 0xFF && (data[1] & 0xE0) == 0xE0 {
-            return true;
+        // MPEG audio frame header: 11-bit sync, then no reserved version,
+        // layer, bitrate or sample rate values
+        if data.len() < 4 || data[0] != 0xFF || (data[1] & 0xE0) != 0xE0 {
+            return false;
         }
-        false
+        let version = (data[1] >> 3) & 0x03;
+        let layer = (data[1] >> 1) & 0x03;
+        let bitrate = data[2] >> 4;
+        let sample_rate = (data[2] >> 2) & 0x03;
+        version != 0b01 && layer != 0b00 && bitrate != 0x0F && sample_rate != 0b11
     }
 
     /// Check if data is a FLAC file.
@@ -73,15 +78,15 @@ impl AudioFormat {
 
     /// Check if data is an AAC file.
     fn is_aac(data: &[u8]) -> bool {
-        // ADTS sync word
-        if data.len() >= 2 && data[0] == 0xFF && (data[1] & 0xF0) == 0xF0 {
-            return true;
-        }
-        false
+        // ADTS header: 12-bit sync word, layer 0 and a defined sample rate index
+        data.len() >= 7
+            && data[0] == 0xFF
+            && (data[1] & 0xF6) == 0xF0
+            && ((data[2] >> 2) & 0x0F) < 13
     }
 
     /// Detect audio format from magic bytes.
-    fn detect_audio_format(data: &[u8]) -> Option<&'static str> {
+    pub(crate) fn detect_audio_format(data: &[u8]) -> Option<&'static str> {
         if Self::is_mp3(data) {
             Some("MP3")
         } else if Self::is_flac(data) {
@@ -168,8 +173,7 @@ impl AudioFormat {
             }
 
             // Estimate bitrate for compressed formats
-            if meta.codec.is_some() && meta.duration_secs.is_some() {
-                let duration = meta.duration_secs.unwrap();
+            if let (Some(_), Some(duration)) = (&meta.codec, meta.duration_secs) {
                 if duration > 0.0 {
                     let bitrate = (data.len() as f64 * 8.0 / duration) as u32;
                     meta.bitrate = Some(bitrate / 1000); // kbps
@@ -366,23 +370,24 @@ impl Format for AudioFormat {
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         // Try to decode as base64
-        if let Ok(bytes) = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input)
-        {
-            if let Some(meta) = Self::parse_audio(&bytes) {
-                let description = Self::format_description(&meta);
-                let rich_display = Self::build_rich_display(&meta);
-
-                return vec![Interpretation {
-                    value: CoreValue::Bytes(bytes),
-                    source_format: "audio".to_string(),
-                    confidence: 0.95,
-                    description,
-                    rich_display,
-                }];
-            }
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input) {
+            Ok(bytes) => self.parse_bytes(&bytes),
+            Err(_) => vec![],
         }
+    }
+
+    fn parse_bytes(&self, bytes: &[u8]) -> Vec<Interpretation> {
+        let Some(meta) = Self::parse_audio(bytes) else {
+            return vec![];
+        };
 
-        vec![]
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes.to_vec()),
+            source_format: "audio".to_string(),
+            confidence: 0.95,
+            description: Self::format_description(&meta),
+            rich_display: Self::build_rich_display(&meta),
+        }]
     }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
@@ -412,10 +417,12 @@ impl Format for AudioFormat {
             path: vec!["audio-info".to_string()],
             steps: vec![],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Structured,
             display_only: true,
             kind: ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display,
         }]
     }
@@ -444,7 +451,12 @@ mod tests {
     #[test]
     fn test_is_mp3() {
         assert!(AudioFormat::is_mp3(b"ID3\x04\x00"));
-        assert!(AudioFormat::is_mp3(&[0xFF, 0xFB]));
+        // MPEG-1 layer III, 128 kbit/s, 44.1 kHz
+        assert!(AudioFormat::is_mp3(&[0xFF, 0xFB, 0x90, 0x64]));
+        assert!(!AudioFormat::is_mp3(&[0xFF, 0xFB]));
+        // Reserved bitrate and sample rate
+        assert!(!AudioFormat::is_mp3(&[0xFF, 0xFB, 0xF0, 0x00]));
+        assert!(!AudioFormat::is_mp3(&[0xFF, 0xFB, 0x9C, 0x00]));
         assert!(!AudioFormat::is_mp3(b"not mp3"));
     }
 
@@ -0,0 +1,303 @@
+//! Base32 format.
+//!
+//! Supports two alphabets:
+//! - RFC 4648 (`A-Z2-7`, `=` padding), as used by TOTP secrets
+//! - Crockford (`0-9` and letters without `I`, `L`, `O`, `U`)
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{Conversion, ConversionPriority, ConversionStep, CoreValue, Interpretation};
+
+pub struct Base32Format;
+
+/// RFC 4648 §6 alphabet.
+const RFC4648_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Crockford's alphabet (excludes I, L, O, U).
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Shortest unpadded input we consider. Shorter strings are mostly words.
+const MIN_UNPADDED_LEN: usize = 8;
+
+/// Which base32 alphabet an input uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabet {
+    Rfc4648,
+    Crockford,
+}
+
+impl Alphabet {
+    fn chars(self) -> &'static [u8; 32] {
+        match self {
+            Alphabet::Rfc4648 => RFC4648_ALPHABET,
+            Alphabet::Crockford => CROCKFORD_ALPHABET,
+        }
+    }
+}
+
+impl Base32Format {
+    /// Detect the alphabet of a base32 candidate (without padding).
+    ///
+    /// Digits 0, 1, 8 and 9 only occur in Crockford. Mixed case is rejected
+    /// since it's more likely an identifier than encoded data.
+    fn detect_alphabet(s: &str) -> Option<Alphabet> {
+        if s.is_empty() {
+            return None;
+        }
+        let has_upper = s.chars().any(|c| c.is_ascii_uppercase());
+        let has_lower = s.chars().any(|c| c.is_ascii_lowercase());
+        if has_upper && has_lower {
+            return None;
+        }
+
+        let upper = s.to_ascii_uppercase();
+        if upper.bytes().all(|b| RFC4648_ALPHABET.contains(&b)) {
+            Some(Alphabet::Rfc4648)
+        } else if upper.bytes().all(|b| CROCKFORD_ALPHABET.contains(&b)) {
+            Some(Alphabet::Crockford)
+        } else {
+            None
+        }
+    }
+
+    /// Decode base32 data (without padding).
+    ///
+    /// Returns `None` if the length can't come from whole bytes or the
+    /// leftover bits aren't zero (non-canonical encoding).
+    fn decode(s: &str, alphabet: Alphabet) -> Option<Vec<u8>> {
+        let chars = alphabet.chars();
+        let mut bytes = Vec::with_capacity(s.len() * 5 / 8);
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+
+        for b in s.bytes() {
+            let value = chars.iter().position(|&c| c == b.to_ascii_uppercase())? as u32;
+            buffer = (buffer << 5) | value;
+            bits += 5;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((buffer >> bits) as u8);
+                buffer &= (1 << bits) - 1;
+            }
+        }
+
+        // Leftover bits are padding: a whole character or set bits means a bad length
+        if bits >= 5 || buffer != 0 {
+            return None;
+        }
+        Some(bytes)
+    }
+
+    /// Encode bytes with the RFC 4648 alphabet, padded to a multiple of 8.
+    fn encode(bytes: &[u8]) -> String {
+        let mut out = String::with_capacity(bytes.len().div_ceil(5) * 8);
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+
+        for &byte in bytes {
+            buffer = (buffer << 8) | u32::from(byte);
+            bits += 8;
+            while bits >= 5 {
+                bits -= 5;
+                out.push(RFC4648_ALPHABET[((buffer >> bits) & 0x1F) as usize] as char);
+            }
+            buffer &= (1 << bits) - 1;
+        }
+        if bits > 0 {
+            out.push(RFC4648_ALPHABET[((buffer << (5 - bits)) & 0x1F) as usize] as char);
+        }
+        while !out.len().is_multiple_of(8) {
+            out.push('=');
+        }
+        out
+    }
+
+    /// Check that padding (if any) fills the last 8-character block.
+    fn has_valid_padding(input: &str, data: &str) -> bool {
+        let padding = &input[data.len()..];
+        padding.is_empty() || (padding.bytes().all(|b| b == b'=') && input.len().is_multiple_of(8))
+    }
+}
+
+impl Format for Base32Format {
+    fn id(&self) -> &'static str {
+        "base32"
+    }
+
+    fn name(&self) -> &'static str {
+        "Base32"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Encoding",
+            description: "Base32 encoded binary data (RFC 4648 or Crockford alphabet)",
+            examples: &["JBSWY3DPEHPK3PXP", "NBSWY3DP", "91JPRV3F"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let input = input.trim();
+        let data = input.trim_end_matches('=');
+        if !Self::has_valid_padding(input, data) {
+            return vec![];
+        }
+        let padded = data.len() < input.len();
+
+        let Some(alphabet) = Self::detect_alphabet(data) else {
+            return vec![];
+        };
+
+        // Guard against hex, numbers and words: without the distinctive padding,
+        // require a letter outside the hex range and a digit
+        if !padded {
+            let has_non_hex_letter = data
+                .chars()
+                .any(|c| c.is_ascii_alphabetic() && !c.is_ascii_hexdigit());
+            let has_digit = data.chars().any(|c| c.is_ascii_digit());
+            if data.len() < MIN_UNPADDED_LEN || !has_non_hex_letter || !has_digit {
+                return vec![];
+            }
+        } else if alphabet == Alphabet::Crockford {
+            // Crockford base32 doesn't use padding
+            return vec![];
+        }
+
+        let Some(bytes) = Self::decode(data, alphabet) else {
+            return vec![];
+        };
+        if bytes.is_empty() {
+            return vec![];
+        }
+
+        let description = match alphabet {
+            Alphabet::Rfc4648 => format!("{} bytes", bytes.len()),
+            Alphabet::Crockford => format!("{} bytes (Crockford)", bytes.len()),
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes),
+            source_format: "base32".to_string(),
+            confidence: if padded { 0.85 } else { 0.8 },
+            description,
+            rich_display: vec![],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        // Use conversions() instead to support truncation for large data
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        // Use conversions() instead to support truncation for large data
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Bytes(bytes) = value else {
+            return vec![];
+        };
+        if bytes.is_empty() {
+            return vec![];
+        }
+
+        let encoded = Self::encode(bytes);
+
+        // Truncate display for large data (max ~100 chars of base32)
+        let max_chars = 100;
+        let display = if encoded.len() <= max_chars {
+            encoded.clone()
+        } else {
+            let remaining = encoded.len() - max_chars;
+            format!("{}... ({} more chars)", &encoded[..max_chars], remaining)
+        };
+
+        vec![Conversion {
+            value: CoreValue::String(encoded),
+            target_format: "base32".to_string(),
+            display: display.clone(),
+            path: vec!["base32".to_string()],
+            steps: vec![ConversionStep {
+                format: "base32".to_string(),
+                value: CoreValue::Bytes(bytes.clone()),
+                display,
+            }],
+            priority: ConversionPriority::Encoding,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["b32", "crockford"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decoded(input: &str) -> Option<Vec<u8>> {
+        match Base32Format.parse(input).into_iter().next()?.value {
+            CoreValue::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_totp_secret_round_trip() {
+        // The classic TOTP example secret
+        let bytes = decoded("JBSWY3DPEHPK3PXP").unwrap();
+        assert_eq!(bytes, b"Hello!\xde\xad\xbe\xef");
+        assert_eq!(Base32Format::encode(&bytes), "JBSWY3DPEHPK3PXP");
+
+        // Lowercase secrets are common too
+        assert_eq!(decoded("jbswy3dpehpk3pxp"), Some(bytes));
+    }
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        // RFC 4648 §10 test vectors
+        let vectors: &[(&[u8], &str)] = &[
+            (b"f", "MY======"),
+            (b"fo", "MZXQ===="),
+            (b"foo", "MZXW6==="),
+            (b"foob", "MZXW6YQ="),
+            (b"fooba", "MZXW6YTB"),
+            (b"foobar", "MZXW6YTBOI======"),
+        ];
+        for (bytes, encoded) in vectors {
+            assert_eq!(&Base32Format::encode(bytes), encoded);
+        }
+        assert_eq!(decoded("MZXW6YTBOI======").as_deref(), Some(&b"foobar"[..]));
+        assert_eq!(decoded("MY======").as_deref(), Some(&b"f"[..]));
+    }
+
+    #[test]
+    fn test_crockford() {
+        let results = Base32Format.parse("91JPRV3F");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].description.contains("Crockford"));
+        assert!(matches!(&results[0].value, CoreValue::Bytes(b) if b == b"Hello"));
+    }
+
+    #[test]
+    fn test_hex_not_hijacked() {
+        assert!(Base32Format.parse("DEADBEEF").is_empty());
+        assert!(Base32Format.parse("691E01B8").is_empty());
+        assert!(Base32Format.parse("0123456789abcdef").is_empty());
+        assert!(Base32Format.parse("12345678").is_empty());
+    }
+
+    #[test]
+    fn test_reject_words_and_bad_input() {
+        assert!(Base32Format.parse("HELLOWORLD").is_empty()); // no digits
+        assert!(Base32Format.parse("JBSWY3DPehpk3pxp").is_empty()); // mixed case
+        assert!(Base32Format.parse("MZXW6==").is_empty()); // bad padding length
+        assert!(Base32Format.parse("MZXW6YTBOJ").is_empty()); // non-zero trailing bits
+    }
+}
@@ -1,24 +1,102 @@
 //! Base64 format.
 
 use base64::{
-    engine::general_purpose::{STANDARD, STANDARD_NO_PAD},
+    engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD},
     Engine,
 };
 use tracing::{debug, trace};
 
 use crate::format::{Format, FormatInfo};
+use crate::mime::detect_mime;
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
 };
 
 pub struct Base64Format;
 
+/// Which base64 alphabet an input uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabet {
+    /// Standard alphabet (RFC 4648 §4) with `+` and `/`.
+    Standard,
+    /// URL-safe alphabet (RFC 4648 §5) with `-` and `_`, as used by JWT.
+    UrlSafe,
+}
+
 impl Base64Format {
-    /// Check if a string looks like valid base64.
-    fn is_valid_base64_chars(s: &str) -> bool {
-        !s.is_empty()
-            && s.chars()
-                .all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '/' || c == '=')
+    /// Detect the alphabet of a base64 candidate.
+    ///
+    /// Returns `None` for invalid characters or when `+`/`/` and `-`/`_`
+    /// are mixed. Strings using neither are treated as standard.
+    fn detect_alphabet(s: &str) -> Option<Alphabet> {
+        if s.is_empty() {
+            return None;
+        }
+        let mut standard = false;
+        let mut url_safe = false;
+        for c in s.chars() {
+            match c {
+                '+' | '/' => standard = true,
+                '-' | '_' => url_safe = true,
+                '=' => {}
+                c if c.is_ascii_alphanumeric() => {}
+                _ => return None,
+            }
+        }
+        match (standard, url_safe) {
+            (true, true) => None,
+            (_, true) => Some(Alphabet::UrlSafe),
+            _ => Some(Alphabet::Standard),
+        }
+    }
+
+    /// Check if a `-`/`_` separated string looks like an identifier or
+    /// number group rather than base64url data.
+    ///
+    /// Catches kebab/snake case names ("my-package", "MAX_VALUE") and
+    /// dash-separated hex/digit groups (UUIDs, dates, phone numbers).
+    fn looks_like_separated_words(s: &str) -> bool {
+        let parts: Vec<&str> = s.trim_end_matches('=').split(['-', '_']).collect();
+
+        let all_hex_groups = parts
+            .iter()
+            .all(|p| p.chars().all(|c| c.is_ascii_hexdigit()));
+        if all_hex_groups {
+            return true;
+        }
+
+        let is_word = |p: &str| {
+            let mut chars = p.chars();
+            match chars.next() {
+                None => true,
+                Some(first) if first.is_ascii_alphabetic() => {
+                    let rest: Vec<char> = chars.collect();
+                    rest.iter().all(|c| c.is_ascii_lowercase())
+                        || (first.is_ascii_uppercase()
+                            && rest.iter().all(|c| c.is_ascii_uppercase()))
+                }
+                Some(_) => p.chars().all(|c| c.is_ascii_digit()),
+            }
+        };
+        parts.iter().all(|p| is_word(p))
+    }
+
+    /// Decode with the given alphabet, trying padded first and then unpadded.
+    ///
+    /// Returns the bytes and whether the input carried padding.
+    fn decode(s: &str, alphabet: Alphabet) -> Option<(Vec<u8>, bool)> {
+        let (padded, unpadded) = match alphabet {
+            Alphabet::Standard => (&STANDARD, &STANDARD_NO_PAD),
+            Alphabet::UrlSafe => (&URL_SAFE, &URL_SAFE_NO_PAD),
+        };
+        // Many systems emit base64 without padding (URLs, Firestore, JWT, etc.)
+        if let Ok(b) = padded.decode(s) {
+            Some((b, s.ends_with('=')))
+        } else if let Ok(b) = unpadded.decode(s) {
+            Some((b, false))
+        } else {
+            None
+        }
     }
 
     /// Check if a string looks like pure hex (only 0-9, A-F).
@@ -109,8 +187,8 @@ impl Format for Base64Format {
             id: self.id(),
             name: self.name(),
             category: "Encoding",
-            description: "Base64 encoded binary data",
-            examples: &["SGVsbG8gV29ybGQ=", "aR4BuA=="],
+            description: "Base64 encoded binary data (standard or URL-safe alphabet)",
+            examples: &["SGVsbG8gV29ybGQ=", "aR4BuA==", "PDw_Pz4-"],
             aliases: self.aliases(),
             has_validation: true,
         }
@@ -120,9 +198,17 @@ impl Format for Base64Format {
         trace!(input_len = input.len(), "base64: checking input");
 
         // Quick validation of characters
-        if !Self::is_valid_base64_chars(input) {
+        let Some(alphabet) = Self::detect_alphabet(input) else {
             trace!("base64: rejected - invalid characters");
             return vec![];
+        };
+
+        // URL-safe candidates collide with identifiers, dates and UUIDs
+        if alphabet == Alphabet::UrlSafe
+            && (input.len() < 8 || Self::looks_like_separated_words(input))
+        {
+            debug!(input, "base64: rejected - looks like separated words");
+            return vec![];
         }
 
         // Skip pure hex strings - they're hex, not base64
@@ -138,13 +224,7 @@ impl Format for Base64Format {
             return vec![];
         }
 
-        // Try to decode - first with standard (padded), then without padding
-        // Many systems emit base64 without padding (URLs, Firestore, etc.)
-        let (bytes, had_padding) = if let Ok(b) = STANDARD.decode(input) {
-            (b, input.ends_with('='))
-        } else if let Ok(b) = STANDARD_NO_PAD.decode(input) {
-            (b, false)
-        } else {
+        let Some((bytes, had_padding)) = Self::decode(input, alphabet) else {
             trace!("base64: rejected - decode failed");
             return vec![];
         };
@@ -177,13 +257,24 @@ impl Format for Base64Format {
             base_confidence
         };
 
-        debug!(bytes_len = bytes.len(), confidence, "base64: matched");
+        debug!(
+            bytes_len = bytes.len(),
+            confidence,
+            ?alphabet,
+            "base64: matched"
+        );
+
+        let description = match alphabet {
+            Alphabet::Standard => format!("{} bytes", bytes.len()),
+            Alphabet::UrlSafe if had_padding => format!("{} bytes (URL-safe)", bytes.len()),
+            Alphabet::UrlSafe => format!("{} bytes (base64url, unpadded)", bytes.len()),
+        };
 
         vec![Interpretation {
             value: CoreValue::Bytes(bytes.clone()),
             source_format: "base64".to_string(),
             confidence,
-            description: format!("{} bytes", bytes.len()),
+            description,
             rich_display: vec![],
         }]
     }
@@ -199,17 +290,21 @@ impl Format for Base64Format {
     }
 
     fn aliases(&self) -> &'static [&'static str] {
-        &["b64"]
+        &["b64", "base64url", "b64url"]
     }
 
     fn validate(&self, input: &str) -> Option<String> {
         // Check for invalid characters
         for c in input.chars() {
-            if !c.is_ascii_alphanumeric() && c != '+' && c != '/' && c != '=' {
+            if !c.is_ascii_alphanumeric() && !matches!(c, '+' | '/' | '-' | '_' | '=') {
                 return Some(format!("invalid base64 character: '{}'", c));
             }
         }
 
+        let Some(alphabet) = Self::detect_alphabet(input) else {
+            return Some("mixes standard ('+', '/') and URL-safe ('-', '_') alphabets".to_string());
+        };
+
         // Check for proper padding
         let padding_count = input.chars().filter(|&c| c == '=').count();
         if padding_count > 2 {
@@ -225,8 +320,13 @@ impl Format for Base64Format {
         }
 
         // Try to decode
-        match STANDARD.decode(input) {
+        let engine = match alphabet {
+            Alphabet::Standard => &STANDARD,
+            Alphabet::UrlSafe => &URL_SAFE,
+        };
+        match engine.decode(input) {
             Ok(_) => None,
+            Err(_) if Self::decode(input, alphabet).is_some() => None,
             Err(e) => Some(e.to_string()),
         }
     }
@@ -236,34 +336,56 @@ impl Format for Base64Format {
             return vec![];
         };
 
-        let full_b64 = STANDARD.encode(bytes);
+        let standard = STANDARD.encode(bytes);
+        let mut conversions = vec![];
 
+        // Embeddable data URI for recognized file types (PNG, PDF, fonts, ...)
+        if let Some(mime) = detect_mime(bytes) {
+            let uri = format!("data:{};base64,{}", mime, standard);
+            conversions.push(Self::encoding_conversion(bytes, "data-uri", uri));
+        }
+
+        conversions.push(Self::encoding_conversion(bytes, "base64", standard));
+        conversions.push(Self::encoding_conversion(
+            bytes,
+            "base64url",
+            URL_SAFE_NO_PAD.encode(bytes),
+        ));
+        conversions
+    }
+}
+
+impl Base64Format {
+    /// Build a display-only conversion for an encoded string.
+    fn encoding_conversion(bytes: &[u8], target: &str, encoded: String) -> Conversion {
         // Truncate display for large data (max ~100 chars of base64)
         let max_chars = 100;
-        let display = if full_b64.len() <= max_chars {
-            full_b64.clone()
+        let display = if encoded.len() <= max_chars {
+            encoded.clone()
         } else {
-            let remaining = full_b64.len() - max_chars;
-            format!("{}... ({} more chars)", &full_b64[..max_chars], remaining)
+            let remaining = encoded.len() - max_chars;
+            format!("{}... ({} more chars)", &encoded[..max_chars], remaining)
         };
 
-        vec![Conversion {
-            value: CoreValue::String(full_b64),
-            target_format: "base64".to_string(),
+        Conversion {
+            value: CoreValue::String(encoded),
+            target_format: target.to_string(),
             display: display.clone(),
-            path: vec!["base64".to_string()],
+            path: vec![target.to_string()],
             steps: vec![ConversionStep {
-                format: "base64".to_string(),
-                value: CoreValue::Bytes(bytes.clone()),
+                format: target.to_string(),
+                value: CoreValue::Bytes(bytes.to_vec()),
                 display,
             }],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Encoding,
             display_only: true, // Don't explore further from base64 string (avoids codepoints noise)
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
-        }]
+        }
     }
 }
 
@@ -305,7 +427,7 @@ mod tests {
         let format = Base64Format;
         let value = CoreValue::Bytes(vec![0x69, 0x1E, 0x01, 0xB8]);
         let conversions = format.conversions(&value);
-        assert_eq!(conversions.len(), 1);
+        assert_eq!(conversions.len(), 2);
         assert_eq!(conversions[0].target_format, "base64");
         if let CoreValue::String(s) = &conversions[0].value {
             assert_eq!(s, "aR4BuA==");
@@ -314,6 +436,88 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conversions_bytes_to_base64url() {
+        let format = Base64Format;
+        let value = CoreValue::Bytes(vec![0xFB, 0xFF, 0xBF]);
+        let conversions = format.conversions(&value);
+
+        let std = conversions.iter().find(|c| c.target_format == "base64");
+        let url = conversions.iter().find(|c| c.target_format == "base64url");
+        assert_eq!(std.unwrap().display, "+/+/");
+        assert_eq!(url.unwrap().display, "-_-_");
+    }
+
+    #[test]
+    fn test_conversions_png_to_data_uri() {
+        let format = Base64Format;
+        let png = vec![
+            0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0x0D,
+        ];
+        let conversions = format.conversions(&CoreValue::Bytes(png));
+
+        let uri = conversions
+            .iter()
+            .find(|c| c.target_format == "data-uri")
+            .expect("PNG should produce a data URI");
+        assert!(uri.display.starts_with("data:image/png;base64,iVBORw0KGgo"));
+    }
+
+    #[test]
+    fn test_no_data_uri_for_unknown_bytes() {
+        let format = Base64Format;
+        let conversions = format.conversions(&CoreValue::Bytes(vec![0x69, 0x1E, 0x01, 0xB8]));
+        assert!(!conversions.iter().any(|c| c.target_format == "data-uri"));
+    }
+
+    #[test]
+    fn test_parse_base64url_unpadded() {
+        // JWT signature segment: URL-safe alphabet, no padding
+        let format = Base64Format;
+        let results = format.parse("SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c");
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].description.contains("base64url"));
+        if let CoreValue::Bytes(bytes) = &results[0].value {
+            assert_eq!(bytes.len(), 32);
+            assert_eq!(bytes[..3], [0x49, 0xF9, 0x4A]);
+        } else {
+            panic!("Expected Bytes");
+        }
+    }
+
+    #[test]
+    fn test_parse_base64url_padded() {
+        let format = Base64Format;
+        let results = format.parse("PDw_Pz4-Pw==");
+
+        assert_eq!(results.len(), 1);
+        assert!(results[0].description.contains("URL-safe"));
+        if let CoreValue::Bytes(bytes) = &results[0].value {
+            assert_eq!(bytes, b"<<??>>?");
+        } else {
+            panic!("Expected Bytes");
+        }
+    }
+
+    #[test]
+    fn test_mixed_alphabets_rejected() {
+        let format = Base64Format;
+        assert!(format.parse("ab+c-d_e/fgh").is_empty());
+        assert!(format.validate("ab+c-d_e/fgh").is_some());
+    }
+
+    #[test]
+    fn test_separated_words_not_base64url() {
+        let format = Base64Format;
+        assert!(format.parse("my-cool-package").is_empty());
+        assert!(format.parse("MAX_BUFFER_SIZE").is_empty());
+        assert!(format
+            .parse("550e8400-e29b-41d4-a716-446655440000")
+            .is_empty());
+        assert!(format.parse("2024-01-15").is_empty());
+    }
+
     #[test]
     fn test_invalid_base64() {
         let format = Base64Format;
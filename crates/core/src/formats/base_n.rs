@@ -0,0 +1,167 @@
+//! Integers in an arbitrary radix (2-36).
+//!
+//! Parses explicitly prefixed input such as `base36:1z` or `base7:-65`. Binary,
+//! octal and hex keep their dedicated formats; this covers every other base.
+//! The reverse direction, rendering integers in a chosen radix, is driven by
+//! [`ConversionConfig::output_radix`](crate::types::ConversionConfig::output_radix).
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{CoreValue, Interpretation};
+
+pub struct BaseNFormat;
+
+/// Digits for radixes up to 36.
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+impl BaseNFormat {
+    /// Split `base36:1z` into its radix and digits.
+    fn split_prefix(input: &str) -> Option<(u32, &str)> {
+        let (prefix, digits) = input.split_once(':')?;
+        let radix_str = prefix
+            .strip_prefix("base")
+            .or_else(|| prefix.strip_prefix("BASE"))?;
+        if radix_str.is_empty() || !radix_str.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let radix: u32 = radix_str.parse().ok()?;
+        (2..=36).contains(&radix).then_some((radix, digits))
+    }
+
+    /// Parse digits (with an optional sign) in the given radix.
+    fn parse_digits(digits: &str, radix: u32) -> Option<i128> {
+        let unsigned = digits.strip_prefix(['-', '+']).unwrap_or(digits);
+        if unsigned.is_empty() || !unsigned.chars().all(|c| c.is_digit(radix)) {
+            return None;
+        }
+        i128::from_str_radix(digits, radix).ok()
+    }
+
+    /// Render an integer in the given radix (2-36), with lowercase digits.
+    ///
+    /// Returns `None` for a radix outside 2-36.
+    #[must_use]
+    pub fn to_radix(value: i128, radix: u32) -> Option<String> {
+        if !(2..=36).contains(&radix) {
+            return None;
+        }
+        if value == 0 {
+            return Some("0".to_string());
+        }
+
+        let mut n = value.unsigned_abs();
+        let radix = u128::from(radix);
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push(DIGITS[(n % radix) as usize]);
+            n /= radix;
+        }
+        if value < 0 {
+            digits.push(b'-');
+        }
+        digits.reverse();
+        Some(String::from_utf8(digits).expect("radix digits are ASCII"))
+    }
+}
+
+impl Format for BaseNFormat {
+    fn id(&self) -> &'static str {
+        "base-n"
+    }
+
+    fn name(&self) -> &'static str {
+        "Base-N Integer"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Numbers",
+            description: "Integers in any radix from 2 to 36 (base36:1z)",
+            examples: &["base36:zz", "base3:1202", "base7:-65"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let trimmed = input.trim();
+        let Some((radix, digits)) = Self::split_prefix(trimmed) else {
+            return vec![];
+        };
+        let Some(value) = Self::parse_digits(digits, radix) else {
+            return vec![];
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Int {
+                value,
+                original_bytes: None,
+            },
+            source_format: "base-n".to_string(),
+            confidence: 0.95,
+            description: format!("Base {} {} = {} decimal", radix, digits, value),
+            rich_display: vec![],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        // The output radix comes from config, not from the format itself
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["radix", "basen"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> Option<i128> {
+        match BaseNFormat.parse(input).first()?.value {
+            CoreValue::Int { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_prefixed() {
+        assert_eq!(parsed("base36:zz"), Some(1295));
+        assert_eq!(parsed("base36:ZZ"), Some(1295));
+        assert_eq!(parsed("base3:1202"), Some(47));
+        assert_eq!(parsed("base7:-65"), Some(-47));
+
+        let results = BaseNFormat.parse("base36:zz");
+        assert_eq!(results[0].source_format, "base-n");
+        assert_eq!(results[0].description, "Base 36 zz = 1295 decimal");
+    }
+
+    #[test]
+    fn test_rejects_invalid() {
+        assert_eq!(parsed("base36:"), None);
+        assert_eq!(parsed("base2:102"), None);
+        assert_eq!(parsed("base37:zz"), None);
+        assert_eq!(parsed("base1:0"), None);
+        assert_eq!(parsed("base:10"), None);
+        assert_eq!(parsed("zz"), None);
+    }
+
+    #[test]
+    fn test_to_radix() {
+        assert_eq!(BaseNFormat::to_radix(255, 36).as_deref(), Some("73"));
+        assert_eq!(BaseNFormat::to_radix(255, 2).as_deref(), Some("11111111"));
+        assert_eq!(BaseNFormat::to_radix(-47, 7).as_deref(), Some("-65"));
+        assert_eq!(BaseNFormat::to_radix(0, 5).as_deref(), Some("0"));
+        assert_eq!(
+            BaseNFormat::to_radix(i128::MIN, 2).map(|s| s.len()),
+            Some(129)
+        );
+        assert_eq!(BaseNFormat::to_radix(10, 37), None);
+    }
+}
@@ -182,6 +182,7 @@ impl Format for BinaryFormat {
             display: Self::bytes_to_binary_grouped(bytes),
             path: vec!["binary".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority: ConversionPriority::Encoding,
             // Display-only: the string "01001101 10101010" shouldn't be
@@ -189,6 +190,7 @@ impl Format for BinaryFormat {
             display_only: true,
             kind: ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
@@ -5,7 +5,7 @@
 
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
     RichDisplay, RichDisplayOption,
@@ -346,6 +346,10 @@ impl Format for CharFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["unicode", "codepoint"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
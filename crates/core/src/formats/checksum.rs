@@ -0,0 +1,168 @@
+//! Non-cryptographic checksums of binary data.
+//!
+//! Emits CRC-32, CRC-16/CCITT-FALSE and Adler-32 as traits of byte values,
+//! which helps when matching checksums in firmware images and protocols.
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+};
+
+pub struct ChecksumFormat;
+
+impl ChecksumFormat {
+    /// CRC-32 (ISO-HDLC, as used by zip, PNG and Ethernet).
+    fn crc32(bytes: &[u8]) -> u32 {
+        let mut crc = 0xFFFF_FFFFu32;
+        for &byte in bytes {
+            crc ^= u32::from(byte);
+            for _ in 0..8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+            }
+        }
+        !crc
+    }
+
+    /// CRC-16/CCITT-FALSE (polynomial 0x1021, initial value 0xFFFF).
+    fn crc16_ccitt(bytes: &[u8]) -> u16 {
+        let mut crc = 0xFFFFu16;
+        for &byte in bytes {
+            crc ^= u16::from(byte) << 8;
+            for _ in 0..8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ 0x1021
+                } else {
+                    crc << 1
+                };
+            }
+        }
+        crc
+    }
+
+    /// Adler-32 (zlib).
+    fn adler32(bytes: &[u8]) -> u32 {
+        const MOD_ADLER: u32 = 65_521;
+        let (mut a, mut b) = (1u32, 0u32);
+        for &byte in bytes {
+            a = (a + u32::from(byte)) % MOD_ADLER;
+            b = (b + a) % MOD_ADLER;
+        }
+        (b << 16) | a
+    }
+}
+
+impl Format for ChecksumFormat {
+    fn id(&self) -> &'static str {
+        "checksum"
+    }
+
+    fn name(&self) -> &'static str {
+        "Checksum"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Hashing",
+            description: "CRC-32, CRC-16/CCITT-FALSE and Adler-32 of binary data",
+            examples: &[],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, _input: &str) -> Vec<Interpretation> {
+        // Checksums are computed from bytes, never parsed from input
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Bytes(bytes) = value else {
+            return vec![];
+        };
+        if bytes.is_empty() {
+            return vec![];
+        }
+
+        let checksums = [
+            (
+                "checksum-crc32",
+                format!("CRC-32: 0x{:08X}", Self::crc32(bytes)),
+            ),
+            (
+                "checksum-crc16-ccitt",
+                format!("CRC-16/CCITT: 0x{:04X}", Self::crc16_ccitt(bytes)),
+            ),
+            (
+                "checksum-adler32",
+                format!("Adler-32: 0x{:08X}", Self::adler32(bytes)),
+            ),
+        ];
+
+        checksums
+            .into_iter()
+            .map(|(id, display)| Conversion {
+                value: CoreValue::String(display.clone()),
+                target_format: id.to_string(),
+                display: display.clone(),
+                path: vec![id.to_string()],
+                steps: vec![ConversionStep {
+                    format: id.to_string(),
+                    value: CoreValue::String(display.clone()),
+                    display,
+                }],
+                priority: ConversionPriority::Raw,
+                kind: ConversionKind::Trait,
+                display_only: true,
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["crc", "crc32", "adler32"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_vectors() {
+        // Standard "123456789" check values from the CRC catalogue
+        assert_eq!(ChecksumFormat::crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(ChecksumFormat::crc16_ccitt(b"123456789"), 0x29B1);
+        assert_eq!(ChecksumFormat::adler32(b"123456789"), 0x091E_01DE);
+        assert_eq!(ChecksumFormat::adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn test_trait_conversions() {
+        let conversions = ChecksumFormat.conversions(&CoreValue::Bytes(b"123456789".to_vec()));
+        let displays: Vec<_> = conversions.iter().map(|c| c.display.as_str()).collect();
+        assert_eq!(
+            displays,
+            [
+                "CRC-32: 0xCBF43926",
+                "CRC-16/CCITT: 0x29B1",
+                "Adler-32: 0x091E01DE"
+            ]
+        );
+        assert!(conversions.iter().all(|c| c.kind == ConversionKind::Trait));
+
+        assert!(ChecksumFormat
+            .conversions(&CoreValue::Bytes(vec![]))
+            .is_empty());
+    }
+}
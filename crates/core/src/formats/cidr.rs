@@ -2,10 +2,13 @@
 //!
 //! Input: `192.168.1.0/24`
 //! Output: Network address, netmask, broadcast, host range, host count
+//!
+//! Membership checks: `192.168.1.0/24 contains 192.168.1.42` or
+//! `192.168.1.42 in 192.168.1.0/24`
 
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
     RichDisplay, RichDisplayOption,
@@ -264,6 +267,70 @@ fn build_rich_display_v6(cidr: &CidrV6) -> RichDisplayOption {
     RichDisplayOption::new(RichDisplay::KeyValue { pairs })
 }
 
+// =============================================================================
+// Membership Check
+// =============================================================================
+
+/// Split `<cidr> contains <ip>` or `<ip> in <cidr>` into (cidr, ip).
+fn split_membership(input: &str) -> Option<(&str, &str)> {
+    // ASCII lowercasing keeps byte offsets, so positions carry over to `input`
+    let lower = input.to_ascii_lowercase();
+    if let Some(pos) = lower.find(" contains ") {
+        return Some((
+            input[..pos].trim(),
+            input[pos + " contains ".len()..].trim(),
+        ));
+    }
+    if let Some(pos) = lower.find(" in ") {
+        return Some((input[pos + " in ".len()..].trim(), input[..pos].trim()));
+    }
+    None
+}
+
+/// Check whether an address lies inside a CIDR range.
+///
+/// Both sides must be the same address family.
+fn parse_membership(input: &str) -> Option<Interpretation> {
+    let (cidr, addr) = split_membership(input)?;
+    let addr: IpAddr = addr.parse().ok()?;
+
+    let (network, contains, info) = match addr {
+        IpAddr::V4(addr) => {
+            let cidr = CidrV4::parse(cidr)?;
+            let contains = CidrV4::apply_mask(addr, cidr.prefix) == cidr.network;
+            let network = format!("{}/{}", cidr.network, cidr.prefix);
+            (network, contains, build_rich_display_v4(&cidr))
+        }
+        IpAddr::V6(addr) => {
+            let cidr = CidrV6::parse(cidr)?;
+            let contains = CidrV6::apply_mask(addr, cidr.prefix) == cidr.network;
+            let network = format!("{}/{}", cidr.network, cidr.prefix);
+            (network, contains, build_rich_display_v6(&cidr))
+        }
+    };
+
+    let verdict = if contains { "is in" } else { "is not in" };
+    let pairs = vec![
+        ("address".to_string(), addr.to_string()),
+        ("network".to_string(), network.clone()),
+        (
+            "contains".to_string(),
+            if contains { "yes" } else { "no" }.to_string(),
+        ),
+    ];
+
+    Some(Interpretation {
+        description: format!("{} {} {}", addr, verdict, network),
+        value: CoreValue::String(network),
+        source_format: "cidr".to_string(),
+        confidence: 0.95,
+        rich_display: vec![
+            RichDisplayOption::new(RichDisplay::KeyValue { pairs }),
+            info,
+        ],
+    })
+}
+
 /// Format host count with commas for readability.
 fn format_host_count(count: u64) -> String {
     if count >= 1_000_000_000 {
@@ -298,7 +365,11 @@ impl Format for CidrFormat {
             name: self.name(),
             category: "Network",
             description: "CIDR network ranges with netmask, broadcast, host count",
-            examples: &["192.168.1.0/24", "10.0.0.0/8", "172.16.0.0/12"],
+            examples: &[
+                "192.168.1.0/24",
+                "10.0.0.0/8",
+                "192.168.1.0/24 contains 192.168.1.42",
+            ],
             aliases: self.aliases(),
             has_validation: false,
         }
@@ -307,6 +378,11 @@ impl Format for CidrFormat {
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         let trimmed = input.trim();
 
+        // Try a membership check: 192.168.1.0/24 contains 192.168.1.42
+        if let Some(membership) = parse_membership(trimmed) {
+            return vec![membership];
+        }
+
         // Try IPv4 CIDR
         if let Some(cidr) = CidrV4::parse(trimmed) {
             let host_count = cidr.host_count();
@@ -381,6 +457,10 @@ impl Format for CidrFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["subnet", "network"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 /// Build conversions for IPv4 CIDR.
@@ -631,4 +711,54 @@ mod tests {
         assert!(format.parse("192.168.1.0/33").is_empty()); // Invalid prefix
         assert!(format.parse("hello/24").is_empty()); // Invalid IP
     }
+
+    fn membership_pairs(input: &str) -> Vec<(String, String)> {
+        let results = CidrFormat.parse(input);
+        assert_eq!(results.len(), 1, "{input}");
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[0].preferred else {
+            panic!("Expected KeyValue");
+        };
+        pairs.clone()
+    }
+
+    #[test]
+    fn test_contains_check() {
+        let results = CidrFormat.parse("192.168.1.0/24 contains 192.168.1.42");
+        assert_eq!(results[0].description, "192.168.1.42 is in 192.168.1.0/24");
+        assert!(matches!(&results[0].value, CoreValue::String(s) if s == "192.168.1.0/24"));
+
+        let pairs = membership_pairs("192.168.1.0/24 contains 192.168.1.42");
+        assert!(pairs.contains(&("contains".to_string(), "yes".to_string())));
+
+        let pairs = membership_pairs("192.168.1.0/24 CONTAINS 192.168.2.42");
+        assert!(pairs.contains(&("contains".to_string(), "no".to_string())));
+    }
+
+    #[test]
+    fn test_in_check() {
+        let results = CidrFormat.parse("10.20.30.40 in 10.0.0.0/8");
+        assert_eq!(results[0].description, "10.20.30.40 is in 10.0.0.0/8");
+
+        let results = CidrFormat.parse("2001:db9::1 in 2001:db8::/32");
+        assert_eq!(
+            results[0].description,
+            "2001:db9::1 is not in 2001:db8::/32"
+        );
+    }
+
+    #[test]
+    fn test_membership_includes_network_info() {
+        let results = CidrFormat.parse("192.168.1.0/24 contains 192.168.1.42");
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[1].preferred else {
+            panic!("Expected KeyValue");
+        };
+        assert!(pairs.contains(&("usable hosts".to_string(), "254".to_string())));
+        assert!(pairs.contains(&("broadcast".to_string(), "192.168.1.255".to_string())));
+    }
+
+    #[test]
+    fn test_membership_rejects_mixed_families() {
+        assert!(CidrFormat.parse("192.168.1.0/24 contains ::1").is_empty());
+        assert!(CidrFormat.parse("192.168.1.0/24 contains hello").is_empty());
+    }
 }
@@ -1,9 +1,10 @@
-//! Color format (hex RGB/RGBA/ARGB).
+//! Color format (hex RGB/RGBA/ARGB and CSS named colors).
 
-use crate::format::{Format, FormatInfo};
+use super::css_colors::{color_name, lookup_color};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
-    Conversion, ConversionPriority, ConversionStep, CoreValue, Interpretation, RichDisplay,
-    RichDisplayOption,
+    ColorAlphaOrder, Conversion, ConversionConfig, ConversionKind, ConversionPriority,
+    ConversionStep, CoreValue, Interpretation, RichDisplay, RichDisplayOption,
 };
 
 /// Represents a parsed color with RGBA components.
@@ -195,8 +196,9 @@ impl ColorFormat {
 
     /// Parse a hex color string like #RGB, #RRGGBB, #RRGGBBAA, or #AARRGGBB.
     /// For 6-char and 8-char hex, the # prefix is REQUIRED to avoid false positives
-    /// on strings like "DEADBEEF" or "CAFEBABE".
-    fn parse_hex_color(s: &str) -> Option<(Rgba, &'static str)> {
+    /// on strings like "DEADBEEF" or "CAFEBABE". `alpha_order` decides where
+    /// the alpha channel of 8-char hex is.
+    fn parse_hex_color(s: &str, alpha_order: ColorAlphaOrder) -> Option<(Rgba, &'static str)> {
         let has_prefix = s.starts_with('#');
         let hex = s.strip_prefix('#').unwrap_or(s);
 
@@ -232,16 +234,26 @@ impl ColorFormat {
                 let b1 = u8::from_str_radix(&hex[2..4], 16).ok()?;
                 let b2 = u8::from_str_radix(&hex[4..6], 16).ok()?;
                 let b3 = u8::from_str_radix(&hex[6..8], 16).ok()?;
-                // Return as RGBA, conversions will show ARGB alternative
-                Some((
-                    Rgba {
-                        r: b0,
-                        g: b1,
-                        b: b2,
-                        a: Some(b3),
-                    },
-                    "RGBA",
-                ))
+                Some(match alpha_order {
+                    ColorAlphaOrder::RgbaFirst => (
+                        Rgba {
+                            r: b0,
+                            g: b1,
+                            b: b2,
+                            a: Some(b3),
+                        },
+                        "RGBA",
+                    ),
+                    ColorAlphaOrder::ArgbFirst => (
+                        Rgba {
+                            r: b1,
+                            g: b2,
+                            b: b3,
+                            a: Some(b0),
+                        },
+                        "ARGB",
+                    ),
+                })
             }
             _ => None,
         }
@@ -278,6 +290,113 @@ impl ColorFormat {
         }
     }
 
+    /// Parse a CSS named color ("rebeccapurple", "Tomato").
+    fn parse_named_color(s: &str) -> Option<Rgba> {
+        let s = s.trim();
+        if !s.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let rgb = lookup_color(s)?;
+        Some(Rgba {
+            r: (rgb >> 16) as u8,
+            g: (rgb >> 8) as u8,
+            b: rgb as u8,
+            a: None,
+        })
+    }
+
+    /// Parse a single color in any supported syntax except bare hex.
+    fn parse_any_color(s: &str) -> Option<Rgba> {
+        let s = s.trim();
+        let parsed = if s.starts_with('#') {
+            Self::parse_hex_color(s, ColorAlphaOrder::default())
+        } else {
+            Self::parse_0x_color(s)
+                .or_else(|| Self::parse_rgb_function(s))
+                .or_else(|| Self::parse_hsl_function(s))
+        };
+        parsed
+            .map(|(rgba, _)| rgba)
+            .or_else(|| Self::parse_named_color(s))
+    }
+
+    /// Parse a foreground/background pair: "#000 on #FFF" or "#000 / #FFF".
+    fn parse_color_pair(s: &str) -> Option<(Rgba, Rgba)> {
+        let s = s.trim();
+        let lower = s.to_ascii_lowercase();
+        let (fg, bg) = if let Some(pos) = lower.find(" on ") {
+            (&s[..pos], &s[pos + 4..])
+        } else if s.matches('/').count() == 1 {
+            s.split_once('/')?
+        } else {
+            return None;
+        };
+        Some((Self::parse_any_color(fg)?, Self::parse_any_color(bg)?))
+    }
+
+    /// WCAG 2.1 relative luminance of an sRGB color.
+    fn relative_luminance(r: u8, g: u8, b: u8) -> f64 {
+        let linear = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+    }
+
+    /// WCAG 2.1 contrast ratio (1 to 21) of a foreground on a background.
+    ///
+    /// A translucent foreground is blended over the background first; the
+    /// background's own alpha is ignored.
+    fn contrast_ratio(fg: Rgba, bg: Rgba) -> f64 {
+        let alpha = fg.a.unwrap_or(255) as f64 / 255.0;
+        let blend = |f: u8, b: u8| (f as f64 * alpha + b as f64 * (1.0 - alpha)).round() as u8;
+        let fg_lum =
+            Self::relative_luminance(blend(fg.r, bg.r), blend(fg.g, bg.g), blend(fg.b, bg.b));
+        let bg_lum = Self::relative_luminance(bg.r, bg.g, bg.b);
+
+        let (lighter, darker) = if fg_lum > bg_lum {
+            (fg_lum, bg_lum)
+        } else {
+            (bg_lum, fg_lum)
+        };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// Interpretation reporting the contrast ratio and WCAG AA/AAA results.
+    fn make_contrast_interpretation(fg: Rgba, bg: Rgba) -> Interpretation {
+        let ratio = Self::contrast_ratio(fg, bg);
+        // Round for display only; WCAG thresholds apply to the exact ratio
+        let ratio_display = format!("{}:1", (ratio * 100.0).round() / 100.0);
+        let verdict = |threshold: f64| if ratio >= threshold { "pass" } else { "fail" };
+
+        let pairs = vec![
+            ("Contrast ratio".to_string(), ratio_display.clone()),
+            ("AA normal text".to_string(), verdict(4.5).to_string()),
+            ("AA large text".to_string(), verdict(3.0).to_string()),
+            ("AAA normal text".to_string(), verdict(7.0).to_string()),
+            ("AAA large text".to_string(), verdict(4.5).to_string()),
+        ];
+
+        Interpretation {
+            value: CoreValue::Float(ratio),
+            source_format: "color-contrast".to_string(),
+            confidence: 0.9,
+            description: format!(
+                "Contrast {}: normal text AA {}, AAA {}; large text AA {}, AAA {}",
+                ratio_display,
+                verdict(4.5),
+                verdict(7.0),
+                verdict(3.0),
+                verdict(4.5)
+            ),
+            rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })],
+        }
+    }
+
     fn make_interpretation(
         rgba: Rgba,
         format_hint: &str,
@@ -344,6 +463,33 @@ impl ColorFormat {
 
         ((h * 60.0) as u16, (s * 100.0) as u8, (l * 100.0) as u8)
     }
+
+    /// Convert RGB to HSV (a.k.a. HSB), rounded to whole degrees and percent.
+    fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u16, u8, u8) {
+        let r = r as f64 / 255.0;
+        let g = g as f64 / 255.0;
+        let b = b as f64 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let d = max - min;
+
+        let v = (max * 100.0).round() as u8;
+        if d.abs() < f64::EPSILON {
+            return (0, 0, v);
+        }
+
+        let s = (d / max * 100.0).round() as u8;
+        let h = if (max - r).abs() < f64::EPSILON {
+            (g - b) / d + (if g < b { 6.0 } else { 0.0 })
+        } else if (max - g).abs() < f64::EPSILON {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+
+        ((h * 60.0).round() as u16 % 360, s, v)
+    }
 }
 
 impl Format for ColorFormat {
@@ -360,12 +506,13 @@ impl Format for ColorFormat {
             id: self.id(),
             name: self.name(),
             category: "Colors",
-            description: "Color parsing (hex, rgb(), hsl()) with conversions",
+            description: "Color parsing (hex, rgb(), hsl(), CSS names) with conversions",
             examples: &[
                 "#FF5733",
                 "rgb(255, 87, 51)",
                 "hsl(120, 100%, 50%)",
                 "rgba(255, 128, 0, 0.5)",
+                "rebeccapurple",
             ],
             aliases: self.aliases(),
             has_validation: true,
@@ -373,8 +520,12 @@ impl Format for ColorFormat {
     }
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
-        // Try #RGB / #RRGGBB / #RRGGBBAA
-        if let Some((rgba, format_hint)) = Self::parse_hex_color(input) {
+        self.parse_with_config(input, &ConversionConfig::default())
+    }
+
+    fn parse_with_config(&self, input: &str, config: &ConversionConfig) -> Vec<Interpretation> {
+        // Try #RGB / #RRGGBB / #RRGGBBAA (or #AARRGGBB)
+        if let Some((rgba, format_hint)) = Self::parse_hex_color(input, config.color_alpha_order) {
             return vec![Self::make_interpretation(
                 rgba,
                 format_hint,
@@ -413,6 +564,20 @@ impl Format for ColorFormat {
             )];
         }
 
+        // Try CSS named colors. Many are everyday words ("red", "tan"), so stay
+        // below plain text and the threshold for reinterpreting decoded strings
+        if let Some(rgba) = Self::parse_named_color(input) {
+            let mut interpretation =
+                Self::make_interpretation(rgba, "CSS named color", false, "color-name");
+            interpretation.confidence = 0.69;
+            return vec![interpretation];
+        }
+
+        // Try a foreground/background pair for a WCAG contrast check
+        if let Some((fg, bg)) = Self::parse_color_pair(input) {
+            return vec![Self::make_contrast_interpretation(fg, bg)];
+        }
+
         vec![]
     }
 
@@ -516,15 +681,65 @@ impl Format for ColorFormat {
             }],
             priority: ConversionPriority::Semantic,
             display_only: true,
-            rich_display: color_display,
+            rich_display: color_display.clone(),
+            ..Default::default()
+        });
+
+        // hsv()/hsva() format (HSB in design tools)
+        let (h, s, v) = Self::rgb_to_hsv(r, g, b);
+        let hsv_display = if let Some(alpha) = a {
+            let alpha_f = alpha as f64 / 255.0;
+            format!("hsva({}, {}%, {}%, {:.2})", h, s, v, alpha_f)
+        } else {
+            format!("hsv({}, {}%, {}%)", h, s, v)
+        };
+        conversions.push(Conversion {
+            value: CoreValue::String(hsv_display.clone()),
+            target_format: "color-hsv".to_string(),
+            display: hsv_display.clone(),
+            path: vec!["color-hsv".to_string()],
+            steps: vec![ConversionStep {
+                format: "color-hsv".to_string(),
+                value: CoreValue::String(hsv_display.clone()),
+                display: hsv_display,
+            }],
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Representation,
+            display_only: true,
+            rich_display: color_display.clone(),
             ..Default::default()
         });
 
+        // CSS name, for exact matches of opaque colors
+        let rgb = u32::from_be_bytes([0, r, g, b]);
+        if let Some(name) = color_name(rgb).filter(|_| a.is_none_or(|alpha| alpha == 255)) {
+            conversions.push(Conversion {
+                value: CoreValue::String(name.to_string()),
+                target_format: "color-name".to_string(),
+                display: name.to_string(),
+                path: vec!["color-name".to_string()],
+                steps: vec![ConversionStep {
+                    format: "color-name".to_string(),
+                    value: CoreValue::String(name.to_string()),
+                    display: name.to_string(),
+                }],
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                rich_display: color_display,
+                ..Default::default()
+            });
+        }
+
         conversions
     }
 
     fn aliases(&self) -> &'static [&'static str] {
-        &["col", "rgb", "argb", "hsl"]
+        &["col", "rgb", "argb", "hsl", "css-color"]
+    }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
     }
 
     fn validate(&self, input: &str) -> Option<String> {
@@ -642,6 +857,36 @@ mod tests {
         assert_eq!(l, 100);
     }
 
+    #[test]
+    fn test_rgb_to_hsv() {
+        // Pure red
+        assert_eq!(ColorFormat::rgb_to_hsv(255, 0, 0), (0, 100, 100));
+        // Mid gray
+        assert_eq!(ColorFormat::rgb_to_hsv(128, 128, 128), (0, 0, 50));
+        // Saturated orange (#FF5733)
+        assert_eq!(ColorFormat::rgb_to_hsv(255, 87, 51), (11, 80, 100));
+    }
+
+    #[test]
+    fn test_hsv_conversion_keeps_alpha() {
+        let format = ColorFormat;
+        let hsv = |bytes: Vec<u8>| {
+            format
+                .conversions(&CoreValue::Bytes(bytes))
+                .into_iter()
+                .find(|c| c.target_format == "color-hsv")
+                .unwrap()
+        };
+
+        let opaque = hsv(vec![255, 87, 51]);
+        assert_eq!(opaque.display, "hsv(11, 80%, 100%)");
+        assert_eq!(opaque.kind, ConversionKind::Representation);
+        assert_eq!(
+            hsv(vec![255, 87, 51, 128]).display,
+            "hsva(11, 80%, 100%, 0.50)"
+        );
+    }
+
     #[test]
     fn test_parse_rgb_function() {
         let format = ColorFormat;
@@ -734,6 +979,105 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_alpha_order_argb_first() {
+        let config = ConversionConfig {
+            color_alpha_order: ColorAlphaOrder::ArgbFirst,
+            ..Default::default()
+        };
+        let results = ColorFormat.parse_with_config("#80FF0000", &config);
+        assert_eq!(results.len(), 1);
+        // 50% alpha red rather than a transparent green-yellow
+        assert_eq!(results[0].value, CoreValue::Bytes(vec![255, 0, 0, 128]));
+        assert!(results[0]
+            .description
+            .starts_with("ARGB: RGBA(255, 0, 0, 128)"));
+
+        // CSS order by default
+        let results = ColorFormat.parse("#80FF0000");
+        assert_eq!(results[0].value, CoreValue::Bytes(vec![128, 255, 0, 0]));
+    }
+
+    #[test]
+    fn test_parse_named_colors() {
+        let format = ColorFormat;
+        for (name, hex) in [
+            ("rebeccapurple", "#663399"),
+            ("Tomato", "#FF6347"),
+            ("cornflowerblue", "#6495ED"),
+        ] {
+            let results = format.parse(name);
+            assert_eq!(results.len(), 1, "{} should parse", name);
+            assert_eq!(results[0].source_format, "color-name");
+            assert_eq!(results[0].confidence, 0.69);
+
+            let conversions = format.conversions(&results[0].value);
+            let color_hex = conversions
+                .iter()
+                .find(|c| c.target_format == "color-hex")
+                .unwrap();
+            assert_eq!(color_hex.display, hex);
+        }
+
+        assert!(format.parse("banana").is_empty());
+        assert!(format.parse("dark red").is_empty());
+    }
+
+    #[test]
+    fn test_exact_css_name_conversion() {
+        let format = ColorFormat;
+        let name = |bytes: Vec<u8>| {
+            format
+                .conversions(&CoreValue::Bytes(bytes))
+                .into_iter()
+                .find(|c| c.target_format == "color-name")
+                .map(|c| c.display)
+        };
+
+        assert_eq!(
+            name(vec![0x66, 0x33, 0x99]).as_deref(),
+            Some("rebeccapurple")
+        );
+        assert_eq!(name(vec![0x00, 0xFF, 0xFF]).as_deref(), Some("aqua"));
+        assert_eq!(name(vec![0x66, 0x33, 0x98]), None);
+        // Translucent colors aren't the named color
+        assert_eq!(name(vec![0x66, 0x33, 0x99, 0x80]), None);
+    }
+
+    #[test]
+    fn test_contrast_black_on_white() {
+        let results = ColorFormat.parse("#000000 on #FFFFFF");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source_format, "color-contrast");
+        assert!(matches!(results[0].value, CoreValue::Float(r) if r == 21.0));
+
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[0].preferred else {
+            panic!("Expected KeyValue");
+        };
+        assert_eq!(pairs[0], ("Contrast ratio".to_string(), "21:1".to_string()));
+        assert!(pairs[1..].iter().all(|(_, verdict)| verdict == "pass"));
+
+        // Slash form and names, in either order
+        let results = ColorFormat.parse("white / black");
+        assert!(matches!(results[0].value, CoreValue::Float(r) if r == 21.0));
+    }
+
+    #[test]
+    fn test_contrast_low_pair_fails() {
+        // #777 on white is a classic near miss at 4.48:1
+        let results = ColorFormat.parse("#777 on #fff");
+        assert_eq!(
+            results[0].description,
+            "Contrast 4.48:1: normal text AA fail, AAA fail; large text AA pass, AAA fail"
+        );
+
+        let results = ColorFormat.parse("#999999 / #AAAAAA");
+        assert!(results[0].description.contains("large text AA fail"));
+
+        assert!(ColorFormat.parse("abc/def").is_empty());
+        assert!(ColorFormat.parse("#000 on nothing").is_empty());
+    }
+
     #[test]
     fn test_hex_without_prefix_not_color() {
         // 6-char and 8-char hex without # prefix should NOT be parsed as color
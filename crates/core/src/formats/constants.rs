@@ -3,7 +3,7 @@
 //! - Number → Name: Show as trait on integers (e.g., `443` → "HTTPS port")
 //! - Name → Number: Parse strings (e.g., `ESC` → 27, `ssh` → 22)
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
     RichDisplay, RichDisplayOption,
@@ -17,7 +17,7 @@ struct Constant {
     aliases: &'static [&'static str],
     /// The integer value
     value: i128,
-    /// Display text when showing as trait (e.g., "HTTPS (port 443/tcp)")
+    /// Display text when showing as trait (e.g., "HTTPS (port 443/tcp, well-known)")
     trait_display: &'static str,
     /// Display text when parsed from name (e.g., "port 443/tcp (HTTPS)")
     parse_display: &'static str,
@@ -137,82 +137,85 @@ const HTTP_STATUS: &[Constant] = &[
 ];
 
 /// Well-known TCP/UDP ports - the "greatest hits" that developers look up.
+///
+/// Trait displays include the IANA range class (RFC 6335): well-known
+/// (0-1023), registered (1024-49151) or dynamic (49152-65535).
 const PORTS: &[Constant] = &[
     Constant {
         name: "ftp",
         aliases: &[],
         value: 21,
-        trait_display: "FTP (port 21/tcp)",
+        trait_display: "FTP (port 21/tcp, well-known)",
         parse_display: "port 21/tcp (FTP)",
     },
     Constant {
         name: "ssh",
         aliases: &[],
         value: 22,
-        trait_display: "SSH (port 22/tcp)",
+        trait_display: "SSH (port 22/tcp, well-known)",
         parse_display: "port 22/tcp (SSH)",
     },
     Constant {
         name: "telnet",
         aliases: &[],
         value: 23,
-        trait_display: "Telnet (port 23/tcp)",
+        trait_display: "Telnet (port 23/tcp, well-known)",
         parse_display: "port 23/tcp (Telnet)",
     },
     Constant {
         name: "smtp",
         aliases: &[],
         value: 25,
-        trait_display: "SMTP (port 25/tcp)",
+        trait_display: "SMTP (port 25/tcp, well-known)",
         parse_display: "port 25/tcp (SMTP)",
     },
     Constant {
         name: "dns",
         aliases: &[],
         value: 53,
-        trait_display: "DNS (port 53/udp)",
+        trait_display: "DNS (port 53/udp, well-known)",
         parse_display: "port 53/udp (DNS)",
     },
     Constant {
         name: "http",
         aliases: &[],
         value: 80,
-        trait_display: "HTTP (port 80/tcp)",
+        trait_display: "HTTP (port 80/tcp, well-known)",
         parse_display: "port 80/tcp (HTTP)",
     },
     Constant {
         name: "https",
         aliases: &[],
         value: 443,
-        trait_display: "HTTPS (port 443/tcp)",
+        trait_display: "HTTPS (port 443/tcp, well-known)",
         parse_display: "port 443/tcp (HTTPS)",
     },
     Constant {
         name: "mysql",
         aliases: &[],
         value: 3306,
-        trait_display: "MySQL (port 3306/tcp)",
+        trait_display: "MySQL (port 3306/tcp, registered)",
         parse_display: "port 3306/tcp (MySQL)",
     },
     Constant {
         name: "postgres",
         aliases: &["postgresql"],
         value: 5432,
-        trait_display: "PostgreSQL (port 5432/tcp)",
+        trait_display: "PostgreSQL (port 5432/tcp, registered)",
         parse_display: "port 5432/tcp (PostgreSQL)",
     },
     Constant {
         name: "redis",
         aliases: &[],
         value: 6379,
-        trait_display: "Redis (port 6379/tcp)",
+        trait_display: "Redis (port 6379/tcp, registered)",
         parse_display: "port 6379/tcp (Redis)",
     },
     Constant {
         name: "mongodb",
         aliases: &["mongo"],
         value: 27017,
-        trait_display: "MongoDB (port 27017/tcp)",
+        trait_display: "MongoDB (port 27017/tcp, registered)",
         parse_display: "port 27017/tcp (MongoDB)",
     },
 ];
@@ -223,21 +226,21 @@ const DEV_PORTS: &[Constant] = &[
         name: "dev",
         aliases: &["vite", "react"],
         value: 3000,
-        trait_display: "dev server (port 3000)",
+        trait_display: "dev server (port 3000, registered)",
         parse_display: "port 3000 (dev server)",
     },
     Constant {
         name: "alt-http",
         aliases: &[],
         value: 8080,
-        trait_display: "HTTP alt (port 8080)",
+        trait_display: "HTTP alt (port 8080, registered)",
         parse_display: "port 8080 (HTTP alt)",
     },
     Constant {
         name: "alt-https",
         aliases: &[],
         value: 8443,
-        trait_display: "HTTPS alt (port 8443)",
+        trait_display: "HTTPS alt (port 8443, registered)",
         parse_display: "port 8443 (HTTPS alt)",
     },
 ];
@@ -556,6 +559,183 @@ const MAGIC_NUMBERS: &[Constant] = &[
     },
 ];
 
+/// Integer type limits and the power-of-two boundaries just past them.
+const INTEGER_LIMITS: &[Constant] = &[
+    Constant {
+        name: "i8::MAX",
+        aliases: &["INT8_MAX", "SCHAR_MAX"],
+        value: 127,
+        trait_display: "i8::MAX",
+        parse_display: "127 (i8::MAX)",
+    },
+    Constant {
+        name: "i8::MIN",
+        aliases: &["INT8_MIN", "SCHAR_MIN"],
+        value: -128,
+        trait_display: "i8::MIN",
+        parse_display: "-128 (i8::MIN)",
+    },
+    Constant {
+        name: "u8::MAX",
+        aliases: &["UINT8_MAX", "UCHAR_MAX"],
+        value: 255,
+        trait_display: "u8::MAX",
+        parse_display: "255 (u8::MAX)",
+    },
+    Constant {
+        name: "i16::MAX",
+        aliases: &["INT16_MAX", "SHRT_MAX"],
+        value: 32_767,
+        trait_display: "i16::MAX",
+        parse_display: "32767 (i16::MAX)",
+    },
+    Constant {
+        name: "i16::MIN",
+        aliases: &["INT16_MIN", "SHRT_MIN"],
+        value: -32_768,
+        trait_display: "i16::MIN",
+        parse_display: "-32768 (i16::MIN)",
+    },
+    Constant {
+        name: "u16::MAX",
+        aliases: &["UINT16_MAX", "USHRT_MAX"],
+        value: 65_535,
+        trait_display: "u16::MAX",
+        parse_display: "65535 (u16::MAX)",
+    },
+    Constant {
+        name: "u16 overflow",
+        aliases: &[],
+        value: 65_536,
+        trait_display: "2^16 (u16 overflow)",
+        parse_display: "65536 (2^16)",
+    },
+    Constant {
+        name: "i32::MAX",
+        aliases: &["INT32_MAX", "INT_MAX"],
+        value: 2_147_483_647,
+        trait_display: "i32::MAX (INT_MAX)",
+        parse_display: "2147483647 (i32::MAX)",
+    },
+    Constant {
+        name: "i32 overflow",
+        aliases: &[],
+        value: 2_147_483_648,
+        trait_display: "2^31 (i32 overflow)",
+        parse_display: "2147483648 (2^31)",
+    },
+    Constant {
+        name: "i32::MIN",
+        aliases: &["INT32_MIN", "INT_MIN"],
+        value: -2_147_483_648,
+        trait_display: "i32::MIN (INT_MIN)",
+        parse_display: "-2147483648 (i32::MIN)",
+    },
+    Constant {
+        name: "u32::MAX",
+        aliases: &["UINT32_MAX", "UINT_MAX"],
+        value: 4_294_967_295,
+        trait_display: "u32::MAX (UINT_MAX)",
+        parse_display: "4294967295 (u32::MAX)",
+    },
+    Constant {
+        name: "u32 overflow",
+        aliases: &[],
+        value: 4_294_967_296,
+        trait_display: "2^32 (u32 overflow)",
+        parse_display: "4294967296 (2^32)",
+    },
+    Constant {
+        name: "MAX_SAFE_INTEGER",
+        aliases: &["Number.MAX_SAFE_INTEGER"],
+        value: 9_007_199_254_740_991,
+        trait_display: "JavaScript MAX_SAFE_INTEGER (2^53 - 1)",
+        parse_display: "9007199254740991 (JavaScript Number.MAX_SAFE_INTEGER)",
+    },
+    Constant {
+        name: "MIN_SAFE_INTEGER",
+        aliases: &["Number.MIN_SAFE_INTEGER"],
+        value: -9_007_199_254_740_991,
+        trait_display: "JavaScript MIN_SAFE_INTEGER (-(2^53 - 1))",
+        parse_display: "-9007199254740991 (JavaScript Number.MIN_SAFE_INTEGER)",
+    },
+    Constant {
+        name: "i64::MAX",
+        aliases: &["INT64_MAX", "LLONG_MAX"],
+        value: 9_223_372_036_854_775_807,
+        trait_display: "i64::MAX",
+        parse_display: "9223372036854775807 (i64::MAX)",
+    },
+    Constant {
+        name: "i64 overflow",
+        aliases: &[],
+        value: 9_223_372_036_854_775_808,
+        trait_display: "2^63 (i64 overflow)",
+        parse_display: "9223372036854775808 (2^63)",
+    },
+    Constant {
+        name: "i64::MIN",
+        aliases: &["INT64_MIN", "LLONG_MIN"],
+        value: -9_223_372_036_854_775_808,
+        trait_display: "i64::MIN",
+        parse_display: "-9223372036854775808 (i64::MIN)",
+    },
+    Constant {
+        name: "u64::MAX",
+        aliases: &["UINT64_MAX", "ULLONG_MAX"],
+        value: 18_446_744_073_709_551_615,
+        trait_display: "u64::MAX",
+        parse_display: "18446744073709551615 (u64::MAX)",
+    },
+];
+
+/// RFC 1918 private IPv4 range boundaries, as the 32-bit integers found in
+/// databases and packet headers.
+const PRIVATE_IPV4_BOUNDARIES: &[Constant] = &[
+    Constant {
+        name: "10.0.0.0/8 start",
+        aliases: &[],
+        value: 0x0A00_0000,
+        trait_display: "IPv4 10.0.0.0 (RFC 1918 private range start)",
+        parse_display: "167772160 (IPv4 10.0.0.0)",
+    },
+    Constant {
+        name: "10.0.0.0/8 end",
+        aliases: &[],
+        value: 0x0AFF_FFFF,
+        trait_display: "IPv4 10.255.255.255 (RFC 1918 private range end)",
+        parse_display: "184549375 (IPv4 10.255.255.255)",
+    },
+    Constant {
+        name: "172.16.0.0/12 start",
+        aliases: &[],
+        value: 0xAC10_0000,
+        trait_display: "IPv4 172.16.0.0 (RFC 1918 private range start)",
+        parse_display: "2886729728 (IPv4 172.16.0.0)",
+    },
+    Constant {
+        name: "172.16.0.0/12 end",
+        aliases: &[],
+        value: 0xAC1F_FFFF,
+        trait_display: "IPv4 172.31.255.255 (RFC 1918 private range end)",
+        parse_display: "2887778303 (IPv4 172.31.255.255)",
+    },
+    Constant {
+        name: "192.168.0.0/16 start",
+        aliases: &[],
+        value: 0xC0A8_0000,
+        trait_display: "IPv4 192.168.0.0 (RFC 1918 private range start)",
+        parse_display: "3232235520 (IPv4 192.168.0.0)",
+    },
+    Constant {
+        name: "192.168.0.0/16 end",
+        aliases: &[],
+        value: 0xC0A8_FFFF,
+        trait_display: "IPv4 192.168.255.255 (RFC 1918 private range end)",
+        parse_display: "3232301055 (IPv4 192.168.255.255)",
+    },
+];
+
 // =============================================================================
 // Helper Functions
 // =============================================================================
@@ -572,6 +752,8 @@ fn find_constants_for_value(value: i128) -> Vec<&'static Constant> {
         ASCII_CTRL,
         EXIT_CODES,
         MAGIC_NUMBERS,
+        INTEGER_LIMITS,
+        PRIVATE_IPV4_BOUNDARIES,
     ] {
         for constant in list {
             if constant.value == value {
@@ -595,6 +777,8 @@ fn find_constant_by_name(name: &str) -> Option<&'static Constant> {
         ASCII_CTRL,
         EXIT_CODES,
         MAGIC_NUMBERS,
+        INTEGER_LIMITS,
+        PRIVATE_IPV4_BOUNDARIES,
     ] {
         for constant in list {
             // Match canonical name
@@ -633,7 +817,8 @@ impl Format for ConstantsFormat {
             id: self.id(),
             name: self.name(),
             category: "Reference",
-            description: "Bidirectional lookup for HTTP status codes, ports, signals, ASCII codes",
+            description:
+                "Bidirectional lookup for HTTP status codes, ports, signals, ASCII codes, magic numbers and integer limits",
             examples: &["ssh", "ESC", "Not Found", "SIGKILL"],
             aliases: self.aliases(),
             has_validation: false,
@@ -712,6 +897,10 @@ impl Format for ConstantsFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["const", "lookup"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
@@ -775,7 +964,21 @@ mod tests {
         };
         let conversions = format.conversions(&value);
         assert_eq!(conversions.len(), 1);
-        assert!(conversions[0].display.contains("HTTPS"));
+        assert_eq!(conversions[0].display, "HTTPS (port 443/tcp, well-known)");
+    }
+
+    #[test]
+    fn test_trait_port_range_class() {
+        let format = ConstantsFormat;
+        let value = CoreValue::Int {
+            value: 5432,
+            original_bytes: None,
+        };
+        let conversions = format.conversions(&value);
+        assert_eq!(
+            conversions[0].display,
+            "PostgreSQL (port 5432/tcp, registered)"
+        );
     }
 
     #[test]
@@ -871,4 +1074,63 @@ mod tests {
         assert!(conversions[0].display.contains("hexspeak"));
         assert!(conversions[0].display.contains("cafe"));
     }
+
+    // Integer limit tests
+    fn trait_display(value: i128) -> Option<String> {
+        ConstantsFormat
+            .conversions(&CoreValue::Int {
+                value,
+                original_bytes: None,
+            })
+            .first()
+            .map(|c| c.display.clone())
+    }
+
+    #[test]
+    fn test_trait_integer_limits() {
+        assert_eq!(
+            trait_display(2_147_483_647).as_deref(),
+            Some("i32::MAX (INT_MAX)")
+        );
+        assert_eq!(
+            trait_display(-2_147_483_648).as_deref(),
+            Some("i32::MIN (INT_MIN)")
+        );
+        assert_eq!(
+            trait_display(4_294_967_296).as_deref(),
+            Some("2^32 (u32 overflow)")
+        );
+        assert_eq!(trait_display(u64::MAX.into()).as_deref(), Some("u64::MAX"));
+        // Shares its value with an ASCII control character
+        assert_eq!(
+            trait_display(127).as_deref(),
+            Some("DEL (ASCII 127), i8::MAX")
+        );
+    }
+
+    #[test]
+    fn test_trait_ipv4_boundaries() {
+        assert_eq!(
+            trait_display(3_232_235_520).as_deref(),
+            Some("IPv4 192.168.0.0 (RFC 1918 private range start)")
+        );
+        assert_eq!(
+            trait_display(2_887_778_303).as_deref(),
+            Some("IPv4 172.31.255.255 (RFC 1918 private range end)")
+        );
+    }
+
+    #[test]
+    fn test_parse_int_max() {
+        let results = ConstantsFormat.parse("INT_MAX");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].value,
+            CoreValue::Int {
+                value: 2_147_483_647,
+                original_bytes: None,
+            }
+        );
+        assert_eq!(results[0].description, "2147483647 (i32::MAX)");
+    }
 }
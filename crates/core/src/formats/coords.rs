@@ -9,12 +9,14 @@
 //! - UTM: `18T 585506 4472274`
 //! - MGRS: `18TWL8550607274`
 //! - SWEREF 99 TM: `N 6580822, E 674032`
+//! - Maidenhead locator: `JO99ah` or `JO99ah89`
+//! - British National Grid (OSGB36): `TQ 30 80` or `TQ301805`
 
 use geoconvert::{LatLon, Mgrs, UtmUps};
 use regex::Regex;
 use std::sync::OnceLock;
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
     RichDisplay, RichDisplayOption,
@@ -31,10 +33,33 @@ const SWEREF99_SCALE_FACTOR: f64 = 0.9996;
 const SWEREF99_FALSE_EASTING: f64 = 500_000.0;
 const SWEREF99_FALSE_NORTHING: f64 = 0.0;
 
+/// Area of use for SWEREF 99 TM (EPSG:3006) as (min, max) latitude and longitude.
+/// Outside this zone the projection's scale error grows quickly.
+const SWEREF99_LAT_RANGE: (f64, f64) = (54.96, 69.07);
+const SWEREF99_LON_RANGE: (f64, f64) = (10.03, 24.17);
+
 // WGS84 ellipsoid parameters
 const WGS84_A: f64 = 6_378_137.0; // Semi-major axis
 const WGS84_F: f64 = 1.0 / 298.257_223_563; // Flattening
 
+// British National Grid (EPSG:27700): Transverse Mercator on the Airy 1830 ellipsoid
+const AIRY1830_A: f64 = 6_377_563.396;
+const AIRY1830_B: f64 = 6_356_256.909;
+const BNG_SCALE_FACTOR: f64 = 0.999_601_271_7;
+const BNG_ORIGIN_LAT: f64 = 49.0;
+const BNG_ORIGIN_LON: f64 = -2.0;
+const BNG_FALSE_EASTING: f64 = 400_000.0;
+const BNG_FALSE_NORTHING: f64 = -100_000.0;
+
+/// Extent of the grid's lettered 100km squares as (max easting, max northing).
+const BNG_EXTENT: (f64, f64) = (700_000.0, 1_300_000.0);
+
+/// Helmert transform from WGS84 to OSGB36 (Ordnance Survey, accurate to ~5m):
+/// translation tx, ty, tz (m), scale (ppm), rotation rx, ry, rz (arcseconds).
+const WGS84_TO_OSGB36: [f64; 7] = [
+    -446.448, 125.157, -542.060, 20.4894, -0.1502, -0.2470, -0.8421,
+];
+
 /// Regex patterns for coordinate parsing.
 fn patterns() -> &'static CoordPatterns {
     static PATTERNS: OnceLock<CoordPatterns> = OnceLock::new();
@@ -56,6 +81,10 @@ struct CoordPatterns {
     sweref: Regex,
     /// Plus Code: 87G8P27Q+VF or 8FVC9G8F+6X
     plus_code: Regex,
+    /// Maidenhead: JO99ah or JO99ah89 (field, square, subsquare, extended)
+    maidenhead: Regex,
+    /// British National Grid: TQ 30 80, TQ 301 805 or TQ301805
+    bng: Regex,
 }
 
 impl CoordPatterns {
@@ -101,6 +130,16 @@ impl CoordPatterns {
                 r"^(?P<code>[23456789CFGHJMPQRVWX]{4,8}\+[23456789CFGHJMPQRVWX]{2,3})$",
             )
             .unwrap(),
+
+            // Maidenhead: 6 or 8 characters (4-char squares are too collision-prone)
+            maidenhead: Regex::new(r"(?i)^[A-R]{2}\d{2}[A-X]{2}(?:\d{2})?$").unwrap(),
+
+            // BNG: 100km square letters + easting/northing digits. Unlike MGRS
+            // there's no zone number, and only squares over Great Britain
+            bng: Regex::new(
+                r"(?i)^(?P<square>[HJNOST][A-HJ-Z])\s*(?:(?P<easting>\d{2,5})\s+(?P<northing>\d{2,5})|(?P<digits>\d{4}|\d{6}|\d{8}|\d{10}))$",
+            )
+            .unwrap(),
         }
     }
 }
@@ -195,6 +234,253 @@ impl CoordsFormat {
         ))
     }
 
+    /// Parse Maidenhead locator (amateur radio grid square).
+    /// Returns the center of the locator's cell.
+    fn parse_maidenhead(input: &str) -> Option<(f64, f64, String)> {
+        if !patterns().maidenhead.is_match(input) {
+            return None;
+        }
+
+        // "AB12cd" is valid hex - leave those to the hex format
+        if input.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let chars: Vec<u8> = input.to_ascii_uppercase().into_bytes();
+        let mut lon = f64::from(chars[0] - b'A') * 20.0 + f64::from(chars[2] - b'0') * 2.0;
+        let mut lat = f64::from(chars[1] - b'A') * 10.0 + f64::from(chars[3] - b'0');
+        lon += f64::from(chars[4] - b'A') * 2.0 / 24.0;
+        lat += f64::from(chars[5] - b'A') / 24.0;
+
+        let (lon_cell, lat_cell) = if chars.len() == 8 {
+            lon += f64::from(chars[6] - b'0') * 2.0 / 240.0;
+            lat += f64::from(chars[7] - b'0') / 240.0;
+            (2.0 / 240.0, 1.0 / 240.0)
+        } else {
+            (2.0 / 24.0, 1.0 / 24.0)
+        };
+
+        let lat = lat + lat_cell / 2.0 - 90.0;
+        let lon = lon + lon_cell / 2.0 - 180.0;
+
+        Some((lat, lon, "Maidenhead".to_string()))
+    }
+
+    /// Parse a British National Grid reference.
+    /// Returns the center of the referenced grid square.
+    fn parse_bng(input: &str) -> Option<(f64, f64, String)> {
+        let caps = patterns().bng.captures(input)?;
+
+        let (easting, northing) = match caps.name("digits") {
+            Some(digits) => digits.as_str().split_at(digits.as_str().len() / 2),
+            None => (
+                caps.name("easting")?.as_str(),
+                caps.name("northing")?.as_str(),
+            ),
+        };
+        if easting.len() != northing.len() {
+            return None;
+        }
+
+        let (square_e, square_n) = Self::bng_square_origin(caps.name("square")?.as_str())?;
+        let cell = 10f64.powi(5 - easting.len() as i32);
+        let easting = square_e + easting.parse::<f64>().ok()? * cell + cell / 2.0;
+        let northing = square_n + northing.parse::<f64>().ok()? * cell + cell / 2.0;
+
+        let (lat, lon) = Self::bng_to_osgb36(easting, northing);
+        let (lat, lon) = Self::osgb36_to_wgs84(lat, lon);
+
+        Self::is_valid_lat_lon(lat, lon).then(|| (lat, lon, "British National Grid".to_string()))
+    }
+
+    /// Easting and northing of the south-west corner of a 100km square ("TQ").
+    fn bng_square_origin(square: &str) -> Option<(f64, f64)> {
+        // Letters index a 5x5 grid without I
+        let index = |c: u8| {
+            let i = i32::from(c.to_ascii_uppercase() - b'A');
+            if i > 7 {
+                i - 1
+            } else {
+                i
+            }
+        };
+        let bytes = square.as_bytes();
+        let (l1, l2) = (index(*bytes.first()?), index(*bytes.get(1)?));
+
+        let e100k = ((l1 - 2) % 5) * 5 + l2 % 5;
+        let n100k = (19 - (l1 / 5) * 5) - l2 / 5;
+        Some((f64::from(e100k) * 100_000.0, f64::from(n100k) * 100_000.0))
+    }
+
+    /// Meridional arc on the Airy 1830 ellipsoid, scaled by the grid's scale factor.
+    fn bng_meridional_arc(phi: f64) -> f64 {
+        let n = (AIRY1830_A - AIRY1830_B) / (AIRY1830_A + AIRY1830_B);
+        let phi0 = BNG_ORIGIN_LAT.to_radians();
+        let (d, sum) = (phi - phi0, phi + phi0);
+
+        AIRY1830_B
+            * BNG_SCALE_FACTOR
+            * ((1.0 + n + 5.0 / 4.0 * n.powi(2) + 5.0 / 4.0 * n.powi(3)) * d
+                - (3.0 * n + 3.0 * n.powi(2) + 21.0 / 8.0 * n.powi(3)) * d.sin() * sum.cos()
+                + (15.0 / 8.0 * n.powi(2) + 15.0 / 8.0 * n.powi(3))
+                    * (2.0 * d).sin()
+                    * (2.0 * sum).cos()
+                - 35.0 / 24.0 * n.powi(3) * (3.0 * d).sin() * (3.0 * sum).cos())
+    }
+
+    /// Radii of curvature (ν, ρ) on the Airy 1830 ellipsoid, scaled by the grid's scale factor.
+    fn bng_radii(phi: f64) -> (f64, f64) {
+        let e2 = 1.0 - AIRY1830_B.powi(2) / AIRY1830_A.powi(2);
+        let denom = 1.0 - e2 * phi.sin().powi(2);
+        let nu = AIRY1830_A * BNG_SCALE_FACTOR / denom.sqrt();
+        let rho = AIRY1830_A * BNG_SCALE_FACTOR * (1.0 - e2) / denom.powf(1.5);
+        (nu, rho)
+    }
+
+    /// Project OSGB36 latitude/longitude to grid (easting, northing).
+    /// Formulas from the Ordnance Survey's "A guide to coordinate systems in Great Britain".
+    fn osgb36_to_bng(lat: f64, lon: f64) -> (f64, f64) {
+        let phi = lat.to_radians();
+        let (nu, rho) = Self::bng_radii(phi);
+        let eta2 = nu / rho - 1.0;
+        let (sin, cos, tan) = (phi.sin(), phi.cos(), phi.tan());
+        let tan2 = tan.powi(2);
+
+        let i = Self::bng_meridional_arc(phi) + BNG_FALSE_NORTHING;
+        let ii = nu / 2.0 * sin * cos;
+        let iii = nu / 24.0 * sin * cos.powi(3) * (5.0 - tan2 + 9.0 * eta2);
+        let iiia = nu / 720.0 * sin * cos.powi(5) * (61.0 - 58.0 * tan2 + tan2.powi(2));
+        let iv = nu * cos;
+        let v = nu / 6.0 * cos.powi(3) * (nu / rho - tan2);
+        let vi = nu / 120.0
+            * cos.powi(5)
+            * (5.0 - 18.0 * tan2 + tan2.powi(2) + 14.0 * eta2 - 58.0 * tan2 * eta2);
+
+        let dl = lon.to_radians() - BNG_ORIGIN_LON.to_radians();
+        let northing = i + ii * dl.powi(2) + iii * dl.powi(4) + iiia * dl.powi(6);
+        let easting = BNG_FALSE_EASTING + iv * dl + v * dl.powi(3) + vi * dl.powi(5);
+        (easting, northing)
+    }
+
+    /// Convert grid (easting, northing) to OSGB36 latitude/longitude.
+    fn bng_to_osgb36(easting: f64, northing: f64) -> (f64, f64) {
+        // Find the latitude whose meridional arc matches the northing
+        let mut phi = BNG_ORIGIN_LAT.to_radians();
+        let mut m = 0.0;
+        loop {
+            phi += (northing - BNG_FALSE_NORTHING - m) / (AIRY1830_A * BNG_SCALE_FACTOR);
+            m = Self::bng_meridional_arc(phi);
+            if (northing - BNG_FALSE_NORTHING - m).abs() < 0.000_01 {
+                break;
+            }
+        }
+
+        let (nu, rho) = Self::bng_radii(phi);
+        let eta2 = nu / rho - 1.0;
+        let (tan, sec) = (phi.tan(), 1.0 / phi.cos());
+        let tan2 = tan.powi(2);
+
+        let vii = tan / (2.0 * rho * nu);
+        let viii = tan / (24.0 * rho * nu.powi(3)) * (5.0 + 3.0 * tan2 + eta2 - 9.0 * tan2 * eta2);
+        let ix = tan / (720.0 * rho * nu.powi(5)) * (61.0 + 90.0 * tan2 + 45.0 * tan2.powi(2));
+        let x = sec / nu;
+        let xi = sec / (6.0 * nu.powi(3)) * (nu / rho + 2.0 * tan2);
+        let xii = sec / (120.0 * nu.powi(5)) * (5.0 + 28.0 * tan2 + 24.0 * tan2.powi(2));
+        let xiia = sec / (5040.0 * nu.powi(7))
+            * (61.0 + 662.0 * tan2 + 1320.0 * tan2.powi(2) + 720.0 * tan2.powi(3));
+
+        let de = easting - BNG_FALSE_EASTING;
+        let lat = phi - vii * de.powi(2) + viii * de.powi(4) - ix * de.powi(6);
+        let lon = BNG_ORIGIN_LON.to_radians() + x * de - xi * de.powi(3) + xii * de.powi(5)
+            - xiia * de.powi(7);
+        (lat.to_degrees(), lon.to_degrees())
+    }
+
+    /// Convert latitude/longitude (at height 0) to geocentric cartesian coordinates.
+    fn to_cartesian(lat: f64, lon: f64, a: f64, b: f64) -> [f64; 3] {
+        let (phi, lambda) = (lat.to_radians(), lon.to_radians());
+        let e2 = 1.0 - b.powi(2) / a.powi(2);
+        let nu = a / (1.0 - e2 * phi.sin().powi(2)).sqrt();
+        [
+            nu * phi.cos() * lambda.cos(),
+            nu * phi.cos() * lambda.sin(),
+            nu * (1.0 - e2) * phi.sin(),
+        ]
+    }
+
+    /// Convert geocentric cartesian coordinates to latitude/longitude.
+    fn from_cartesian([x, y, z]: [f64; 3], a: f64, b: f64) -> (f64, f64) {
+        let e2 = 1.0 - b.powi(2) / a.powi(2);
+        let p = x.hypot(y);
+        let mut phi = z.atan2(p * (1.0 - e2));
+        for _ in 0..10 {
+            let nu = a / (1.0 - e2 * phi.sin().powi(2)).sqrt();
+            phi = (z + e2 * nu * phi.sin()).atan2(p);
+        }
+        (phi.to_degrees(), y.atan2(x).to_degrees())
+    }
+
+    /// Apply a 7-parameter Helmert transform (or its inverse) to cartesian coordinates.
+    fn helmert([x, y, z]: [f64; 3], params: [f64; 7], inverse: bool) -> [f64; 3] {
+        let sign = if inverse { -1.0 } else { 1.0 };
+        let [tx, ty, tz, s, rx, ry, rz] = params.map(|p| p * sign);
+        let s = 1.0 + s * 1e-6;
+        let arcsec = |r: f64| (r / 3600.0).to_radians();
+        let (rx, ry, rz) = (arcsec(rx), arcsec(ry), arcsec(rz));
+        [
+            tx + s * x - rz * y + ry * z,
+            ty + rz * x + s * y - rx * z,
+            tz - ry * x + rx * y + s * z,
+        ]
+    }
+
+    /// Convert WGS84 latitude/longitude to OSGB36.
+    fn wgs84_to_osgb36(lat: f64, lon: f64) -> (f64, f64) {
+        let wgs84_b = WGS84_A * (1.0 - WGS84_F);
+        let cartesian = Self::to_cartesian(lat, lon, WGS84_A, wgs84_b);
+        let osgb = Self::helmert(cartesian, WGS84_TO_OSGB36, false);
+        Self::from_cartesian(osgb, AIRY1830_A, AIRY1830_B)
+    }
+
+    /// Convert OSGB36 latitude/longitude to WGS84.
+    fn osgb36_to_wgs84(lat: f64, lon: f64) -> (f64, f64) {
+        let wgs84_b = WGS84_A * (1.0 - WGS84_F);
+        let cartesian = Self::to_cartesian(lat, lon, AIRY1830_A, AIRY1830_B);
+        let wgs84 = Self::helmert(cartesian, WGS84_TO_OSGB36, true);
+        Self::from_cartesian(wgs84, WGS84_A, wgs84_b)
+    }
+
+    /// Format as a 1m British National Grid reference ("TQ 38890 77234").
+    /// Returns `None` outside the grid's lettered squares.
+    fn format_bng(lat: f64, lon: f64) -> Option<String> {
+        let (lat, lon) = Self::wgs84_to_osgb36(lat, lon);
+        let (easting, northing) = Self::osgb36_to_bng(lat, lon);
+        if !(0.0..BNG_EXTENT.0).contains(&easting) || !(0.0..BNG_EXTENT.1).contains(&northing) {
+            return None;
+        }
+
+        // Grid references truncate rather than round
+        let (e, n) = (easting.floor() as i32, northing.floor() as i32);
+        let (e100k, n100k) = (e / 100_000, n / 100_000);
+        let mut l1 = (19 - n100k) - (19 - n100k) % 5 + (e100k + 10) / 5;
+        let mut l2 = ((19 - n100k) * 5) % 25 + e100k % 5;
+        // Skip I
+        if l1 > 7 {
+            l1 += 1;
+        }
+        if l2 > 7 {
+            l2 += 1;
+        }
+
+        Some(format!(
+            "{}{} {:05} {:05}",
+            char::from(b'A' + l1 as u8),
+            char::from(b'A' + l2 as u8),
+            e % 100_000,
+            n % 100_000
+        ))
+    }
+
     /// Parse UTM format using geoconvert.
     fn parse_utm(input: &str) -> Option<(f64, f64, String)> {
         let caps = patterns().utm.captures(input)?;
@@ -298,15 +584,21 @@ impl CoordsFormat {
 
         let chi = (xi_prime.sin() / eta_prime.cosh()).asin();
 
-        // Latitude
-        let a_star1 = n / 2.0 - 2.0 * n.powi(2) / 3.0 + 5.0 * n.powi(3) / 16.0;
-        let a_star2 = 13.0 * n.powi(2) / 48.0 - 3.0 * n.powi(3) / 5.0;
-        let a_star3 = 61.0 * n.powi(3) / 240.0;
+        // Latitude (conformal → geodetic)
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let a_star = e2 + e2.powi(2) + e2.powi(3) + e2.powi(4);
+        let b_star = -(7.0 * e2.powi(2) + 17.0 * e2.powi(3) + 30.0 * e2.powi(4)) / 6.0;
+        let c_star = (224.0 * e2.powi(3) + 889.0 * e2.powi(4)) / 120.0;
+        let d_star = -4279.0 * e2.powi(4) / 1260.0;
 
+        let sin_chi = chi.sin();
         let phi = chi
-            + a_star1 * (2.0 * chi).sin()
-            + a_star2 * (4.0 * chi).sin()
-            + a_star3 * (6.0 * chi).sin();
+            + sin_chi
+                * chi.cos()
+                * (a_star
+                    + b_star * sin_chi.powi(2)
+                    + c_star * sin_chi.powi(4)
+                    + d_star * sin_chi.powi(6));
 
         // Longitude
         let lambda0 = SWEREF99_CENTRAL_MERIDIAN.to_radians();
@@ -318,6 +610,66 @@ impl CoordsFormat {
         Some((lat, lon))
     }
 
+    /// Convert WGS84 coordinates to SWEREF 99 TM as (northing, easting).
+    /// Uses Transverse Mercator forward projection (Gauss-Krüger).
+    fn wgs84_to_sweref99(lat: f64, lon: f64) -> Option<(f64, f64)> {
+        let e2 = WGS84_F * (2.0 - WGS84_F);
+        let n = WGS84_F / (2.0 - WGS84_F);
+        let a_hat = WGS84_A / (1.0 + n) * (1.0 + n.powi(2) / 4.0 + n.powi(4) / 64.0);
+
+        // Conformal latitude
+        let a = e2;
+        let b = (5.0 * e2.powi(2) - e2.powi(3)) / 6.0;
+        let c = (104.0 * e2.powi(3) - 45.0 * e2.powi(4)) / 120.0;
+        let d = 1237.0 * e2.powi(4) / 1260.0;
+
+        let phi = lat.to_radians();
+        let sin_phi = phi.sin();
+        let phi_star = phi
+            - sin_phi
+                * phi.cos()
+                * (a + b * sin_phi.powi(2) + c * sin_phi.powi(4) + d * sin_phi.powi(6));
+
+        let delta_lambda = lon.to_radians() - SWEREF99_CENTRAL_MERIDIAN.to_radians();
+        let xi_prime = (phi_star.tan() / delta_lambda.cos()).atan();
+        let eta_prime = (phi_star.cos() * delta_lambda.sin()).atanh();
+
+        // Coefficients for forward projection
+        let beta1 =
+            n / 2.0 - 2.0 * n.powi(2) / 3.0 + 5.0 * n.powi(3) / 16.0 + 41.0 * n.powi(4) / 180.0;
+        let beta2 = 13.0 * n.powi(2) / 48.0 - 3.0 * n.powi(3) / 5.0 + 557.0 * n.powi(4) / 1440.0;
+        let beta3 = 61.0 * n.powi(3) / 240.0 - 103.0 * n.powi(4) / 140.0;
+        let beta4 = 49561.0 * n.powi(4) / 161_280.0;
+
+        let xi = xi_prime
+            + beta1 * (2.0 * xi_prime).sin() * (2.0 * eta_prime).cosh()
+            + beta2 * (4.0 * xi_prime).sin() * (4.0 * eta_prime).cosh()
+            + beta3 * (6.0 * xi_prime).sin() * (6.0 * eta_prime).cosh()
+            + beta4 * (8.0 * xi_prime).sin() * (8.0 * eta_prime).cosh();
+
+        let eta = eta_prime
+            + beta1 * (2.0 * xi_prime).cos() * (2.0 * eta_prime).sinh()
+            + beta2 * (4.0 * xi_prime).cos() * (4.0 * eta_prime).sinh()
+            + beta3 * (6.0 * xi_prime).cos() * (6.0 * eta_prime).sinh()
+            + beta4 * (8.0 * xi_prime).cos() * (8.0 * eta_prime).sinh();
+
+        let northing = SWEREF99_SCALE_FACTOR * a_hat * xi + SWEREF99_FALSE_NORTHING;
+        let easting = SWEREF99_SCALE_FACTOR * a_hat * eta + SWEREF99_FALSE_EASTING;
+
+        (northing.is_finite() && easting.is_finite()).then_some((northing, easting))
+    }
+
+    /// Check if coordinates fall within the SWEREF 99 TM area of use.
+    fn in_sweref99_zone(lat: f64, lon: f64) -> bool {
+        (SWEREF99_LAT_RANGE.0..=SWEREF99_LAT_RANGE.1).contains(&lat)
+            && (SWEREF99_LON_RANGE.0..=SWEREF99_LON_RANGE.1).contains(&lon)
+    }
+
+    /// Format as SWEREF 99 TM (meters, same notation the parser accepts).
+    fn format_sweref99(northing: f64, easting: f64) -> String {
+        format!("N {:.0}, E {:.0}", northing, easting)
+    }
+
     /// Check if coordinates are valid.
     fn is_valid_lat_lon(lat: f64, lon: f64) -> bool {
         lat.is_finite()
@@ -327,7 +679,7 @@ impl CoordsFormat {
     }
 
     /// Format as decimal degrees.
-    fn format_dd(lat: f64, lon: f64) -> String {
+    pub(crate) fn format_dd(lat: f64, lon: f64) -> String {
         format!("{:.6}, {:.6}", lat, lon)
     }
 
@@ -388,6 +740,42 @@ impl CoordsFormat {
         pluscodes::encode(&coord, 10).ok()
     }
 
+    /// Format as an 8-character Maidenhead locator (~900 m × 450 m cell).
+    fn format_maidenhead(lat: f64, lon: f64) -> Option<String> {
+        if !Self::is_valid_lat_lon(lat, lon) {
+            return None;
+        }
+
+        // 180° wraps to -180°; the north pole belongs to the topmost cell
+        let lon = (lon + 180.0).rem_euclid(360.0);
+        let lat = (lat + 90.0).min(180.0 - 1e-9);
+
+        let lon_field = (lon / 20.0).floor();
+        let lat_field = (lat / 10.0).floor();
+        let lon_square = ((lon % 20.0) / 2.0).floor();
+        let lat_square = (lat % 10.0).floor();
+        let lon_sub = (lon % 2.0) * 12.0;
+        let lat_sub = (lat % 1.0) * 24.0;
+        let lon_ext = (lon_sub.fract() * 10.0).floor();
+        let lat_ext = (lat_sub.fract() * 10.0).floor();
+
+        let letter = |base: u8, n: f64| (base + n as u8) as char;
+        Some(
+            [
+                letter(b'A', lon_field),
+                letter(b'A', lat_field),
+                letter(b'0', lon_square),
+                letter(b'0', lat_square),
+                letter(b'a', lon_sub.floor()),
+                letter(b'a', lat_sub.floor()),
+                letter(b'0', lon_ext),
+                letter(b'0', lat_ext),
+            ]
+            .iter()
+            .collect(),
+        )
+    }
+
     /// Format as UTM using geoconvert.
     fn format_utm(lat: f64, lon: f64) -> Option<String> {
         let latlon = LatLon::create(lat, lon).ok()?;
@@ -420,7 +808,11 @@ impl CoordsFormat {
     }
 
     /// Build rich display info for coordinates.
-    fn build_rich_display(lat: f64, lon: f64, source_format: &str) -> Vec<RichDisplayOption> {
+    pub(crate) fn build_rich_display(
+        lat: f64,
+        lon: f64,
+        source_format: &str,
+    ) -> Vec<RichDisplayOption> {
         let mut pairs = vec![
             ("Format".to_string(), source_format.to_string()),
             ("Latitude".to_string(), format!("{:.6}°", lat)),
@@ -458,13 +850,15 @@ impl Format for CoordsFormat {
             name: self.name(),
             category: "Location",
             description:
-                "Geographic coordinates (DD, DMS, DDM, UTM, MGRS, Geohash, Plus Code, SWEREF 99)",
+                "Geographic coordinates (DD, DMS, DDM, UTM, MGRS, Geohash, Plus Code, SWEREF 99, Maidenhead, British National Grid)",
             examples: &[
                 "59.3293, 18.0686",
                 "40° 26' 46\" N, 79° 56' 55\" W",
                 "9FFW83PH+X9",
                 "u6sce",
                 "18TWL8550607274",
+                "JO99ah89",
+                "TQ 30 80",
             ],
             aliases: self.aliases(),
             has_validation: false,
@@ -478,23 +872,31 @@ impl Format for CoordsFormat {
         // Note: geohash is NOT parsed as input (too many false positives with words)
         // but IS available as a conversion output from coordinates
         let parsers: &[CoordParser] = &[
-            Self::parse_mgrs,      // Most specific pattern
-            Self::parse_plus_code, // Has + character
-            Self::parse_utm,       // Zone + band + coords
-            Self::parse_sweref99,  // Swedish format
-            Self::parse_dms,       // Has degree/minute/second symbols
-            Self::parse_ddm,       // Has degree/minute symbols
-            Self::parse_dd,        // Most general - just two numbers
+            Self::parse_mgrs,       // Most specific pattern
+            Self::parse_plus_code,  // Has + character
+            Self::parse_utm,        // Zone + band + coords
+            Self::parse_bng,        // 100km square letters + digits
+            Self::parse_sweref99,   // Swedish format
+            Self::parse_maidenhead, // Letters + digits grid
+            Self::parse_dms,        // Has degree/minute/second symbols
+            Self::parse_ddm,        // Has degree/minute symbols
+            Self::parse_dd,         // Most general - just two numbers
         ];
 
         for parser in parsers {
             if let Some((lat, lon, format_name)) = parser(trimmed) {
                 let description = format!("{}: {:.6}, {:.6}", format_name, lat, lon);
+                // Grid locators are short alphanumerics and may be something else
+                let confidence = match format_name.as_str() {
+                    "Maidenhead" => 0.85,
+                    "British National Grid" => 0.88,
+                    _ => 0.9,
+                };
 
                 return vec![Interpretation {
                     value: CoreValue::Coordinates { lat, lon },
                     source_format: "coords".to_string(),
-                    confidence: 0.9,
+                    confidence,
                     description,
                     rich_display: Self::build_rich_display(lat, lon, &format_name),
                 }];
@@ -504,6 +906,35 @@ impl Format for CoordsFormat {
         vec![]
     }
 
+    fn explain(&self, input: &str) -> Option<String> {
+        if let Some(interp) = self.parse(input).first() {
+            return Some(format!(
+                "matched {}, confidence {:.2}",
+                interp.description, interp.confidence
+            ));
+        }
+
+        let trimmed = input.trim();
+        // Geohash base32 alphabet: digits and lowercase letters except a, i, l, o
+        let is_geohash_like = (1..=12).contains(&trimmed.len())
+            && trimmed
+                .chars()
+                .all(|c| c.is_ascii_digit() || (c.is_ascii_lowercase() && !"ailo".contains(c)));
+        if !is_geohash_like {
+            return Some("rejected: no coordinate notation matched".to_string());
+        }
+        let why = if trimmed.chars().all(|c| c.is_ascii_digit()) {
+            "an all-digit string is almost always a plain number"
+        } else {
+            "short geohash-alphabet strings are usually words"
+        };
+        Some(format!(
+            "rejected: valid geohash alphabet, but geohash is never detected from input \
+             (confidence 0): {}; geohash is only offered as an output",
+            why
+        ))
+    }
+
     fn can_format(&self, _value: &CoreValue) -> bool {
         false
     }
@@ -651,8 +1082,64 @@ impl Format for CoordsFormat {
             });
         }
 
-        // Note: SWEREF 99 TM output is not implemented yet due to complex projection formulas.
-        // Parsing of SWEREF 99 coordinates is supported.
+        // Maidenhead locator
+        if let Some(locator) = Self::format_maidenhead(lat, lon) {
+            conversions.push(Conversion {
+                value: CoreValue::Coordinates { lat, lon },
+                target_format: "maidenhead".to_string(),
+                display: locator.clone(),
+                path: vec!["maidenhead".to_string()],
+                steps: vec![ConversionStep {
+                    format: "maidenhead".to_string(),
+                    value: CoreValue::Coordinates { lat, lon },
+                    display: locator,
+                }],
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            });
+        }
+
+        // British National Grid (only within the grid's squares)
+        if let Some(bng) = Self::format_bng(lat, lon) {
+            conversions.push(Conversion {
+                value: CoreValue::Coordinates { lat, lon },
+                target_format: "bng".to_string(),
+                display: bng.clone(),
+                path: vec!["bng".to_string()],
+                steps: vec![ConversionStep {
+                    format: "bng".to_string(),
+                    value: CoreValue::Coordinates { lat, lon },
+                    display: bng,
+                }],
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            });
+        }
+
+        // SWEREF 99 TM (emitted everywhere, but lossy outside Sweden's zone)
+        if let Some((northing, easting)) = Self::wgs84_to_sweref99(lat, lon) {
+            let sweref = Self::format_sweref99(northing, easting);
+            conversions.push(Conversion {
+                value: CoreValue::Coordinates { lat, lon },
+                target_format: "sweref99".to_string(),
+                display: sweref.clone(),
+                path: vec!["sweref99".to_string()],
+                steps: vec![ConversionStep {
+                    format: "sweref99".to_string(),
+                    value: CoreValue::Coordinates { lat, lon },
+                    display: sweref,
+                }],
+                is_lossy: !Self::in_sweref99_zone(lat, lon),
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            });
+        }
 
         conversions
     }
@@ -676,8 +1163,17 @@ impl Format for CoordsFormat {
             "plus-code",
             "sweref",
             "sweref99",
+            "maidenhead",
+            "locator",
+            "bng",
+            "osgb",
+            "osgb36",
         ]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
@@ -766,7 +1262,7 @@ mod tests {
         let conversions = format.conversions(&value);
 
         // Should have DD, DMS, DDM, Geohash, Plus Code, UTM, MGRS, SWEREF99
-        assert!(conversions.len() >= 7);
+        assert!(conversions.len() >= 8);
 
         // Check that we have the expected formats
         let formats: Vec<_> = conversions
@@ -779,7 +1275,114 @@ mod tests {
         assert!(formats.contains(&"plus-code"));
         assert!(formats.contains(&"utm"));
         assert!(formats.contains(&"mgrs"));
-        // Note: sweref99 output is not implemented yet
+        assert!(formats.contains(&"sweref99"));
+    }
+
+    #[test]
+    fn test_maidenhead_stockholm() {
+        let locator = CoordsFormat::format_maidenhead(59.3293, 18.0686).unwrap();
+        assert_eq!(locator, "JO99ah89");
+
+        let format = CoordsFormat;
+        let results = format.parse("JO99ah");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.85);
+        let CoreValue::Coordinates { lat, lon } = results[0].value else {
+            panic!("expected coordinates");
+        };
+        assert!((lat - 59.3293).abs() < 0.05 && (lon - 18.0686).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_maidenhead_round_trip_edges() {
+        for (lat, lon) in [
+            (59.3293, 18.0686),
+            (90.0, 0.0),
+            (-90.0, 0.0),
+            (89.999, 179.999),
+            (-33.8688, 179.99),
+            (10.0, -180.0),
+            (0.0, 180.0),
+        ] {
+            let locator = CoordsFormat::format_maidenhead(lat, lon).unwrap();
+            let (lat2, lon2, _) = CoordsFormat::parse_maidenhead(&locator).unwrap();
+            assert_eq!(
+                CoordsFormat::format_maidenhead(lat2, lon2).unwrap(),
+                locator,
+                "({}, {}) did not round-trip",
+                lat,
+                lon
+            );
+        }
+
+        // Antimeridian wraps to the westernmost field
+        assert!(CoordsFormat::format_maidenhead(0.0, 180.0)
+            .unwrap()
+            .starts_with("AJ"));
+        assert!(CoordsFormat::format_maidenhead(90.0, 0.0)
+            .unwrap()
+            .starts_with("JR"));
+    }
+
+    #[test]
+    fn test_maidenhead_rejects_hex_and_short_squares() {
+        let format = CoordsFormat;
+        assert!(format.parse("AB12cd").is_empty());
+        assert!(format.parse("JO99").is_empty());
+    }
+
+    #[test]
+    fn test_sweref99_round_trip() {
+        // Stockholm, known SWEREF 99 TM pair from Lantmäteriet
+        let (northing, easting) = (6_580_822.0, 674_032.0);
+        let (lat, lon) = CoordsFormat::sweref99_to_wgs84(northing, easting).unwrap();
+        let (n2, e2) = CoordsFormat::wgs84_to_sweref99(lat, lon).unwrap();
+
+        assert!(
+            (n2 - northing).abs() < 1.0,
+            "northing {} vs {}",
+            n2,
+            northing
+        );
+        assert!((e2 - easting).abs() < 1.0, "easting {} vs {}", e2, easting);
+    }
+
+    #[test]
+    fn test_sweref99_reference_point() {
+        // Lantmäteriet reference pair (grid rounded to whole metres):
+        // N 6580822, E 674032 = N 59° 19' 48.83", E 18° 3' 33.11"
+        let dms = |d: f64, m: f64, s: f64| d + m / 60.0 + s / 3600.0;
+        let (lat, lon) = (dms(59.0, 19.0, 48.83), dms(18.0, 3.0, 33.11));
+
+        let (northing, easting) = CoordsFormat::wgs84_to_sweref99(lat, lon).unwrap();
+        assert_eq!(
+            (northing.round(), easting.round()),
+            (6_580_822.0, 674_032.0)
+        );
+
+        // ~1 m: the grid pair is only given to the metre
+        let (lat2, lon2) = CoordsFormat::sweref99_to_wgs84(6_580_822.0, 674_032.0).unwrap();
+        assert!((lat2 - lat).abs() < 1e-5, "lat {} vs {}", lat2, lat);
+        assert!((lon2 - lon).abs() < 2e-5, "lon {} vs {}", lon2, lon);
+    }
+
+    #[test]
+    fn test_sweref99_lossy_outside_sweden() {
+        let format = CoordsFormat;
+        let sweref = |lat, lon| {
+            format
+                .conversions(&CoreValue::Coordinates { lat, lon })
+                .into_iter()
+                .find(|c| c.target_format == "sweref99")
+                .unwrap()
+        };
+
+        let stockholm = sweref(59.3293, 18.0686);
+        assert!(!stockholm.is_lossy);
+        assert!(stockholm.display.starts_with("N 658"));
+
+        // New York: still emitted, but flagged
+        assert!(sweref(40.7128, -74.0060).is_lossy);
     }
 
     #[test]
@@ -807,6 +1410,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bng_projection() {
+        // Worked example from the Ordnance Survey's coordinate systems guide
+        let lat = 52.0 + 39.0 / 60.0 + 27.2531 / 3600.0;
+        let lon = 1.0 + 43.0 / 60.0 + 4.5177 / 3600.0;
+        let (easting, northing) = CoordsFormat::osgb36_to_bng(lat, lon);
+        assert!((easting - 651_409.903).abs() < 0.01, "easting {}", easting);
+        assert!(
+            (northing - 313_177.270).abs() < 0.01,
+            "northing {}",
+            northing
+        );
+
+        let (lat2, lon2) = CoordsFormat::bng_to_osgb36(easting, northing);
+        assert!((lat2 - lat).abs() < 1e-8 && (lon2 - lon).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_bng_royal_observatory() {
+        // Airy Transit Circle, Royal Observatory Greenwich (WGS84)
+        let (lat, lon) = (51.477_811, -0.001_475);
+        let bng = CoordsFormat::format_bng(lat, lon).unwrap();
+        assert!(bng.starts_with("TQ 388"), "{}", bng);
+
+        let results = CoordsFormat.parse(&bng);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.88);
+        let CoreValue::Coordinates {
+            lat: lat2,
+            lon: lon2,
+        } = results[0].value
+        else {
+            panic!("expected coordinates");
+        };
+        // 1m grid reference, so within a couple of meters
+        assert!((lat2 - lat).abs() < 0.000_03, "lat {} vs {}", lat2, lat);
+        assert!((lon2 - lon).abs() < 0.000_05, "lon {} vs {}", lon2, lon);
+    }
+
+    #[test]
+    fn test_bng_parsing() {
+        let format = CoordsFormat;
+        let center = |input: &str| match format.parse(input).first().map(|i| &i.value) {
+            Some(CoreValue::Coordinates { lat, lon }) => Some((*lat, *lon)),
+            _ => None,
+        };
+
+        // The same 1km square, written three ways
+        let (lat, lon) = center("TQ 30 80").unwrap();
+        assert!((lat - 51.51).abs() < 0.02 && (lon + 0.13).abs() < 0.02);
+        let (lat2, lon2) = center("tq3080").unwrap();
+        assert!((lat - lat2).abs() < 1e-9 && (lon - lon2).abs() < 1e-9);
+        assert!(center("TQ301805").is_some());
+
+        // MGRS keeps its zone number, and isn't taken for a grid reference
+        let results = format.parse("18TWL8550607274");
+        assert!(results[0].description.starts_with("MGRS"));
+
+        // Not a grid reference
+        assert!(center("TQ 301 80").is_none());
+        assert!(center("TQ12345").is_none());
+        assert!(center("AB 30 80").is_none());
+        assert!(CoordsFormat::format_bng(40.7128, -74.0060).is_none());
+    }
+
     #[test]
     fn test_invalid_coordinates() {
         let format = CoordsFormat;
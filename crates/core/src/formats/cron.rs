@@ -11,10 +11,11 @@
 
 use chrono::{Datelike, Local, TimeZone, Timelike, Utc};
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
+use crate::time::format_relative_precise;
 use crate::types::{
-    Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
-    RichDisplayOption,
+    Conversion, ConversionConfig, ConversionKind, ConversionPriority, CoreValue, Interpretation,
+    RelativeTimeStyle, RichDisplay, RichDisplayOption,
 };
 
 pub struct CronFormat;
@@ -593,6 +594,66 @@ impl CronFormat {
 
         true
     }
+
+    /// Description and upcoming runs, with relative times in `style`.
+    fn styled_source_conversions(value: &CoreValue, style: RelativeTimeStyle) -> Vec<Conversion> {
+        let CoreValue::String(cron_str) = value else {
+            return vec![];
+        };
+
+        let Some(expr) = CronExpr::parse(cron_str) else {
+            return vec![];
+        };
+
+        let next_times = expr.next_times(5);
+        let mut conversions = Vec::new();
+
+        // Add conversion showing the human-readable description
+        conversions.push(Conversion {
+            value: CoreValue::String(expr.describe()),
+            target_format: "cron-description".to_string(),
+            display: expr.describe(),
+            path: vec!["cron-description".to_string()],
+            is_lossy: false,
+            confidence: 1.0,
+            steps: vec![],
+            priority: ConversionPriority::Semantic,
+            display_only: true,
+            kind: ConversionKind::Representation,
+            hidden: false,
+            depth_limited: false,
+            rich_display: vec![],
+        });
+
+        // Add conversion for next execution time as DateTime
+        if let Some(next) = next_times.first() {
+            let utc_time = next.with_timezone(&Utc);
+            let iso = utc_time.to_rfc3339();
+            let relative = format_relative_precise(Utc::now(), utc_time, style);
+
+            conversions.push(Conversion {
+                value: CoreValue::DateTime(utc_time),
+                target_format: "cron-next".to_string(),
+                display: format!("{} ({})", next.format("%Y-%m-%d %H:%M:%S"), relative),
+                path: vec!["cron-next".to_string()],
+                is_lossy: false,
+                confidence: 1.0,
+                steps: vec![],
+                priority: ConversionPriority::Semantic,
+                display_only: false,
+                kind: ConversionKind::Conversion,
+                hidden: false,
+                depth_limited: false,
+                rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
+                    epoch_millis: utc_time.timestamp_millis(),
+                    iso,
+                    relative,
+                })],
+            });
+        }
+
+        conversions
+    }
 }
 
 impl Format for CronFormat {
@@ -679,99 +740,24 @@ impl Format for CronFormat {
     }
 
     fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
-        let CoreValue::String(cron_str) = value else {
-            return vec![];
-        };
-
-        let Some(expr) = CronExpr::parse(cron_str) else {
-            return vec![];
-        };
-
-        let next_times = expr.next_times(5);
-        let mut conversions = Vec::new();
-
-        // Add conversion showing the human-readable description
-        conversions.push(Conversion {
-            value: CoreValue::String(expr.describe()),
-            target_format: "cron-description".to_string(),
-            display: expr.describe(),
-            path: vec!["cron-description".to_string()],
-            is_lossy: false,
-            steps: vec![],
-            priority: ConversionPriority::Semantic,
-            display_only: true,
-            kind: ConversionKind::Representation,
-            hidden: false,
-            rich_display: vec![],
-        });
-
-        // Add conversion for next execution time as DateTime
-        if let Some(next) = next_times.first() {
-            let utc_time = next.with_timezone(&Utc);
-            let iso = utc_time.to_rfc3339();
-            let relative = format_relative(*next);
-
-            conversions.push(Conversion {
-                value: CoreValue::DateTime(utc_time),
-                target_format: "cron-next".to_string(),
-                display: format!("{} ({})", next.format("%Y-%m-%d %H:%M:%S"), relative),
-                path: vec!["cron-next".to_string()],
-                is_lossy: false,
-                steps: vec![],
-                priority: ConversionPriority::Semantic,
-                display_only: false,
-                kind: ConversionKind::Conversion,
-                hidden: false,
-                rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
-                    epoch_millis: utc_time.timestamp_millis(),
-                    iso,
-                    relative,
-                })],
-            });
-        }
+        Self::styled_source_conversions(value, RelativeTimeStyle::default())
+    }
 
-        conversions
+    fn source_conversions_with_config(
+        &self,
+        value: &CoreValue,
+        config: &ConversionConfig,
+    ) -> Vec<Conversion> {
+        Self::styled_source_conversions(value, config.relative_time_style)
     }
 
     fn aliases(&self) -> &'static [&'static str] {
         &["crontab"]
     }
-}
-
-/// Format a relative time string.
-fn format_relative(dt: chrono::DateTime<Local>) -> String {
-    let now = Local::now();
-    let diff = dt.signed_duration_since(now);
-    let secs = diff.num_seconds();
-
-    if secs < 0 {
-        return "in the past".to_string();
-    }
-
-    if secs < 60 {
-        return format!("in {} seconds", secs);
-    }
-
-    let mins = secs / 60;
-    if mins < 60 {
-        return format!("in {} minute{}", mins, if mins == 1 { "" } else { "s" });
-    }
 
-    let hours = mins / 60;
-    if hours < 24 {
-        let remaining_mins = mins % 60;
-        if remaining_mins > 0 {
-            return format!("in {}h {}m", hours, remaining_mins);
-        }
-        return format!("in {} hour{}", hours, if hours == 1 { "" } else { "s" });
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
     }
-
-    let days = hours / 24;
-    if days < 7 {
-        return format!("in {} day{}", days, if days == 1 { "" } else { "s" });
-    }
-
-    format!("in {} days", days)
 }
 
 #[cfg(test)]
@@ -890,4 +876,15 @@ mod tests {
         assert!(!results.is_empty());
         assert!(results[0].confidence >= 0.8);
     }
+
+    #[test]
+    fn test_next_run_relative_time() {
+        let conversions = CronFormat.source_conversions(&CoreValue::String("0 * * * *".into()));
+        let next = conversions
+            .iter()
+            .find(|c| c.target_format == "cron-next")
+            .unwrap();
+        // Shared wording with the other datetime formats
+        assert!(next.display.contains("(in "), "got {}", next.display);
+    }
 }
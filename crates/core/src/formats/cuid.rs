@@ -3,7 +3,7 @@
 //! CUID2 is a secure, collision-resistant ID using base36 (lowercase a-z, 0-9).
 //! Default length is 24 characters, starting with a letter.
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{CoreValue, Interpretation};
 
 pub struct CuidFormat;
@@ -106,6 +106,10 @@ impl Format for CuidFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["cuid2"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
 }
 
 #[cfg(test)]
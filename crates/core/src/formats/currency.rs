@@ -13,10 +13,11 @@
 use std::env;
 
 use crate::format::{Format, FormatInfo};
-use crate::formats::currency_rates::{self, RateCache};
+use crate::formats::currency_rates;
 use crate::formats::units::parse_number;
 use crate::types::{
-    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+    Conversion, ConversionConfig, ConversionKind, ConversionPriority, ConversionStep, CoreValue,
+    Interpretation,
 };
 
 pub struct CurrencyFormat;
@@ -72,6 +73,65 @@ const CURRENCY_CODES: &[&str] = &[
     "ILS", "AED", "SAR", "QAR", "KWD", "BHD", "OMR", "UAH", "KZT", "GEL", "AZN",
 ];
 
+/// ISO 4217 numeric codes of the currencies above, as (numeric, alphabetic, name).
+/// Sorted by numeric code.
+const NUMERIC_CODES: &[(u16, &str, &str)] = &[
+    (32, "ARS", "Argentine Peso"),
+    (36, "AUD", "Australian Dollar"),
+    (48, "BHD", "Bahraini Dinar"),
+    (124, "CAD", "Canadian Dollar"),
+    (152, "CLP", "Chilean Peso"),
+    (156, "CNY", "Yuan Renminbi"),
+    (170, "COP", "Colombian Peso"),
+    (203, "CZK", "Czech Koruna"),
+    (208, "DKK", "Danish Krone"),
+    (344, "HKD", "Hong Kong Dollar"),
+    (348, "HUF", "Forint"),
+    (352, "ISK", "Iceland Krona"),
+    (356, "INR", "Indian Rupee"),
+    (360, "IDR", "Rupiah"),
+    (376, "ILS", "New Israeli Sheqel"),
+    (392, "JPY", "Yen"),
+    (398, "KZT", "Tenge"),
+    (404, "KES", "Kenyan Shilling"),
+    (410, "KRW", "Won"),
+    (414, "KWD", "Kuwaiti Dinar"),
+    (458, "MYR", "Malaysian Ringgit"),
+    (484, "MXN", "Mexican Peso"),
+    (512, "OMR", "Rial Omani"),
+    (554, "NZD", "New Zealand Dollar"),
+    (566, "NGN", "Naira"),
+    (578, "NOK", "Norwegian Krone"),
+    (604, "PEN", "Sol"),
+    (608, "PHP", "Philippine Peso"),
+    (634, "QAR", "Qatari Rial"),
+    (643, "RUB", "Russian Ruble"),
+    (682, "SAR", "Saudi Riyal"),
+    (702, "SGD", "Singapore Dollar"),
+    (704, "VND", "Dong"),
+    (710, "ZAR", "Rand"),
+    (752, "SEK", "Swedish Krona"),
+    (756, "CHF", "Swiss Franc"),
+    (764, "THB", "Baht"),
+    (784, "AED", "UAE Dirham"),
+    (818, "EGP", "Egyptian Pound"),
+    (826, "GBP", "Pound Sterling"),
+    (840, "USD", "US Dollar"),
+    (944, "AZN", "Azerbaijan Manat"),
+    (946, "RON", "Romanian Leu"),
+    (949, "TRY", "Turkish Lira"),
+    (975, "BGN", "Bulgarian Lev"),
+    (978, "EUR", "Euro"),
+    (980, "UAH", "Hryvnia"),
+    (981, "GEL", "Lari"),
+    (985, "PLN", "Zloty"),
+    (986, "BRL", "Brazilian Real"),
+];
+
+/// Confidence for the numeric code trait. Bare three-digit numbers are far
+/// more often just numbers, so this stays well below decimal.
+const NUMERIC_CODE_CONFIDENCE: f32 = 0.4;
+
 /// Check if a currency code is known (built-in or plugin).
 fn is_known_currency(code: &str) -> bool {
     let code_upper = code.to_uppercase();
@@ -285,6 +345,40 @@ impl CurrencyFormat {
         }
     }
 
+    /// Trait for an integer that is an ISO 4217 numeric code (840 = USD).
+    ///
+    /// Only meaningful when the user typed the three digits, so the decimal
+    /// format attaches it to its own interpretation rather than to every int.
+    pub(crate) fn numeric_code_trait(numeric: i128) -> Option<Conversion> {
+        let (alpha, name) = Self::numeric_code(numeric)?;
+        let display = format!("ISO 4217 {:03} = {} ({})", numeric, alpha, name);
+        Some(Conversion {
+            value: CoreValue::String(alpha.to_string()),
+            target_format: "currency-code".to_string(),
+            display: display.clone(),
+            path: vec!["currency-code".to_string()],
+            steps: vec![ConversionStep {
+                format: "currency-code".to_string(),
+                value: CoreValue::String(alpha.to_string()),
+                display,
+            }],
+            confidence: NUMERIC_CODE_CONFIDENCE,
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Trait,
+            display_only: true,
+            ..Default::default()
+        })
+    }
+
+    /// Look up an ISO 4217 numeric code, returning (alphabetic code, name).
+    fn numeric_code(code: i128) -> Option<(&'static str, &'static str)> {
+        let code = u16::try_from(code).ok()?;
+        NUMERIC_CODES
+            .binary_search_by_key(&code, |&(n, _, _)| n)
+            .ok()
+            .map(|idx| (NUMERIC_CODES[idx].1, NUMERIC_CODES[idx].2))
+    }
+
     /// Format a number with thousand separators and appropriate decimals.
     fn format_number(value: f64) -> String {
         let abs_value = value.abs();
@@ -387,12 +481,15 @@ impl Format for CurrencyFormat {
     }
 
     fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
-        let CoreValue::Currency { amount, code } = value else {
-            return vec![];
-        };
+        self.conversions_with_config(value, &ConversionConfig::default())
+    }
 
-        let Some(cache) = RateCache::get() else {
-            // No rates available, can't convert
+    fn conversions_with_config(
+        &self,
+        value: &CoreValue,
+        config: &ConversionConfig,
+    ) -> Vec<Conversion> {
+        let CoreValue::Currency { amount, code } = value else {
             return vec![];
         };
 
@@ -405,7 +502,7 @@ impl Format for CurrencyFormat {
                 continue;
             }
 
-            let Some(converted) = cache.convert(*amount, code, target) else {
+            let Some(converted) = currency_rates::convert(*amount, code, target, config) else {
                 continue;
             };
 
@@ -441,7 +538,8 @@ impl Format for CurrencyFormat {
                 continue;
             }
 
-            let Some(converted) = cache.convert(*amount, code, &plugin_code) else {
+            let Some(converted) = currency_rates::convert(*amount, code, &plugin_code, config)
+            else {
                 continue;
             };
 
@@ -586,4 +684,31 @@ mod tests {
             Some((100.0, 1.0))
         );
     }
+
+    #[test]
+    fn test_numeric_code_trait() {
+        let trait_for = CurrencyFormat::numeric_code_trait;
+
+        let usd = trait_for(840).unwrap();
+        assert_eq!(usd.display, "ISO 4217 840 = USD (US Dollar)");
+        assert_eq!(usd.value, CoreValue::String("USD".to_string()));
+        assert_eq!(usd.kind, ConversionKind::Trait);
+        assert!(usd.confidence < 0.5);
+
+        assert_eq!(trait_for(978).unwrap().display, "ISO 4217 978 = EUR (Euro)");
+        assert_eq!(
+            trait_for(36).unwrap().display,
+            "ISO 4217 036 = AUD (Australian Dollar)"
+        );
+        assert!(trait_for(841).is_none());
+        assert!(trait_for(-840).is_none());
+    }
+
+    #[test]
+    fn test_numeric_codes_sorted_and_known() {
+        assert!(NUMERIC_CODES.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(NUMERIC_CODES
+            .iter()
+            .all(|(_, alpha, _)| CURRENCY_CODES.contains(alpha)));
+    }
 }
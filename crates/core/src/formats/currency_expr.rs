@@ -6,7 +6,8 @@
 use std::env;
 use std::sync::RwLock;
 
-use super::currency_rates::{self, RateCache};
+use super::currency_rates;
+use crate::types::ConversionConfig;
 
 /// Explicitly set target currency (from CLI or config).
 static TARGET_CURRENCY: RwLock<Option<String>> = RwLock::new(None);
@@ -222,7 +223,7 @@ fn get_locale_currency_with_source() -> Option<(String, String)> {
 ///
 /// Returns the converted amount, or None if conversion fails.
 /// Also tracks that the result is in the target currency (for expression result display).
-pub fn convert_to_target(amount: f64, source: &str) -> Option<f64> {
+pub fn convert_to_target(amount: f64, source: &str, config: &ConversionConfig) -> Option<f64> {
     let target = get_target_currency();
     // Result is in the target currency
     set_result_currency(&target);
@@ -232,9 +233,7 @@ pub fn convert_to_target(amount: f64, source: &str) -> Option<f64> {
         return Some(amount);
     }
 
-    // Get the rate cache
-    let cache = RateCache::get()?;
-    cache.convert(amount, source, &target)
+    currency_rates::convert(amount, source, &target, config)
 }
 
 /// Convert amount from target currency to a specific currency.
@@ -243,7 +242,7 @@ pub fn convert_to_target(amount: f64, source: &str) -> Option<f64> {
 /// Example: If target is SEK, convert_from_target(100, "EUR") converts 100 SEK to EUR.
 /// Note: Using toXXX/inXXX marks the result as having mixed currencies, so no currency
 /// will be displayed (since `toEUR(USD(100))` mixes SEK intermediate with EUR output).
-pub fn convert_from_target(amount: f64, dest: &str) -> Option<f64> {
+pub fn convert_from_target(amount: f64, dest: &str, config: &ConversionConfig) -> Option<f64> {
     // Mark that explicit conversion was used - don't display currency
     set_result_currency_explicit();
     let source = get_target_currency();
@@ -253,9 +252,7 @@ pub fn convert_from_target(amount: f64, dest: &str) -> Option<f64> {
         return Some(amount);
     }
 
-    // Get the rate cache
-    let cache = RateCache::get()?;
-    cache.convert(amount, &source, dest)
+    currency_rates::convert(amount, &source, dest, config)
 }
 
 /// Get all currency codes that should have expression functions.
@@ -364,7 +361,7 @@ mod tests {
     fn test_convert_same_currency() {
         set_target_currency(Some("USD".to_string()));
 
-        let result = convert_to_target(100.0, "USD");
+        let result = convert_to_target(100.0, "USD", &ConversionConfig::default());
         assert_eq!(result, Some(100.0));
 
         set_target_currency(None);
@@ -376,7 +373,7 @@ mod tests {
         set_target_currency(Some("EUR".to_string()));
 
         // Converting from EUR to EUR should be identity
-        let result = convert_from_target(100.0, "EUR");
+        let result = convert_from_target(100.0, "EUR", &ConversionConfig::default());
         assert_eq!(result, Some(100.0));
 
         set_target_currency(None);
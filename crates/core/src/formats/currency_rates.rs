@@ -6,6 +6,10 @@
 //! Also supports plugin-provided currencies (like BTC, ETH) that provide
 //! rates to a known base currency (usually USD), which are then chained
 //! through the ECB rates for full convertibility.
+//!
+//! Rates pinned in the [`ConversionConfig`] take precedence over both, so
+//! output can be reproduced without network access. In offline mode, rates are never
+//! fetched; only pinned rates and the disk cache are used.
 
 use std::collections::HashMap;
 use std::fs;
@@ -15,6 +19,8 @@ use std::sync::{LazyLock, Mutex, OnceLock, RwLock};
 use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::types::ConversionConfig;
+
 /// Cached exchange rates with retry support.
 /// Uses OnceLock for the Mutex itself, then Mutex for interior mutability.
 static RATE_CACHE: OnceLock<Mutex<CacheState>> = OnceLock::new();
@@ -76,7 +82,8 @@ impl RateCache {
     /// This will retry fetching if the cache is empty and enough time has passed
     /// since the last failed attempt (5 minute backoff). This ensures library
     /// consumers in long-running processes can recover from transient failures.
-    pub fn get() -> Option<RateCache> {
+    /// When `offline` is set, only the in-memory and disk caches are consulted.
+    pub fn get(offline: bool) -> Option<RateCache> {
         let state_mutex = RATE_CACHE.get_or_init(|| {
             Mutex::new(CacheState {
                 cache: None,
@@ -100,9 +107,13 @@ impl RateCache {
         };
 
         if should_fetch {
-            state.last_attempt = Some(Utc::now());
+            // An offline lookup never hits the network, so it mustn't delay
+            // the next online fetch
+            if !offline {
+                state.last_attempt = Some(Utc::now());
+            }
 
-            if let Some(fresh) = Self::load_or_fetch_inner(state.cache.as_ref()) {
+            if let Some(fresh) = Self::load_or_fetch_inner(state.cache.as_ref(), offline) {
                 state.cache = Some(fresh.clone());
                 return Some(fresh);
             }
@@ -113,14 +124,14 @@ impl RateCache {
     }
 
     /// Load from disk or fetch from API.
-    fn load_or_fetch_inner(existing: Option<&RateCache>) -> Option<Self> {
+    fn load_or_fetch_inner(existing: Option<&RateCache>, offline: bool) -> Option<Self> {
         // Try loading from disk first
         if let Some(cached) = Self::load_from_disk() {
             if !cached.is_expired() {
                 return Some(cached);
             }
             // Cache expired, try to refresh
-            if let Some(fresh) = Self::fetch_from_api() {
+            if let Some(fresh) = Self::fetch_from_api(offline) {
                 fresh.save_to_disk();
                 return Some(fresh);
             }
@@ -129,7 +140,7 @@ impl RateCache {
         }
 
         // No disk cache, try to fetch
-        if let Some(fresh) = Self::fetch_from_api() {
+        if let Some(fresh) = Self::fetch_from_api(offline) {
             fresh.save_to_disk();
             return Some(fresh);
         }
@@ -168,8 +179,12 @@ impl RateCache {
         }
     }
 
-    /// Fetch rates from Frankfurter API.
-    fn fetch_from_api() -> Option<Self> {
+    /// Fetch rates from Frankfurter API, unless `offline` is set.
+    fn fetch_from_api(offline: bool) -> Option<Self> {
+        if offline {
+            return None;
+        }
+
         // Frankfurter uses EUR as base by default
         let url = "https://api.frankfurter.app/latest";
 
@@ -273,6 +288,49 @@ pub fn clear_plugin_currencies() {
     }
 }
 
+/// Value of one unit of `code` in USD, if pinned in the config.
+///
+/// USD is the pivot, so it's implicitly pinned once any rate is.
+fn pinned_rate(config: &ConversionConfig, code: &str) -> Option<f64> {
+    let pinned = || {
+        config
+            .custom_rates
+            .iter()
+            .filter(|(_, rate)| rate.is_finite() && **rate > 0.0)
+    };
+    pinned()
+        .find(|(pinned_code, _)| pinned_code.eq_ignore_ascii_case(code))
+        .map(|(_, rate)| *rate)
+        .or_else(|| (code == "USD" && pinned().next().is_some()).then_some(1.0))
+}
+
+/// Convert an amount between currencies.
+///
+/// Rates pinned in the config are consulted first; currencies without one
+/// fall back to the cached ECB and plugin rates, chained through USD. In
+/// offline mode (`config.offline`) those are never fetched from the network.
+pub fn convert(amount: f64, from: &str, to: &str, config: &ConversionConfig) -> Option<f64> {
+    let from_upper = from.to_uppercase();
+    let to_upper = to.to_uppercase();
+    if from_upper == to_upper {
+        return Some(amount);
+    }
+
+    let from_usd = pinned_rate(config, &from_upper);
+    let to_usd = pinned_rate(config, &to_upper);
+
+    match (from_usd, to_usd) {
+        (Some(from_rate), Some(to_rate)) => Some(amount * from_rate / to_rate),
+        (Some(from_rate), None) => {
+            RateCache::get(config.offline)?.convert(amount * from_rate, "USD", &to_upper)
+        }
+        (None, Some(to_rate)) => RateCache::get(config.offline)?
+            .convert(amount, &from_upper, "USD")
+            .map(|usd| usd / to_rate),
+        (None, None) => RateCache::get(config.offline)?.convert(amount, &from_upper, &to_upper),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -511,4 +569,41 @@ mod tests {
 
         clear_plugin_currencies();
     }
+
+    fn pinned(rates: &[(&str, f64)]) -> ConversionConfig {
+        ConversionConfig {
+            custom_rates: rates
+                .iter()
+                .map(|(code, rate)| ((*code).to_string(), *rate))
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_custom_rates_take_precedence() {
+        let config = pinned(&[("eur", 1.25), ("SEK", 0.1)]);
+
+        // 1 EUR = 1.25 USD, so 100 USD = 80 EUR
+        let result = convert(100.0, "USD", "EUR", &config).unwrap();
+        assert!((result - 80.0).abs() < 1e-9, "got {}", result);
+
+        // Cross rate between two pinned currencies: 1 EUR = 12.5 SEK
+        let result = convert(1.0, "EUR", "SEK", &config).unwrap();
+        assert!((result - 12.5).abs() < 1e-9, "got {}", result);
+    }
+
+    #[test]
+    fn test_custom_rates_ignore_invalid() {
+        let config = pinned(&[("EUR", 0.0), ("GBP", f64::NAN)]);
+        assert_eq!(pinned_rate(&config, "EUR"), None);
+        assert_eq!(pinned_rate(&config, "GBP"), None);
+        // Nothing valid is pinned, so USD isn't either
+        assert_eq!(pinned_rate(&config, "USD"), None);
+    }
+
+    #[test]
+    fn test_offline_skips_fetch() {
+        assert!(RateCache::fetch_from_api(true).is_none());
+    }
 }
@@ -0,0 +1,200 @@
+//! CUSIP securities identifier format.
+//!
+//! Nine characters identifying North American securities: a six-character
+//! issuer code, a two-character issue number and a check digit:
+//! - `037833100` (Apple common stock)
+//! - `38259P508` (Google class A)
+
+use crate::format::{specificity, Format, FormatInfo};
+use crate::types::{CoreValue, Interpretation, RichDisplay, RichDisplayOption};
+
+pub struct CusipFormat;
+
+/// Confidence for a CUSIP with letters or `*@#` and a matching check digit.
+const CUSIP_CONFIDENCE: f32 = 0.9;
+
+/// Confidence for an all-digit CUSIP. One in ten 9-digit numbers has a
+/// matching check digit, so these stay below the decimal reading.
+const NUMERIC_CUSIP_CONFIDENCE: f32 = 0.6;
+
+/// Confidence when the issuer code is all letters. Real issuers are mostly
+/// digits, while words like `PASSWORD6` fit the shape by chance.
+const WORD_CUSIP_CONFIDENCE: f32 = 0.3;
+
+impl CusipFormat {
+    /// Check the CUSIP shape: eight characters from `0-9A-Z*@#` and a check
+    /// digit. Lowercase is rejected; CUSIPs are always written in uppercase.
+    fn normalize(input: &str) -> Option<String> {
+        let cusip = input.trim().to_string();
+        let bytes = cusip.as_bytes();
+        if bytes.len() != 9
+            || !bytes[..8].iter().all(|b| {
+                b.is_ascii_digit() || b.is_ascii_uppercase() || matches!(b, b'*' | b'@' | b'#')
+            })
+            || !bytes[8].is_ascii_digit()
+        {
+            return None;
+        }
+        Some(cusip)
+    }
+
+    /// Check digit for the first eight characters (modulus 10, double-add-double).
+    fn check_digit(cusip: &str) -> u8 {
+        let sum: u32 = cusip
+            .bytes()
+            .take(8)
+            .enumerate()
+            .map(|(i, b)| {
+                let value = match b {
+                    b'0'..=b'9' => u32::from(b - b'0'),
+                    b'A'..=b'Z' => u32::from(b - b'A') + 10,
+                    b'*' => 36,
+                    b'@' => 37,
+                    _ => 38, // '#'
+                };
+                let value = if i % 2 == 1 { value * 2 } else { value };
+                value / 10 + value % 10
+            })
+            .sum();
+        ((10 - sum % 10) % 10) as u8
+    }
+
+    fn is_valid(cusip: &str) -> bool {
+        cusip.as_bytes()[8] - b'0' == Self::check_digit(cusip)
+    }
+}
+
+impl Format for CusipFormat {
+    fn id(&self) -> &'static str {
+        "cusip"
+    }
+
+    fn name(&self) -> &'static str {
+        "CUSIP"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Identifiers",
+            description: "North American securities identifier with check digit",
+            examples: &["037833100", "38259P508"],
+            aliases: self.aliases(),
+            has_validation: true,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some(cusip) = Self::normalize(input) else {
+            return vec![];
+        };
+        if !Self::is_valid(&cusip) {
+            return vec![];
+        }
+
+        let confidence = if cusip.bytes().all(|b| b.is_ascii_digit()) {
+            NUMERIC_CUSIP_CONFIDENCE
+        } else if cusip[..6].bytes().all(|b| b.is_ascii_uppercase()) {
+            WORD_CUSIP_CONFIDENCE
+        } else {
+            CUSIP_CONFIDENCE
+        };
+        let pairs = vec![
+            ("Issuer".to_string(), cusip[..6].to_string()),
+            ("Issue".to_string(), cusip[6..8].to_string()),
+            ("Check digit".to_string(), cusip[8..].to_string()),
+        ];
+
+        vec![Interpretation {
+            value: CoreValue::String(cusip.clone()),
+            source_format: "cusip".to_string(),
+            confidence,
+            description: format!("CUSIP: {} (issuer {})", cusip, &cusip[..6]),
+            rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
+
+    fn validate(&self, input: &str) -> Option<String> {
+        let Some(cusip) = Self::normalize(input) else {
+            return Some(
+                "expected 8 characters from 0-9, A-Z, *, @ or # and a check digit".to_string(),
+            );
+        };
+
+        if !Self::is_valid(&cusip) {
+            return Some(format!(
+                "checksum failed, expected {} got {}",
+                Self::check_digit(&cusip),
+                &cusip[8..]
+            ));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apple_cusip() {
+        let results = CusipFormat.parse("037833100");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].description, "CUSIP: 037833100 (issuer 037833)");
+        // All digits, so it stays below the decimal reading
+        assert!(results[0].confidence < 0.85);
+    }
+
+    #[test]
+    fn test_parse_alphanumeric_cusip() {
+        let results = CusipFormat.parse("38259P508");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.9);
+        assert!(matches!(&results[0].value, CoreValue::String(s) if s == "38259P508"));
+    }
+
+    #[test]
+    fn test_checksum_failure() {
+        assert!(CusipFormat.parse("037833101").is_empty());
+        assert_eq!(
+            CusipFormat.validate("037833101").as_deref(),
+            Some("checksum failed, expected 0 got 1")
+        );
+        assert_eq!(CusipFormat.validate("38259P508"), None);
+    }
+
+    #[test]
+    fn test_rejects_wrong_shape() {
+        assert!(CusipFormat.parse("03783310").is_empty());
+        assert!(CusipFormat.parse("0378331000").is_empty());
+        assert!(CusipFormat.parse("03783310X").is_empty());
+        assert!(CusipFormat.parse("38259p508").is_empty());
+    }
+
+    #[test]
+    fn test_words_are_not_cusips() {
+        // Both have matching check digits
+        assert!(CusipFormat.parse("password6").is_empty());
+        assert!(CusipFormat.parse("deadbeef7").is_empty());
+
+        for word in ["PASSWORD6", "DEADBEEF7"] {
+            let results = CusipFormat.parse(word);
+            assert_eq!(results.len(), 1, "{word}");
+            assert!(results[0].confidence < 0.5, "{word}");
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Data URI format (RFC 2397).
+//!
+//! Parses `data:[<mediatype>][;base64],<data>` URIs as found in CSS and HTML.
+//! Base64 payloads decode to bytes so image/PDF/archive formats can pick them
+//! up through conversion chaining; text payloads are percent-decoded.
+
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use base64::Engine;
+use percent_encoding::percent_decode_str;
+
+use crate::format::{Format, FormatInfo};
+use crate::mime::detect_mime;
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+    RichDisplay, RichDisplayOption,
+};
+
+pub struct DataUriFormat;
+
+/// Media type assumed when a data URI omits it (RFC 2397).
+const DEFAULT_MEDIA_TYPE: &str = "text/plain";
+
+/// A parsed data URI.
+struct DataUri {
+    /// Declared media type without parameters, lowercased.
+    media_type: String,
+    /// Whether the payload was base64-encoded.
+    base64: bool,
+    /// Decoded payload.
+    data: Vec<u8>,
+}
+
+impl DataUriFormat {
+    fn parse_data_uri(input: &str) -> Option<DataUri> {
+        let rest = input.trim();
+        if !rest.get(..5)?.eq_ignore_ascii_case("data:") {
+            return None;
+        }
+        let (header, payload) = rest[5..].split_once(',')?;
+
+        let mut params = header.split(';');
+        let media_type = params.next().unwrap_or_default().trim().to_lowercase();
+        let base64 = params.any(|p| p.trim().eq_ignore_ascii_case("base64"));
+
+        let media_type = if media_type.is_empty() {
+            DEFAULT_MEDIA_TYPE.to_string()
+        } else if media_type.contains('/') {
+            media_type
+        } else {
+            return None;
+        };
+
+        let data = if base64 {
+            // Data URIs in HTML often contain percent-encoded or wrapped base64
+            let cleaned: String = percent_decode_str(payload)
+                .decode_utf8()
+                .ok()?
+                .chars()
+                .filter(|c| !c.is_whitespace())
+                .collect();
+            STANDARD
+                .decode(&cleaned)
+                .or_else(|_| STANDARD_NO_PAD.decode(&cleaned))
+                .ok()?
+        } else {
+            percent_decode_str(payload).collect()
+        };
+
+        Some(DataUri {
+            media_type,
+            base64,
+            data,
+        })
+    }
+
+    /// The sniffed media type, when the magic bytes contradict the declared one.
+    fn mime_mismatch(uri: &DataUri) -> Option<&'static str> {
+        detect_mime(&uri.data).filter(|sniffed| *sniffed != uri.media_type)
+    }
+
+    /// Whether a media type holds text we should expose as a string.
+    fn is_text_type(media_type: &str) -> bool {
+        media_type.starts_with("text/")
+            || matches!(
+                media_type,
+                "application/json" | "application/xml" | "image/svg+xml"
+            )
+    }
+}
+
+impl Format for DataUriFormat {
+    fn id(&self) -> &'static str {
+        "data-uri"
+    }
+
+    fn name(&self) -> &'static str {
+        "Data URI"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Encoding",
+            description: "data: URIs with base64 or percent-encoded payloads",
+            examples: &[
+                "data:text/plain,Hello%20World",
+                "data:image/png;base64,iVBORw0KGgo=",
+            ],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some(uri) = Self::parse_data_uri(input) else {
+            return vec![];
+        };
+
+        let encoding = if uri.base64 {
+            "base64"
+        } else {
+            "percent-encoded"
+        };
+        let mut pairs = vec![
+            ("MIME type".to_string(), uri.media_type.clone()),
+            ("Encoding".to_string(), encoding.to_string()),
+            ("Size".to_string(), format!("{} bytes", uri.data.len())),
+        ];
+
+        // Declared type vs magic bytes (only meaningful when the bytes are recognized)
+        let sniffed = detect_mime(&uri.data);
+        let mismatch = Self::mime_mismatch(&uri);
+        if let Some(sniffed) = sniffed {
+            pairs.push(("Detected".to_string(), sniffed.to_string()));
+        }
+        if let Some(sniffed) = mismatch {
+            pairs.push((
+                "Warning".to_string(),
+                format!("declared {} but content is {}", uri.media_type, sniffed),
+            ));
+        }
+
+        let text = Self::is_text_type(&uri.media_type)
+            .then(|| String::from_utf8(uri.data.clone()).ok())
+            .flatten();
+        let mut description = format!(
+            "{}, {} bytes ({})",
+            uri.media_type,
+            uri.data.len(),
+            encoding
+        );
+        if let Some(sniffed) = mismatch {
+            description.push_str(&format!(" ⚠ content is {}", sniffed));
+        }
+        let value = match text {
+            Some(text) => CoreValue::String(text),
+            None => CoreValue::Bytes(uri.data),
+        };
+
+        vec![Interpretation {
+            value,
+            source_format: "data-uri".to_string(),
+            confidence: 0.95,
+            description,
+            rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })],
+        }]
+    }
+
+    /// A declared media type the content contradicts becomes a trait.
+    fn annotate_conversions(
+        &self,
+        input: &str,
+        _interpretation: &Interpretation,
+        conversions: &mut Vec<Conversion>,
+    ) {
+        let Some(uri) = Self::parse_data_uri(input) else {
+            return;
+        };
+        let Some(sniffed) = Self::mime_mismatch(&uri) else {
+            return;
+        };
+        let display = format!("declared {} but content is {}", uri.media_type, sniffed);
+        conversions.insert(
+            0,
+            Conversion {
+                value: CoreValue::String(sniffed.to_string()),
+                target_format: "mime-mismatch".to_string(),
+                display: display.clone(),
+                path: vec!["data-uri".to_string(), "mime-mismatch".to_string()],
+                steps: vec![ConversionStep {
+                    format: "mime-mismatch".to_string(),
+                    value: CoreValue::String(sniffed.to_string()),
+                    display,
+                }],
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Trait,
+                display_only: true,
+                ..Default::default()
+            },
+        );
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["datauri", "data-url"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TINY_PNG: &str = "data:image/png;base64,iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+    #[test]
+    fn test_parse_png_data_uri() {
+        let results = DataUriFormat.parse(TINY_PNG);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.95);
+        assert!(results[0].description.starts_with("image/png, 70 bytes"));
+
+        let CoreValue::Bytes(bytes) = &results[0].value else {
+            panic!("Expected Bytes");
+        };
+        assert!(bytes.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn test_parse_text_data_uri() {
+        let results = DataUriFormat.parse("data:text/plain,Hello%20World");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(&results[0].value, CoreValue::String(s) if s == "Hello World"));
+
+        // Media type defaults to text/plain
+        let results = DataUriFormat.parse("data:,Hi");
+        assert!(results[0].description.starts_with("text/plain"));
+    }
+
+    #[test]
+    fn test_mime_mismatch_warning() {
+        let uri = TINY_PNG.replace("image/png", "image/jpeg");
+        let results = DataUriFormat.parse(&uri);
+        assert!(results[0].description.contains("⚠ content is image/png"));
+
+        let mut conversions = Vec::new();
+        DataUriFormat.annotate_conversions(&uri, &results[0], &mut conversions);
+        assert_eq!(conversions.len(), 1);
+        assert_eq!(conversions[0].kind, ConversionKind::Trait);
+        assert_eq!(
+            conversions[0].display,
+            "declared image/jpeg but content is image/png"
+        );
+
+        // Matching types get no trait
+        let mut conversions = Vec::new();
+        DataUriFormat.annotate_conversions(TINY_PNG, &results[0], &mut conversions);
+        assert!(conversions.is_empty());
+    }
+
+    #[test]
+    fn test_not_data_uri() {
+        assert!(DataUriFormat.parse("data").is_empty());
+        assert!(DataUriFormat.parse("database:foo,bar").is_empty());
+        assert!(DataUriFormat.parse("data:image/png;base64,!!!").is_empty());
+    }
+}
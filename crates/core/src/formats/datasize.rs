@@ -8,10 +8,11 @@
 //!
 //! Also converts raw byte counts to human-readable sizes.
 
+use super::DurationFormat;
 use crate::format::{Format, FormatInfo};
 use crate::types::{
-    Conversion, ConversionPriority, ConversionStep, CoreValue, Interpretation, RichDisplay,
-    RichDisplayOption,
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+    RichDisplay, RichDisplayOption,
 };
 
 pub struct DataSizeFormat;
@@ -34,6 +35,13 @@ const IEC_UNITS: &[(&str, u64)] = &[
     ("PiB", 1_125_899_906_842_624),
 ];
 
+/// Reference bandwidths for transfer time estimates, in bits per second.
+const REFERENCE_BANDWIDTHS: &[(&str, u64)] =
+    &[("100 Mbps", 100_000_000), ("1 Gbps", 1_000_000_000)];
+
+/// Smallest size (1 MB) that gets transfer time estimates.
+const MIN_TRANSFER_ESTIMATE_BYTES: u64 = 1_000_000;
+
 impl DataSizeFormat {
     /// Parse a data size string like "1.5MB" or "512 KiB".
     fn parse_size(s: &str) -> Option<(u64, &'static str)> {
@@ -109,6 +117,15 @@ impl DataSizeFormat {
         format!("{} B", bytes)
     }
 
+    /// Whether the human-readable size shows `bytes` exactly (no rounding).
+    fn is_exact(bytes: u64, units: &[(&str, u64)]) -> bool {
+        units
+            .iter()
+            .rev()
+            .find(|(_, multiplier)| bytes >= *multiplier)
+            .is_none_or(|(_, multiplier)| bytes.is_multiple_of(*multiplier))
+    }
+
     /// Format bytes with thousands separator.
     fn format_with_commas(n: u64) -> String {
         let s = n.to_string();
@@ -218,6 +235,7 @@ impl Format for DataSizeFormat {
                     value: CoreValue::String(iec.clone()),
                     display: iec.clone(),
                 }],
+                is_lossy: !Self::is_exact(bytes, IEC_UNITS),
                 priority: ConversionPriority::Semantic,
                 display_only: true,
                 rich_display: vec![RichDisplayOption::new(RichDisplay::DataSize {
@@ -241,6 +259,7 @@ impl Format for DataSizeFormat {
                     value: CoreValue::String(si.clone()),
                     display: si.clone(),
                 }],
+                is_lossy: !Self::is_exact(bytes, SI_UNITS),
                 priority: ConversionPriority::Semantic,
                 display_only: true,
                 rich_display: vec![RichDisplayOption::new(RichDisplay::DataSize {
@@ -254,6 +273,41 @@ impl Format for DataSizeFormat {
         conversions
     }
 
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        // Transfer times only make sense for sizes, not every integer
+        let CoreValue::Int { value: bytes, .. } = value else {
+            return vec![];
+        };
+        let Ok(bytes) = u64::try_from(*bytes) else {
+            return vec![];
+        };
+        // Small sizes transfer in milliseconds at any speed
+        if bytes < MIN_TRANSFER_ESTIMATE_BYTES {
+            return vec![];
+        }
+
+        REFERENCE_BANDWIDTHS
+            .iter()
+            .map(|(name, bits_per_second)| {
+                let millis = (bytes as f64 * 8.0 * 1000.0 / *bits_per_second as f64).ceil() as u64;
+                let display = format!(
+                    "~{} at {}",
+                    DurationFormat::millis_to_human(millis.max(1)),
+                    name
+                );
+                Conversion {
+                    value: CoreValue::String(display.clone()),
+                    target_format: "transfer-time".to_string(),
+                    display,
+                    priority: ConversionPriority::Semantic,
+                    kind: ConversionKind::Trait,
+                    display_only: true,
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
     fn aliases(&self) -> &'static [&'static str] {
         &["size", "bytes", "filesize"]
     }
@@ -375,6 +429,42 @@ mod tests {
         assert_eq!(si.unwrap().display, "1.05 MB");
     }
 
+    #[test]
+    fn test_rounded_sizes_are_lossy() {
+        let format = DataSizeFormat;
+        let conversions = format.conversions(&CoreValue::Int {
+            value: 1048576,
+            original_bytes: None,
+        });
+        let lossy = |target: &str| {
+            conversions
+                .iter()
+                .find(|c| c.target_format == target)
+                .unwrap()
+                .is_lossy
+        };
+
+        assert!(!lossy("datasize-iec"));
+        assert!(lossy("datasize-si"));
+    }
+
+    #[test]
+    fn test_transfer_time_traits() {
+        let results = DataSizeFormat.parse("8 GB");
+        let conversions = DataSizeFormat.source_conversions(&results[0].value);
+        let displays: Vec<_> = conversions.iter().map(|c| c.display.as_str()).collect();
+        // 64 Gbit over 1 Gbps is 64 seconds
+        assert_eq!(displays, ["~10m40s at 100 Mbps", "~1m4s at 1 Gbps"]);
+        assert!(conversions
+            .iter()
+            .all(|c| c.kind == ConversionKind::Trait && c.target_format == "transfer-time"));
+
+        let small = DataSizeFormat.parse("1KB");
+        assert!(DataSizeFormat
+            .source_conversions(&small[0].value)
+            .is_empty());
+    }
+
     #[test]
     fn test_format_with_commas() {
         assert_eq!(DataSizeFormat::format_with_commas(1000), "1,000");
@@ -1,11 +1,13 @@
 //! DateTime format (epoch timestamp handling).
 
 use chrono::{DateTime, TimeZone, Utc};
+use chrono_tz::Tz;
 
 use crate::format::{Format, FormatInfo};
+use crate::time::format_relative;
 use crate::types::{
-    Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
-    RichDisplayOption,
+    Conversion, ConversionConfig, ConversionKind, ConversionPriority, CoreValue, Interpretation,
+    RelativeTimeStyle, RichDisplay, RichDisplayOption,
 };
 
 /// Reasonable epoch range: 2000-01-01 to 2100-01-01
@@ -49,42 +51,15 @@ const MAX_FILETIME: i64 = (MAX_EPOCH_SECONDS + FILETIME_EPOCH_DIFF) * FILETIME_T
 pub struct DateTimeFormat;
 
 impl DateTimeFormat {
-    /// Format a datetime relative to now (e.g., "2 hours ago", "in 3 days").
-    fn format_relative(dt: DateTime<Utc>) -> String {
-        let now = Utc::now();
-        let diff = dt.signed_duration_since(now);
-        let secs = diff.num_seconds();
-        let abs_secs = secs.abs();
-
-        let (value, unit) = if abs_secs < 60 {
-            (abs_secs, if abs_secs == 1 { "second" } else { "seconds" })
-        } else if abs_secs < 3600 {
-            let mins = abs_secs / 60;
-            (mins, if mins == 1 { "minute" } else { "minutes" })
-        } else if abs_secs < 86400 {
-            let hours = abs_secs / 3600;
-            (hours, if hours == 1 { "hour" } else { "hours" })
-        } else if abs_secs < 604800 {
-            let days = abs_secs / 86400;
-            (days, if days == 1 { "day" } else { "days" })
-        } else if abs_secs < 2592000 {
-            let weeks = abs_secs / 604800;
-            (weeks, if weeks == 1 { "week" } else { "weeks" })
-        } else if abs_secs < 31536000 {
-            let months = abs_secs / 2592000;
-            (months, if months == 1 { "month" } else { "months" })
-        } else {
-            let years = abs_secs / 31536000;
-            (years, if years == 1 { "year" } else { "years" })
-        };
+    /// Parse an IANA timezone name such as `Europe/Stockholm` (case-insensitive).
+    pub fn parse_timezone(name: &str) -> Option<Tz> {
+        Tz::from_str_insensitive(name.trim()).ok()
+    }
 
-        if secs < 0 {
-            format!("{} {} ago", value, unit)
-        } else if secs > 0 {
-            format!("in {} {}", value, unit)
-        } else {
-            "now".to_string()
-        }
+    /// Render a datetime in a timezone as RFC 3339 with the zone name,
+    /// e.g. `2023-12-24T17:26:29-05:00 (America/New_York)`.
+    pub fn format_in_timezone(dt: &DateTime<Utc>, tz: Tz) -> String {
+        format!("{} ({})", dt.with_timezone(&tz).to_rfc3339(), tz.name())
     }
 
     /// Check if a value is a reasonable epoch in seconds.
@@ -118,11 +93,20 @@ impl DateTimeFormat {
         i64::try_from(secs).ok().map(|s| (s, nanos))
     }
 
+    /// Epoch and relative time conversions, with relative times in `style`.
+    fn styled_conversions(value: &CoreValue, style: RelativeTimeStyle) -> Vec<Conversion> {
+        match value {
+            CoreValue::Int { value: int_val, .. } => Self::conversions_from_int(*int_val, style),
+            CoreValue::DateTime(dt) => Self::conversions_from_datetime(dt, style),
+            _ => vec![],
+        }
+    }
+
     /// Convert a DateTime to epoch conversions (seconds, millis, relative time).
-    fn conversions_from_datetime(dt: &DateTime<Utc>) -> Vec<Conversion> {
+    fn conversions_from_datetime(dt: &DateTime<Utc>, style: RelativeTimeStyle) -> Vec<Conversion> {
         let epoch_secs = dt.timestamp();
         let epoch_millis = dt.timestamp_millis();
-        let relative = Self::format_relative(*dt);
+        let relative = format_relative(Utc::now(), *dt, style);
 
         vec![
             Conversion {
@@ -134,11 +118,13 @@ impl DateTimeFormat {
                 display: epoch_secs.to_string(),
                 path: vec!["epoch-seconds".to_string()],
                 is_lossy: false,
+                confidence: 1.0,
                 steps: vec![],
                 priority: ConversionPriority::Semantic,
                 display_only: true,
                 kind: ConversionKind::Conversion,
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             },
             Conversion {
@@ -150,11 +136,13 @@ impl DateTimeFormat {
                 display: epoch_millis.to_string(),
                 path: vec!["epoch-millis".to_string()],
                 is_lossy: false,
+                confidence: 1.0,
                 steps: vec![],
                 priority: ConversionPriority::Semantic,
                 display_only: true,
                 kind: ConversionKind::Conversion,
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             },
             Conversion {
@@ -162,12 +150,15 @@ impl DateTimeFormat {
                 target_format: "relative-time".to_string(),
                 display: relative,
                 path: vec!["relative-time".to_string()],
-                is_lossy: false,
+                // Rounded to the largest unit ("3 days ago")
+                is_lossy: true,
+                confidence: 1.0,
                 steps: vec![],
                 priority: ConversionPriority::Semantic,
                 display_only: true,
                 kind: ConversionKind::Representation,
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             },
         ]
@@ -205,6 +196,73 @@ impl DateTimeFormat {
             .map(|dt| dt.and_utc())
     }
 
+    /// Try to parse an ISO 8601 week date: YYYY-Www-D, or YYYY-Www for its Monday
+    fn parse_iso_week_date(input: &str) -> Option<DateTime<Utc>> {
+        use chrono::{NaiveDate, Weekday};
+
+        let trimmed = input.trim();
+        let (year, rest) = trimmed.split_once("-W")?;
+        let (week, day) = match rest.split_once('-') {
+            Some((week, day)) => (week, day),
+            None => (rest, "1"),
+        };
+
+        if year.len() != 4
+            || week.len() != 2
+            || day.len() != 1
+            || ![year, week, day]
+                .iter()
+                .all(|part| part.bytes().all(|b| b.is_ascii_digit()))
+        {
+            return None;
+        }
+
+        let year: i32 = year.parse().ok()?;
+        if !(1900..=2100).contains(&year) {
+            return None;
+        }
+
+        let weekday = match day {
+            "1" => Weekday::Mon,
+            "2" => Weekday::Tue,
+            "3" => Weekday::Wed,
+            "4" => Weekday::Thu,
+            "5" => Weekday::Fri,
+            "6" => Weekday::Sat,
+            "7" => Weekday::Sun,
+            _ => return None,
+        };
+
+        NaiveDate::from_isoywd_opt(year, week.parse().ok()?, weekday)?
+            .and_hms_opt(0, 0, 0)
+            .map(|dt| dt.and_utc())
+    }
+
+    /// Try to parse an ISO 8601 ordinal date: YYYY-DDD
+    fn parse_iso_ordinal_date(input: &str) -> Option<DateTime<Utc>> {
+        use chrono::NaiveDate;
+
+        let trimmed = input.trim();
+
+        // Exactly 4 + 3 digits, so ranges like 2024-45 or 10-100 don't match
+        let (year, day) = trimmed.split_once('-')?;
+        if year.len() != 4
+            || day.len() != 3
+            || !year.bytes().chain(day.bytes()).all(|b| b.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let year: i32 = year.parse().ok()?;
+        if !(1900..=2100).contains(&year) {
+            return None;
+        }
+
+        NaiveDate::from_yo_opt(year, day.parse().ok()?)?
+            .and_hms_opt(0, 0, 0)
+            .map(|dt| dt.and_utc())
+    }
+
     /// Try to parse EU dot format: DD.MM.YYYY
     fn parse_eu_dot_date(input: &str) -> Option<DateTime<Utc>> {
         use chrono::NaiveDate;
@@ -370,6 +428,39 @@ impl DateTimeFormat {
         None
     }
 
+    /// Try to parse the `ls -l` / syslog style "Jan 15 10:30" (no year).
+    ///
+    /// Like `ls`, the year is the current one unless that puts the
+    /// timestamp in the future, in which case it's last year.
+    fn parse_month_day_time(input: &str) -> Option<DateTime<Utc>> {
+        use chrono::{Datelike, NaiveDate, NaiveTime};
+
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        if parts.len() != 3 {
+            return None;
+        }
+
+        // "Jan" or "January"
+        let month = (1..=12).find(|&m| {
+            let name = Self::month_name(m);
+            name.eq_ignore_ascii_case(parts[0]) || name[..3].eq_ignore_ascii_case(parts[0])
+        })?;
+        let day: u32 = parts[1].parse().ok()?;
+        let time = NaiveTime::parse_from_str(parts[2], "%H:%M")
+            .or_else(|_| NaiveTime::parse_from_str(parts[2], "%H:%M:%S"))
+            .ok()?;
+
+        let now = Utc::now();
+        let at_year =
+            |year| NaiveDate::from_ymd_opt(year, month, day).map(|d| d.and_time(time).and_utc());
+        let dt = at_year(now.year())?;
+        if dt > now + chrono::Duration::days(1) {
+            at_year(now.year() - 1)
+        } else {
+            Some(dt)
+        }
+    }
+
     /// Try to parse "28 Dec 2025" or "28 December 2025" format.
     fn parse_day_month_year(input: &str) -> Option<DateTime<Utc>> {
         use chrono::NaiveDate;
@@ -556,7 +647,7 @@ impl DateTimeFormat {
     }
 
     /// Convert an integer to epoch timestamp conversions.
-    fn conversions_from_int(int_val: i128) -> Vec<Conversion> {
+    fn conversions_from_int(int_val: i128, style: RelativeTimeStyle) -> Vec<Conversion> {
         let mut conversions = vec![];
 
         // Try as epoch seconds
@@ -564,18 +655,20 @@ impl DateTimeFormat {
             if Self::is_valid_epoch_seconds(secs) {
                 if let Some(dt) = Utc.timestamp_opt(secs, 0).single() {
                     let iso = dt.to_rfc3339();
-                    let relative = Self::format_relative(dt);
+                    let relative = format_relative(Utc::now(), dt, style);
                     conversions.push(Conversion {
                         value: CoreValue::DateTime(dt),
                         target_format: "epoch-seconds".to_string(),
                         display: format!("{} ({})", iso, relative),
                         path: vec!["epoch-seconds".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Semantic,
                         display_only: false,
                         kind: ConversionKind::default(),
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
                             epoch_millis: secs * 1000,
                             iso: iso.clone(),
@@ -591,18 +684,20 @@ impl DateTimeFormat {
                 let nanos = ((secs % 1000) * 1_000_000) as u32;
                 if let Some(dt) = Utc.timestamp_opt(epoch_secs, nanos).single() {
                     let iso = dt.to_rfc3339();
-                    let relative = Self::format_relative(dt);
+                    let relative = format_relative(Utc::now(), dt, style);
                     conversions.push(Conversion {
                         value: CoreValue::DateTime(dt),
                         target_format: "epoch-millis".to_string(),
                         display: format!("{} ({})", iso, relative),
                         path: vec!["epoch-millis".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Semantic,
                         display_only: false,
                         kind: ConversionKind::default(),
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
                             epoch_millis: secs,
                             iso: iso.clone(),
@@ -617,18 +712,20 @@ impl DateTimeFormat {
                 let unix_secs = secs + APPLE_REFERENCE_DATE;
                 if let Some(dt) = Utc.timestamp_opt(unix_secs, 0).single() {
                     let iso = dt.to_rfc3339();
-                    let relative = Self::format_relative(dt);
+                    let relative = format_relative(Utc::now(), dt, style);
                     conversions.push(Conversion {
                         value: CoreValue::DateTime(dt),
                         target_format: "apple-cocoa".to_string(),
                         display: format!("{} ({})", iso, relative),
                         path: vec!["apple-cocoa".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Semantic,
                         display_only: false,
                         kind: ConversionKind::default(),
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
                             epoch_millis: unix_secs * 1000,
                             iso: iso.clone(),
@@ -645,18 +742,20 @@ impl DateTimeFormat {
             if let Some((unix_secs, nanos)) = Self::filetime_to_unix(int_val) {
                 if let Some(dt) = Utc.timestamp_opt(unix_secs, nanos).single() {
                     let iso = dt.to_rfc3339();
-                    let relative = Self::format_relative(dt);
+                    let relative = format_relative(Utc::now(), dt, style);
                     conversions.push(Conversion {
                         value: CoreValue::DateTime(dt),
                         target_format: "filetime".to_string(),
                         display: format!("{} ({})", iso, relative),
                         path: vec!["filetime".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Semantic,
                         display_only: false,
                         kind: ConversionKind::default(),
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
                             epoch_millis: unix_secs * 1000 + (nanos / 1_000_000) as i64,
                             iso: iso.clone(),
@@ -715,6 +814,28 @@ impl Format for DateTimeFormat {
             }];
         }
 
+        // Try ISO 8601 week date: YYYY-Www-D
+        if let Some(dt) = Self::parse_iso_week_date(input) {
+            return vec![Interpretation {
+                value: CoreValue::DateTime(dt),
+                source_format: "datetime".to_string(),
+                confidence: 0.90,
+                description: format!("ISO 8601 week date ({})", dt.format("%Y-%m-%d")),
+                rich_display: vec![],
+            }];
+        }
+
+        // Try ISO 8601 ordinal date: YYYY-DDD
+        if let Some(dt) = Self::parse_iso_ordinal_date(input) {
+            return vec![Interpretation {
+                value: CoreValue::DateTime(dt),
+                source_format: "datetime".to_string(),
+                confidence: 0.90,
+                description: format!("ISO 8601 ordinal date ({})", dt.format("%Y-%m-%d")),
+                rich_display: vec![],
+            }];
+        }
+
         // Try Asian/ISO slash format: YYYY/MM/DD
         if let Some(dt) = Self::parse_asian_date(input) {
             return vec![Interpretation {
@@ -770,6 +891,17 @@ impl Format for DateTimeFormat {
             }];
         }
 
+        // Try "Jan 15 10:30" (ls -l and syslog timestamps)
+        if let Some(dt) = Self::parse_month_day_time(input) {
+            return vec![Interpretation {
+                value: CoreValue::DateTime(dt),
+                source_format: "datetime".to_string(),
+                confidence: 0.80,
+                description: "Date and time (Month Day HH:MM)".to_string(),
+                rich_display: vec![],
+            }];
+        }
+
         // Try "28 Dec 2025" or "28 December 2025" format
         if let Some(dt) = Self::parse_day_month_year(input) {
             return vec![Interpretation {
@@ -827,11 +959,15 @@ impl Format for DateTimeFormat {
     }
 
     fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
-        match value {
-            CoreValue::Int { value: int_val, .. } => Self::conversions_from_int(*int_val),
-            CoreValue::DateTime(dt) => Self::conversions_from_datetime(dt),
-            _ => vec![],
-        }
+        Self::styled_conversions(value, RelativeTimeStyle::default())
+    }
+
+    fn conversions_with_config(
+        &self,
+        value: &CoreValue,
+        config: &ConversionConfig,
+    ) -> Vec<Conversion> {
+        Self::styled_conversions(value, config.relative_time_style)
     }
 
     fn aliases(&self) -> &'static [&'static str] {
@@ -858,6 +994,51 @@ mod tests {
         }
     }
 
+    fn parsed_date(input: &str) -> Option<String> {
+        match &DateTimeFormat.parse(input).first()?.value {
+            CoreValue::DateTime(dt) => Some(dt.format("%Y-%m-%d").to_string()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_parse_iso_week_date() {
+        assert_eq!(parsed_date("2024-W03-1").as_deref(), Some("2024-01-15"));
+        assert_eq!(parsed_date("2024-W03").as_deref(), Some("2024-01-15"));
+        // Week 1 of 2025 starts in 2024
+        assert_eq!(parsed_date("2025-W01-2").as_deref(), Some("2024-12-31"));
+        assert_eq!(
+            DateTimeFormat.parse("2024-W03-1")[0].description,
+            "ISO 8601 week date (2024-01-15)"
+        );
+        assert_eq!(parsed_date("2024-W03-8"), None);
+        assert_eq!(parsed_date("2024-W54-1"), None);
+    }
+
+    #[test]
+    fn test_parse_iso_ordinal_date() {
+        assert_eq!(parsed_date("2024-045").as_deref(), Some("2024-02-14"));
+        assert_eq!(parsed_date("2024-366").as_deref(), Some("2024-12-31"));
+        assert_eq!(DateTimeFormat.parse("2024-045")[0].confidence, 0.90);
+        // Not a leap year
+        assert_eq!(parsed_date("2023-366"), None);
+        // Other dash-separated numbers
+        assert_eq!(parsed_date("2024-45"), None);
+        assert_eq!(parsed_date("1234-567"), None);
+        assert_eq!(parsed_date("555-0123"), None);
+    }
+
+    #[test]
+    fn test_format_in_timezone() {
+        let dt = Utc.timestamp_opt(1703456789, 0).unwrap();
+        let tz = DateTimeFormat::parse_timezone("america/new_york").unwrap();
+        assert_eq!(
+            DateTimeFormat::format_in_timezone(&dt, tz),
+            "2023-12-24T17:26:29-05:00 (America/New_York)"
+        );
+        assert!(DateTimeFormat::parse_timezone("Mars/Olympus_Mons").is_none());
+    }
+
     #[test]
     fn test_epoch_seconds_conversion() {
         let format = DateTimeFormat;
@@ -958,4 +1139,25 @@ mod tests {
         // Should not have filetime (too small)
         assert!(!conversions.iter().any(|c| c.target_format == "filetime"));
     }
+
+    #[test]
+    fn test_parse_month_day_time() {
+        use chrono::{Datelike, Timelike};
+
+        let results = DateTimeFormat.parse("Jan 15 10:30");
+        assert_eq!(results.len(), 1);
+        let CoreValue::DateTime(dt) = results[0].value else {
+            panic!("Expected DateTime");
+        };
+        assert_eq!(
+            (dt.month(), dt.day(), dt.hour(), dt.minute()),
+            (1, 15, 10, 30)
+        );
+        // Never in the future, like ls
+        assert!(dt <= Utc::now() + chrono::Duration::days(1));
+
+        assert!(!DateTimeFormat.parse("December 3 08:15:42").is_empty());
+        assert!(DateTimeFormat.parse("Foo 15 10:30").is_empty());
+        assert!(DateTimeFormat.parse("Jan 32 10:30").is_empty());
+    }
 }
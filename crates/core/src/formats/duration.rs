@@ -10,13 +10,14 @@
 //! - `1:30:00` (HH:MM:SS format)
 //!
 //! Converts integers to human-readable durations and shows absolute time.
+//! Parsed durations also convert to datetimes offset from now and from the epoch.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 
 use crate::format::{Format, FormatInfo};
 use crate::types::{
-    Conversion, ConversionPriority, ConversionStep, CoreValue, Interpretation, RichDisplay,
-    RichDisplayOption,
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+    RichDisplay, RichDisplayOption,
 };
 
 pub struct DurationFormat;
@@ -375,7 +376,7 @@ impl DurationFormat {
     }
 
     /// Format milliseconds as human-readable.
-    fn millis_to_human(millis: u64) -> String {
+    pub(crate) fn millis_to_human(millis: u64) -> String {
         Duration::from_millis(millis).format_human()
     }
 
@@ -532,6 +533,52 @@ impl Format for DurationFormat {
         conversions
     }
 
+    /// Datetimes offset by a parsed duration: from now and from the Unix epoch.
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Int { value: secs, .. } = value else {
+            return vec![];
+        };
+        let Ok(secs) = i64::try_from(*secs) else {
+            return vec![];
+        };
+        let Some(offset) = chrono::Duration::try_seconds(secs) else {
+            return vec![];
+        };
+
+        let human = Self::seconds_to_human(secs.unsigned_abs());
+        let mut conversions = Vec::new();
+
+        if let Some(from_now) = Utc::now().checked_add_signed(offset) {
+            conversions.push(Conversion {
+                value: CoreValue::DateTime(from_now),
+                target_format: "from-now".to_string(),
+                display: format!("{} (in {})", from_now.format("%Y-%m-%dT%H:%M:%SZ"), human),
+                priority: ConversionPriority::Primary,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            });
+        }
+
+        if let Some(from_epoch) = DateTime::UNIX_EPOCH.checked_add_signed(offset) {
+            conversions.push(Conversion {
+                value: CoreValue::DateTime(from_epoch),
+                target_format: "from-epoch".to_string(),
+                display: format!(
+                    "{} (epoch + {})",
+                    from_epoch.format("%Y-%m-%dT%H:%M:%SZ"),
+                    human
+                ),
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            });
+        }
+
+        conversions
+    }
+
     fn aliases(&self) -> &'static [&'static str] {
         &["dur", "time", "interval"]
     }
@@ -541,6 +588,31 @@ impl Format for DurationFormat {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_now_and_epoch() {
+        let format = DurationFormat;
+        let value = format.parse("90m").remove(0).value;
+        let expected = Utc::now() + chrono::Duration::minutes(90);
+
+        let conversions = format.source_conversions(&value);
+        let datetime = |target: &str| match &conversions
+            .iter()
+            .find(|c| c.target_format == target)
+            .unwrap()
+            .value
+        {
+            CoreValue::DateTime(dt) => *dt,
+            other => panic!("Expected DateTime, got {:?}", other),
+        };
+
+        let drift = (datetime("from-now") - expected).num_seconds().abs();
+        assert!(drift <= 2, "from-now drifted {}s", drift);
+        assert_eq!(
+            datetime("from-epoch").to_rfc3339(),
+            "1970-01-01T01:30:00+00:00"
+        );
+    }
+
     #[test]
     fn test_parse_simple_units() {
         let format = DurationFormat;
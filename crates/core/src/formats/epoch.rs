@@ -7,7 +7,10 @@ use chrono::{DateTime, TimeZone, Utc};
 use tracing::{debug, trace};
 
 use crate::format::{Format, FormatInfo};
-use crate::types::{CoreValue, Interpretation, RichDisplay, RichDisplayOption};
+use crate::time::format_relative;
+use crate::types::{
+    ConversionConfig, CoreValue, Interpretation, RelativeTimeStyle, RichDisplay, RichDisplayOption,
+};
 
 use super::datetime::{
     MAX_EPOCH_MICROS, MAX_EPOCH_MILLIS, MAX_EPOCH_NANOS, MAX_EPOCH_SECONDS, MIN_EPOCH_MICROS,
@@ -16,10 +19,73 @@ use super::datetime::{
 
 pub struct EpochFormat;
 
+/// Epoch precisions: (unit name, units per second, valid range, confidence penalty).
+const PRECISIONS: &[(&str, i64, (i64, i64), f32)] = &[
+    ("seconds", 1, (MIN_EPOCH_SECONDS, MAX_EPOCH_SECONDS), 0.0),
+    (
+        "milliseconds",
+        1_000,
+        (MIN_EPOCH_MILLIS, MAX_EPOCH_MILLIS),
+        0.05,
+    ),
+    (
+        "microseconds",
+        1_000_000,
+        (MIN_EPOCH_MICROS, MAX_EPOCH_MICROS),
+        0.10,
+    ),
+    (
+        "nanoseconds",
+        1_000_000_000,
+        (MIN_EPOCH_NANOS, MAX_EPOCH_NANOS),
+        0.15,
+    ),
+];
+
 impl EpochFormat {
+    /// Side-by-side comparison of the value read at every epoch precision.
+    ///
+    /// Rows are sorted by plausibility: units whose result falls in the
+    /// supported range (2000–2100) first, ranked by proximity to now.
+    fn precision_table(value: i64) -> RichDisplayOption {
+        let mut candidates: Vec<(f32, Vec<String>)> = PRECISIONS
+            .iter()
+            .map(|(unit, per_sec, (min, max), penalty)| {
+                let secs = value.div_euclid(*per_sec);
+                let nanos = (value.rem_euclid(*per_sec) * (1_000_000_000 / per_sec)) as u32;
+                let dt = Utc.timestamp_opt(secs, nanos).single();
+
+                let plausibility = match dt {
+                    Some(dt) if (*min..=*max).contains(&value) => {
+                        Self::calculate_confidence(dt) - penalty
+                    }
+                    _ => 0.0,
+                };
+                let date = dt.map_or_else(|| "out of range".to_string(), |dt| dt.to_rfc3339());
+                let label = if plausibility > 0.0 {
+                    format!("{:.0}%", plausibility * 100.0)
+                } else {
+                    "implausible".to_string()
+                };
+                (plausibility, vec![unit.to_string(), date, label])
+            })
+            .collect();
+        candidates.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        RichDisplayOption::new(RichDisplay::Table {
+            headers: vec!["Unit".into(), "Date".into(), "Plausibility".into()],
+            rows: candidates.into_iter().map(|(_, row)| row).collect(),
+        })
+    }
+
     /// Calculate dynamic confidence based on proximity to current time.
     /// Timestamps closer to "now" are more likely to be intentional.
     fn calculate_confidence(dt: DateTime<Utc>) -> f32 {
+        Self::proximity(dt).0
+    }
+
+    /// Confidence from proximity to now, with the bucket it fell into.
+    fn proximity(dt: DateTime<Utc>) -> (f32, &'static str) {
         let now = Utc::now();
         let diff_secs = (dt.timestamp() - now.timestamp()).abs();
 
@@ -28,78 +94,19 @@ impl EpochFormat {
         const THIRTY_YEARS: i64 = 30 * YEAR;
 
         if diff_secs < WEEK {
-            0.95 // Within a week - almost certainly intentional
+            (0.95, "within a week of now") // Almost certainly intentional
         } else if diff_secs < YEAR {
-            0.90 // Within a year - very likely a timestamp
+            (0.90, "within a year of now") // Very likely a timestamp
         } else if diff_secs < THIRTY_YEARS {
-            0.87 // Within 30 years - probably a timestamp (beats decimal's 0.85)
+            // Probably a timestamp (beats decimal's 0.85)
+            (0.87, "within 30 years of now")
         } else {
-            0.75 // Distant (but still valid range) - less certain
+            (0.75, "more than 30 years from now") // Valid range, less certain
         }
     }
 
-    /// Format a datetime relative to now (e.g., "2 hours ago", "in 3 days").
-    fn format_relative(dt: DateTime<Utc>) -> String {
-        let now = Utc::now();
-        let diff = dt.signed_duration_since(now);
-        let secs = diff.num_seconds();
-        let abs_secs = secs.abs();
-
-        let (value, unit) = if abs_secs < 60 {
-            (abs_secs, if abs_secs == 1 { "second" } else { "seconds" })
-        } else if abs_secs < 3600 {
-            let mins = abs_secs / 60;
-            (mins, if mins == 1 { "minute" } else { "minutes" })
-        } else if abs_secs < 86400 {
-            let hours = abs_secs / 3600;
-            (hours, if hours == 1 { "hour" } else { "hours" })
-        } else if abs_secs < 604800 {
-            let days = abs_secs / 86400;
-            (days, if days == 1 { "day" } else { "days" })
-        } else if abs_secs < 2592000 {
-            let weeks = abs_secs / 604800;
-            (weeks, if weeks == 1 { "week" } else { "weeks" })
-        } else if abs_secs < 31536000 {
-            let months = abs_secs / 2592000;
-            (months, if months == 1 { "month" } else { "months" })
-        } else {
-            let years = abs_secs / 31536000;
-            (years, if years == 1 { "year" } else { "years" })
-        };
-
-        if secs < 0 {
-            format!("{} {} ago", value, unit)
-        } else if secs > 0 {
-            format!("in {} {}", value, unit)
-        } else {
-            "now".to_string()
-        }
-    }
-}
-
-impl Format for EpochFormat {
-    fn id(&self) -> &'static str {
-        "epoch"
-    }
-
-    fn name(&self) -> &'static str {
-        "Epoch Timestamp"
-    }
-
-    fn info(&self) -> FormatInfo {
-        FormatInfo {
-            id: self.id(),
-            name: self.name(),
-            category: "Timestamps",
-            description:
-                "Unix epoch timestamp (seconds, milliseconds, microseconds, or nanoseconds)",
-            examples: &["1735344000", "1735344000000", "1735344000000000"],
-            aliases: self.aliases(),
-            has_validation: false,
-        }
-    }
-
-    fn parse(&self, input: &str) -> Vec<Interpretation> {
+    /// Parse an epoch timestamp, describing relative times in `style`.
+    fn parse_styled(input: &str, style: RelativeTimeStyle) -> Vec<Interpretation> {
         let trimmed = input.trim();
         trace!(input_len = trimmed.len(), "epoch: checking input");
 
@@ -116,7 +123,7 @@ impl Format for EpochFormat {
             if let Some(dt) = Utc.timestamp_opt(value, 0).single() {
                 let confidence = Self::calculate_confidence(dt);
                 let iso = dt.to_rfc3339();
-                let relative = Self::format_relative(dt);
+                let relative = format_relative(Utc::now(), dt, style);
 
                 debug!(epoch = value, confidence, iso, "epoch: matched as seconds");
 
@@ -125,11 +132,14 @@ impl Format for EpochFormat {
                     source_format: "epoch-seconds".to_string(),
                     confidence,
                     description: format!("{} ({})", iso, relative),
-                    rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
-                        epoch_millis: value * 1000,
-                        iso: iso.clone(),
-                        relative,
-                    })],
+                    rich_display: vec![
+                        RichDisplayOption::new(RichDisplay::DateTime {
+                            epoch_millis: value * 1000,
+                            iso: iso.clone(),
+                            relative,
+                        }),
+                        Self::precision_table(value),
+                    ],
                 });
             }
         }
@@ -144,7 +154,7 @@ impl Format for EpochFormat {
                 let base_confidence = Self::calculate_confidence(dt);
                 let confidence = (base_confidence - 0.05).max(0.70);
                 let iso = dt.to_rfc3339();
-                let relative = Self::format_relative(dt);
+                let relative = format_relative(Utc::now(), dt, style);
 
                 debug!(
                     epoch = value,
@@ -156,11 +166,14 @@ impl Format for EpochFormat {
                     source_format: "epoch-millis".to_string(),
                     confidence,
                     description: format!("{} ({})", iso, relative),
-                    rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
-                        epoch_millis: value,
-                        iso: iso.clone(),
-                        relative,
-                    })],
+                    rich_display: vec![
+                        RichDisplayOption::new(RichDisplay::DateTime {
+                            epoch_millis: value,
+                            iso: iso.clone(),
+                            relative,
+                        }),
+                        Self::precision_table(value),
+                    ],
                 });
             }
         }
@@ -173,7 +186,7 @@ impl Format for EpochFormat {
                 let base_confidence = Self::calculate_confidence(dt);
                 let confidence = (base_confidence - 0.10).max(0.65);
                 let iso = dt.to_rfc3339();
-                let relative = Self::format_relative(dt);
+                let relative = format_relative(Utc::now(), dt, style);
 
                 debug!(
                     epoch = value,
@@ -185,11 +198,14 @@ impl Format for EpochFormat {
                     source_format: "epoch-micros".to_string(),
                     confidence,
                     description: format!("{} ({})", iso, relative),
-                    rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
-                        epoch_millis: value / 1000,
-                        iso: iso.clone(),
-                        relative,
-                    })],
+                    rich_display: vec![
+                        RichDisplayOption::new(RichDisplay::DateTime {
+                            epoch_millis: value / 1000,
+                            iso: iso.clone(),
+                            relative,
+                        }),
+                        Self::precision_table(value),
+                    ],
                 });
             }
         }
@@ -202,7 +218,7 @@ impl Format for EpochFormat {
                 let base_confidence = Self::calculate_confidence(dt);
                 let confidence = (base_confidence - 0.15).max(0.60);
                 let iso = dt.to_rfc3339();
-                let relative = Self::format_relative(dt);
+                let relative = format_relative(Utc::now(), dt, style);
 
                 debug!(
                     epoch = value,
@@ -214,17 +230,88 @@ impl Format for EpochFormat {
                     source_format: "epoch-nanos".to_string(),
                     confidence,
                     description: format!("{} ({})", iso, relative),
-                    rich_display: vec![RichDisplayOption::new(RichDisplay::DateTime {
-                        epoch_millis: value / 1_000_000,
-                        iso: iso.clone(),
-                        relative,
-                    })],
+                    rich_display: vec![
+                        RichDisplayOption::new(RichDisplay::DateTime {
+                            epoch_millis: value / 1_000_000,
+                            iso: iso.clone(),
+                            relative,
+                        }),
+                        Self::precision_table(value),
+                    ],
                 });
             }
         }
 
         results
     }
+}
+
+impl Format for EpochFormat {
+    fn id(&self) -> &'static str {
+        "epoch"
+    }
+
+    fn name(&self) -> &'static str {
+        "Epoch Timestamp"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Timestamps",
+            description:
+                "Unix epoch timestamp (seconds, milliseconds, microseconds, or nanoseconds)",
+            examples: &["1735344000", "1735344000000", "1735344000000000"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        Self::parse_styled(input, RelativeTimeStyle::default())
+    }
+
+    fn parse_with_config(&self, input: &str, config: &ConversionConfig) -> Vec<Interpretation> {
+        Self::parse_styled(input, config.relative_time_style)
+    }
+
+    fn explain(&self, input: &str) -> Option<String> {
+        let Ok(value) = input.trim().parse::<i64>() else {
+            return Some("rejected: not an integer".to_string());
+        };
+
+        let readings: Vec<String> = PRECISIONS
+            .iter()
+            .map(|(unit, per_sec, (min, max), penalty)| {
+                if !(*min..=*max).contains(&value) {
+                    return format!("{}: outside 2000-2100", unit);
+                }
+                let secs = value.div_euclid(*per_sec);
+                let nanos = (value.rem_euclid(*per_sec) * (1_000_000_000 / per_sec)) as u32;
+                match Utc.timestamp_opt(secs, nanos).single() {
+                    Some(dt) => {
+                        let (confidence, bucket) = Self::proximity(dt);
+                        let penalty_note = if *penalty > 0.0 {
+                            format!(" minus {:.2} for {}", penalty, unit)
+                        } else {
+                            String::new()
+                        };
+                        format!(
+                            "{}: {} is {}, confidence {:.2}{}",
+                            unit,
+                            dt.format("%Y-%m-%d"),
+                            bucket,
+                            confidence - penalty,
+                            penalty_note
+                        )
+                    }
+                    None => format!("{}: not a valid date", unit),
+                }
+            })
+            .collect();
+        Some(readings.join("; "))
+    }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
         false // Formatting handled by datetime.rs conversions
@@ -306,4 +393,36 @@ mod tests {
         // After 2100
         assert!(format.parse("5000000000").is_empty());
     }
+
+    #[test]
+    fn test_precision_table_13_digits() {
+        let format = EpochFormat;
+        let results = format.parse("1735344000000");
+        let millis = results
+            .iter()
+            .find(|i| i.source_format == "epoch-millis")
+            .expect("Should have epoch-millis interpretation");
+
+        let table = millis
+            .rich_display
+            .iter()
+            .find_map(|opt| match &opt.preferred {
+                RichDisplay::Table { headers, rows } => Some((headers, rows)),
+                _ => None,
+            })
+            .expect("Should have precision comparison table");
+
+        assert_eq!(table.0, &["Unit", "Date", "Plausibility"]);
+        assert_eq!(table.1.len(), 4);
+
+        // Milliseconds is the plausible reading and sorts first
+        assert_eq!(table.1[0][0], "milliseconds");
+        assert!(table.1[0][1].starts_with("2024-12-28"));
+        assert!(table.1[0][2].ends_with('%'));
+
+        // Seconds is still shown, far in the future and implausible
+        let seconds = table.1.iter().find(|r| r[0] == "seconds").unwrap();
+        assert!(seconds[1].starts_with("+56960"), "{:?}", seconds);
+        assert_eq!(seconds[2], "implausible");
+    }
 }
@@ -5,12 +5,99 @@
 //! - `0xFF + 1` → 256
 //! - `0b1010 | 0b0101` → 15 (using bitor function)
 //! - `2 ^ 16` → 65536
+//! - `1/3 + 1/6` → 1/2 (exact while only integers and fractions are involved)
+
+use evalexpr::{Node, Operator, Value};
 
 use crate::format::{Format, FormatInfo};
-use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
+use crate::formats::FractionFormat;
+use crate::types::{
+    Conversion, ConversionConfig, ConversionKind, ConversionPriority, CoreValue, Interpretation,
+};
 
 pub struct ExprFormat;
 
+/// Largest exponent evaluated exactly; bigger powers overflow i64 anyway.
+const MAX_EXACT_EXPONENT: i64 = 64;
+
+/// A number during exact evaluation: a fraction while only integers and
+/// fractions are involved, a float once anything else (`math::sqrt`, `0.5`) is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Number {
+    /// Numerator and denominator in lowest terms, denominator positive
+    Exact(i64, i64),
+    Approx(f64),
+}
+
+impl Number {
+    /// Reduce a fraction, falling back to a float if it doesn't fit in i64.
+    fn ratio(num: i128, den: i128) -> Self {
+        if den == 0 {
+            // Division by zero: NaN or infinity, which parse() rejects
+            return Self::Approx(num as f64 / 0.0);
+        }
+        let divisor = gcd(num.unsigned_abs(), den.unsigned_abs()) as i128;
+        let (num, den) = (num / divisor, den / divisor);
+        let (num, den) = if den < 0 { (-num, -den) } else { (num, den) };
+        match (i64::try_from(num), i64::try_from(den)) {
+            (Ok(num), Ok(den)) => Self::Exact(num, den),
+            _ => Self::Approx(num as f64 / den as f64),
+        }
+    }
+
+    fn to_f64(self) -> f64 {
+        match self {
+            Self::Exact(num, den) => num as f64 / den as f64,
+            Self::Approx(f) => f,
+        }
+    }
+
+    /// Apply an arithmetic operator, exactly when both sides are exact.
+    fn apply(operator: &Operator, a: Self, b: Self) -> Option<Self> {
+        let (Self::Exact(an, ad), Self::Exact(bn, bd)) = (a, b) else {
+            let (a, b) = (a.to_f64(), b.to_f64());
+            return Some(Self::Approx(match operator {
+                Operator::Add => a + b,
+                Operator::Sub => a - b,
+                Operator::Mul => a * b,
+                Operator::Div => a / b,
+                Operator::Mod => a % b,
+                Operator::Exp => a.powf(b),
+                _ => return None,
+            }));
+        };
+        let (an, ad, bn, bd) = (an as i128, ad as i128, bn as i128, bd as i128);
+
+        Some(match operator {
+            Operator::Add => Self::ratio(an * bd + bn * ad, ad * bd),
+            Operator::Sub => Self::ratio(an * bd - bn * ad, ad * bd),
+            Operator::Mul => Self::ratio(an * bn, ad * bd),
+            Operator::Div => Self::ratio(an * bd, ad * bn),
+            Operator::Mod if ad == 1 && bd == 1 => match an.checked_rem(bn) {
+                Some(rem) => Self::ratio(rem, 1),
+                None => Self::Approx(f64::NAN),
+            },
+            Operator::Exp if bd == 1 && bn.abs() <= i128::from(MAX_EXACT_EXPONENT) => {
+                let exponent = bn.unsigned_abs() as u32;
+                match (an.checked_pow(exponent), ad.checked_pow(exponent)) {
+                    (Some(num), Some(den)) if bn >= 0 => Self::ratio(num, den),
+                    (Some(num), Some(den)) => Self::ratio(den, num),
+                    _ => Self::Approx(a.to_f64().powf(b.to_f64())),
+                }
+            }
+            _ => return Self::apply(operator, Self::Approx(a.to_f64()), b),
+        })
+    }
+}
+
+/// Greatest common divisor (1 for 0/0, so reducing never divides by zero).
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
 impl ExprFormat {
     /// Check if input looks like an expression (has operators or function calls).
     fn looks_like_expression(input: &str) -> bool {
@@ -30,8 +117,9 @@ impl ExprFormat {
         // - URLs (has :// or starts with http)
         let looks_like_uuid = input.len() == 36 && input.chars().filter(|c| *c == '-').count() == 4;
         let looks_like_url = input.contains("://") || input.starts_with("http");
-        let looks_like_date =
-            input.contains('/') && input.chars().filter(|c| *c == '/').count() >= 2;
+        // (12/25/2025, but not 1/3 + 1/3 + 1/3)
+        let looks_like_date = input.chars().filter(|c| *c == '/').count() >= 2
+            && input.chars().all(|c| c.is_ascii_digit() || c == '/');
 
         (has_operator || has_function_call)
             && has_alphanumeric
@@ -95,6 +183,83 @@ impl ExprFormat {
 
         result
     }
+
+    /// Evaluate an expression tree, keeping integer and fraction arithmetic exact.
+    ///
+    /// Function arguments are evaluated exactly too; `min`, `max` and
+    /// `math::abs` keep fractions, and other functions get them as floats.
+    /// Variables are evaluated by evalexpr. A float result makes everything
+    /// that depends on it a float too. Returns `None` for anything that isn't
+    /// a number.
+    fn evaluate(node: &Node, config: &ConversionConfig) -> Option<Number> {
+        let operand = |i: usize| {
+            node.children()
+                .get(i)
+                .and_then(|child| Self::evaluate(child, config))
+        };
+
+        match node.operator() {
+            Operator::RootNode if node.children().len() == 1 => operand(0),
+            Operator::Const {
+                value: Value::Int(i),
+            } => Some(Number::Exact(*i, 1)),
+            Operator::Const {
+                value: Value::Float(f),
+            } => Some(Number::Approx(*f)),
+            Operator::Neg => match operand(0)? {
+                Number::Exact(num, den) => Some(Number::ratio(-(num as i128), den as i128)),
+                Number::Approx(f) => Some(Number::Approx(-f)),
+            },
+            operator @ (Operator::Add
+            | Operator::Sub
+            | Operator::Mul
+            | Operator::Div
+            | Operator::Mod
+            | Operator::Exp) => Number::apply(operator, operand(0)?, operand(1)?),
+            Operator::FunctionIdentifier { identifier } => {
+                let arguments = node.children().first()?;
+                let arguments = match arguments.children() {
+                    [] => vec![],
+                    [tuple] if matches!(tuple.operator(), Operator::Tuple) => tuple
+                        .children()
+                        .iter()
+                        .map(|child| Self::evaluate(child, config))
+                        .collect::<Option<_>>()?,
+                    _ => vec![Self::evaluate(arguments, config)?],
+                };
+                Self::call(identifier, &arguments, config)
+            }
+            _ => match crate::expr_context::eval_node(node, config).ok()? {
+                Value::Int(i) => Some(Number::Exact(i, 1)),
+                Value::Float(f) => Some(Number::Approx(f)),
+                _ => None,
+            },
+        }
+    }
+
+    /// Call a function on exactly evaluated arguments.
+    fn call(identifier: &str, arguments: &[Number], config: &ConversionConfig) -> Option<Number> {
+        let by_value = |a: &&Number, b: &&Number| a.to_f64().total_cmp(&b.to_f64());
+        match (identifier, arguments) {
+            ("min", [_, ..]) => arguments.iter().min_by(by_value).copied(),
+            ("max", [_, ..]) => arguments.iter().max_by(by_value).copied(),
+            ("math::abs", [Number::Exact(num, den)]) => Some(Number::Exact(num.abs(), *den)),
+            _ => {
+                let arguments: Vec<Value> = arguments
+                    .iter()
+                    .map(|argument| match *argument {
+                        Number::Exact(num, 1) => Value::Int(num),
+                        other => Value::Float(other.to_f64()),
+                    })
+                    .collect();
+                match crate::expr_context::call_function(identifier, &arguments, config).ok()? {
+                    Value::Int(i) => Some(Number::Exact(i, 1)),
+                    Value::Float(f) => Some(Number::Approx(f)),
+                    _ => None,
+                }
+            }
+        }
+    }
 }
 
 impl Format for ExprFormat {
@@ -119,6 +284,10 @@ impl Format for ExprFormat {
     }
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
+        self.parse_with_config(input, &ConversionConfig::default())
+    }
+
+    fn parse_with_config(&self, input: &str, config: &ConversionConfig) -> Vec<Interpretation> {
         let trimmed = input.trim();
 
         // Quick check - does this look like an expression?
@@ -130,15 +299,31 @@ impl Format for ExprFormat {
         let processed = Self::preprocess(trimmed);
 
         // Try to evaluate using the global context (which may have plugin vars/funcs)
-        let eval_result = match crate::expr_context::eval(&processed) {
+        let eval_result = match crate::expr_context::eval(&processed, config) {
             Ok(r) => r,
             Err(_) => return vec![],
         };
 
+        // Redo the arithmetic exactly, since evalexpr truncates integer division (1/3 = 0),
+        // also inside function arguments (USD(1/2))
+        let exact = evalexpr::build_operator_tree(&processed)
+            .ok()
+            .and_then(|tree| Self::evaluate(&tree, config));
+
         // Convert evalexpr Value to our CoreValue
         // If currency was used, wrap in CoreValue::Currency for proper formatting
-        let (core_value, description) = match eval_result.value {
-            evalexpr::Value::Int(i) => {
+        let (core_value, description) = match (exact, eval_result.value) {
+            (Some(Number::Exact(num, den)), _) if den != 1 => {
+                let desc = format!(
+                    "{} = {}/{} ≈ {}",
+                    trimmed,
+                    num,
+                    den,
+                    num as f64 / den as f64
+                );
+                (CoreValue::Rational { num, den }, desc)
+            }
+            (Some(Number::Exact(i, _)), _) | (None, Value::Int(i)) => {
                 let desc = if let Some(ref currency) = eval_result.result_currency {
                     format!("{} = {} {}", trimmed, i, currency)
                 } else {
@@ -157,7 +342,7 @@ impl Format for ExprFormat {
                 };
                 (value, desc)
             }
-            evalexpr::Value::Float(f) => {
+            (Some(Number::Approx(f)), _) | (None, Value::Float(f)) => {
                 // Only accept if it's a "clean" result (not NaN/Inf)
                 if !f.is_finite() {
                     return vec![];
@@ -243,11 +428,13 @@ impl Format for ExprFormat {
                 display: i.to_string(),
                 path: vec![], // Will be set by BFS
                 is_lossy: false,
+                confidence: 1.0,
                 steps: vec![], // Will be set by BFS
                 priority: ConversionPriority::Primary,
                 kind: ConversionKind::Conversion,
                 display_only: true, // Don't explore further from result
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             }],
             CoreValue::Float(f) => vec![Conversion {
@@ -256,13 +443,50 @@ impl Format for ExprFormat {
                 display: f.to_string(),
                 path: vec![], // Will be set by BFS
                 is_lossy: false,
+                confidence: 1.0,
                 steps: vec![], // Will be set by BFS
                 priority: ConversionPriority::Primary,
                 kind: ConversionKind::Conversion,
                 display_only: true, // Don't explore further from result
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             }],
+            CoreValue::Rational { num, den } => vec![
+                Conversion {
+                    value: CoreValue::Rational {
+                        num: *num,
+                        den: *den,
+                    },
+                    target_format: "result".to_string(),
+                    display: FractionFormat::format_fraction(*num, *den),
+                    path: vec![], // Will be set by BFS
+                    is_lossy: false,
+                    confidence: 1.0,
+                    steps: vec![], // Will be set by BFS
+                    priority: ConversionPriority::Primary,
+                    kind: ConversionKind::Conversion,
+                    display_only: true, // Don't explore further from result
+                    hidden: false,
+                    depth_limited: false,
+                    rich_display: vec![],
+                },
+                Conversion {
+                    value: CoreValue::Float(*num as f64 / *den as f64),
+                    target_format: "float".to_string(),
+                    display: (*num as f64 / *den as f64).to_string(),
+                    path: vec![], // Will be set by BFS
+                    is_lossy: true,
+                    confidence: 1.0,
+                    steps: vec![], // Will be set by BFS
+                    priority: ConversionPriority::Primary,
+                    kind: ConversionKind::Representation,
+                    display_only: true,
+                    hidden: false,
+                    depth_limited: false,
+                    rich_display: vec![],
+                },
+            ],
             CoreValue::Currency { amount, code } => vec![Conversion {
                 value: CoreValue::Currency {
                     amount: *amount,
@@ -272,11 +496,13 @@ impl Format for ExprFormat {
                 display: format!("{:.2} {}", amount, code),
                 path: vec![], // Will be set by BFS
                 is_lossy: false,
+                confidence: 1.0,
                 steps: vec![], // Will be set by BFS
                 priority: ConversionPriority::Primary,
                 kind: ConversionKind::Conversion,
                 display_only: true, // Don't explore further from result
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             }],
             _ => vec![],
@@ -391,12 +617,88 @@ mod tests {
         let results = format.parse("2 ^ 16");
 
         assert_eq!(results.len(), 1);
-        // evalexpr returns float for power
+        // Integer powers are exact
+        if let CoreValue::Int { value, .. } = &results[0].value {
+            assert_eq!(*value, 65536);
+        } else {
+            panic!("Expected Int, got {:?}", results[0].value);
+        }
+    }
+
+    #[test]
+    fn test_exact_fractions() {
+        let format = ExprFormat;
+
+        let results = format.parse("1/3 + 1/3 + 1/3");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].value,
+            CoreValue::Int {
+                value: 1,
+                original_bytes: None
+            }
+        );
+
+        let results = format.parse("1/3 * 2");
+        assert_eq!(results[0].value, CoreValue::Rational { num: 2, den: 3 });
+        assert_eq!(results[0].description, "1/3 * 2 = 2/3 ≈ 0.6666666666666666");
+
+        // Integer division no longer truncates
+        let results = format.parse("7 / -2");
+        assert_eq!(results[0].value, CoreValue::Rational { num: -7, den: 2 });
+        let results = format.parse("2 ^ -2");
+        assert_eq!(results[0].value, CoreValue::Rational { num: 1, den: 4 });
+    }
+
+    #[test]
+    fn test_exact_function_arguments() {
+        let format = ExprFormat;
+
+        // min/max/abs keep fractions
+        let results = format.parse("max(1/2, 1/3)");
+        assert_eq!(results[0].value, CoreValue::Rational { num: 1, den: 2 });
+        let results = format.parse("min(1/2, 1/3) * 3");
+        assert_eq!(
+            results[0].value,
+            CoreValue::Int {
+                value: 1,
+                original_bytes: None
+            }
+        );
+        let results = format.parse("math::abs(-7/2)");
+        assert_eq!(results[0].value, CoreValue::Rational { num: 7, den: 2 });
+
+        // Other functions get the untruncated value (evalexpr alone gives sqrt(0) = 0)
+        let results = format.parse("math::sqrt(1/4)");
+        assert_eq!(results[0].value, CoreValue::Float(0.5));
+        let results = format.parse("floor(7/2) + 1");
+        assert_eq!(results[0].value, CoreValue::Float(4.0));
+    }
+
+    #[test]
+    fn test_irrational_falls_back_to_float() {
+        let format = ExprFormat;
+        let results = format.parse("math::sqrt(2) + 1/3");
+        assert_eq!(results.len(), 1);
         if let CoreValue::Float(value) = &results[0].value {
-            assert_eq!(*value, 65536.0);
+            assert!((value - (2f64.sqrt() + 1.0 / 3.0)).abs() < 1e-12);
         } else {
             panic!("Expected Float, got {:?}", results[0].value);
         }
+
+        // Overflowing exact arithmetic degrades the same way
+        let results = format.parse("2 ^ 100 / 3");
+        assert!(matches!(results[0].value, CoreValue::Float(_)));
+    }
+
+    #[test]
+    fn test_rational_conversions() {
+        let conversions = ExprFormat.source_conversions(&CoreValue::Rational { num: 7, den: 2 });
+        let displays: Vec<_> = conversions
+            .iter()
+            .map(|c| (c.target_format.as_str(), c.display.as_str()))
+            .collect();
+        assert_eq!(displays, [("result", "7/2 (3 1/2)"), ("float", "3.5")]);
     }
 
     #[test]
@@ -28,14 +28,34 @@ struct FontMetadata {
 }
 
 impl FontFormat {
+    /// Check for an sfnt header (shared by TTF and OTF) with the given version tag.
+    ///
+    /// Four bytes like `00 01 00 00` are common in binary data, so the table
+    /// count and the binary search fields derived from it must agree too.
+    fn is_sfnt(data: &[u8], tag: &[u8; 4]) -> bool {
+        if data.len() < 12 || !data.starts_with(tag) {
+            return false;
+        }
+        let read_u16 = |offset: usize| u16::from_be_bytes([data[offset], data[offset + 1]]);
+        let num_tables = read_u16(4);
+        if !(1..=64).contains(&num_tables) {
+            return false;
+        }
+        let entry_selector = num_tables.ilog2() as u16;
+        let search_range = 16 << entry_selector;
+        read_u16(6) == search_range
+            && read_u16(8) == entry_selector
+            && read_u16(10) == num_tables * 16 - search_range
+    }
+
     /// Check if data is a TrueType font (TTF).
     fn is_ttf(data: &[u8]) -> bool {
-        data.len() >= 4 && data.starts_with(&[0x00, 0x01, 0x00, 0x00])
+        Self::is_sfnt(data, &[0x00, 0x01, 0x00, 0x00])
     }
 
     /// Check if data is an OpenType font (OTF).
     fn is_otf(data: &[u8]) -> bool {
-        data.len() >= 4 && data.starts_with(b"OTTO")
+        Self::is_sfnt(data, b"OTTO")
     }
 
     /// Check if data is a WOFF font.
@@ -49,7 +69,7 @@ impl FontFormat {
     }
 
     /// Detect font type from magic bytes.
-    fn detect_font_type(data: &[u8]) -> Option<&'static str> {
+    pub(crate) fn detect_font_type(data: &[u8]) -> Option<&'static str> {
         if Self::is_ttf(data) {
             Some("TrueType")
         } else if Self::is_otf(data) {
@@ -205,23 +225,24 @@ impl Format for FontFormat {
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         // Try to decode as base64
-        if let Ok(bytes) = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input)
-        {
-            if let Some(meta) = Self::parse_font(&bytes) {
-                let description = Self::format_description(&meta);
-                let rich_display = Self::build_rich_display(&meta);
-
-                return vec![Interpretation {
-                    value: CoreValue::Bytes(bytes),
-                    source_format: "font".to_string(),
-                    confidence: 0.95,
-                    description,
-                    rich_display,
-                }];
-            }
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input) {
+            Ok(bytes) => self.parse_bytes(&bytes),
+            Err(_) => vec![],
         }
+    }
 
-        vec![]
+    fn parse_bytes(&self, bytes: &[u8]) -> Vec<Interpretation> {
+        let Some(meta) = Self::parse_font(bytes) else {
+            return vec![];
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes.to_vec()),
+            source_format: "font".to_string(),
+            confidence: 0.95,
+            description: Self::format_description(&meta),
+            rich_display: Self::build_rich_display(&meta),
+        }]
     }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
@@ -251,10 +272,12 @@ impl Format for FontFormat {
             path: vec!["font-info".to_string()],
             steps: vec![],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Structured,
             display_only: true,
             kind: ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display,
         }]
     }
@@ -268,13 +291,14 @@ impl Format for FontFormat {
 mod tests {
     use super::*;
 
+    /// sfnt header with 16 tables (search range 256, entry selector 4, range shift 0).
+    const TTF_HEADER: [u8; 12] = [0, 1, 0, 0, 0, 16, 1, 0, 0, 4, 0, 0];
+    const OTF_HEADER: [u8; 12] = [b'O', b'T', b'T', b'O', 0, 16, 1, 0, 0, 4, 0, 0];
+
     #[test]
     fn test_detect_font_type() {
-        assert_eq!(
-            FontFormat::detect_font_type(&[0x00, 0x01, 0x00, 0x00]),
-            Some("TrueType")
-        );
-        assert_eq!(FontFormat::detect_font_type(b"OTTO"), Some("OpenType"));
+        assert_eq!(FontFormat::detect_font_type(&TTF_HEADER), Some("TrueType"));
+        assert_eq!(FontFormat::detect_font_type(&OTF_HEADER), Some("OpenType"));
         assert_eq!(FontFormat::detect_font_type(b"wOFF"), Some("WOFF"));
         assert_eq!(FontFormat::detect_font_type(b"wOF2"), Some("WOFF2"));
         assert_eq!(FontFormat::detect_font_type(b"not a font"), None);
@@ -282,13 +306,19 @@ mod tests {
 
     #[test]
     fn test_is_ttf() {
-        assert!(FontFormat::is_ttf(&[0x00, 0x01, 0x00, 0x00, 0xFF]));
-        assert!(!FontFormat::is_ttf(b"OTTO"));
+        assert!(FontFormat::is_ttf(&TTF_HEADER));
+        assert!(!FontFormat::is_ttf(&OTF_HEADER));
+        // Right version tag, but the table count and search fields disagree
+        assert!(!FontFormat::is_ttf(&[
+            0, 1, 0, 0, 0xFF, 0xFF, 0, 0, 0, 0, 0, 0
+        ]));
+        assert!(!FontFormat::is_ttf(&[0, 1, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0]));
     }
 
     #[test]
     fn test_is_otf() {
-        assert!(FontFormat::is_otf(b"OTTO"));
-        assert!(!FontFormat::is_otf(&[0x00, 0x01, 0x00, 0x00]));
+        assert!(FontFormat::is_otf(&OTF_HEADER));
+        assert!(!FontFormat::is_otf(&TTF_HEADER));
+        assert!(!FontFormat::is_otf(b"OTTO"));
     }
 }
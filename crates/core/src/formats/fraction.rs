@@ -0,0 +1,315 @@
+//! Fraction format.
+//!
+//! Parses simple and mixed fractions and reads them as decimals:
+//! - `3/8` → 0.375
+//! - `1 1/2` → 1.5
+//!
+//! Decimals go the other way, to the closest simple fraction found by
+//! continued fractions (`0.333` ≈ 1/3).
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
+
+pub struct FractionFormat;
+
+/// Largest denominator for an approximation of a decimal.
+const MAX_APPROX_DENOMINATOR: i64 = 100;
+
+/// Error accepted for an approximation (0.333 ≈ 1/3).
+const APPROX_TOLERANCE: f64 = 1e-3;
+
+/// `3/8`, `-3/8`, `1 1/2`
+fn fraction_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"^(?P<sign>-)?(?:(?P<whole>\d{1,9})\s+)?(?P<num>\d{1,9})/(?P<den>\d{1,9})$")
+            .unwrap()
+    })
+}
+
+/// `0.333`, `-3.14` (a fractional part is required)
+fn decimal_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"^-?\d{0,9}\.\d{1,9}$").unwrap())
+}
+
+impl FractionFormat {
+    /// Parse a simple or mixed fraction into (numerator, denominator).
+    fn parse_fraction(input: &str) -> Option<(i64, i64)> {
+        let caps = fraction_regex().captures(input)?;
+        let number = |name: &str| caps.name(name).map(|m| m.as_str());
+
+        let (num_str, den_str) = (number("num")?, number("den")?);
+        // "03/08" and "2025/12" are dates, not fractions
+        if [num_str, den_str]
+            .iter()
+            .any(|s| s.len() > 1 && s.starts_with('0'))
+            || (num_str.len() == 4 && den_str.len() <= 2)
+        {
+            return None;
+        }
+
+        let numerator: i64 = num_str.parse().ok()?;
+        let denominator: i64 = den_str.parse().ok()?;
+        if denominator == 0 {
+            return None;
+        }
+
+        let numerator = match number("whole") {
+            // A mixed number's fraction is proper: "1 1/2", not "1 3/2"
+            Some(whole) if numerator < denominator => {
+                whole.parse::<i64>().ok()? * denominator + numerator
+            }
+            Some(_) => return None,
+            None => numerator,
+        };
+
+        let sign = if number("sign").is_some() { -1 } else { 1 };
+        Some((sign * numerator, denominator))
+    }
+
+    /// Closest fraction to a value as (numerator, denominator, exact).
+    ///
+    /// Walks the continued fraction convergents, stopping at the first one
+    /// that's exact or within [`APPROX_TOLERANCE`]. Returns `None` for whole
+    /// numbers and values with no simple fraction nearby.
+    fn approximate(value: f64) -> Option<(i64, i64, bool)> {
+        if !value.is_finite() || value.fract() == 0.0 || value.abs() > 1e9 {
+            return None;
+        }
+
+        // Convergents h/k, seeded with h(-1)/k(-1) = 1/0 and h(-2)/k(-2) = 0/1
+        let (mut h, mut h_prev) = (1i64, 0i64);
+        let (mut k, mut k_prev) = (0i64, 1i64);
+        let mut x = value;
+
+        loop {
+            let a = x.floor();
+            let (h_next, k_next) = (a as i64 * h + h_prev, a as i64 * k + k_prev);
+            if k_next > 1_000_000 {
+                return None;
+            }
+            (h_prev, h, k_prev, k) = (h, h_next, k, k_next);
+
+            let error = (value - h as f64 / k as f64).abs();
+            if error <= 1e-9 * value.abs().max(1.0) {
+                return Some((h, k, true));
+            }
+            if h != 0 && k <= MAX_APPROX_DENOMINATOR && error <= APPROX_TOLERANCE {
+                return Some((h, k, false));
+            }
+
+            let remainder = x - a;
+            if remainder == 0.0 {
+                return None;
+            }
+            x = 1.0 / remainder;
+        }
+    }
+
+    /// Render a fraction, adding the mixed form when it's improper: "3/2 (1 1/2)".
+    pub(crate) fn format_fraction(numerator: i64, denominator: i64) -> String {
+        let whole = numerator / denominator;
+        if whole == 0 {
+            return format!("{}/{}", numerator, denominator);
+        }
+        format!(
+            "{}/{} ({} {}/{})",
+            numerator,
+            denominator,
+            whole,
+            (numerator % denominator).abs(),
+            denominator
+        )
+    }
+}
+
+/// Format a decimal without float noise (0.375 rather than 0.37500000000000006).
+fn format_decimal(n: f64) -> String {
+    let rounded = (n * 1e9).round() / 1e9;
+    if rounded == 0.0 {
+        return "0".to_string();
+    }
+    rounded.to_string()
+}
+
+impl Format for FractionFormat {
+    fn id(&self) -> &'static str {
+        "fraction"
+    }
+
+    fn name(&self) -> &'static str {
+        "Fraction"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Math",
+            description: "Simple and mixed fractions, and fraction approximations of decimals",
+            examples: &["3/8", "1 1/2", "0.333"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let input = input.trim();
+
+        if let Some((numerator, denominator)) = Self::parse_fraction(input) {
+            let value = numerator as f64 / denominator as f64;
+            return vec![Interpretation {
+                value: CoreValue::Float(value),
+                source_format: "fraction".to_string(),
+                confidence: 0.85,
+                description: format!("{} = {}", input, format_decimal(value)),
+                rich_display: vec![],
+            }];
+        }
+
+        // Decimals only interest us when a simple fraction is close by
+        if decimal_regex().is_match(input) {
+            if let Ok(value) = input.parse::<f64>() {
+                if let Some((numerator, denominator, exact)) = Self::approximate(value) {
+                    let relation = if exact { "=" } else { "≈" };
+                    return vec![Interpretation {
+                        value: CoreValue::Float(value),
+                        source_format: "fraction".to_string(),
+                        confidence: 0.5,
+                        description: format!(
+                            "{} {} {}/{}",
+                            input, relation, numerator, denominator
+                        ),
+                        rich_display: vec![],
+                    }];
+                }
+            }
+        }
+
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        // Only offered when the input was a fraction or decimal, not for every float
+        let CoreValue::Float(value) = value else {
+            return vec![];
+        };
+
+        let mut conversions = vec![Conversion {
+            value: CoreValue::Float(*value),
+            target_format: "decimal".to_string(),
+            display: format_decimal(*value),
+            priority: ConversionPriority::Primary,
+            kind: ConversionKind::Conversion,
+            display_only: true,
+            ..Default::default()
+        }];
+
+        if let Some((numerator, denominator, exact)) = Self::approximate(*value) {
+            let fraction = Self::format_fraction(numerator, denominator);
+            conversions.push(Conversion {
+                value: CoreValue::String(format!("{}/{}", numerator, denominator)),
+                target_format: "fraction".to_string(),
+                display: if exact {
+                    fraction
+                } else {
+                    format!("{} (approximate)", fraction)
+                },
+                is_lossy: !exact,
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            });
+        }
+
+        conversions
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["frac", "fractions"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> Option<f64> {
+        match FractionFormat.parse(input).first()?.value {
+            CoreValue::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    fn fraction_display(value: f64) -> Option<String> {
+        FractionFormat
+            .source_conversions(&CoreValue::Float(value))
+            .into_iter()
+            .find(|c| c.target_format == "fraction")
+            .map(|c| c.display)
+    }
+
+    #[test]
+    fn test_simple_fraction() {
+        assert_eq!(parsed("3/8"), Some(0.375));
+        assert_eq!(parsed("-3/8"), Some(-0.375));
+        assert_eq!(FractionFormat.parse("3/8")[0].confidence, 0.85);
+        assert_eq!(fraction_display(0.375).as_deref(), Some("3/8"));
+    }
+
+    #[test]
+    fn test_mixed_fraction() {
+        assert_eq!(parsed("1 1/2"), Some(1.5));
+        assert_eq!(parsed("2 3/4"), Some(2.75));
+        assert_eq!(fraction_display(1.5).as_deref(), Some("3/2 (1 1/2)"));
+        // Improper fraction part
+        assert_eq!(parsed("1 3/2"), None);
+    }
+
+    #[test]
+    fn test_reduces_fraction() {
+        let value = parsed("6/8").unwrap();
+        assert_eq!(fraction_display(value).as_deref(), Some("3/4"));
+    }
+
+    #[test]
+    fn test_decimal_approximation() {
+        assert_eq!(FractionFormat::approximate(0.333), Some((1, 3, false)));
+        assert_eq!(
+            fraction_display(0.333).as_deref(),
+            Some("1/3 (approximate)")
+        );
+        assert_eq!(FractionFormat::approximate(0.1), Some((1, 10, true)));
+        assert_eq!(FractionFormat::approximate(2.45), Some((49, 20, true)));
+        assert_eq!(FractionFormat::approximate(0.6667), Some((2, 3, false)));
+        assert_eq!(FractionFormat::approximate(2.0), None);
+
+        let results = FractionFormat.parse("0.333");
+        assert_eq!(results[0].description, "0.333 ≈ 1/3");
+    }
+
+    #[test]
+    fn test_rejects_dates_and_bad_input() {
+        assert_eq!(parsed("03/08"), None);
+        assert_eq!(parsed("2025/12"), None);
+        assert_eq!(parsed("12/25/2025"), None);
+        assert_eq!(parsed("1/0"), None);
+        // Spaced division is arithmetic
+        assert_eq!(parsed("100 / 4"), None);
+        assert_eq!(parsed("42"), None);
+    }
+}
@@ -1,9 +1,11 @@
 //! Hash detection format.
 //!
 //! Identifies potential hash values by their length and character set.
+//! SHA-1 and SHA-256 lengths are also noted as possible git object IDs, with
+//! the abbreviated form `git log --oneline` shows.
 
 use crate::format::{Format, FormatInfo};
-use crate::types::{CoreValue, Interpretation};
+use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
 
 pub struct HashFormat;
 
@@ -24,6 +26,9 @@ const HASH_TYPES: &[(&str, usize, &str)] = &[
     ("BLAKE2b-512", 128, "512-bit"),
 ];
 
+/// Length of git's default abbreviated object ID.
+const GIT_ABBREV_LEN: usize = 7;
+
 impl HashFormat {
     /// Check if a string looks like a hex hash.
     fn is_hex_hash(s: &str) -> bool {
@@ -125,6 +130,48 @@ impl Format for HashFormat {
         None
     }
 
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Bytes(bytes) = value else {
+            return vec![];
+        };
+        // Git object IDs are SHA-1, or SHA-256 in repositories using the newer format
+        let algorithm = match bytes.len() {
+            20 => "SHA-1",
+            32 => "SHA-256",
+            _ => return vec![],
+        };
+
+        let note = format!("Possible git object ID ({})", algorithm);
+        let abbrev: String = bytes
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect::<String>()
+            .chars()
+            .take(GIT_ABBREV_LEN)
+            .collect();
+
+        vec![
+            Conversion {
+                value: CoreValue::String(note.clone()),
+                target_format: "git-object".to_string(),
+                display: note,
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Trait,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::String(abbrev.clone()),
+                target_format: "git-short".to_string(),
+                display: abbrev,
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            },
+        ]
+    }
+
     fn aliases(&self) -> &'static [&'static str] {
         &["md5", "sha1", "sha256", "sha512"]
     }
@@ -225,4 +272,38 @@ mod tests {
         let md5 = format.parse("d41d8cd98f00b204e9800998ecf8427e");
         assert!(md5[0].confidence < sha224[0].confidence);
     }
+
+    #[test]
+    fn test_git_object_conversions() {
+        let results = HashFormat.parse("da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        let conversions = HashFormat.source_conversions(&results[0].value);
+
+        let note = conversions
+            .iter()
+            .find(|c| c.target_format == "git-object")
+            .unwrap();
+        assert_eq!(note.kind, ConversionKind::Trait);
+        assert_eq!(note.display, "Possible git object ID (SHA-1)");
+
+        let short = conversions
+            .iter()
+            .find(|c| c.target_format == "git-short")
+            .unwrap();
+        assert_eq!(short.kind, ConversionKind::Representation);
+        assert_eq!(short.display, "da39a3e");
+    }
+
+    #[test]
+    fn test_git_object_sha256_and_other_lengths() {
+        let sha256 = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+        let results = HashFormat.parse(sha256);
+        let conversions = HashFormat.source_conversions(&results[0].value);
+        assert!(conversions
+            .iter()
+            .any(|c| c.display == "Possible git object ID (SHA-256)"));
+
+        // MD5 length is never a git object ID
+        let results = HashFormat.parse("d41d8cd98f00b204e9800998ecf8427e");
+        assert!(HashFormat.source_conversions(&results[0].value).is_empty());
+    }
 }
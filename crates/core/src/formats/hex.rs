@@ -9,15 +9,22 @@
 //! - Comma-separated: `0x69, 0x1E, 0x01, 0xB8`
 //! - C array style: `{0x69, 0x1E, 0x01, 0xB8}`
 //! - Hex editor style with optional ASCII: `00000000  69 1E 01 B8  |i...|`
+//!
+//! Two operands joined by a bitwise operator (`DEADBEEF ^ CAFEBABE`) are
+//! combined byte by byte, with the Hamming distance between them.
 
 use crate::format::{Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+    RichDisplay, RichDisplayOption,
 };
 
 /// Maximum bytes to display in hex output before truncating.
 const MAX_HEX_DISPLAY_BYTES: usize = 64;
 
+/// Longest input decoded as BCD (16 digits covers card numbers and timestamps).
+const MAX_BCD_BYTES: usize = 8;
+
 pub struct HexFormat;
 
 /// Known hash types by byte length (not hex length).
@@ -40,6 +47,39 @@ fn hash_hint_for_length(byte_len: usize) -> Option<&'static str> {
         .map(|(_, name)| *name)
 }
 
+/// Bitwise operators accepted between two hex operands.
+const BITWISE_OPERATORS: [char; 3] = ['^', '&', '|'];
+
+/// Two hex operands combined with a bitwise operator.
+struct BitwiseHex {
+    operator: char,
+    left: Vec<u8>,
+    right: Vec<u8>,
+    result: Vec<u8>,
+    /// The operands had different lengths and the shorter one was zero-padded.
+    padded: bool,
+    confidence: f32,
+}
+
+impl BitwiseHex {
+    fn operation(&self) -> &'static str {
+        match self.operator {
+            '^' => "XOR",
+            '&' => "AND",
+            _ => "OR",
+        }
+    }
+
+    /// Number of bits that differ between the operands.
+    fn hamming_distance(&self) -> u32 {
+        self.left
+            .iter()
+            .zip(&self.right)
+            .map(|(a, b)| (a ^ b).count_ones())
+            .sum()
+    }
+}
+
 /// Result of normalizing hex input
 struct NormalizedHex {
     hex: String,
@@ -48,6 +88,41 @@ struct NormalizedHex {
 }
 
 impl HexFormat {
+    /// Confidence for decoded hex, with the reason it was assigned.
+    fn score(normalized: &NormalizedHex, byte_len: usize) -> (f32, &'static str) {
+        // Check if the hex contains any letters (A-F) - makes it unambiguously hex
+        let has_hex_letters = normalized.hex.chars().any(|c| c.is_ascii_alphabetic());
+
+        // Short digit-only colon-separated inputs (like "15:00") could be times
+        let is_odd_length = normalized.format_hint.contains("odd-length");
+        let has_0x_prefix = normalized.format_hint.starts_with("0x prefix");
+        if normalized.high_confidence {
+            if has_0x_prefix {
+                // 0x prefix is unambiguous hex - always high confidence (even if odd-length)
+                (0.95, "0x prefix is unambiguous")
+            } else if is_odd_length {
+                // Odd-length hex is ambiguous - lower confidence
+                if has_hex_letters {
+                    (0.50, "odd length, but has A-F letters")
+                } else {
+                    (0.30, "odd length and digits only, could be anything")
+                }
+            } else if !has_hex_letters && byte_len <= 2 {
+                (0.50, "short and digits only, could be a time like 15:00")
+            } else if !has_hex_letters && byte_len <= 4 {
+                (0.70, "digits only, could be an IP-like value or time")
+            } else {
+                (0.92, "has A-F letters or is long enough to be unambiguous")
+            }
+        } else if is_odd_length {
+            (0.25, "odd length without hex letters")
+        } else if byte_len >= 2 {
+            (0.6, "bare hex digits without separators")
+        } else {
+            (0.4, "single byte of bare hex digits")
+        }
+    }
+
     /// Check if a string contains only valid hex characters.
     fn is_valid_hex(s: &str) -> bool {
         !s.is_empty() && s.chars().all(|c| c.is_ascii_hexdigit())
@@ -172,6 +247,115 @@ impl HexFormat {
         None
     }
 
+    /// Parse `<hex> <op> <hex>` with one of `^`, `&` or `|`.
+    ///
+    /// Operands of different lengths are right-aligned, like numbers, by
+    /// zero-padding the shorter one.
+    fn parse_bitwise(input: &str) -> Option<BitwiseHex> {
+        let trimmed = input.trim();
+        let mut operators = trimmed.chars().filter(|c| BITWISE_OPERATORS.contains(c));
+        let operator = operators.next()?;
+        if operators.next().is_some() {
+            return None;
+        }
+
+        let (left, right) = trimmed.split_once(operator)?;
+        let mut operands = Vec::with_capacity(2);
+        let mut confidence = 1.0f32;
+        for operand in [left, right] {
+            let normalized = Self::normalize(operand)?;
+            let bytes = Self::decode(&normalized.hex)?;
+            confidence = confidence.min(Self::score(&normalized, bytes.len()).0);
+            operands.push(bytes);
+        }
+        let mut right = operands.pop()?;
+        let mut left = operands.pop()?;
+
+        let len = left.len().max(right.len());
+        let padded = left.len() != right.len();
+        for operand in [&mut left, &mut right] {
+            let padding = len - operand.len();
+            operand.splice(0..0, std::iter::repeat_n(0, padding));
+        }
+
+        let result = left
+            .iter()
+            .zip(&right)
+            .map(|(a, b)| match operator {
+                '^' => a ^ b,
+                '&' => a & b,
+                _ => a | b,
+            })
+            .collect();
+
+        Some(BitwiseHex {
+            operator,
+            left,
+            right,
+            result,
+            padded,
+            confidence,
+        })
+    }
+
+    fn bitwise_interpretation(op: BitwiseHex) -> Interpretation {
+        let mut pairs = vec![
+            ("Operation".to_string(), op.operation().to_string()),
+            ("Left".to_string(), Self::encode(&op.left)),
+            ("Right".to_string(), Self::encode(&op.right)),
+            ("Result".to_string(), Self::encode(&op.result)),
+            (
+                "Hamming distance".to_string(),
+                format!("{} bits", op.hamming_distance()),
+            ),
+        ];
+        let padding_note = if op.padded {
+            pairs.push((
+                "Padding".to_string(),
+                format!("zero-padded to {} bytes (lossy)", op.result.len()),
+            ));
+            " (operands zero-padded)"
+        } else {
+            ""
+        };
+
+        Interpretation {
+            description: format!(
+                "{} {} {} = {}{}",
+                Self::encode(&op.left),
+                op.operator,
+                Self::encode(&op.right),
+                Self::encode(&op.result),
+                padding_note
+            ),
+            value: CoreValue::Bytes(op.result),
+            source_format: "hex".to_string(),
+            confidence: op.confidence,
+            rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })],
+        }
+    }
+
+    /// The `hamming-distance` trait between the operands of a bitwise input.
+    fn hamming_trait(op: &BitwiseHex) -> Conversion {
+        let display = format!("hamming distance {} bits", op.hamming_distance());
+        Conversion {
+            value: CoreValue::String(display.clone()),
+            target_format: "hamming-distance".to_string(),
+            display: display.clone(),
+            path: vec!["hex".to_string(), "hamming-distance".to_string()],
+            steps: vec![ConversionStep {
+                format: "hamming-distance".to_string(),
+                value: CoreValue::String(display.clone()),
+                display,
+            }],
+            is_lossy: op.padded,
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Trait,
+            display_only: true,
+            ..Default::default()
+        }
+    }
+
     /// Parse separator-delimited hex bytes (colon, dash).
     /// Only allows single bytes (1-2 hex chars per part) to avoid
     /// conflicting with IPv6 which uses 4-char hex groups with colons.
@@ -303,6 +487,26 @@ impl HexFormat {
         Some(bytes)
     }
 
+    /// Decode packed BCD (two decimal digits per byte), keeping leading zeros.
+    ///
+    /// Returns `None` if any nibble is A-F or the value is too long to be a
+    /// plausible BCD number.
+    fn decode_bcd(bytes: &[u8]) -> Option<String> {
+        if bytes.is_empty() || bytes.len() > MAX_BCD_BYTES {
+            return None;
+        }
+        let mut digits = String::with_capacity(bytes.len() * 2);
+        for &byte in bytes {
+            for nibble in [byte >> 4, byte & 0x0F] {
+                if nibble > 9 {
+                    return None;
+                }
+                digits.push(char::from(b'0' + nibble));
+            }
+        }
+        Some(digits)
+    }
+
     /// Encode bytes to hex string.
     fn encode(bytes: &[u8]) -> String {
         bytes.iter().map(|b| format!("{b:02X}")).collect()
@@ -352,49 +556,17 @@ impl Format for HexFormat {
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         let Some(normalized) = Self::normalize(input) else {
-            return vec![];
+            return Self::parse_bitwise(input)
+                .map(Self::bitwise_interpretation)
+                .into_iter()
+                .collect();
         };
 
         let Some(bytes) = Self::decode(&normalized.hex) else {
             return vec![];
         };
 
-        // Check if the hex contains any letters (A-F) - makes it unambiguously hex
-        let has_hex_letters = normalized.hex.chars().any(|c| c.is_ascii_alphabetic());
-
-        // Determine confidence based on format detection
-        // Short digit-only colon-separated inputs (like "15:00") could be times
-        let is_odd_length = normalized.format_hint.contains("odd-length");
-        let has_0x_prefix = normalized.format_hint.starts_with("0x prefix");
-        let confidence = if normalized.high_confidence {
-            // 0x prefix is unambiguous hex - always high confidence (even if odd-length)
-            if has_0x_prefix {
-                0.95
-            } else if is_odd_length {
-                // Odd-length hex is ambiguous - lower confidence
-                if has_hex_letters {
-                    0.50 // Has letters, more likely hex
-                } else {
-                    0.30 // Pure digits, could be anything
-                }
-            } else if !has_hex_letters && bytes.len() <= 2 {
-                // Could be time like "15:00" - lower confidence
-                0.50
-            } else if !has_hex_letters && bytes.len() <= 4 {
-                // Could be IP-like or time - moderate confidence
-                0.70
-            } else {
-                // Has letters or is long enough to be unambiguous
-                0.92
-            }
-        } else if is_odd_length {
-            // Low confidence odd-length without hex letters
-            0.25
-        } else if bytes.len() >= 2 {
-            0.6
-        } else {
-            0.4
-        };
+        let (confidence, _) = Self::score(&normalized, bytes.len());
 
         // Build description with optional hash hint
         let hash_hint = hash_hint_for_length(bytes.len());
@@ -434,7 +606,7 @@ impl Format for HexFormat {
         let trimmed = input.trim();
 
         // Try to normalize - if it fails, we need to explain why
-        if Self::normalize(trimmed).is_some() {
+        if Self::normalize(trimmed).is_some() || Self::parse_bitwise(trimmed).is_some() {
             return None; // Valid hex
         }
 
@@ -465,6 +637,31 @@ impl Format for HexFormat {
         Some("not a valid hex format".to_string())
     }
 
+    fn explain(&self, input: &str) -> Option<String> {
+        let Some(normalized) = Self::normalize(input) else {
+            if let Some(op) = Self::parse_bitwise(input) {
+                return Some(format!(
+                    "bitwise {} of two {}-byte hex operands, confidence {:.2}",
+                    op.operation(),
+                    op.result.len(),
+                    op.confidence
+                ));
+            }
+            return self.validate(input);
+        };
+        let Some(bytes) = Self::decode(&normalized.hex) else {
+            return Some("hex digits don't decode to bytes".to_string());
+        };
+        let (confidence, reason) = Self::score(&normalized, bytes.len());
+        Some(format!(
+            "{} bytes ({}): {}, confidence {:.2}",
+            bytes.len(),
+            normalized.format_hint,
+            reason,
+            confidence
+        ))
+    }
+
     fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
         let CoreValue::Bytes(bytes) = value else {
             return vec![];
@@ -484,18 +681,43 @@ impl Format for HexFormat {
                 display: Self::encode_truncated(bytes, MAX_HEX_DISPLAY_BYTES),
             }],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Encoding,
             display_only: true, // Don't explore further from hex string (avoids codepoints noise)
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
 
+    /// Bitwise inputs get the Hamming distance between their operands, and
+    /// everything derived from zero-padded operands is marked lossy.
+    fn annotate_conversions(
+        &self,
+        input: &str,
+        interpretation: &Interpretation,
+        conversions: &mut Vec<Conversion>,
+    ) {
+        let Some(op) = Self::parse_bitwise(input) else {
+            return;
+        };
+        if !matches!(&interpretation.value, CoreValue::Bytes(bytes) if *bytes == op.result) {
+            return;
+        }
+        if op.padded {
+            for conv in conversions.iter_mut() {
+                conv.is_lossy = true;
+            }
+        }
+        conversions.insert(0, Self::hamming_trait(&op));
+    }
+
     fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
         let CoreValue::Bytes(bytes) = value else {
             return vec![];
         };
+        let mut conversions = Vec::new();
 
         // For small hex values (1-4 bytes), emit a Primary decimal representation
         // This signals to UIs that for "0xff" the user wants to see "255" prominently,
@@ -510,7 +732,7 @@ impl Format for HexFormat {
             let int_value: i128 = bytes.iter().fold(0i128, |acc, &b| (acc << 8) | (b as i128));
             let display = int_value.to_string();
 
-            return vec![Conversion {
+            conversions.push(Conversion {
                 value: CoreValue::Int {
                     value: int_value,
                     original_bytes: Some(bytes.clone()),
@@ -527,15 +749,43 @@ impl Format for HexFormat {
                     display,
                 }],
                 is_lossy: false,
+                confidence: 1.0,
                 priority: ConversionPriority::Primary,
                 display_only: true,
                 kind: ConversionKind::Representation,
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
-            }];
+            });
+        }
+
+        // Binary-coded decimal (RTC registers, smartcards): 0x2359 → 2359
+        if let Some(digits) = Self::decode_bcd(bytes) {
+            let int_value: i128 = digits.parse().unwrap_or_default();
+            conversions.push(Conversion {
+                value: CoreValue::Int {
+                    value: int_value,
+                    original_bytes: None,
+                },
+                target_format: "bcd".to_string(),
+                display: digits.clone(),
+                path: vec!["bcd".to_string()],
+                steps: vec![ConversionStep {
+                    format: "bcd".to_string(),
+                    value: CoreValue::Int {
+                        value: int_value,
+                        original_bytes: None,
+                    },
+                    display: digits,
+                }],
+                priority: ConversionPriority::Semantic,
+                display_only: true,
+                kind: ConversionKind::Representation,
+                ..Default::default()
+            });
         }
 
-        vec![]
+        conversions
     }
 }
 
@@ -543,6 +793,19 @@ impl Format for HexFormat {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_explain_reports_confidence_reason() {
+        let reason = HexFormat.explain("1704067200").unwrap();
+        assert!(reason.contains("bare hex digits"), "{}", reason);
+        assert!(reason.contains("0.60"), "{}", reason);
+
+        let reason = HexFormat.explain("0xDEADBEEF").unwrap();
+        assert!(reason.contains("0x prefix is unambiguous"), "{}", reason);
+
+        let reason = HexFormat.explain("xyz").unwrap();
+        assert!(reason.contains("invalid hex character"), "{}", reason);
+    }
+
     #[test]
     fn test_parse_hex_with_prefix() {
         let format = HexFormat;
@@ -597,6 +860,24 @@ mod tests {
         assert!(results[0].confidence <= 0.4);
     }
 
+    #[test]
+    fn test_bcd_source_conversion() {
+        let bcd = |bytes: Vec<u8>| {
+            HexFormat
+                .source_conversions(&CoreValue::Bytes(bytes))
+                .into_iter()
+                .find(|c| c.target_format == "bcd")
+        };
+
+        let conv = bcd(vec![0x12, 0x34]).unwrap();
+        assert_eq!(conv.display, "1234");
+        assert_eq!(conv.kind, ConversionKind::Representation);
+        assert_eq!(bcd(vec![0x00, 0x59]).unwrap().display, "0059");
+
+        // A-F nibbles aren't valid BCD
+        assert!(bcd(vec![0x1A]).is_none());
+    }
+
     #[test]
     fn test_conversions_bytes_to_hex() {
         let format = HexFormat;
@@ -677,4 +958,70 @@ mod tests {
             panic!("Expected Bytes");
         }
     }
+
+    #[test]
+    fn test_parse_xor_of_two_values() {
+        let results = HexFormat.parse("DEADBEEF ^ CAFEBABE");
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].source_format, "hex");
+        assert!(matches!(
+            &results[0].value,
+            CoreValue::Bytes(bytes) if bytes == &[0x14, 0x53, 0x04, 0x51]
+        ));
+        assert_eq!(results[0].description, "DEADBEEF ^ CAFEBABE = 14530451");
+    }
+
+    #[test]
+    fn test_bitwise_hamming_distance_trait() {
+        let input = "DEADBEEF ^ CAFEBABE";
+        let interpretation = HexFormat.parse(input).remove(0);
+        let mut conversions = vec![];
+        HexFormat.annotate_conversions(input, &interpretation, &mut conversions);
+
+        assert_eq!(conversions.len(), 1);
+        assert_eq!(conversions[0].target_format, "hamming-distance");
+        assert_eq!(conversions[0].kind, ConversionKind::Trait);
+        assert_eq!(conversions[0].display, "hamming distance 10 bits");
+        assert!(!conversions[0].is_lossy);
+    }
+
+    #[test]
+    fn test_bitwise_hamming_distance() {
+        let results = HexFormat.parse("0xFF00&0x0F0F");
+
+        assert!(matches!(
+            &results[0].value,
+            CoreValue::Bytes(bytes) if bytes == &[0x0F, 0x00]
+        ));
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[0].preferred else {
+            panic!("Expected KeyValue");
+        };
+        assert!(pairs.contains(&("Operation".to_string(), "AND".to_string())));
+        assert!(pairs.contains(&("Hamming distance".to_string(), "8 bits".to_string())));
+    }
+
+    #[test]
+    fn test_bitwise_zero_pads_shorter_operand() {
+        let results = HexFormat.parse("DEADBEEF | CAFE");
+
+        assert!(matches!(
+            &results[0].value,
+            CoreValue::Bytes(bytes) if bytes == &[0xDE, 0xAD, 0xFE, 0xFF]
+        ));
+        assert!(results[0].description.contains("operands zero-padded"));
+
+        let input = "DEADBEEF | CAFE";
+        let mut conversions = vec![Conversion::default()];
+        HexFormat.annotate_conversions(input, &results[0], &mut conversions);
+        assert_eq!(conversions.len(), 2);
+        assert!(conversions.iter().all(|c| c.is_lossy));
+    }
+
+    #[test]
+    fn test_bitwise_rejects_non_hex_operands() {
+        assert!(HexFormat.parse("DEADBEEF ^ hello").is_empty());
+        assert!(HexFormat.parse("AA ^ BB ^ CC").is_empty());
+        assert!(HexFormat.parse("^ CAFEBABE").is_empty());
+    }
 }
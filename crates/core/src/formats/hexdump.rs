@@ -126,11 +126,13 @@ impl Format for HexdumpFormat {
                 display,
             }],
             is_lossy: false,
+            confidence: 1.0,
             // Priority between Encoding and Raw - shows when no structured data found
             priority: ConversionPriority::Encoding,
             display_only: true, // Terminal format - don't re-encode the hexdump string
             kind: ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
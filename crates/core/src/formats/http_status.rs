@@ -0,0 +1,234 @@
+//! HTTP status code format.
+//!
+//! Annotates integers that are registered HTTP status codes with their
+//! reason phrase, e.g. `404` → "HTTP 404 Not Found (client error)".
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+};
+
+pub struct HttpStatusFormat;
+
+/// Confidence for the status trait. Bare numbers are ambiguous, so this
+/// stays below decimal/epoch readings of the same value.
+const HTTP_STATUS_CONFIDENCE: f32 = 0.6;
+
+/// Registered status codes (IANA HTTP Status Code Registry) plus 418.
+const STATUS_CODES: &[(u16, &str)] = &[
+    (100, "Continue"),
+    (101, "Switching Protocols"),
+    (102, "Processing"),
+    (103, "Early Hints"),
+    (200, "OK"),
+    (201, "Created"),
+    (202, "Accepted"),
+    (203, "Non-Authoritative Information"),
+    (204, "No Content"),
+    (205, "Reset Content"),
+    (206, "Partial Content"),
+    (207, "Multi-Status"),
+    (208, "Already Reported"),
+    (226, "IM Used"),
+    (300, "Multiple Choices"),
+    (301, "Moved Permanently"),
+    (302, "Found"),
+    (303, "See Other"),
+    (304, "Not Modified"),
+    (305, "Use Proxy"),
+    (307, "Temporary Redirect"),
+    (308, "Permanent Redirect"),
+    (400, "Bad Request"),
+    (401, "Unauthorized"),
+    (402, "Payment Required"),
+    (403, "Forbidden"),
+    (404, "Not Found"),
+    (405, "Method Not Allowed"),
+    (406, "Not Acceptable"),
+    (407, "Proxy Authentication Required"),
+    (408, "Request Timeout"),
+    (409, "Conflict"),
+    (410, "Gone"),
+    (411, "Length Required"),
+    (412, "Precondition Failed"),
+    (413, "Content Too Large"),
+    (414, "URI Too Long"),
+    (415, "Unsupported Media Type"),
+    (416, "Range Not Satisfiable"),
+    (417, "Expectation Failed"),
+    (418, "I'm a teapot"),
+    (421, "Misdirected Request"),
+    (422, "Unprocessable Content"),
+    (423, "Locked"),
+    (424, "Failed Dependency"),
+    (425, "Too Early"),
+    (426, "Upgrade Required"),
+    (428, "Precondition Required"),
+    (429, "Too Many Requests"),
+    (431, "Request Header Fields Too Large"),
+    (451, "Unavailable For Legal Reasons"),
+    (500, "Internal Server Error"),
+    (501, "Not Implemented"),
+    (502, "Bad Gateway"),
+    (503, "Service Unavailable"),
+    (504, "Gateway Timeout"),
+    (505, "HTTP Version Not Supported"),
+    (506, "Variant Also Negotiates"),
+    (507, "Insufficient Storage"),
+    (508, "Loop Detected"),
+    (510, "Not Extended"),
+    (511, "Network Authentication Required"),
+];
+
+impl HttpStatusFormat {
+    /// Look up the reason phrase for a status code.
+    fn reason_phrase(code: u16) -> Option<&'static str> {
+        STATUS_CODES
+            .binary_search_by_key(&code, |(c, _)| *c)
+            .ok()
+            .map(|idx| STATUS_CODES[idx].1)
+    }
+
+    /// Status class named after the first digit (RFC 9110 §15).
+    fn category(code: u16) -> &'static str {
+        match code / 100 {
+            1 => "informational",
+            2 => "success",
+            3 => "redirection",
+            4 => "client error",
+            _ => "server error",
+        }
+    }
+}
+
+impl Format for HttpStatusFormat {
+    fn id(&self) -> &'static str {
+        "http-status"
+    }
+
+    fn name(&self) -> &'static str {
+        "HTTP Status"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Network",
+            description: "HTTP status code reason phrases",
+            examples: &["404", "418", "503"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, _input: &str) -> Vec<Interpretation> {
+        // Status codes are plain integers; only offered as a conversion
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Int { value: int_val, .. } = value else {
+            return vec![];
+        };
+        let Some(code) = u16::try_from(*int_val)
+            .ok()
+            .filter(|c| (100..=599).contains(c))
+        else {
+            return vec![];
+        };
+        let Some(reason) = Self::reason_phrase(code) else {
+            return vec![];
+        };
+
+        let display = format!("HTTP {} {} ({})", code, reason, Self::category(code));
+        vec![Conversion {
+            value: CoreValue::String(display.clone()),
+            target_format: "http-status".to_string(),
+            display: display.clone(),
+            path: vec!["http-status".to_string()],
+            steps: vec![ConversionStep {
+                format: "http-status".to_string(),
+                value: CoreValue::String(display.clone()),
+                display,
+            }],
+            confidence: HTTP_STATUS_CONFIDENCE,
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Trait,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["http", "status"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_display(code: i128) -> Option<String> {
+        HttpStatusFormat
+            .conversions(&CoreValue::Int {
+                value: code,
+                original_bytes: None,
+            })
+            .into_iter()
+            .next()
+            .map(|c| c.display)
+    }
+
+    #[test]
+    fn test_known_codes() {
+        assert_eq!(
+            status_display(404).as_deref(),
+            Some("HTTP 404 Not Found (client error)")
+        );
+        assert_eq!(
+            status_display(418).as_deref(),
+            Some("HTTP 418 I'm a teapot (client error)")
+        );
+        assert_eq!(
+            status_display(503).as_deref(),
+            Some("HTTP 503 Service Unavailable (server error)")
+        );
+    }
+
+    #[test]
+    fn test_category_grouping() {
+        for &(code, _) in STATUS_CODES {
+            let expected = match code {
+                100..=199 => "informational",
+                200..=299 => "success",
+                300..=399 => "redirection",
+                400..=499 => "client error",
+                _ => "server error",
+            };
+            assert_eq!(HttpStatusFormat::category(code), expected, "{}", code);
+        }
+    }
+
+    #[test]
+    fn test_table_sorted() {
+        // reason_phrase relies on binary search
+        assert!(STATUS_CODES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_unregistered_codes() {
+        assert_eq!(status_display(299), None);
+        assert_eq!(status_display(99), None);
+        assert_eq!(status_display(600), None);
+        assert_eq!(status_display(-404), None);
+    }
+}
@@ -0,0 +1,428 @@
+//! IBAN (International Bank Account Number) format.
+//!
+//! Validates the ISO 7064 mod-97 checksum and breaks the BBAN into bank,
+//! branch and account segments for countries with a known structure:
+//! - `GB82WEST12345698765432`
+//! - `DE89 3704 0044 0532 0130 00`
+
+use crate::format::{specificity, Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
+    RichDisplayOption,
+};
+
+pub struct IbanFormat;
+
+/// IBAN structure for one country: code, name, total length and BBAN segments.
+struct CountrySpec {
+    code: &'static str,
+    name: &'static str,
+    length: usize,
+    segments: &'static [(&'static str, usize)],
+}
+
+const COUNTRIES: &[CountrySpec] = &[
+    CountrySpec {
+        code: "AT",
+        name: "Austria",
+        length: 20,
+        segments: &[("Bank code", 5), ("Account", 11)],
+    },
+    CountrySpec {
+        code: "BE",
+        name: "Belgium",
+        length: 16,
+        segments: &[("Bank code", 3), ("Account", 7), ("National check", 2)],
+    },
+    CountrySpec {
+        code: "CH",
+        name: "Switzerland",
+        length: 21,
+        segments: &[("Bank code", 5), ("Account", 12)],
+    },
+    CountrySpec {
+        code: "DE",
+        name: "Germany",
+        length: 22,
+        segments: &[("Bank code", 8), ("Account", 10)],
+    },
+    CountrySpec {
+        code: "DK",
+        name: "Denmark",
+        length: 18,
+        segments: &[("Bank code", 4), ("Account", 10)],
+    },
+    CountrySpec {
+        code: "ES",
+        name: "Spain",
+        length: 24,
+        segments: &[
+            ("Bank code", 4),
+            ("Branch", 4),
+            ("National check", 2),
+            ("Account", 10),
+        ],
+    },
+    CountrySpec {
+        code: "FI",
+        name: "Finland",
+        length: 18,
+        segments: &[("Bank code", 3), ("Account", 11)],
+    },
+    CountrySpec {
+        code: "FR",
+        name: "France",
+        length: 27,
+        segments: &[
+            ("Bank code", 5),
+            ("Branch", 5),
+            ("Account", 11),
+            ("National check", 2),
+        ],
+    },
+    CountrySpec {
+        code: "GB",
+        name: "United Kingdom",
+        length: 22,
+        segments: &[("Bank code", 4), ("Sort code", 6), ("Account", 8)],
+    },
+    CountrySpec {
+        code: "IE",
+        name: "Ireland",
+        length: 22,
+        segments: &[("Bank code", 4), ("Sort code", 6), ("Account", 8)],
+    },
+    CountrySpec {
+        code: "IT",
+        name: "Italy",
+        length: 27,
+        segments: &[
+            ("National check", 1),
+            ("Bank code", 5),
+            ("Branch", 5),
+            ("Account", 12),
+        ],
+    },
+    CountrySpec {
+        code: "LU",
+        name: "Luxembourg",
+        length: 20,
+        segments: &[("Bank code", 3), ("Account", 13)],
+    },
+    CountrySpec {
+        code: "NL",
+        name: "Netherlands",
+        length: 18,
+        segments: &[("Bank code", 4), ("Account", 10)],
+    },
+    CountrySpec {
+        code: "NO",
+        name: "Norway",
+        length: 15,
+        segments: &[("Bank code", 4), ("Account", 6), ("National check", 1)],
+    },
+    CountrySpec {
+        code: "PL",
+        name: "Poland",
+        length: 28,
+        segments: &[("Bank code", 8), ("Account", 16)],
+    },
+    CountrySpec {
+        code: "PT",
+        name: "Portugal",
+        length: 25,
+        segments: &[
+            ("Bank code", 4),
+            ("Branch", 4),
+            ("Account", 11),
+            ("National check", 2),
+        ],
+    },
+    CountrySpec {
+        code: "SE",
+        name: "Sweden",
+        length: 24,
+        segments: &[("Bank code", 3), ("Account", 17)],
+    },
+];
+
+impl IbanFormat {
+    /// Normalize to the electronic form (uppercase, no spaces or hyphens).
+    ///
+    /// Returns `None` unless the result has the IBAN shape: two letters, two
+    /// check digits and an alphanumeric BBAN, 15-34 characters in total.
+    fn normalize(input: &str) -> Option<String> {
+        let iban: String = input
+            .trim()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-'))
+            .map(|c| c.to_ascii_uppercase())
+            .collect();
+
+        let bytes = iban.as_bytes();
+        if !(15..=34).contains(&bytes.len())
+            || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+            || !bytes[2..4].iter().all(u8::is_ascii_digit)
+            || !bytes[4..].iter().all(u8::is_ascii_alphanumeric)
+        {
+            return None;
+        }
+        Some(iban)
+    }
+
+    /// ISO 7064 mod-97 remainder of the rearranged IBAN.
+    fn mod97(iban: &str) -> u32 {
+        let rearranged = iban[4..].bytes().chain(iban[..4].bytes());
+        rearranged.fold(0, |acc, b| {
+            if b.is_ascii_digit() {
+                (acc * 10 + u32::from(b - b'0')) % 97
+            } else {
+                // Letters expand to two digits: A = 10 ... Z = 35
+                (acc * 100 + u32::from(b - b'A' + 10)) % 97
+            }
+        })
+    }
+
+    /// Check digits that would make this IBAN valid.
+    fn expected_check_digits(iban: &str) -> u32 {
+        let zeroed = format!("{}00{}", &iban[..2], &iban[4..]);
+        98 - Self::mod97(&zeroed)
+    }
+
+    fn country(code: &str) -> Option<&'static CountrySpec> {
+        COUNTRIES.iter().find(|c| c.code == code)
+    }
+
+    /// Print form: groups of four separated by spaces.
+    fn format_print(iban: &str) -> String {
+        iban.as_bytes()
+            .chunks(4)
+            .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn build_rich_display(iban: &str, spec: Option<&CountrySpec>) -> RichDisplayOption {
+        let country = spec.map_or_else(|| iban[..2].to_string(), |s| s.name.to_string());
+        let mut pairs = vec![
+            ("Country".to_string(), country),
+            ("Check digits".to_string(), iban[2..4].to_string()),
+        ];
+
+        match spec {
+            Some(spec) => {
+                let mut offset = 4;
+                for (label, len) in spec.segments {
+                    pairs.push((label.to_string(), iban[offset..offset + len].to_string()));
+                    offset += len;
+                }
+            }
+            None => pairs.push(("BBAN".to_string(), iban[4..].to_string())),
+        }
+
+        RichDisplayOption::new(RichDisplay::KeyValue { pairs })
+    }
+}
+
+impl Format for IbanFormat {
+    fn id(&self) -> &'static str {
+        "iban"
+    }
+
+    fn name(&self) -> &'static str {
+        "IBAN"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Identifiers",
+            description: "International Bank Account Number with mod-97 checksum",
+            examples: &["GB82WEST12345698765432", "DE89 3704 0044 0532 0130 00"],
+            aliases: self.aliases(),
+            has_validation: true,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some(iban) = Self::normalize(input) else {
+            return vec![];
+        };
+        if Self::mod97(&iban) != 1 {
+            return vec![];
+        }
+
+        let spec = Self::country(&iban[..2]);
+        // Known countries must also have the registered length
+        if spec.is_some_and(|s| s.length != iban.len()) {
+            return vec![];
+        }
+
+        let (confidence, country) = match spec {
+            // Country, length and checksum all agree; a grouped IBAN like
+            // "DE89 3704 ..." must also beat space-separated hex
+            Some(spec) => (0.95, spec.name),
+            // Checksum passes but the structure is unknown to us
+            None => (0.8, &iban[..2]),
+        };
+
+        vec![Interpretation {
+            value: CoreValue::String(iban.clone()),
+            source_format: "iban".to_string(),
+            confidence,
+            description: format!("IBAN ({}): {}", country, Self::format_print(&iban)),
+            rich_display: vec![Self::build_rich_display(&iban, spec)],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::String(iban) = value else {
+            return vec![];
+        };
+        let print = Self::format_print(iban);
+
+        vec![
+            Conversion {
+                value: CoreValue::String(iban.clone()),
+                target_format: "iban-electronic".to_string(),
+                display: iban.clone(),
+                priority: ConversionPriority::Primary,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::String(print.clone()),
+                target_format: "iban-print".to_string(),
+                display: print,
+                priority: ConversionPriority::Primary,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["bank-account"]
+    }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
+
+    fn validate(&self, input: &str) -> Option<String> {
+        let Some(iban) = Self::normalize(input) else {
+            return Some(
+                "expected 2-letter country code, 2 check digits and up to 30 alphanumeric characters"
+                    .to_string(),
+            );
+        };
+
+        if let Some(spec) = Self::country(&iban[..2]) {
+            if spec.length != iban.len() {
+                return Some(format!(
+                    "{} IBANs are {} characters, got {}",
+                    spec.name,
+                    spec.length,
+                    iban.len()
+                ));
+            }
+        }
+
+        if Self::mod97(&iban) != 1 {
+            return Some(format!(
+                "checksum failed, expected {:02} got {}",
+                Self::expected_check_digits(&iban),
+                &iban[2..4]
+            ));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair<'a>(interp: &'a Interpretation, key: &str) -> Option<&'a str> {
+        let RichDisplay::KeyValue { pairs } = &interp.rich_display[0].preferred else {
+            return None;
+        };
+        pairs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn test_parse_gb_iban() {
+        let results = IbanFormat.parse("GB82WEST12345698765432");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.95);
+        assert_eq!(
+            results[0].description,
+            "IBAN (United Kingdom): GB82 WEST 1234 5698 7654 32"
+        );
+        assert_eq!(pair(&results[0], "Bank code"), Some("WEST"));
+        assert_eq!(pair(&results[0], "Sort code"), Some("123456"));
+        assert_eq!(pair(&results[0], "Account"), Some("98765432"));
+    }
+
+    #[test]
+    fn test_parse_de_iban_print_form() {
+        let results = IbanFormat.parse("de89 3704 0044 0532 0130 00");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(&results[0].value, CoreValue::String(s) if s == "DE89370400440532013000"));
+        assert_eq!(pair(&results[0], "Country"), Some("Germany"));
+        assert_eq!(pair(&results[0], "Bank code"), Some("37040044"));
+        assert_eq!(pair(&results[0], "Account"), Some("0532013000"));
+    }
+
+    #[test]
+    fn test_checksum_failure() {
+        assert!(IbanFormat.parse("GB00WEST12345698765432").is_empty());
+        assert_eq!(
+            IbanFormat.validate("GB00WEST12345698765432").as_deref(),
+            Some("checksum failed, expected 82 got 00")
+        );
+        assert_eq!(IbanFormat.validate("GB82WEST12345698765432"), None);
+    }
+
+    #[test]
+    fn test_wrong_length_for_country() {
+        // Valid checksum shape doesn't matter if the length is wrong for GB
+        assert!(IbanFormat.parse("GB82WEST1234569876543").is_empty());
+        assert!(IbanFormat
+            .validate("GB82WEST1234569876543")
+            .is_some_and(|msg| msg.contains("22 characters")));
+    }
+
+    #[test]
+    fn test_electronic_and_print_conversions() {
+        let value = CoreValue::String("GB82WEST12345698765432".to_string());
+        let conversions = IbanFormat.source_conversions(&value);
+        let print = conversions
+            .iter()
+            .find(|c| c.target_format == "iban-print")
+            .unwrap();
+        assert_eq!(print.display, "GB82 WEST 1234 5698 7654 32");
+    }
+
+    #[test]
+    fn test_not_iban() {
+        assert!(IbanFormat.parse("hello").is_empty());
+        assert!(IbanFormat.parse("1234567890123456").is_empty());
+        assert!(IbanFormat.parse("GB82").is_empty());
+    }
+}
@@ -8,13 +8,24 @@
 use std::collections::HashMap;
 use std::io::Cursor;
 
+use exif::{In, Tag};
 use image::GenericImageView;
 
 use crate::format::{Format, FormatInfo};
+use crate::formats::CoordsFormat;
 use crate::types::{CoreValue, Interpretation, RichDisplay, RichDisplayOption};
 
 pub struct ImageFormat;
 
+/// A downscaled RGB image for previews.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thumbnail {
+    pub width: u32,
+    pub height: u32,
+    /// Pixels in row-major order.
+    pub pixels: Vec<(u8, u8, u8)>,
+}
+
 /// Common screen resolutions for screenshot detection.
 const SCREEN_RESOLUTIONS: &[(u32, u32, &str)] = &[
     // Desktop
@@ -116,8 +127,34 @@ struct ImageMetadata {
 }
 
 impl ImageFormat {
+    /// Check for an ICO header whose first directory entry is plausible.
+    ///
+    /// The `00 00 01 00` signature alone matches plenty of binary data.
+    fn is_ico(data: &[u8]) -> bool {
+        if data.len() < 22 || !data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
+            return false;
+        }
+        let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let read_u32 = |offset: usize| {
+            u32::from_le_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ])
+        };
+        let count = read_u16(4);
+        // First entry: reserved byte, color planes, bits per pixel, size and offset
+        count > 0
+            && data[9] == 0
+            && read_u16(10) <= 1
+            && matches!(read_u16(12), 0 | 1 | 4 | 8 | 16 | 24 | 32)
+            && read_u32(14) > 0
+            && read_u32(18) >= 6 + 16 * u32::from(count)
+    }
+
     /// Detect image format from magic bytes.
-    fn detect_format(data: &[u8]) -> Option<&'static str> {
+    pub(crate) fn detect_format(data: &[u8]) -> Option<&'static str> {
         if data.len() < 12 {
             return None;
         }
@@ -135,15 +172,19 @@ impl ImageFormat {
         if data.starts_with(b"RIFF") && data.len() >= 12 && &data[8..12] == b"WEBP" {
             return Some("WebP");
         }
-        if data.starts_with(b"BM") {
-            return Some("BMP");
+        if data.starts_with(b"BM") && data.len() >= 18 {
+            // DIB header size distinguishes real bitmaps from text starting with "BM"
+            let header_size = u32::from_le_bytes([data[14], data[15], data[16], data[17]]);
+            if matches!(header_size, 12 | 40 | 52 | 56 | 108 | 124) {
+                return Some("BMP");
+            }
         }
         if data.starts_with(&[0x49, 0x49, 0x2A, 0x00])
             || data.starts_with(&[0x4D, 0x4D, 0x00, 0x2A])
         {
             return Some("TIFF");
         }
-        if data.starts_with(&[0x00, 0x00, 0x01, 0x00]) {
+        if Self::is_ico(data) {
             return Some("ICO");
         }
         // HEIC/HEIF (ftyp box)
@@ -202,69 +243,105 @@ impl ImageFormat {
         None
     }
 
-    /// Parse EXIF data from image bytes.
-    fn parse_exif(data: &[u8]) -> HashMap<String, String> {
-        let mut result = HashMap::new();
-
-        let Ok(exif) = exif::Reader::new().read_from_container(&mut Cursor::new(data)) else {
-            return result;
-        };
-
-        for field in exif.fields() {
-            let tag_name = format!("{}", field.tag);
-            let value = field.display_value().to_string();
-            result.insert(tag_name, value);
-        }
-
-        result
+    /// Collect EXIF fields as display strings, keyed by tag name.
+    fn exif_fields(exif: &exif::Exif) -> HashMap<String, String> {
+        exif.fields()
+            .map(|field| (field.tag.to_string(), field.display_value().to_string()))
+            .collect()
     }
 
-    /// Extract GPS coordinates from EXIF data.
-    fn extract_gps(exif_data: &HashMap<String, String>) -> (Option<f64>, Option<f64>, Option<f64>) {
-        let lat = Self::parse_gps_coord(
-            exif_data.get("GPSLatitude"),
-            exif_data.get("GPSLatitudeRef"),
-        );
-        let lon = Self::parse_gps_coord(
-            exif_data.get("GPSLongitude"),
-            exif_data.get("GPSLongitudeRef"),
-        );
-        let alt = exif_data.get("GPSAltitude").and_then(|s| {
-            // Parse "123.4 m" or just "123.4"
-            s.split_whitespace().next()?.parse().ok()
-        });
+    /// Extract the GPS position (latitude, longitude, altitude) from EXIF GPS tags.
+    fn extract_gps(exif: &exif::Exif) -> (Option<f64>, Option<f64>, Option<f64>) {
+        let lat = Self::gps_coord(exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, b'S')
+            .filter(|lat| lat.abs() <= 90.0);
+        let lon = Self::gps_coord(exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, b'W')
+            .filter(|lon| lon.abs() <= 180.0);
+
+        let alt = exif
+            .get_field(Tag::GPSAltitude, In::PRIMARY)
+            .and_then(|field| match &field.value {
+                exif::Value::Rational(values) => values.first().map(|r| r.to_f64()),
+                _ => None,
+            })
+            .filter(|alt| alt.is_finite())
+            .map(|alt| {
+                // Altitude reference 1 means below sea level
+                let below_sea_level = exif
+                    .get_field(Tag::GPSAltitudeRef, In::PRIMARY)
+                    .and_then(|field| field.value.get_uint(0))
+                    == Some(1);
+                if below_sea_level {
+                    -alt
+                } else {
+                    alt
+                }
+            });
 
         (lat, lon, alt)
     }
 
-    /// Parse GPS coordinate from EXIF format.
-    fn parse_gps_coord(coord: Option<&String>, ref_dir: Option<&String>) -> Option<f64> {
-        let coord = coord?;
+    /// Read a GPS coordinate: degrees, minutes and seconds as rationals, negated
+    /// when the reference tag holds `negative_ref` (S or W).
+    fn gps_coord(exif: &exif::Exif, tag: Tag, ref_tag: Tag, negative_ref: u8) -> Option<f64> {
+        let field = exif.get_field(tag, In::PRIMARY)?;
+        let exif::Value::Rational(parts) = &field.value else {
+            return None;
+        };
+        let [deg, min, sec] = parts.as_slice() else {
+            return None;
+        };
 
-        // EXIF GPS format: "deg min sec" or "deg/1 min/1 sec/100"
-        // Try to parse various formats
-        let parts: Vec<&str> = coord.split_whitespace().collect();
+        let decimal = deg.to_f64() + min.to_f64() / 60.0 + sec.to_f64() / 3600.0;
+        if !decimal.is_finite() {
+            return None;
+        }
 
-        let (deg, min, sec) = if parts.len() >= 3 {
-            // "37 deg 48 min 12.34 sec" format
-            let deg: f64 = parts.first()?.parse().ok()?;
-            let min: f64 = parts.get(2).or(parts.get(1))?.parse().ok().unwrap_or(0.0);
-            let sec: f64 = parts.get(4).or(parts.get(2))?.parse().ok().unwrap_or(0.0);
-            (deg, min, sec)
+        let reference = exif
+            .get_field(ref_tag, In::PRIMARY)
+            .and_then(|field| match &field.value {
+                exif::Value::Ascii(values) => values.first().and_then(|v| v.first().copied()),
+                _ => None,
+            });
+        Some(if reference == Some(negative_ref) {
+            -decimal
         } else {
-            return None;
-        };
+            decimal
+        })
+    }
 
-        let mut decimal = deg + min / 60.0 + sec / 3600.0;
+    /// Downscale an image for previews, preserving aspect ratio.
+    ///
+    /// Returns `None` if the data isn't a decodable image.
+    pub fn thumbnail(data: &[u8], max_width: u32, max_height: u32) -> Option<Thumbnail> {
+        Self::detect_format(data)?;
+        let img = image::load_from_memory(data).ok()?;
+        let (width, height) = img.dimensions();
 
-        // Apply direction
-        if let Some(dir) = ref_dir {
-            if dir == "S" || dir == "W" {
-                decimal = -decimal;
-            }
-        }
+        // Never upscale tiny images
+        let thumb = if width > max_width || height > max_height {
+            img.thumbnail(max_width, max_height)
+        } else {
+            img
+        };
+        let rgb = thumb.to_rgb8();
+        let pixels = rgb.pixels().map(|p| (p[0], p[1], p[2])).collect();
+        Some(Thumbnail {
+            width: rgb.width(),
+            height: rgb.height(),
+            pixels,
+        })
+    }
+
+    /// Decode base64 in any common variant (standard/URL-safe, padded or not).
+    fn decode_base64(input: &str) -> Option<Vec<u8>> {
+        use base64::engine::general_purpose::{
+            STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD,
+        };
+        use base64::Engine;
 
-        Some(decimal)
+        [STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD]
+            .iter()
+            .find_map(|engine| engine.decode(input.trim()).ok())
     }
 
     /// Analyze image for dominant colors and brightness.
@@ -302,7 +379,7 @@ impl ImageFormat {
 
         // Get top 5 dominant colors
         let mut colors: Vec<_> = color_counts.into_iter().collect();
-        colors.sort_by(|a, b| b.1.cmp(&a.1));
+        colors.sort_by_key(|c| std::cmp::Reverse(c.1));
         let dominant: Vec<(u8, u8, u8)> = colors.into_iter().take(5).map(|(c, _)| c).collect();
 
         let avg_brightness = if pixel_count > 0 {
@@ -337,8 +414,11 @@ impl ImageFormat {
         };
 
         // Parse EXIF
-        let exif_data = Self::parse_exif(data);
-        let (gps_lat, gps_lon, gps_alt) = Self::extract_gps(&exif_data);
+        let exif = exif::Reader::new()
+            .read_from_container(&mut Cursor::new(data))
+            .ok();
+        let exif_data = exif.as_ref().map(Self::exif_fields).unwrap_or_default();
+        let (gps_lat, gps_lon, gps_alt) = exif.as_ref().map(Self::extract_gps).unwrap_or_default();
 
         // Analyze image
         let (dominant_colors, average_brightness) = Self::analyze_image(&img);
@@ -556,28 +636,26 @@ impl Format for ImageFormat {
     }
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
-        // Images are binary, but we might receive hex or base64 encoded data
-        // For now, we don't parse from string - the bytes come from file reading
-        // This would be handled by a file loader that passes bytes directly
-
-        // Try to decode as base64
-        if let Ok(bytes) = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input)
-        {
-            if let Some(meta) = Self::parse_image(&bytes) {
-                let description = Self::format_description(&meta);
-                let rich_display = Self::build_rich_display(&meta);
-
-                return vec![Interpretation {
-                    value: CoreValue::Bytes(bytes),
-                    source_format: "image".to_string(),
-                    confidence: 0.95,
-                    description,
-                    rich_display,
-                }];
-            }
+        // Images are binary; as text they arrive base64-encoded. Raw bytes
+        // (e.g. from file reading) go straight to parse_bytes.
+        match Self::decode_base64(input) {
+            Some(bytes) => self.parse_bytes(&bytes),
+            None => vec![],
         }
+    }
 
-        vec![]
+    fn parse_bytes(&self, bytes: &[u8]) -> Vec<Interpretation> {
+        let Some(meta) = Self::parse_image(bytes) else {
+            return vec![];
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes.to_vec()),
+            source_format: "image".to_string(),
+            confidence: 0.95,
+            description: Self::format_description(&meta),
+            rich_display: Self::build_rich_display(&meta),
+        }]
     }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
@@ -610,13 +688,39 @@ impl Format for ImageFormat {
             path: vec!["image-info".to_string()],
             steps: vec![],
             is_lossy: false,
+            confidence: 1.0,
             priority: crate::types::ConversionPriority::Structured,
             display_only: true,
             kind: crate::types::ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display,
         });
 
+        // GPS position, explored further as coordinates (DMS, geohash, ...)
+        if let (Some(lat), Some(lon)) = (meta.gps_lat, meta.gps_lon) {
+            let display = CoordsFormat::format_dd(lat, lon);
+            conversions.push(crate::types::Conversion {
+                value: CoreValue::Coordinates { lat, lon },
+                target_format: "gps".to_string(),
+                display: display.clone(),
+                path: vec!["gps".to_string()],
+                steps: vec![crate::types::ConversionStep {
+                    format: "gps".to_string(),
+                    value: CoreValue::Coordinates { lat, lon },
+                    display,
+                }],
+                is_lossy: false,
+                confidence: 1.0,
+                priority: crate::types::ConversionPriority::Semantic,
+                display_only: false,
+                kind: crate::types::ConversionKind::Conversion,
+                hidden: false,
+                depth_limited: false,
+                rich_display: CoordsFormat::build_rich_display(lat, lon, "EXIF GPS"),
+            });
+        }
+
         conversions
     }
 
@@ -647,6 +751,24 @@ mod tests {
         assert_eq!(ImageFormat::detect_format(&data), Some("PNG"));
     }
 
+    #[test]
+    fn test_detect_ico() {
+        // One 16x16 32-bit entry of 1128 bytes at offset 22
+        let data = [
+            0, 0, 1, 0, 1, 0, 16, 16, 0, 0, 1, 0, 32, 0, 0x68, 0x04, 0, 0, 22, 0, 0, 0,
+        ];
+        assert_eq!(ImageFormat::detect_format(&data), Some("ICO"));
+
+        // Signature followed by an implausible entry
+        let mut bad = data;
+        bad[12] = 7;
+        assert_eq!(ImageFormat::detect_format(&bad), None);
+        assert_eq!(
+            ImageFormat::detect_format(&[0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0]),
+            None
+        );
+    }
+
     #[test]
     fn test_detect_gif() {
         let data = b"GIF89a......";
@@ -692,4 +814,64 @@ mod tests {
         assert_eq!(ImageFormat::gcd(16, 9), 1);
         assert_eq!(ImageFormat::gcd(100, 100), 100);
     }
+
+    const TINY_PNG_B64: &str =
+        "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+    #[test]
+    fn test_parse_unpadded_base64_image() {
+        let format = ImageFormat;
+        let results = format.parse(TINY_PNG_B64.trim_end_matches('='));
+        assert_eq!(results.len(), 1);
+        assert!(results[0].description.contains("PNG 1×1"));
+    }
+
+    /// 8×8 JPEG with EXIF GPS: 59° 19' 45.48" N, 18° 4' 6.96" E, 28 m.
+    const GPS_JPEG: &[u8] = include_bytes!("../../tests/fixtures/gps.jpg");
+
+    #[test]
+    fn test_exif_gps_extracted() {
+        let meta = ImageFormat::parse_image(GPS_JPEG).unwrap();
+        assert!((meta.gps_lat.unwrap() - 59.3293).abs() < 1e-9);
+        assert!((meta.gps_lon.unwrap() - 18.0686).abs() < 1e-9);
+        assert_eq!(meta.gps_alt, Some(28.0));
+    }
+
+    #[test]
+    fn test_exif_gps_chains_into_coordinates() {
+        let formats: Vec<Box<dyn Format>> = vec![Box::new(ImageFormat), Box::new(CoordsFormat)];
+        let conversions = crate::convert::find_all_conversions(
+            &formats,
+            &CoreValue::Bytes(GPS_JPEG.to_vec()),
+            None,
+            Some("image"),
+            None,
+        );
+
+        let gps = conversions
+            .iter()
+            .find(|c| c.target_format == "gps")
+            .expect("gps conversion");
+        assert!(matches!(gps.value, CoreValue::Coordinates { .. }));
+        assert!(gps
+            .rich_display
+            .iter()
+            .any(|opt| matches!(opt.preferred, RichDisplay::Map { .. })));
+
+        let geohash = conversions
+            .iter()
+            .find(|c| c.target_format == "geohash")
+            .expect("geohash conversion");
+        assert_eq!(geohash.display, "u6sce0t4h");
+        assert_eq!(geohash.path, ["image", "gps", "geohash"]);
+    }
+
+    #[test]
+    fn test_thumbnail_does_not_upscale() {
+        let bytes = ImageFormat::decode_base64(TINY_PNG_B64).unwrap();
+        let thumb = ImageFormat::thumbnail(&bytes, 32, 16).unwrap();
+        assert_eq!((thumb.width, thumb.height), (1, 1));
+        assert_eq!(thumb.pixels.len(), 1);
+        assert!(ImageFormat::thumbnail(b"not an image", 32, 16).is_none());
+    }
 }
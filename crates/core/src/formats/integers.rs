@@ -5,6 +5,8 @@ use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
 };
 
+use super::CurrencyFormat;
+
 // =============================================================================
 // Number Trait Detection
 // =============================================================================
@@ -244,34 +246,90 @@ fn factorial_of(n: i128) -> Option<u32> {
         .map(|idx| FACTORIALS[idx].1)
 }
 
-/// Check if a number passes the Luhn checksum (used in OCR, credit cards, IMEI, etc.).
-/// Returns true if the number has a valid Luhn check digit.
-/// Only valid for positive integers with at least 2 digits.
-fn is_valid_luhn(n: i128) -> bool {
-    if n < 10 {
-        return false; // Need at least 2 digits
-    }
+/// Encode an integer as reflected binary (Gray) code.
+fn gray_encode(n: u64) -> u64 {
+    n ^ (n >> 1)
+}
 
-    let mut sum = 0i128;
-    let mut double = false;
-    let mut num = n;
+/// Decode a Gray code back to its integer by folding in each shifted prefix.
+fn gray_decode(gray: u64) -> u64 {
+    let mut n = gray;
+    let mut shifted = gray >> 1;
+    while shifted != 0 {
+        n ^= shifted;
+        shifted >>= 1;
+    }
+    n
+}
 
-    while num > 0 {
-        let mut digit = num % 10;
-        num /= 10;
+/// Check if a string of decimal digits passes the Luhn checksum (used in OCR,
+/// credit cards, IMEI, etc.).
+/// Returns false for anything but ASCII digits, and for fewer than 2 digits.
+pub(crate) fn luhn_valid(digits: &str) -> bool {
+    if digits.len() < 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
 
-        if double {
-            digit *= 2;
-            if digit > 9 {
-                digit -= 9;
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = u32::from(b - b'0');
+            if i % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
             }
-        }
+        })
+        .sum();
+
+    sum.is_multiple_of(10)
+}
+
+/// Check if a number passes the Luhn checksum.
+/// Only valid for positive integers with at least 2 digits.
+fn is_valid_luhn(n: i128) -> bool {
+    n >= 10 && luhn_valid(&n.to_string())
+}
 
-        sum += digit;
-        double = !double;
+/// Digits of a card-style number like `4532 0151 1283 0366` or `4532-0151-...`.
+///
+/// Groups of at least three digits may be separated by single spaces or by
+/// single dashes (not both), so dates like `2024-01-15` aren't digit strings.
+pub(crate) fn separated_digits(s: &str) -> Option<String> {
+    let separator = if s.contains(' ') { ' ' } else { '-' };
+    let groups: Vec<&str> = s.split(separator).collect();
+    let card_group = |g: &&str| g.len() >= 3 && g.bytes().all(|b| b.is_ascii_digit());
+    if !groups.iter().all(card_group) {
+        return None;
     }
+    Some(groups.concat())
+}
 
-    sum % 10 == 0
+/// The `luhn` trait conversion.
+fn luhn_trait() -> Conversion {
+    let display = "valid Luhn checksum".to_string();
+    Conversion {
+        value: CoreValue::String(display.clone()),
+        target_format: "luhn".to_string(),
+        display: display.clone(),
+        path: vec!["luhn".to_string()],
+        steps: vec![ConversionStep {
+            format: "luhn".to_string(),
+            value: CoreValue::String(display.clone()),
+            display,
+        }],
+        priority: ConversionPriority::Semantic,
+        kind: ConversionKind::Trait,
+        display_only: true,
+        ..Default::default()
+    }
 }
 
 /// Check if a 10-digit number is a valid ISBN-10 (when check digit is 0-9, not X).
@@ -362,6 +420,85 @@ fn is_valid_ean8(n: i128) -> bool {
     sum % 10 == 0
 }
 
+/// Confidence for scientific notation with a decimal point or signed
+/// exponent (`6.022e23`, `1e+3`), which hex can't produce.
+const SCIENTIFIC_CONFIDENCE: f32 = 0.8;
+
+/// Confidence for bare `1e3`-style input, which is also valid hex.
+const SCIENTIFIC_AMBIGUOUS_CONFIDENCE: f32 = 0.4;
+
+/// Parse scientific notation like `6.022e23`, `1.5E9` or `2.5e-3`.
+///
+/// Exact integers (within i128) become `Int` so the usual integer conversions
+/// apply; anything else becomes `Float`.
+fn parse_scientific(input: &str) -> Option<Interpretation> {
+    let (mantissa, exponent) = input.split_once(['e', 'E'])?;
+
+    let unsigned = mantissa.strip_prefix(['-', '+']).unwrap_or(mantissa);
+    let (int_part, frac_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let exp_digits = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+    if int_part.is_empty()
+        || exp_digits.is_empty()
+        || ![int_part, frac_part, exp_digits]
+            .iter()
+            .all(|part| part.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+    let exp: i32 = exponent.parse().ok()?;
+
+    // Exact integer value: all mantissa digits scaled by what's left of the exponent
+    let shift = i64::from(exp) - frac_part.len() as i64;
+    let int_value = u32::try_from(shift)
+        .ok()
+        .and_then(|shift| 10i128.checked_pow(shift))
+        .and_then(|scale| {
+            format!("{int_part}{frac_part}")
+                .parse::<i128>()
+                .ok()?
+                .checked_mul(scale)
+        })
+        .map(|v| if mantissa.starts_with('-') { -v } else { v });
+
+    let (value, display) = match int_value {
+        Some(value) => (
+            CoreValue::Int {
+                value,
+                original_bytes: None,
+            },
+            value.to_string(),
+        ),
+        None => {
+            let float: f64 = input.parse().ok()?;
+            if !float.is_finite() {
+                return None;
+            }
+            // Too large for an integer, so keep it in normalized notation
+            let display = if float.abs() >= 1.0 {
+                format!("{float:e}")
+            } else {
+                float.to_string()
+            };
+            (CoreValue::Float(float), display)
+        }
+    };
+
+    // "1e3" is also hex; a decimal point or exponent sign rules that out
+    let confidence = if frac_part.is_empty() && exp_digits.len() == exponent.len() {
+        SCIENTIFIC_AMBIGUOUS_CONFIDENCE
+    } else {
+        SCIENTIFIC_CONFIDENCE
+    };
+
+    Some(Interpretation {
+        value,
+        source_format: "decimal".to_string(),
+        confidence,
+        description: format!("Scientific notation: {input} = {display}"),
+        rich_display: vec![],
+    })
+}
+
 pub struct DecimalFormat;
 
 impl Format for DecimalFormat {
@@ -378,8 +515,8 @@ impl Format for DecimalFormat {
             id: self.id(),
             name: self.name(),
             category: "Numbers",
-            description: "Decimal integer parsing",
-            examples: &["1763574200", "-42", "255"],
+            description: "Decimal integers, including scientific notation",
+            examples: &["1763574200", "-42", "255", "6.022e23"],
             aliases: self.aliases(),
             has_validation: false,
         }
@@ -387,7 +524,24 @@ impl Format for DecimalFormat {
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         let Ok(value) = input.parse::<i128>() else {
-            return vec![];
+            // Card-style groups ("4111 1111 1111 1111") read as the number they
+            // spell, so card and Luhn checks see them like an ungrouped number
+            if let Some(value) = separated_digits(input)
+                .filter(|digits| (13..=19).contains(&digits.len()))
+                .and_then(|digits| digits.parse::<i128>().ok())
+            {
+                return vec![Interpretation {
+                    value: CoreValue::Int {
+                        value,
+                        original_bytes: None,
+                    },
+                    source_format: "decimal".to_string(),
+                    confidence: 0.5,
+                    description: format!("Integer: {value}"),
+                    rich_display: vec![],
+                }];
+            }
+            return parse_scientific(input).into_iter().collect();
         };
 
         // Higher confidence for pure numeric input
@@ -423,6 +577,13 @@ impl Format for DecimalFormat {
     }
 
     fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        // Digit strings keep leading zeros and grouping, so only the Luhn trait applies
+        if let CoreValue::String(s) = value {
+            return separated_digits(s.trim())
+                .filter(|digits| luhn_valid(digits))
+                .map(|_| vec![luhn_trait()])
+                .unwrap_or_default();
+        }
         let CoreValue::Int { value: int_val, .. } = value else {
             return vec![];
         };
@@ -730,23 +891,8 @@ impl Format for DecimalFormat {
         }
 
         // Luhn checksum detection (OCR references, credit cards, IMEI, etc.)
-        if *int_val >= 10 && is_valid_luhn(*int_val) {
-            let display = "valid Luhn checksum".to_string();
-            conversions.push(Conversion {
-                value: CoreValue::String(display.clone()),
-                target_format: "luhn".to_string(),
-                display: display.clone(),
-                path: vec!["luhn".to_string()],
-                steps: vec![ConversionStep {
-                    format: "luhn".to_string(),
-                    value: CoreValue::String(display.clone()),
-                    display,
-                }],
-                priority: ConversionPriority::Semantic,
-                kind: ConversionKind::Trait,
-                display_only: true,
-                ..Default::default()
-            });
+        if is_valid_luhn(*int_val) {
+            conversions.push(luhn_trait());
         }
 
         // ISBN-10 detection (10 digits, check digit 0-9)
@@ -839,6 +985,56 @@ impl Format for DecimalFormat {
         conversions
     }
 
+    /// A typed three-digit number may be an ISO 4217 numeric code (840 = USD).
+    fn annotate_conversions(
+        &self,
+        input: &str,
+        interpretation: &Interpretation,
+        conversions: &mut Vec<Conversion>,
+    ) {
+        let input = input.trim();
+        if input.len() != 3 || !input.bytes().all(|b| b.is_ascii_digit()) {
+            return;
+        }
+        if let CoreValue::Int { value, .. } = &interpretation.value {
+            conversions.extend(CurrencyFormat::numeric_code_trait(*value));
+        }
+    }
+
+    /// Gray code both ways, only for the input integer: the value encoded,
+    /// and the value read as a Gray code. Listed after the base representations.
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        // Encoder-sized values only
+        let CoreValue::Int { value: int_val, .. } = value else {
+            return vec![];
+        };
+        let Ok(val) = u16::try_from(*int_val).map(u64::from) else {
+            return vec![];
+        };
+
+        let gray_display = format!("{:b}", gray_encode(val));
+        let decoded = gray_decode(val);
+        let decoded_display = format!("{} (0b{:b})", decoded, decoded);
+        [("gray", gray_display), ("gray-decoded", decoded_display)]
+            .into_iter()
+            .map(|(target, display)| Conversion {
+                value: CoreValue::String(display.clone()),
+                target_format: target.to_string(),
+                display: display.clone(),
+                path: vec![target.to_string()],
+                steps: vec![ConversionStep {
+                    format: target.to_string(),
+                    value: CoreValue::String(display.clone()),
+                    display,
+                }],
+                priority: ConversionPriority::Encoding,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            })
+            .collect()
+    }
+
     fn aliases(&self) -> &'static [&'static str] {
         &["dec", "int", "num"]
     }
@@ -865,6 +1061,31 @@ impl BytesToIntFormat {
         }
         result
     }
+
+    /// Fixed-width integer view (e.g., `u32-le`, `i16-be`) of a byte sequence.
+    fn sized_conversion(target_format: String, value: i128, bytes: &[u8]) -> Conversion {
+        let display = value.to_string();
+        let int = CoreValue::Int {
+            value,
+            original_bytes: Some(bytes.to_vec()),
+        };
+        Conversion {
+            value: int.clone(),
+            target_format: target_format.clone(),
+            display: display.clone(),
+            path: vec![target_format.clone()],
+            steps: vec![ConversionStep {
+                format: target_format,
+                value: int,
+                display,
+            }],
+            priority: ConversionPriority::Raw,
+            kind: ConversionKind::Representation,
+            // int-be/int-le already carry these values through the BFS
+            display_only: true,
+            ..Default::default()
+        }
+    }
 }
 
 impl Format for BytesToIntFormat {
@@ -938,10 +1159,12 @@ impl Format for BytesToIntFormat {
                 display: be_display,
             }],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Raw,
             display_only: false,
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }];
 
@@ -964,14 +1187,43 @@ impl Format for BytesToIntFormat {
                     display: le_display,
                 }],
                 is_lossy: false,
+                confidence: 1.0,
                 priority: ConversionPriority::Raw,
                 display_only: false,
                 kind: ConversionKind::default(),
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![],
             });
         }
 
+        // Explicit-width views for common field sizes, so both byte orders can be
+        // compared side by side (signed only when the sign bit is set)
+        let bits = match bytes.len() {
+            2 => 16,
+            4 => 32,
+            8 => 64,
+            _ => return conversions,
+        };
+        let orders = [
+            ("be", be_value, bytes[0]),
+            ("le", le_value, bytes[bytes.len() - 1]),
+        ];
+        for (order, value, most_significant) in orders {
+            conversions.push(Self::sized_conversion(
+                format!("u{}-{}", bits, order),
+                value,
+                bytes,
+            ));
+            if most_significant & 0x80 != 0 {
+                conversions.push(Self::sized_conversion(
+                    format!("i{}-{}", bits, order),
+                    value - (1i128 << bits),
+                    bytes,
+                ));
+            }
+        }
+
         conversions
     }
 }
@@ -993,6 +1245,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_scientific() {
+        let results = DecimalFormat.parse("6.022e23");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].value,
+            CoreValue::Int {
+                value: 602_200_000_000_000_000_000_000,
+                original_bytes: None
+            }
+        );
+        assert_eq!(
+            results[0].description,
+            "Scientific notation: 6.022e23 = 602200000000000000000000"
+        );
+        assert!(results[0].confidence >= 0.8);
+
+        let results = DecimalFormat.parse("-1.5E+9");
+        assert_eq!(
+            results[0].value,
+            CoreValue::Int {
+                value: -1_500_000_000,
+                original_bytes: None
+            }
+        );
+
+        // Fractions and values beyond i128 stay floating point
+        assert_eq!(
+            DecimalFormat.parse("2.5e-3")[0].value,
+            CoreValue::Float(0.0025)
+        );
+        assert_eq!(
+            DecimalFormat.parse("1.5e40")[0].description,
+            "Scientific notation: 1.5e40 = 1.5e40"
+        );
+    }
+
+    #[test]
+    fn test_scientific_ambiguous_with_hex() {
+        // Bare "1e3" is also hex, so it's only a weak reading
+        let results = DecimalFormat.parse("1e3");
+        assert!(results[0].confidence < 0.5);
+        assert!(DecimalFormat.parse("1e+3")[0].confidence >= 0.8);
+
+        for input in ["e3", "1e", ".5e3", "1e3.5", "0x1e3", "1e3e3", "1,5e3"] {
+            assert!(DecimalFormat.parse(input).is_empty(), "{input}");
+        }
+    }
+
     #[test]
     fn test_parse_negative() {
         let format = DecimalFormat;
@@ -1027,7 +1328,13 @@ mod tests {
         let value = CoreValue::Bytes(vec![0x69, 0x1E, 0x01, 0xB8]);
         let conversions = format.conversions(&value);
 
-        assert_eq!(conversions.len(), 2);
+        assert_eq!(
+            conversions
+                .iter()
+                .filter(|c| c.target_format.starts_with("int-"))
+                .count(),
+            2
+        );
 
         let be = conversions
             .iter()
@@ -1042,6 +1349,52 @@ mod tests {
         assert_eq!(le.display, "3087081065");
     }
 
+    #[test]
+    fn test_bytes_to_sized_int_conversions() {
+        let display = |conversions: &[Conversion], id: &str| {
+            conversions
+                .iter()
+                .find(|c| c.target_format == id)
+                .map(|c| c.display.clone())
+        };
+
+        // Sign bit set in both byte orders: all four combinations
+        let conversions =
+            BytesToIntFormat.conversions(&CoreValue::Bytes(vec![0xFF, 0xFF, 0xFF, 0xFE]));
+        assert_eq!(
+            display(&conversions, "u32-be").as_deref(),
+            Some("4294967294")
+        );
+        assert_eq!(display(&conversions, "i32-be").as_deref(), Some("-2"));
+        assert_eq!(
+            display(&conversions, "u32-le").as_deref(),
+            Some("4278190079")
+        );
+        assert_eq!(
+            display(&conversions, "i32-le").as_deref(),
+            Some("-16777217")
+        );
+
+        // Signed views only when the sign bit is set
+        let conversions =
+            BytesToIntFormat.conversions(&CoreValue::Bytes(vec![0x69, 0x1E, 0x01, 0xB8]));
+        assert_eq!(
+            display(&conversions, "u32-be").as_deref(),
+            Some("1763574200")
+        );
+        assert_eq!(display(&conversions, "i32-be"), None);
+        assert_eq!(
+            display(&conversions, "i32-le").as_deref(),
+            Some("-1207886231")
+        );
+
+        // Other widths
+        let conversions = BytesToIntFormat.conversions(&CoreValue::Bytes(vec![0x80, 0x00]));
+        assert_eq!(display(&conversions, "i16-be").as_deref(), Some("-32768"));
+        let conversions = BytesToIntFormat.conversions(&CoreValue::Bytes(vec![0x01, 0x02, 0x03]));
+        assert!(!conversions.iter().any(|c| c.target_format.starts_with('u')));
+    }
+
     #[test]
     fn test_luhn_valid() {
         // Known valid Luhn numbers
@@ -1071,6 +1424,62 @@ mod tests {
         let luhn = conversions.iter().find(|c| c.target_format == "luhn");
         assert!(luhn.is_some(), "Should have Luhn trait");
         assert_eq!(luhn.unwrap().display, "valid Luhn checksum");
+        assert_eq!(luhn.unwrap().kind, ConversionKind::Trait);
+    }
+
+    #[test]
+    fn test_parse_card_style_groups() {
+        let format = DecimalFormat;
+        for input in ["4111 1111 1111 1111", "4111-1111-1111-1111"] {
+            let results = format.parse(input);
+            assert_eq!(results.len(), 1, "{}", input);
+            assert_eq!(
+                results[0].value,
+                CoreValue::Int {
+                    value: 4_111_111_111_111_111,
+                    original_bytes: None,
+                }
+            );
+            assert_eq!(results[0].confidence, 0.5);
+        }
+
+        // Too short for a card number, or not card-style groups
+        for input in ["1234 5678", "2024-01-15", "4111 1111-1111 1111"] {
+            assert!(format.parse(input).is_empty(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_luhn_valid_digit_string() {
+        // Visa test card number
+        assert!(luhn_valid("4111111111111111"));
+        assert!(!luhn_valid("4111111111111112"));
+        assert!(luhn_valid("0079927398713")); // Leading zeros don't change the sum
+        assert!(!luhn_valid("7992 7398 713"));
+        assert!(!luhn_valid("0"));
+        assert!(!luhn_valid(""));
+
+        let format = DecimalFormat;
+        let traits = format.conversions(&CoreValue::String("4111 1111 1111 1111".to_string()));
+        assert_eq!(traits.len(), 1);
+        assert_eq!(traits[0].target_format, "luhn");
+        assert!(format
+            .conversions(&CoreValue::String("4111-1111-1111-1112".to_string()))
+            .is_empty());
+        assert!(format
+            .conversions(&CoreValue::String("4111  1111".to_string()))
+            .is_empty());
+        // Dates and mixed separators aren't grouped digits (20240115 passes Luhn)
+        assert!(luhn_valid("20240115"));
+        for text in ["2024-01-15", "4111 1111-1111 1111", "4111 1111 1111 111a"] {
+            assert!(
+                format
+                    .conversions(&CoreValue::String(text.to_string()))
+                    .is_empty(),
+                "{}",
+                text
+            );
+        }
     }
 
     #[test]
@@ -1131,4 +1540,70 @@ mod tests {
         assert!(isbn.is_some(), "Should have ISBN-13 trait");
         assert_eq!(isbn.unwrap().display, "valid ISBN-13");
     }
+
+    #[test]
+    fn test_gray_code() {
+        assert_eq!(gray_encode(0), 0);
+        assert_eq!(gray_encode(4), 0b110);
+        assert_eq!(gray_encode(7), 0b100);
+        assert_eq!(gray_encode(1 << 40), 0b11 << 39);
+        assert_eq!(gray_decode(0b110), 4);
+        for n in [0, 1, 2, 4, 255, 1 << 40, u64::MAX] {
+            assert_eq!(gray_decode(gray_encode(n)), n);
+        }
+    }
+
+    #[test]
+    fn test_gray_code_conversions() {
+        let four = CoreValue::Int {
+            value: 4,
+            original_bytes: None,
+        };
+        // Derived integers don't get Gray code lines
+        assert!(!DecimalFormat
+            .conversions(&four)
+            .iter()
+            .any(|c| c.target_format.starts_with("gray")));
+
+        let conversions = DecimalFormat.source_conversions(&four);
+        let display = |target: &str| {
+            conversions
+                .iter()
+                .find(|c| c.target_format == target)
+                .map(|c| (c.display.as_str(), c.kind))
+        };
+        assert_eq!(
+            display("gray"),
+            Some(("110", ConversionKind::Representation))
+        );
+        // 4 read as a Gray code (0b100) decodes to 7
+        assert_eq!(
+            display("gray-decoded"),
+            Some(("7 (0b111)", ConversionKind::Representation))
+        );
+    }
+
+    #[test]
+    fn test_numeric_currency_code_only_for_three_digit_input() {
+        let currency_codes = |input: &str| {
+            let interpretation = DecimalFormat.parse(input).remove(0);
+            let mut conversions = Vec::new();
+            DecimalFormat.annotate_conversions(input, &interpretation, &mut conversions);
+            conversions
+                .into_iter()
+                .filter(|c| c.target_format == "currency-code")
+                .map(|c| c.display)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(currency_codes("840"), ["ISO 4217 840 = USD (US Dollar)"]);
+        assert_eq!(
+            currency_codes("036"),
+            ["ISO 4217 036 = AUD (Australian Dollar)"]
+        );
+        // 32 is ARS and 48 is BHD, but two digits aren't a numeric code
+        assert!(currency_codes("32").is_empty());
+        assert!(currency_codes("48").is_empty());
+        assert!(currency_codes("1840").is_empty());
+    }
 }
@@ -2,7 +2,7 @@
 
 use std::net::{Ipv4Addr, Ipv6Addr};
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
     RichDisplayOption,
@@ -223,11 +223,13 @@ impl Format for IpAddrFormat {
                     display: addr.to_string(),
                     path: vec!["ipv4".to_string()],
                     is_lossy: false,
+                    confidence: 1.0,
                     steps: vec![],
                     priority: ConversionPriority::Semantic,
                     display_only: false,
                     kind: ConversionKind::default(),
                     hidden: false,
+                    depth_limited: false,
                     rich_display: vec![],
                 }]
             }
@@ -244,11 +246,13 @@ impl Format for IpAddrFormat {
                     display: addr.to_string(),
                     path: vec!["ipv6".to_string()],
                     is_lossy: false,
+                    confidence: 1.0,
                     steps: vec![],
                     priority: ConversionPriority::Semantic,
                     display_only: false,
                     kind: ConversionKind::default(),
                     hidden: false,
+                    depth_limited: false,
                     rich_display: vec![],
                 }];
 
@@ -265,6 +269,10 @@ impl Format for IpAddrFormat {
         &["ipv4", "ipv6"]
     }
 
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
+
     fn validate(&self, input: &str) -> Option<String> {
         // Try IPv4
         if let Err(e) = input.parse::<Ipv4Addr>() {
@@ -5,7 +5,7 @@
 //! - `978-0-306-40615-7`
 //! - `019853453X`
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{CoreValue, Interpretation};
 
 pub struct IsbnFormat;
@@ -21,14 +21,9 @@ impl IsbnFormat {
         for (i, c) in input.chars().enumerate() {
             match c {
                 '0'..='9' => digits.push(c as u8 - b'0'),
-                'X' | 'x' => {
-                    // X only valid as last character of ISBN-10
-                    if i == input.len() - 1 {
-                        has_x = true;
-                    } else {
-                        return None;
-                    }
-                }
+                // X only valid as last character of ISBN-10
+                'X' | 'x' if i == input.len() - 1 => has_x = true,
+                'X' | 'x' => return None,
                 '-' | ' ' => {} // Ignore separators
                 _ => return None,
             }
@@ -95,6 +90,22 @@ impl IsbnFormat {
         input.contains('-') || input.contains(' ')
     }
 
+    /// Confidence for a valid ISBN-10, with the reason it was assigned.
+    fn isbn10_confidence(input: &str, digits: &[u8], has_x: bool) -> (f32, &'static str) {
+        if has_x {
+            (0.95, "X check digit is a strong ISBN signal")
+        } else if Self::has_isbn_formatting(input) {
+            (0.90, "hyphen/space formatting suggests an intentional ISBN")
+        } else if Self::looks_like_epoch(digits) {
+            (
+                0.40,
+                "bare 10-digit number in the epoch range, probably a timestamp",
+            )
+        } else {
+            (0.70, "bare number that happens to have a valid check digit")
+        }
+    }
+
     /// Check if a 10-digit number looks like a Unix epoch timestamp.
     /// Timestamps from ~2001 to ~2286 are 10 digits starting with 1.
     fn looks_like_epoch(digits: &[u8]) -> bool {
@@ -148,20 +159,7 @@ impl Format for IsbnFormat {
         if total_len == 10 && Self::validate_isbn10(&digits, has_x) {
             let formatted = Self::format_isbn10(&digits, has_x);
 
-            // Determine confidence based on formatting and content
-            let confidence = if has_x {
-                // X check digit is strong ISBN signal
-                0.95
-            } else if Self::has_isbn_formatting(input) {
-                // Formatted with hyphens/spaces - likely intentional
-                0.90
-            } else if Self::looks_like_epoch(&digits) {
-                // Pure numeric that looks like a timestamp - probably not ISBN
-                0.40
-            } else {
-                // Pure numeric, not timestamp-like
-                0.70
-            };
+            let (confidence, _) = Self::isbn10_confidence(input, &digits, has_x);
 
             return vec![Interpretation {
                 value: CoreValue::String(formatted.clone()),
@@ -196,6 +194,25 @@ impl Format for IsbnFormat {
         vec![]
     }
 
+    fn explain(&self, input: &str) -> Option<String> {
+        let Some((digits, has_x)) = Self::extract_digits(input) else {
+            return Some("rejected: not made of ISBN digits".to_string());
+        };
+
+        match digits.len() + usize::from(has_x) {
+            10 if Self::validate_isbn10(&digits, has_x) => {
+                let (confidence, reason) = Self::isbn10_confidence(input, &digits, has_x);
+                Some(format!("ISBN-10: {}, confidence {:.2}", reason, confidence))
+            }
+            10 => Some("rejected: ISBN-10 check digit doesn't match".to_string()),
+            13 if !has_x && Self::validate_isbn13(&digits) => {
+                Some("ISBN-13/EAN-13 check digit matches, confidence 0.90".to_string())
+            }
+            13 => Some("rejected: ISBN-13 check digit doesn't match".to_string()),
+            n => Some(format!("rejected: {} digits, need 10 or 13", n)),
+        }
+    }
+
     fn can_format(&self, _value: &CoreValue) -> bool {
         false
     }
@@ -207,6 +224,10 @@ impl Format for IsbnFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["isbn10", "isbn13", "ean"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,248 @@
+//! ISIN (International Securities Identification Number) format.
+//!
+//! Twelve characters: a two-letter country prefix, a nine-character national
+//! code (the CUSIP for US and Canadian securities) and a Luhn check digit:
+//! - `US0378331005` (Apple)
+//! - `GB0002634946` (BAE Systems)
+
+use crate::format::{specificity, Format, FormatInfo};
+use crate::formats::integers::luhn_valid;
+use crate::types::{CoreValue, Interpretation, RichDisplay, RichDisplayOption};
+
+pub struct IsinFormat;
+
+/// Confidence once the check digit matches.
+const ISIN_CONFIDENCE: f32 = 0.9;
+
+/// Confidence for a matching check digit behind a prefix not in `COUNTRIES`.
+/// One in ten 12-character strings of the right shape passes the check.
+const UNKNOWN_PREFIX_CONFIDENCE: f32 = 0.3;
+
+/// Known ISIN prefixes, as (code, country or issuer).
+const COUNTRIES: &[(&str, &str)] = &[
+    ("AU", "Australia"),
+    ("CA", "Canada"),
+    ("CH", "Switzerland"),
+    ("CN", "China"),
+    ("DE", "Germany"),
+    ("DK", "Denmark"),
+    ("ES", "Spain"),
+    ("EU", "European Union"),
+    ("FI", "Finland"),
+    ("FR", "France"),
+    ("GB", "United Kingdom"),
+    ("HK", "Hong Kong"),
+    ("IE", "Ireland"),
+    ("IN", "India"),
+    ("IT", "Italy"),
+    ("JP", "Japan"),
+    ("KR", "South Korea"),
+    ("LU", "Luxembourg"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("SE", "Sweden"),
+    ("US", "United States"),
+    ("XS", "International (Euroclear/Clearstream)"),
+];
+
+impl IsinFormat {
+    /// Check the ISIN shape: two uppercase letters, nine uppercase
+    /// alphanumerics and a check digit. ISINs are never written in lowercase.
+    fn normalize(input: &str) -> Option<String> {
+        let isin = input.trim().to_string();
+        let bytes = isin.as_bytes();
+        if bytes.len() != 12
+            || !bytes[..2].iter().all(u8::is_ascii_uppercase)
+            || !bytes[2..11]
+                .iter()
+                .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+            || !bytes[11].is_ascii_digit()
+        {
+            return None;
+        }
+        Some(isin)
+    }
+
+    /// Letters expanded to two digits (A = 10 ... Z = 35), as the check digit
+    /// algorithm sees them.
+    fn expand(isin: &str) -> String {
+        isin.bytes()
+            .map(|b| {
+                if b.is_ascii_digit() {
+                    char::from(b).to_string()
+                } else {
+                    (u32::from(b - b'A') + 10).to_string()
+                }
+            })
+            .collect()
+    }
+
+    fn is_valid(isin: &str) -> bool {
+        luhn_valid(&Self::expand(isin))
+    }
+
+    /// Check digit that would make this ISIN valid.
+    fn expected_check_digit(isin: &str) -> u8 {
+        (0..=9)
+            .find(|d| Self::is_valid(&format!("{}{}", &isin[..11], d)))
+            .unwrap_or(0)
+    }
+
+    fn country(code: &str) -> Option<&'static str> {
+        COUNTRIES
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, name)| *name)
+    }
+
+    fn build_rich_display(isin: &str) -> RichDisplayOption {
+        let country = Self::country(&isin[..2]).unwrap_or(&isin[..2]);
+        // US and Canadian national codes are CUSIPs
+        let nsin_label = if matches!(&isin[..2], "US" | "CA") {
+            "CUSIP"
+        } else {
+            "NSIN"
+        };
+        let pairs = vec![
+            ("Country".to_string(), country.to_string()),
+            (nsin_label.to_string(), isin[2..11].to_string()),
+            ("Check digit".to_string(), isin[11..].to_string()),
+        ];
+        RichDisplayOption::new(RichDisplay::KeyValue { pairs })
+    }
+}
+
+impl Format for IsinFormat {
+    fn id(&self) -> &'static str {
+        "isin"
+    }
+
+    fn name(&self) -> &'static str {
+        "ISIN"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Identifiers",
+            description: "International Securities Identification Number with check digit",
+            examples: &["US0378331005", "GB0002634946"],
+            aliases: self.aliases(),
+            has_validation: true,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some(isin) = Self::normalize(input) else {
+            return vec![];
+        };
+        if !Self::is_valid(&isin) {
+            return vec![];
+        }
+
+        let (country, confidence) = match Self::country(&isin[..2]) {
+            Some(country) => (country, ISIN_CONFIDENCE),
+            None => (&isin[..2], UNKNOWN_PREFIX_CONFIDENCE),
+        };
+        vec![Interpretation {
+            value: CoreValue::String(isin.clone()),
+            source_format: "isin".to_string(),
+            confidence,
+            description: format!("ISIN ({}): {}", country, isin),
+            rich_display: vec![Self::build_rich_display(&isin)],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["security"]
+    }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
+
+    fn validate(&self, input: &str) -> Option<String> {
+        let Some(isin) = Self::normalize(input) else {
+            return Some(
+                "expected 2-letter country code, 9 uppercase alphanumerics and a check digit"
+                    .to_string(),
+            );
+        };
+
+        if !Self::is_valid(&isin) {
+            return Some(format!(
+                "checksum failed, expected {} got {}",
+                Self::expected_check_digit(&isin),
+                &isin[11..]
+            ));
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_apple_isin() {
+        let results = IsinFormat.parse("US0378331005");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.9);
+        assert_eq!(results[0].description, "ISIN (United States): US0378331005");
+
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[0].preferred else {
+            panic!("Expected KeyValue");
+        };
+        assert!(pairs.contains(&("CUSIP".to_string(), "037833100".to_string())));
+    }
+
+    #[test]
+    fn test_parse_gb_isin() {
+        let results = IsinFormat.parse("GB0002634946");
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].description,
+            "ISIN (United Kingdom): GB0002634946"
+        );
+    }
+
+    #[test]
+    fn test_checksum_failure() {
+        assert!(IsinFormat.parse("US0378331006").is_empty());
+        assert_eq!(
+            IsinFormat.validate("US0378331006").as_deref(),
+            Some("checksum failed, expected 5 got 6")
+        );
+        assert_eq!(IsinFormat.validate("US0378331005"), None);
+    }
+
+    #[test]
+    fn test_rejects_wrong_shape() {
+        assert!(IsinFormat.parse("US037833100").is_empty());
+        assert!(IsinFormat.parse("1S0378331005").is_empty());
+        assert!(IsinFormat.parse("US037833100X").is_empty());
+        assert!(IsinFormat.parse("gb0002634946").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_prefix_is_unlikely() {
+        // Passes the check digit, but "HE" isn't a country
+        assert!(IsinFormat.parse("helloworld18").is_empty());
+
+        let results = IsinFormat.parse("HELLOWORLD18");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].confidence < 0.5);
+        assert_eq!(results[0].description, "ISIN (HE): HELLOWORLD18");
+    }
+}
@@ -85,10 +85,12 @@ impl Format for JsonFormat {
                 display: formatted,
             }],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Structured,
             display_only: false,
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
@@ -0,0 +1,246 @@
+//! JSON Web Key (JWK) format.
+//!
+//! Recognizes JSON objects shaped like an RFC 7517 key (`{"kty":"RSA",...}`)
+//! and reports the key type, size and intended use. Chains off the JSON parse,
+//! so it only offers a trait on already-parsed JSON.
+
+use base64::Engine;
+use serde_json::{Map, Value};
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+};
+
+pub struct JwkFormat;
+
+/// Confidence for JWK detection. Requiring a known `kty` together with its
+/// key parameters makes accidental matches unlikely.
+const JWK_CONFIDENCE: f32 = 0.9;
+
+impl JwkFormat {
+    /// Decode a base64url key parameter (padding optional).
+    fn decode_param(key: &Map<String, Value>, name: &str) -> Option<Vec<u8>> {
+        let encoded = key.get(name)?.as_str()?;
+        base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(encoded.trim_end_matches('='))
+            .ok()
+    }
+
+    /// Bit length of a big-endian unsigned integer.
+    fn bit_length(bytes: &[u8]) -> usize {
+        let Some(first) = bytes.iter().position(|&b| b != 0) else {
+            return 0;
+        };
+        (bytes.len() - first) * 8 - bytes[first].leading_zeros() as usize
+    }
+
+    /// Describe the key type and size, e.g. "RSA 2048-bit" or "EC P-256".
+    ///
+    /// Returns `None` when the parameters required for `kty` are missing.
+    fn describe_key(key: &Map<String, Value>) -> Option<String> {
+        match key.get("kty")?.as_str()? {
+            "RSA" => {
+                let modulus = Self::decode_param(key, "n")?;
+                Self::decode_param(key, "e")?;
+                Some(format!("RSA {}-bit", Self::bit_length(&modulus)))
+            }
+            "EC" => {
+                let curve = key.get("crv")?.as_str()?;
+                let x = Self::decode_param(key, "x")?;
+                let bits = match curve {
+                    "P-256" | "secp256k1" => 256,
+                    "P-384" => 384,
+                    "P-521" => 521,
+                    _ => x.len() * 8,
+                };
+                Some(format!("EC {curve} ({bits}-bit)"))
+            }
+            "OKP" => {
+                let curve = key.get("crv")?.as_str()?;
+                Self::decode_param(key, "x")?;
+                Some(format!("OKP {curve}"))
+            }
+            "oct" => {
+                let secret = Self::decode_param(key, "k")?;
+                Some(format!("symmetric {}-bit", secret.len() * 8))
+            }
+            _ => None,
+        }
+    }
+
+    /// Summarize a JWK, or `None` if the object isn't one.
+    fn describe(key: &Map<String, Value>) -> Option<String> {
+        let mut parts = vec![Self::describe_key(key)?];
+
+        // Symmetric keys are always secret; others are private when `d` is present
+        if key.get("kty").and_then(Value::as_str) != Some("oct") {
+            let visibility = if key.contains_key("d") {
+                "private"
+            } else {
+                "public"
+            };
+            parts[0] = format!("{} {visibility} key", parts[0]);
+        }
+
+        match key.get("use").and_then(Value::as_str) {
+            Some("sig") => parts.push("use: signature".to_string()),
+            Some("enc") => parts.push("use: encryption".to_string()),
+            Some(other) => parts.push(format!("use: {other}")),
+            None => {
+                if let Some(ops) = key.get("key_ops").and_then(Value::as_array) {
+                    let ops: Vec<&str> = ops.iter().filter_map(Value::as_str).collect();
+                    if !ops.is_empty() {
+                        parts.push(format!("ops: {}", ops.join(", ")));
+                    }
+                }
+            }
+        }
+        if let Some(alg) = key.get("alg").and_then(Value::as_str) {
+            parts.push(format!("alg: {alg}"));
+        }
+        if let Some(kid) = key.get("kid").and_then(Value::as_str) {
+            parts.push(format!("kid: {kid}"));
+        }
+
+        Some(format!("JWK: {}", parts.join(", ")))
+    }
+}
+
+impl Format for JwkFormat {
+    fn id(&self) -> &'static str {
+        "jwk"
+    }
+
+    fn name(&self) -> &'static str {
+        "JSON Web Key"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Data",
+            description: "Key type, size and use of JWK objects (RFC 7517)",
+            examples: &[],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, _input: &str) -> Vec<Interpretation> {
+        // JWKs are parsed as JSON first; only offered as a conversion
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Json(Value::Object(key)) = value else {
+            return vec![];
+        };
+        let Some(display) = Self::describe(key) else {
+            return vec![];
+        };
+
+        vec![Conversion {
+            value: CoreValue::String(display.clone()),
+            target_format: "jwk".to_string(),
+            display: display.clone(),
+            path: vec!["jwk".to_string()],
+            steps: vec![ConversionStep {
+                format: "jwk".to_string(),
+                value: CoreValue::String(display.clone()),
+                display,
+            }],
+            confidence: JWK_CONFIDENCE,
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Trait,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn b64url(bytes: &[u8]) -> String {
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    fn jwk_display(key: Value) -> Option<String> {
+        JwkFormat
+            .conversions(&CoreValue::Json(key))
+            .first()
+            .map(|c| c.display.clone())
+    }
+
+    #[test]
+    fn test_rsa_jwk() {
+        let mut modulus = vec![0xC5; 256];
+        modulus[255] = 0x01;
+        let key = json!({
+            "kty": "RSA",
+            "n": b64url(&modulus),
+            "e": "AQAB",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": "key-1",
+        });
+
+        assert_eq!(
+            jwk_display(key).unwrap(),
+            "JWK: RSA 2048-bit public key, use: signature, alg: RS256, kid: key-1"
+        );
+    }
+
+    #[test]
+    fn test_ec_p256_jwk() {
+        let key = json!({
+            "kty": "EC",
+            "crv": "P-256",
+            "x": b64url(&[0x11; 32]),
+            "y": b64url(&[0x22; 32]),
+            "d": b64url(&[0x33; 32]),
+            "use": "enc",
+        });
+
+        assert_eq!(
+            jwk_display(key).unwrap(),
+            "JWK: EC P-256 (256-bit) private key, use: encryption"
+        );
+    }
+
+    #[test]
+    fn test_symmetric_jwk() {
+        let key = json!({"kty": "oct", "k": b64url(&[0xAB; 32]), "key_ops": ["sign", "verify"]});
+        assert_eq!(
+            jwk_display(key).unwrap(),
+            "JWK: symmetric 256-bit, ops: sign, verify"
+        );
+    }
+
+    #[test]
+    fn test_not_a_jwk() {
+        assert!(jwk_display(json!({"kty": "RSA"})).is_none());
+        assert!(jwk_display(json!({"kty": "banana", "n": "AQAB"})).is_none());
+        assert!(jwk_display(json!({"name": "value"})).is_none());
+        assert!(jwk_display(json!(["kty", "RSA"])).is_none());
+    }
+
+    #[test]
+    fn test_bit_length_ignores_leading_zeros() {
+        assert_eq!(JwkFormat::bit_length(&[0x00, 0x01, 0x00]), 9);
+        assert_eq!(JwkFormat::bit_length(&[0x80]), 8);
+        assert_eq!(JwkFormat::bit_length(&[]), 0);
+    }
+}
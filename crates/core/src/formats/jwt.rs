@@ -3,7 +3,7 @@
 use base64::Engine;
 use chrono::{TimeZone, Utc};
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{CoreValue, Interpretation};
 
 pub struct JwtFormat;
@@ -153,6 +153,10 @@ impl Format for JwtFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["token"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
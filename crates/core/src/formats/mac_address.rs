@@ -1,7 +1,7 @@
 //! MAC address format with OUI vendor lookup.
 
 use super::mac_oui_data::lookup_vendor;
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
     RichDisplayOption,
@@ -70,6 +70,28 @@ fn address_type(bytes: &[u8; 6]) -> &'static str {
     }
 }
 
+/// Describe the I/G and U/L bits of the first octet.
+fn address_bits(bytes: &[u8; 6]) -> String {
+    let scope = if bytes[0] & 0x02 != 0 {
+        "locally administered"
+    } else {
+        "universally administered"
+    };
+    let cast = if bytes[0] & 0x01 != 0 {
+        "multicast"
+    } else {
+        "unicast"
+    };
+    format!("{}, {}", scope, cast)
+}
+
+/// Expand a MAC-48 to EUI-64 by inserting FF:FE between OUI and NIC.
+fn eui64(bytes: &[u8; 6]) -> [u8; 8] {
+    [
+        bytes[0], bytes[1], bytes[2], 0xFF, 0xFE, bytes[3], bytes[4], bytes[5],
+    ]
+}
+
 /// Parse a MAC address string into 6 bytes and detect the notation.
 fn parse_mac(input: &str) -> Option<([u8; 6], MacNotation)> {
     let input = input.trim();
@@ -159,6 +181,15 @@ fn parse_raw_hex(input: &str) -> Option<[u8; 6]> {
     Some(bytes)
 }
 
+/// Format MAC address as colon-separated lowercase (canonical form).
+fn format_mac_canonical(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
 /// Format MAC address as colon-separated uppercase.
 fn format_mac_colon(bytes: &[u8; 6]) -> String {
     format!(
@@ -297,19 +328,67 @@ impl Format for MacAddressFormat {
             display: description,
             path: vec!["mac-address".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority: ConversionPriority::Semantic,
             kind: ConversionKind::Conversion,
             display_only: true, // Don't further convert MAC string
             hidden: false,
+            depth_limited: false,
             rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })],
         }]
     }
 
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Bytes(bytes) = value else {
+            return vec![];
+        };
+        let Ok(arr) = <[u8; 6]>::try_from(bytes.as_slice()) else {
+            return vec![];
+        };
+
+        let canonical = format_mac_canonical(&arr);
+        let eui = format_mac_canonical(&eui64(&arr));
+
+        vec![
+            Conversion {
+                value: CoreValue::String(canonical.clone()),
+                target_format: "mac-canonical".to_string(),
+                display: canonical,
+                priority: ConversionPriority::Primary,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::Bytes(eui64(&arr).to_vec()),
+                target_format: "eui-64".to_string(),
+                display: eui,
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::String(address_bits(&arr)),
+                target_format: "mac-bits".to_string(),
+                display: address_bits(&arr),
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Trait,
+                display_only: true,
+                ..Default::default()
+            },
+        ]
+    }
+
     fn aliases(&self) -> &'static [&'static str] {
         &["mac", "ethernet", "hw-address"]
     }
 
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
+
     fn validate(&self, input: &str) -> Option<String> {
         if parse_mac(input).is_some() {
             return None;
@@ -458,6 +537,40 @@ mod tests {
         assert!(vendor.unwrap().contains("Cisco"));
     }
 
+    #[test]
+    fn test_apple_vendor_detection() {
+        let format = MacAddressFormat;
+        let results = format.parse("00:03:93:12:34:56");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].description.contains("Apple"));
+    }
+
+    #[test]
+    fn test_source_conversions() {
+        let format = MacAddressFormat;
+        let value = CoreValue::Bytes(vec![0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E]);
+        let convs = format.source_conversions(&value);
+        let display = |target: &str| {
+            convs
+                .iter()
+                .find(|c| c.target_format == target)
+                .map(|c| c.display.as_str())
+        };
+
+        assert_eq!(display("mac-canonical"), Some("00:1a:2b:3c:4d:5e"));
+        assert_eq!(display("eui-64"), Some("00:1a:2b:ff:fe:3c:4d:5e"));
+        assert_eq!(
+            display("mac-bits"),
+            Some("universally administered, unicast")
+        );
+    }
+
+    #[test]
+    fn test_address_bits_local_multicast() {
+        let bytes = [0x03, 0x00, 0x00, 0x00, 0x00, 0x01];
+        assert_eq!(address_bits(&bytes), "locally administered, multicast");
+    }
+
     #[test]
     fn test_invalid_mac() {
         let format = MacAddressFormat;
@@ -2,18 +2,24 @@
 
 mod archive;
 mod audio;
+mod base32;
 mod base64;
+mod base_n;
 mod binary;
 mod char;
+mod checksum;
 mod cidr;
 mod color;
 mod constants;
 mod coords;
 mod cron;
+mod css_colors;
 mod cuid;
 mod currency;
 pub mod currency_expr;
 pub mod currency_rates;
+mod cusip;
+mod data_uri;
 mod datasize;
 mod datetime;
 mod duration;
@@ -21,27 +27,40 @@ mod epoch;
 mod escape;
 mod expr;
 mod font;
+mod fraction;
 mod graph;
 mod hash;
 mod hex;
 mod hexdump;
+mod http_status;
+mod iban;
 mod image;
 mod integers;
 mod ipaddr;
 mod isbn;
+mod isin;
 mod json;
+mod jwk;
 mod jwt;
 mod mac_address;
 mod mac_oui_data;
+mod morse;
 mod msgpack;
+mod music_note;
 mod nanoid;
 mod natural_date;
 mod octal;
 mod office;
+mod pan;
+mod pcap;
 mod pdf;
+mod percent;
 mod permissions;
 mod plist;
 mod protobuf;
+mod qr_payload;
+mod semver;
+mod snowflake;
 mod temperature;
 mod ulid;
 mod units;
@@ -50,12 +69,16 @@ mod url_parser;
 mod utf8;
 mod uuid;
 mod video;
+mod xml;
 
 pub use archive::ArchiveFormat;
 pub use audio::AudioFormat;
+pub use base32::Base32Format;
 pub use base64::Base64Format;
+pub use base_n::BaseNFormat;
 pub use binary::BinaryFormat;
 pub use char::CharFormat;
+pub use checksum::ChecksumFormat;
 pub use cidr::CidrFormat;
 pub use color::ColorFormat;
 pub use constants::ConstantsFormat;
@@ -63,6 +86,8 @@ pub use coords::CoordsFormat;
 pub use cron::CronFormat;
 pub use cuid::CuidFormat;
 pub use currency::CurrencyFormat;
+pub use cusip::CusipFormat;
+pub use data_uri::DataUriFormat;
 pub use datasize::DataSizeFormat;
 pub use datetime::DateTimeFormat;
 pub use duration::DurationFormat;
@@ -70,26 +95,39 @@ pub use epoch::EpochFormat;
 pub use escape::EscapeFormat;
 pub use expr::ExprFormat;
 pub use font::FontFormat;
+pub use fraction::FractionFormat;
 pub use graph::GraphFormat;
 pub use hash::HashFormat;
 pub use hex::HexFormat;
 pub use hexdump::HexdumpFormat;
-pub use image::ImageFormat;
+pub use http_status::HttpStatusFormat;
+pub use iban::IbanFormat;
+pub use image::{ImageFormat, Thumbnail};
 pub use integers::{BytesToIntFormat, DecimalFormat};
 pub use ipaddr::IpAddrFormat;
 pub use isbn::IsbnFormat;
+pub use isin::IsinFormat;
 pub use json::JsonFormat;
+pub use jwk::JwkFormat;
 pub use jwt::JwtFormat;
 pub use mac_address::MacAddressFormat;
+pub use morse::MorseFormat;
 pub use msgpack::MsgPackFormat;
+pub use music_note::MusicNoteFormat;
 pub use nanoid::NanoIdFormat;
 pub use natural_date::NaturalDateFormat;
 pub use octal::OctalFormat;
 pub use office::OfficeFormat;
+pub use pan::PanFormat;
+pub use pcap::PcapFormat;
 pub use pdf::PdfFormat;
+pub use percent::PercentFormat;
 pub use permissions::PermissionsFormat;
 pub use plist::PlistFormat;
 pub use protobuf::ProtobufFormat;
+pub use qr_payload::QrPayloadFormat;
+pub use semver::SemVerFormat;
+pub use snowflake::SnowflakeFormat;
 pub use temperature::TemperatureFormat;
 pub use ulid::UlidFormat;
 pub use units::{
@@ -101,3 +139,4 @@ pub use url_parser::UrlParserFormat;
 pub use utf8::Utf8Format;
 pub use uuid::UuidFormat;
 pub use video::VideoFormat;
+pub use xml::XmlFormat;
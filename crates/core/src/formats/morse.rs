@@ -0,0 +1,285 @@
+//! Morse code format.
+//!
+//! Letters are separated by spaces and words by `/`:
+//! - `... --- ...` → `SOS`
+//! - `.... . .-.. .-.. --- / .-- --- .-. .-.. -..` → `HELLO WORLD`
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{Conversion, ConversionPriority, ConversionStep, CoreValue, Interpretation};
+
+pub struct MorseFormat;
+
+/// International Morse code (ITU-R M.1677-1).
+const MORSE_TABLE: &[(char, &str)] = &[
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+/// Longest text we offer a Morse encoding for.
+const MAX_ENCODE_LEN: usize = 32;
+
+impl MorseFormat {
+    /// Decode Morse code to uppercase text.
+    ///
+    /// Returns `None` if any letter is not a known code.
+    fn decode(input: &str) -> Option<String> {
+        let words: Vec<String> = input
+            .split('/')
+            .map(|word| {
+                word.split_whitespace()
+                    .map(|code| {
+                        MORSE_TABLE
+                            .iter()
+                            .find(|(_, c)| *c == code)
+                            .map(|(ch, _)| *ch)
+                    })
+                    .collect::<Option<String>>()
+            })
+            .collect::<Option<_>>()?;
+
+        Some(
+            words
+                .into_iter()
+                .filter(|w| !w.is_empty())
+                .collect::<Vec<_>>()
+                .join(" "),
+        )
+    }
+
+    /// Whether text is short enough and has something worth encoding.
+    fn encodable(text: &str) -> bool {
+        text.len() <= MAX_ENCODE_LEN && text.chars().any(|c| c.is_ascii_alphanumeric())
+    }
+
+    /// Encode text as Morse code.
+    ///
+    /// Returns `None` if the text contains characters Morse can't represent.
+    fn encode(text: &str) -> Option<String> {
+        let words: Vec<String> = text
+            .split_whitespace()
+            .map(|word| {
+                word.chars()
+                    .map(|ch| {
+                        let upper = ch.to_ascii_uppercase();
+                        MORSE_TABLE
+                            .iter()
+                            .find(|(c, _)| *c == upper)
+                            .map(|(_, code)| *code)
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .map(|codes| codes.join(" "))
+            })
+            .collect::<Option<_>>()?;
+
+        Some(words.join(" / "))
+    }
+
+    /// Whether input is made up of Morse symbols only.
+    ///
+    /// Requires both dots and dashes so ellipses and horizontal rules
+    /// (`...`, `-----`) aren't taken for Morse.
+    fn looks_like_morse(input: &str) -> bool {
+        input.chars().all(|c| matches!(c, '.' | '-' | '/' | ' '))
+            && input.contains('.')
+            && input.contains('-')
+    }
+}
+
+impl Format for MorseFormat {
+    fn id(&self) -> &'static str {
+        "morse"
+    }
+
+    fn name(&self) -> &'static str {
+        "Morse Code"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Encoding",
+            description: "International Morse code (letters split by spaces, words by /)",
+            examples: &["... --- ...", ".... . .-.. .-.. --- / .-- --- .-. .-.. -.."],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let trimmed = input.trim();
+        if !Self::looks_like_morse(trimmed) {
+            return vec![];
+        }
+
+        let Some(decoded) = Self::decode(trimmed) else {
+            return vec![];
+        };
+        // A single letter is more likely a stray symbol than a message
+        if decoded.chars().filter(|c| !c.is_whitespace()).count() < 2 {
+            return vec![];
+        }
+
+        vec![Interpretation {
+            value: CoreValue::String(decoded.clone()),
+            source_format: "morse".to_string(),
+            confidence: 0.85,
+            description: format!("Morse: {}", decoded),
+            rich_display: vec![],
+        }]
+    }
+
+    fn can_format(&self, value: &CoreValue) -> bool {
+        matches!(value, CoreValue::String(s) if Self::encodable(s))
+    }
+
+    fn format(&self, value: &CoreValue) -> Option<String> {
+        match value {
+            CoreValue::String(s) if Self::encodable(s) => Self::encode(s),
+            _ => None,
+        }
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::String(s) = value else {
+            return vec![];
+        };
+        if !Self::encodable(s) {
+            return vec![];
+        }
+        let Some(morse) = Self::encode(s) else {
+            return vec![];
+        };
+
+        vec![Conversion {
+            value: CoreValue::String(morse.clone()),
+            target_format: "morse".to_string(),
+            display: morse.clone(),
+            path: vec!["morse".to_string()],
+            steps: vec![ConversionStep {
+                format: "morse".to_string(),
+                value: CoreValue::String(morse.clone()),
+                display: morse,
+            }],
+            priority: ConversionPriority::Encoding,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["morse-code", "cw"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sos() {
+        let results = MorseFormat.parse("... --- ...");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.85);
+        assert!(matches!(&results[0].value, CoreValue::String(s) if s == "SOS"));
+    }
+
+    #[test]
+    fn test_round_trip_words() {
+        let morse = MorseFormat::encode("Hello World").unwrap();
+        assert_eq!(morse, ".... . .-.. .-.. --- / .-- --- .-. .-.. -..");
+        assert_eq!(MorseFormat::decode(&morse).as_deref(), Some("HELLO WORLD"));
+    }
+
+    #[test]
+    fn test_conversion_from_text() {
+        let conversions = MorseFormat.conversions(&CoreValue::String("SOS".to_string()));
+        assert_eq!(conversions.len(), 1);
+        assert_eq!(conversions[0].display, "... --- ...");
+    }
+
+    #[test]
+    fn test_format_matches_conversion_guards() {
+        let sos = CoreValue::String("SOS".to_string());
+        assert!(MorseFormat.can_format(&sos));
+        assert_eq!(MorseFormat.format(&sos).as_deref(), Some("... --- ..."));
+
+        for text in ["!?", &"A".repeat(MAX_ENCODE_LEN + 1)] {
+            let value = CoreValue::String(text.to_string());
+            assert!(!MorseFormat.can_format(&value));
+            assert_eq!(MorseFormat.format(&value), None);
+        }
+    }
+
+    #[test]
+    fn test_unknown_characters() {
+        // Not representable in Morse: no conversion rather than a partial one
+        assert_eq!(MorseFormat::encode("naïve"), None);
+        assert!(MorseFormat
+            .conversions(&CoreValue::String("日本".to_string()))
+            .is_empty());
+        // Unknown code
+        assert!(MorseFormat.parse("...... ---").is_empty());
+    }
+
+    #[test]
+    fn test_reject_noise() {
+        assert!(MorseFormat.parse("-").is_empty());
+        assert!(MorseFormat.parse("-----").is_empty());
+        assert!(MorseFormat.parse("...").is_empty());
+        assert!(MorseFormat.parse(".-").is_empty()); // single letter
+        assert!(MorseFormat.parse("- - -").is_empty());
+    }
+}
@@ -114,11 +114,13 @@ impl Format for MsgPackFormat {
             display,
             path: vec!["msgpack".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority,
             display_only: false,
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![RichDisplayOption::new(RichDisplay::PacketLayout {
                 segments: decoded.segments,
                 compact,
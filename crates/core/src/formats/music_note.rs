@@ -0,0 +1,305 @@
+//! Musical note format.
+//!
+//! Converts between scientific pitch notation and frequency, using twelve-tone
+//! equal temperament tuned to A4 = 440 Hz:
+//! - `A4`, `C#5`, `Bb3` → frequency and MIDI note number
+//! - `440 Hz`, `261.63 Hz` → nearest note, with the deviation in cents
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
+    RichDisplayOption,
+};
+
+pub struct MusicNoteFormat;
+
+/// Reference pitch: A4 (MIDI note 69).
+const A4_HZ: f64 = 440.0;
+const A4_MIDI: i32 = 69;
+
+/// Frequencies considered audible, and so worth mapping to a note.
+const MIN_AUDIO_HZ: f64 = 20.0;
+const MAX_AUDIO_HZ: f64 = 20_000.0;
+
+/// Note names by semitone above C.
+const NOTE_NAMES: [&str; 12] = [
+    "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+];
+
+/// `A4`, `C#5`, `Bb3`, `C-1`
+fn note_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"^(?P<letter>[A-G])(?P<accidental>[#♯b♭]?)(?P<octave>-1|\d)$").unwrap()
+    })
+}
+
+/// `440 Hz`, `261.63hz`, `1.5 kHz`
+fn frequency_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| Regex::new(r"(?i)^(?P<value>\d+(?:\.\d+)?)\s*(?P<unit>k?hz)$").unwrap())
+}
+
+impl MusicNoteFormat {
+    /// Parse scientific pitch notation into a MIDI note number.
+    fn parse_note(input: &str) -> Option<i32> {
+        let caps = note_regex().captures(input)?;
+        let semitone = match &caps["letter"] {
+            "C" => 0,
+            "D" => 2,
+            "E" => 4,
+            "F" => 5,
+            "G" => 7,
+            "A" => 9,
+            "B" => 11,
+            _ => return None,
+        };
+        let accidental = match &caps["accidental"] {
+            "#" | "♯" => 1,
+            "b" | "♭" => -1,
+            _ => 0,
+        };
+        let octave: i32 = caps["octave"].parse().ok()?;
+
+        let midi = (octave + 1) * 12 + semitone + accidental;
+        (0..=127).contains(&midi).then_some(midi)
+    }
+
+    /// Parse a frequency in the audible range, in Hz.
+    fn parse_frequency(input: &str) -> Option<f64> {
+        let caps = frequency_regex().captures(input)?;
+        let mut hz: f64 = caps["value"].parse().ok()?;
+        if caps["unit"].eq_ignore_ascii_case("khz") {
+            hz *= 1000.0;
+        }
+        (MIN_AUDIO_HZ..=MAX_AUDIO_HZ).contains(&hz).then_some(hz)
+    }
+
+    /// Frequency of a MIDI note in Hz.
+    fn midi_to_frequency(midi: i32) -> f64 {
+        A4_HZ * 2f64.powf(f64::from(midi - A4_MIDI) / 12.0)
+    }
+
+    /// Nearest MIDI note to a frequency, and the deviation from it in cents.
+    fn nearest_note(hz: f64) -> (i32, f64) {
+        let exact = f64::from(A4_MIDI) + 12.0 * (hz / A4_HZ).log2();
+        let midi = exact.round() as i32;
+        (midi, (exact - f64::from(midi)) * 100.0)
+    }
+
+    /// Name of a MIDI note in scientific pitch notation (sharps for accidentals).
+    fn note_name(midi: i32) -> String {
+        format!(
+            "{}{}",
+            NOTE_NAMES[midi.rem_euclid(12) as usize],
+            midi.div_euclid(12) - 1
+        )
+    }
+
+    /// Key facts about a frequency: nearest note, MIDI number and cents off.
+    fn key_values(hz: f64) -> Vec<(String, String)> {
+        let (midi, cents) = Self::nearest_note(hz);
+        vec![
+            ("Note".to_string(), Self::note_name(midi)),
+            ("Frequency".to_string(), format!("{} Hz", format_hz(hz))),
+            ("MIDI note".to_string(), midi.to_string()),
+            ("Cents off".to_string(), format_cents(cents)),
+        ]
+    }
+}
+
+/// Format a frequency to at most two decimals (261.63, 440).
+fn format_hz(hz: f64) -> String {
+    ((hz * 100.0).round() / 100.0).to_string()
+}
+
+/// Format a cents deviation as a signed whole number ("+12", "-3", "0").
+fn format_cents(cents: f64) -> String {
+    let rounded = cents.round() as i32;
+    if rounded == 0 {
+        "0".to_string()
+    } else {
+        format!("{:+}", rounded)
+    }
+}
+
+impl Format for MusicNoteFormat {
+    fn id(&self) -> &'static str {
+        "music-note"
+    }
+
+    fn name(&self) -> &'static str {
+        "Musical Note"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Units",
+            description:
+                "Musical notes in scientific pitch notation and audio frequencies (A4 = 440 Hz)",
+            examples: &["A4", "C#5", "261.63 Hz"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let input = input.trim();
+
+        if let Some(midi) = Self::parse_note(input) {
+            let hz = Self::midi_to_frequency(midi);
+            return vec![Interpretation {
+                value: CoreValue::Float(hz),
+                source_format: "music-note".to_string(),
+                confidence: 0.85,
+                description: format!("{} = {} Hz (MIDI {})", input, format_hz(hz), midi),
+                rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue {
+                    pairs: Self::key_values(hz),
+                })],
+            }];
+        }
+
+        if let Some(hz) = Self::parse_frequency(input) {
+            let (midi, cents) = Self::nearest_note(hz);
+            return vec![Interpretation {
+                value: CoreValue::Float(hz),
+                source_format: "music-note".to_string(),
+                confidence: 0.75,
+                description: format!(
+                    "{} Hz ≈ {} ({} cents)",
+                    format_hz(hz),
+                    Self::note_name(midi),
+                    format_cents(cents)
+                ),
+                rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue {
+                    pairs: Self::key_values(hz),
+                })],
+            }];
+        }
+
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Float(hz) = value else {
+            return vec![];
+        };
+        let (midi, cents) = Self::nearest_note(*hz);
+
+        let note = Self::note_name(midi);
+        let note_display = if cents.round() == 0.0 {
+            note.clone()
+        } else {
+            format!("{} ({} cents)", note, format_cents(cents))
+        };
+
+        vec![
+            Conversion {
+                value: CoreValue::Float(*hz),
+                target_format: "frequency".to_string(),
+                display: format!("{} Hz", format_hz(*hz)),
+                priority: ConversionPriority::Primary,
+                kind: ConversionKind::Conversion,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::String(note),
+                target_format: "note".to_string(),
+                display: note_display,
+                is_lossy: cents.round() != 0.0,
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::String(midi.to_string()),
+                target_format: "midi-note".to_string(),
+                display: midi.to_string(),
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Representation,
+                display_only: true,
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["note", "pitch", "midi"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_value(results: &[Interpretation], key: &str) -> String {
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[0].preferred else {
+            panic!("Expected KeyValue");
+        };
+        pairs
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_a4() {
+        let results = MusicNoteFormat.parse("A4");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].value, CoreValue::Float(440.0));
+        assert_eq!(results[0].confidence, 0.85);
+        assert_eq!(results[0].description, "A4 = 440 Hz (MIDI 69)");
+        assert_eq!(key_value(&results, "MIDI note"), "69");
+        assert_eq!(key_value(&results, "Cents off"), "0");
+    }
+
+    #[test]
+    fn test_accidentals_and_octaves() {
+        assert_eq!(MusicNoteFormat::parse_note("C4"), Some(60));
+        assert_eq!(MusicNoteFormat::parse_note("C#5"), Some(73));
+        assert_eq!(MusicNoteFormat::parse_note("Bb3"), Some(58));
+        assert_eq!(MusicNoteFormat::parse_note("C-1"), Some(0));
+        assert_eq!(MusicNoteFormat::parse_note("G9"), Some(127));
+        assert_eq!(MusicNoteFormat::parse_note("A9"), None);
+        assert_eq!(MusicNoteFormat::parse_note("H4"), None);
+        assert_eq!(MusicNoteFormat::parse_note("a4"), None);
+    }
+
+    #[test]
+    fn test_frequency_to_note() {
+        let results = MusicNoteFormat.parse("261.63 Hz");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].description, "261.63 Hz ≈ C4 (0 cents)");
+        assert_eq!(key_value(&results, "Note"), "C4");
+        assert_eq!(key_value(&results, "MIDI note"), "60");
+
+        let results = MusicNoteFormat.parse("450hz");
+        assert_eq!(key_value(&results, "Note"), "A4");
+        assert_eq!(key_value(&results, "Cents off"), "+39");
+        assert_eq!(MusicNoteFormat::parse_frequency("1.5 kHz"), Some(1500.0));
+    }
+
+    #[test]
+    fn test_rejects_inaudible_frequencies() {
+        assert!(MusicNoteFormat.parse("5 Hz").is_empty());
+        assert!(MusicNoteFormat.parse("50 kHz").is_empty());
+        assert!(MusicNoteFormat.parse("440").is_empty());
+    }
+}
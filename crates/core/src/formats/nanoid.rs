@@ -5,7 +5,7 @@
 
 use tracing::{debug, trace};
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{CoreValue, Interpretation};
 
 pub struct NanoIdFormat;
@@ -132,6 +132,10 @@ impl Format for NanoIdFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["nano"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
 }
 
 #[cfg(test)]
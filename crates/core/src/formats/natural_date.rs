@@ -15,8 +15,11 @@ use chrono::{Datelike, Duration, Local, NaiveTime, TimeZone, Utc, Weekday};
 use regex::Regex;
 use std::sync::OnceLock;
 
-use crate::format::{Format, FormatInfo};
-use crate::types::{CoreValue, Interpretation, RichDisplay, RichDisplayOption};
+use crate::format::{specificity, Format, FormatInfo};
+use crate::time::format_relative;
+use crate::types::{
+    ConversionConfig, CoreValue, Interpretation, RelativeTimeStyle, RichDisplay, RichDisplayOption,
+};
 
 pub struct NaturalDateFormat;
 
@@ -671,67 +674,8 @@ impl NaturalDateFormat {
         })
     }
 
-    /// Format a datetime relative to now
-    fn format_relative(dt: chrono::DateTime<Utc>) -> String {
-        let now = Utc::now();
-        let diff = dt.signed_duration_since(now);
-        let secs = diff.num_seconds();
-        let abs_secs = secs.abs();
-
-        let (value, unit) = if abs_secs < 60 {
-            (abs_secs, if abs_secs == 1 { "second" } else { "seconds" })
-        } else if abs_secs < 3600 {
-            let mins = abs_secs / 60;
-            (mins, if mins == 1 { "minute" } else { "minutes" })
-        } else if abs_secs < 86400 {
-            let hours = abs_secs / 3600;
-            (hours, if hours == 1 { "hour" } else { "hours" })
-        } else if abs_secs < 604800 {
-            let days = abs_secs / 86400;
-            (days, if days == 1 { "day" } else { "days" })
-        } else if abs_secs < 2592000 {
-            let weeks = abs_secs / 604800;
-            (weeks, if weeks == 1 { "week" } else { "weeks" })
-        } else if abs_secs < 31536000 {
-            let months = abs_secs / 2592000;
-            (months, if months == 1 { "month" } else { "months" })
-        } else {
-            let years = abs_secs / 31536000;
-            (years, if years == 1 { "year" } else { "years" })
-        };
-
-        if secs < 0 {
-            format!("{} {} ago", value, unit)
-        } else if secs > 0 {
-            format!("in {} {}", value, unit)
-        } else {
-            "now".to_string()
-        }
-    }
-}
-
-impl Format for NaturalDateFormat {
-    fn id(&self) -> &'static str {
-        "natural-date"
-    }
-
-    fn name(&self) -> &'static str {
-        "Natural Date"
-    }
-
-    fn info(&self) -> FormatInfo {
-        FormatInfo {
-            id: self.id(),
-            name: self.name(),
-            category: "Timestamps",
-            description: "Natural language dates (15:00, tomorrow, next monday, dec 15, christmas)",
-            examples: &["15:00", "tomorrow", "next friday", "dec 15", "christmas"],
-            aliases: self.aliases(),
-            has_validation: false,
-        }
-    }
-
-    fn parse(&self, input: &str) -> Vec<Interpretation> {
+    /// Parse a natural language date, describing relative times in `style`.
+    fn parse_styled(input: &str, style: RelativeTimeStyle) -> Vec<Interpretation> {
         // Try each parser in order of specificity
         let result = Self::parse_relative_word(input)
             .or_else(|| Self::parse_time_of_day(input))
@@ -748,7 +692,7 @@ impl Format for NaturalDateFormat {
         };
 
         let iso = result.datetime.to_rfc3339();
-        let relative = Self::format_relative(result.datetime);
+        let relative = format_relative(Utc::now(), result.datetime, style);
 
         let rich_display = if result.is_now {
             vec![RichDisplayOption::new(RichDisplay::LiveClock {
@@ -770,6 +714,36 @@ impl Format for NaturalDateFormat {
             rich_display,
         }]
     }
+}
+
+impl Format for NaturalDateFormat {
+    fn id(&self) -> &'static str {
+        "natural-date"
+    }
+
+    fn name(&self) -> &'static str {
+        "Natural Date"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Timestamps",
+            description: "Natural language dates (15:00, tomorrow, next monday, dec 15, christmas)",
+            examples: &["15:00", "tomorrow", "next friday", "dec 15", "christmas"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        Self::parse_styled(input, RelativeTimeStyle::default())
+    }
+
+    fn parse_with_config(&self, input: &str, config: &ConversionConfig) -> Vec<Interpretation> {
+        Self::parse_styled(input, config.relative_time_style)
+    }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
         false // Formatting handled by datetime.rs
@@ -782,6 +756,10 @@ impl Format for NaturalDateFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["nat-date", "human-date"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
@@ -317,23 +317,24 @@ impl Format for OfficeFormat {
 
     fn parse(&self, input: &str) -> Vec<Interpretation> {
         // Try to decode as base64
-        if let Ok(bytes) = base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input)
-        {
-            if let Some(meta) = Self::parse_office(&bytes) {
-                let description = Self::format_description(&meta);
-                let rich_display = Self::build_rich_display(&meta);
-
-                return vec![Interpretation {
-                    value: CoreValue::Bytes(bytes),
-                    source_format: "office".to_string(),
-                    confidence: 0.95,
-                    description,
-                    rich_display,
-                }];
-            }
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input) {
+            Ok(bytes) => self.parse_bytes(&bytes),
+            Err(_) => vec![],
         }
+    }
 
-        vec![]
+    fn parse_bytes(&self, bytes: &[u8]) -> Vec<Interpretation> {
+        let Some(meta) = Self::parse_office(bytes) else {
+            return vec![];
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes.to_vec()),
+            source_format: "office".to_string(),
+            confidence: 0.95,
+            description: Self::format_description(&meta),
+            rich_display: Self::build_rich_display(&meta),
+        }]
     }
 
     fn can_format(&self, _value: &CoreValue) -> bool {
@@ -363,10 +364,12 @@ impl Format for OfficeFormat {
             path: vec!["office-info".to_string()],
             steps: vec![],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::Structured,
             display_only: true,
             kind: ConversionKind::Representation,
             hidden: false,
+            depth_limited: false,
             rich_display,
         }]
     }
@@ -0,0 +1,251 @@
+//! Payment card number (PAN) format.
+//!
+//! Annotates 13-19 digit numbers that pass the Luhn checksum with the card
+//! network their issuer prefix (IIN) belongs to, e.g. `4111111111111111` →
+//! "Visa card number ************1111". Only the last four digits are ever
+//! shown.
+
+use crate::format::{Format, FormatInfo};
+use crate::formats::integers::{luhn_valid, separated_digits};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+};
+
+pub struct PanFormat;
+
+/// Confidence for the card network trait. Any Luhn-valid number with a
+/// matching prefix qualifies, so this is a hint rather than a detection.
+const PAN_CONFIDENCE: f32 = 0.4;
+
+/// A card network: its IIN prefix ranges and the card lengths it issues.
+struct Network {
+    name: &'static str,
+    /// Inclusive ranges, compared against as many leading digits as the bounds have.
+    prefixes: &'static [(u32, u32)],
+    lengths: &'static [usize],
+}
+
+/// Card networks, checked in order, so narrower ranges come before broader ones.
+const NETWORKS: &[Network] = &[
+    Network {
+        name: "American Express",
+        prefixes: &[(34, 34), (37, 37)],
+        lengths: &[15],
+    },
+    Network {
+        name: "JCB",
+        prefixes: &[(3528, 3589)],
+        lengths: &[16, 17, 18, 19],
+    },
+    Network {
+        name: "Diners Club",
+        prefixes: &[(300, 305), (36, 36), (38, 39)],
+        lengths: &[14, 15, 16, 17, 18, 19],
+    },
+    Network {
+        name: "Visa",
+        prefixes: &[(4, 4)],
+        lengths: &[13, 16, 19],
+    },
+    Network {
+        name: "Mastercard",
+        prefixes: &[(51, 55), (2221, 2720)],
+        lengths: &[16],
+    },
+    Network {
+        name: "Discover",
+        prefixes: &[(6011, 6011), (644, 649), (65, 65)],
+        lengths: &[16, 17, 18, 19],
+    },
+    Network {
+        name: "UnionPay",
+        prefixes: &[(62, 62)],
+        lengths: &[16, 17, 18, 19],
+    },
+    Network {
+        name: "Maestro",
+        prefixes: &[
+            (50, 50),
+            (56, 58),
+            (6304, 6304),
+            (6759, 6759),
+            (676770, 676774),
+        ],
+        lengths: &[13, 14, 15, 16, 17, 18, 19],
+    },
+];
+
+impl PanFormat {
+    /// Probable card network for a digit string, if the prefix and length fit.
+    fn network(digits: &str) -> Option<&'static str> {
+        if !(13..=19).contains(&digits.len()) || !luhn_valid(digits) {
+            return None;
+        }
+
+        NETWORKS
+            .iter()
+            .find(|network| {
+                network.lengths.contains(&digits.len())
+                    && network.prefixes.iter().any(|&(low, high)| {
+                        let width = low.to_string().len();
+                        digits[..width]
+                            .parse::<u32>()
+                            .is_ok_and(|prefix| (low..=high).contains(&prefix))
+                    })
+            })
+            .map(|network| network.name)
+    }
+
+    /// Mask all but the last four digits.
+    fn mask(digits: &str) -> String {
+        let (hidden, last4) = digits.split_at(digits.len() - 4);
+        format!("{}{}", "*".repeat(hidden.len()), last4)
+    }
+}
+
+impl Format for PanFormat {
+    fn id(&self) -> &'static str {
+        "pan"
+    }
+
+    fn name(&self) -> &'static str {
+        "Payment Card Number"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Identifiers",
+            description: "Card network of Luhn-valid card numbers (masked)",
+            examples: &[],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, _input: &str) -> Vec<Interpretation> {
+        // Card numbers are plain digits; only offered as a conversion, so the
+        // full number never becomes a value that other formats re-encode
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let digits = match value {
+            CoreValue::Int { value, .. } if *value > 0 => value.to_string(),
+            // Grouped like "4111 1111 1111 1111"
+            CoreValue::String(s) => match separated_digits(s.trim()) {
+                Some(digits) => digits,
+                None => return vec![],
+            },
+            _ => return vec![],
+        };
+        let Some(network) = Self::network(&digits) else {
+            return vec![];
+        };
+
+        let display = format!("{} card number {}", network, Self::mask(&digits));
+        vec![Conversion {
+            value: CoreValue::String(display.clone()),
+            target_format: "pan".to_string(),
+            display: display.clone(),
+            path: vec!["pan".to_string()],
+            steps: vec![ConversionStep {
+                format: "pan".to_string(),
+                value: CoreValue::String(display.clone()),
+                display,
+            }],
+            confidence: PAN_CONFIDENCE,
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Trait,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["card", "credit-card"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pan_display(value: CoreValue) -> Option<String> {
+        PanFormat
+            .conversions(&value)
+            .into_iter()
+            .next()
+            .map(|c| c.display)
+    }
+
+    fn int(value: i128) -> CoreValue {
+        CoreValue::Int {
+            value,
+            original_bytes: None,
+        }
+    }
+
+    #[test]
+    fn test_visa() {
+        assert_eq!(
+            pan_display(int(4_111_111_111_111_111)).as_deref(),
+            Some("Visa card number ************1111")
+        );
+    }
+
+    #[test]
+    fn test_amex() {
+        assert_eq!(
+            pan_display(int(378_282_246_310_005)).as_deref(),
+            Some("American Express card number ***********0005")
+        );
+    }
+
+    #[test]
+    fn test_other_networks() {
+        let network = |n: &str| PanFormat::network(n);
+        assert_eq!(network("5555555555554444"), Some("Mastercard"));
+        assert_eq!(network("2223003122003222"), Some("Mastercard"));
+        assert_eq!(network("6011111111111117"), Some("Discover"));
+        assert_eq!(network("3530111333300000"), Some("JCB"));
+        assert_eq!(network("30569309025904"), Some("Diners Club"));
+        assert_eq!(network("6200000000000005"), Some("UnionPay"));
+    }
+
+    #[test]
+    fn test_grouped_digits() {
+        let display = pan_display(CoreValue::String("4111 1111 1111 1111".into())).unwrap();
+        assert_eq!(display, "Visa card number ************1111");
+    }
+
+    #[test]
+    fn test_never_echoes_full_number() {
+        for pan in ["4111111111111111", "378282246310005", "5555555555554444"] {
+            let display = pan_display(CoreValue::String(pan.into())).unwrap();
+            assert!(!display.contains(pan), "{display}");
+            assert!(display.ends_with(&pan[pan.len() - 4..]));
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_cards() {
+        // Fails Luhn
+        assert!(pan_display(int(4_111_111_111_111_112)).is_none());
+        // Too short
+        assert!(pan_display(int(79_927_398_713)).is_none());
+        // Luhn-valid, but no network uses this prefix
+        assert!(pan_display(int(9_111_111_111_111_110)).is_none());
+        // Visa prefix with a length Visa doesn't issue
+        assert!(PanFormat::network("411111111111116").is_none());
+    }
+}
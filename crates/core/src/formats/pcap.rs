@@ -0,0 +1,434 @@
+//! Packet capture file format detection and metadata extraction.
+//!
+//! Detects pcap and pcapng captures and extracts, without dissecting packets:
+//! - Byte order and version
+//! - Link type (Ethernet, 802.11, raw IP, ...)
+//! - Snap length
+//! - Packet count
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
+    RichDisplayOption,
+};
+
+pub struct PcapFormat;
+
+/// pcap magic with microsecond timestamps, as read in the file's byte order.
+const PCAP_MAGIC_MICROS: u32 = 0xA1B2_C3D4;
+/// pcap magic with nanosecond timestamps.
+const PCAP_MAGIC_NANOS: u32 = 0xA1B2_3C4D;
+/// pcapng Section Header Block type (the same in either byte order).
+const PCAPNG_SHB: u32 = 0x0A0D_0D0A;
+/// pcapng byte-order magic inside the Section Header Block.
+const PCAPNG_BYTE_ORDER_MAGIC: u32 = 0x1A2B_3C4D;
+
+/// Common link-layer header types (tcpdump.org LINKTYPE_ values).
+const LINK_TYPES: &[(u32, &str)] = &[
+    (0, "BSD loopback"),
+    (1, "Ethernet"),
+    (101, "Raw IP"),
+    (105, "IEEE 802.11"),
+    (113, "Linux cooked (SLL)"),
+    (127, "IEEE 802.11 + radiotap"),
+    (228, "Raw IPv4"),
+    (229, "Raw IPv6"),
+    (276, "Linux cooked v2 (SLL2)"),
+];
+
+/// Capture metadata extracted from the file headers.
+#[derive(Debug, Clone, Default)]
+struct CaptureMetadata {
+    capture_type: &'static str,
+    version: String,
+    big_endian: bool,
+    nanosecond: bool,
+    link_type: Option<u32>,
+    snap_length: Option<u32>,
+    packet_count: usize,
+    truncated: bool,
+}
+
+/// Reads integers in a capture's byte order.
+#[derive(Clone, Copy)]
+struct Reader<'a> {
+    data: &'a [u8],
+    big_endian: bool,
+}
+
+impl Reader<'_> {
+    fn u16(&self, offset: usize) -> Option<u16> {
+        let bytes: [u8; 2] = self.data.get(offset..offset + 2)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        })
+    }
+
+    fn u32(&self, offset: usize) -> Option<u32> {
+        let bytes: [u8; 4] = self.data.get(offset..offset + 4)?.try_into().ok()?;
+        Some(if self.big_endian {
+            u32::from_be_bytes(bytes)
+        } else {
+            u32::from_le_bytes(bytes)
+        })
+    }
+}
+
+impl PcapFormat {
+    /// Parse a classic pcap global header and count the packet records.
+    fn parse_pcap(data: &[u8]) -> Option<CaptureMetadata> {
+        let magic: [u8; 4] = data.get(..4)?.try_into().ok()?;
+        let (big_endian, nanosecond) = match (u32::from_le_bytes(magic), u32::from_be_bytes(magic))
+        {
+            (PCAP_MAGIC_MICROS, _) => (false, false),
+            (PCAP_MAGIC_NANOS, _) => (false, true),
+            (_, PCAP_MAGIC_MICROS) => (true, false),
+            (_, PCAP_MAGIC_NANOS) => (true, true),
+            _ => return None,
+        };
+        let reader = Reader { data, big_endian };
+
+        let mut meta = CaptureMetadata {
+            capture_type: "pcap",
+            version: format!("{}.{}", reader.u16(4)?, reader.u16(6)?),
+            big_endian,
+            nanosecond,
+            snap_length: Some(reader.u32(16)?),
+            link_type: Some(reader.u32(20)?),
+            ..Default::default()
+        };
+
+        // Packet records: 16-byte header whose third field is the captured length
+        let mut offset = 24;
+        while offset < data.len() {
+            let Some(captured) = reader.u32(offset + 8) else {
+                meta.truncated = true;
+                break;
+            };
+            let end = offset + 16 + captured as usize;
+            if end > data.len() {
+                meta.truncated = true;
+                break;
+            }
+            meta.packet_count += 1;
+            offset = end;
+        }
+
+        Some(meta)
+    }
+
+    /// Walk pcapng blocks, taking the link type from the first interface.
+    fn parse_pcapng(data: &[u8]) -> Option<CaptureMetadata> {
+        if data.get(..4)? != PCAPNG_SHB.to_be_bytes() {
+            return None;
+        }
+        let big_endian = match data.get(8..12)? {
+            b if b == PCAPNG_BYTE_ORDER_MAGIC.to_be_bytes() => true,
+            b if b == PCAPNG_BYTE_ORDER_MAGIC.to_le_bytes() => false,
+            _ => return None,
+        };
+        let reader = Reader { data, big_endian };
+
+        let mut meta = CaptureMetadata {
+            capture_type: "pcapng",
+            version: format!("{}.{}", reader.u16(12)?, reader.u16(14)?),
+            big_endian,
+            ..Default::default()
+        };
+
+        let mut offset = 0;
+        while offset < data.len() {
+            let (Some(block_type), Some(length)) = (reader.u32(offset), reader.u32(offset + 4))
+            else {
+                meta.truncated = true;
+                break;
+            };
+            let length = length as usize;
+            if length < 12 || !length.is_multiple_of(4) || offset + length > data.len() {
+                meta.truncated = true;
+                break;
+            }
+            match block_type {
+                // Interface Description Block
+                1 if meta.link_type.is_none() => {
+                    meta.link_type = reader.u16(offset + 8).map(u32::from);
+                    meta.snap_length = reader.u32(offset + 12);
+                }
+                // Packet (obsolete), Simple Packet and Enhanced Packet Blocks
+                2 | 3 | 6 => meta.packet_count += 1,
+                _ => {}
+            }
+            offset += length;
+        }
+
+        Some(meta)
+    }
+
+    fn parse_capture(data: &[u8]) -> Option<CaptureMetadata> {
+        Self::parse_pcap(data).or_else(|| Self::parse_pcapng(data))
+    }
+
+    fn link_type_name(link_type: u32) -> String {
+        LINK_TYPES
+            .iter()
+            .find(|(id, _)| *id == link_type)
+            .map_or_else(
+                || format!("link type {}", link_type),
+                |(_, name)| (*name).to_string(),
+            )
+    }
+
+    fn byte_order(meta: &CaptureMetadata) -> &'static str {
+        if meta.big_endian {
+            "big-endian"
+        } else {
+            "little-endian"
+        }
+    }
+
+    fn packet_summary(meta: &CaptureMetadata) -> String {
+        let noun = if meta.packet_count == 1 {
+            "packet"
+        } else {
+            "packets"
+        };
+        if meta.truncated {
+            format!("{} {} (truncated)", meta.packet_count, noun)
+        } else {
+            format!("{} {}", meta.packet_count, noun)
+        }
+    }
+
+    /// Format metadata into human-readable description.
+    fn format_description(meta: &CaptureMetadata) -> String {
+        let mut parts = vec![format!(
+            "{} v{} ({})",
+            meta.capture_type,
+            meta.version,
+            Self::byte_order(meta)
+        )];
+        if let Some(link_type) = meta.link_type {
+            parts.push(Self::link_type_name(link_type));
+        }
+        parts.push(Self::packet_summary(meta));
+        parts.join(", ")
+    }
+
+    /// Build RichDisplay options for UI rendering.
+    fn build_rich_display(meta: &CaptureMetadata) -> Vec<RichDisplayOption> {
+        let mut pairs = vec![
+            ("Format".to_string(), meta.capture_type.to_string()),
+            ("Version".to_string(), meta.version.clone()),
+            ("Byte Order".to_string(), Self::byte_order(meta).to_string()),
+        ];
+
+        if meta.capture_type == "pcap" {
+            let precision = if meta.nanosecond {
+                "nanoseconds"
+            } else {
+                "microseconds"
+            };
+            pairs.push(("Timestamps".to_string(), precision.to_string()));
+        }
+
+        if let Some(link_type) = meta.link_type {
+            pairs.push((
+                "Link Type".to_string(),
+                format!("{} ({})", Self::link_type_name(link_type), link_type),
+            ));
+        }
+
+        if let Some(snap_length) = meta.snap_length {
+            pairs.push(("Snap Length".to_string(), snap_length.to_string()));
+        }
+
+        pairs.push(("Packets".to_string(), Self::packet_summary(meta)));
+
+        vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })]
+    }
+}
+
+impl Format for PcapFormat {
+    fn id(&self) -> &'static str {
+        "pcap"
+    }
+
+    fn name(&self) -> &'static str {
+        "Packet Capture"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Network",
+            description: "pcap/pcapng capture with link type and packet count",
+            examples: &["[binary capture data]"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        // Try to decode as base64
+        match base64::Engine::decode(&base64::engine::general_purpose::STANDARD, input) {
+            Ok(bytes) => self.parse_bytes(&bytes),
+            Err(_) => vec![],
+        }
+    }
+
+    fn parse_bytes(&self, bytes: &[u8]) -> Vec<Interpretation> {
+        let Some(meta) = Self::parse_capture(bytes) else {
+            return vec![];
+        };
+
+        vec![Interpretation {
+            value: CoreValue::Bytes(bytes.to_vec()),
+            source_format: "pcap".to_string(),
+            confidence: 0.95,
+            description: Self::format_description(&meta),
+            rich_display: Self::build_rich_display(&meta),
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Bytes(bytes) = value else {
+            return vec![];
+        };
+
+        let Some(meta) = Self::parse_capture(bytes) else {
+            return vec![];
+        };
+
+        let description = Self::format_description(&meta);
+        let rich_display = Self::build_rich_display(&meta);
+
+        vec![Conversion {
+            value: CoreValue::String(description.clone()),
+            target_format: "pcap-info".to_string(),
+            display: description,
+            path: vec!["pcap-info".to_string()],
+            steps: vec![],
+            is_lossy: false,
+            confidence: 1.0,
+            priority: ConversionPriority::Structured,
+            display_only: true,
+            kind: ConversionKind::Representation,
+            hidden: false,
+            depth_limited: false,
+            rich_display,
+        }]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["pcapng", "capture"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal little-endian pcap global header: v2.4, snaplen 65535, Ethernet.
+    const PCAP_HEADER_LE: [u8; 24] = [
+        0xD4, 0xC3, 0xB2, 0xA1, // magic
+        0x02, 0x00, 0x04, 0x00, // version 2.4
+        0x00, 0x00, 0x00, 0x00, // thiszone
+        0x00, 0x00, 0x00, 0x00, // sigfigs
+        0xFF, 0xFF, 0x00, 0x00, // snaplen
+        0x01, 0x00, 0x00, 0x00, // link type 1 (Ethernet)
+    ];
+
+    #[test]
+    fn test_pcap_global_header() {
+        let meta = PcapFormat::parse_capture(&PCAP_HEADER_LE).unwrap();
+        assert_eq!(meta.capture_type, "pcap");
+        assert!(!meta.big_endian);
+        assert!(!meta.nanosecond);
+        assert_eq!(meta.link_type, Some(1));
+        assert_eq!(meta.snap_length, Some(65535));
+        assert_eq!(meta.packet_count, 0);
+        assert_eq!(
+            PcapFormat::format_description(&meta),
+            "pcap v2.4 (little-endian), Ethernet, 0 packets"
+        );
+    }
+
+    #[test]
+    fn test_pcap_big_endian_packets() {
+        // Nanosecond big-endian header, raw IP, then two 4-byte packets
+        let mut data = vec![
+            0xA1, 0xB2, 0x3C, 0x4D, 0x00, 0x02, 0x00, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0x00, 0x00,
+            0x04, 0x00, 0x00, 0x00, 0x00, 0x65,
+        ];
+        for _ in 0..2 {
+            data.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 4]);
+            data.extend_from_slice(&[0x45, 0x00, 0x00, 0x14]);
+        }
+
+        let meta = PcapFormat::parse_capture(&data).unwrap();
+        assert!(meta.big_endian);
+        assert!(meta.nanosecond);
+        assert_eq!(meta.snap_length, Some(1024));
+        assert_eq!(
+            PcapFormat::format_description(&meta),
+            "pcap v2.4 (big-endian), Raw IP, 2 packets"
+        );
+
+        // A cut-off record is reported, not counted
+        data.truncate(data.len() - 2);
+        let meta = PcapFormat::parse_capture(&data).unwrap();
+        assert_eq!(PcapFormat::packet_summary(&meta), "1 packet (truncated)");
+    }
+
+    #[test]
+    fn test_pcapng_blocks() {
+        let mut data = Vec::new();
+        // Section Header Block (28 bytes, no options)
+        data.extend_from_slice(&PCAPNG_SHB.to_le_bytes());
+        data.extend_from_slice(&28u32.to_le_bytes());
+        data.extend_from_slice(&PCAPNG_BYTE_ORDER_MAGIC.to_le_bytes());
+        data.extend_from_slice(&[1, 0, 0, 0]); // version 1.0
+        data.extend_from_slice(&(-1i64).to_le_bytes()); // section length unknown
+        data.extend_from_slice(&28u32.to_le_bytes());
+        // Interface Description Block: IEEE 802.11, snaplen 262144
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&20u32.to_le_bytes());
+        data.extend_from_slice(&105u16.to_le_bytes());
+        data.extend_from_slice(&[0, 0]);
+        data.extend_from_slice(&262_144u32.to_le_bytes());
+        data.extend_from_slice(&20u32.to_le_bytes());
+        // Simple Packet Block with 4 bytes of packet data
+        data.extend_from_slice(&3u32.to_le_bytes());
+        data.extend_from_slice(&20u32.to_le_bytes());
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(&[0xDE, 0xAD, 0xBE, 0xEF]);
+        data.extend_from_slice(&20u32.to_le_bytes());
+
+        let meta = PcapFormat::parse_capture(&data).unwrap();
+        assert_eq!(meta.capture_type, "pcapng");
+        assert!(!meta.big_endian);
+        assert_eq!(meta.link_type, Some(105));
+        assert_eq!(meta.snap_length, Some(262_144));
+        assert_eq!(
+            PcapFormat::format_description(&meta),
+            "pcapng v1.0 (little-endian), IEEE 802.11, 1 packet"
+        );
+    }
+
+    #[test]
+    fn test_not_a_capture() {
+        assert!(PcapFormat::parse_capture(b"not a capture file at all").is_none());
+        assert!(PcapFormat::parse_capture(&PCAP_HEADER_LE[..12]).is_none());
+    }
+}
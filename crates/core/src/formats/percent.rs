@@ -0,0 +1,242 @@
+//! Percentage phrase format.
+//!
+//! Evaluates natural percentage phrasings that aren't valid expression syntax:
+//! - `15% of 240` → 36
+//! - `30 as a % of 120` → 25%
+//! - `240 + 15%` → 276 (and `240 - 15%` → 204)
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
+
+pub struct PercentFormat;
+
+/// A plain decimal number, optionally negative.
+const NUMBER: &str = r"-?\d+(?:\.\d+)?";
+
+/// `15% of 240`
+fn of_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(&format!(
+            r"(?i)^(?P<pct>{NUMBER})\s*%\s+of\s+(?P<base>{NUMBER})$"
+        ))
+        .unwrap()
+    })
+}
+
+/// `30 as a % of 120`, `30 as a percentage of 120`, `30 is what % of 120`
+fn ratio_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(&format!(
+            r"(?i)^(?P<part>{NUMBER})\s+(?:as\s+(?:a\s+)?(?:%|percent|percentage)|is\s+what\s+(?:%|percent))\s+of\s+(?P<whole>{NUMBER})$"
+        ))
+        .unwrap()
+    })
+}
+
+/// `240 + 15%`, `240 - 15%`
+fn change_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(&format!(
+            r"^(?P<base>{NUMBER})\s*(?P<op>[+-])\s*(?P<pct>\d+(?:\.\d+)?)\s*%$"
+        ))
+        .unwrap()
+    })
+}
+
+impl PercentFormat {
+    /// Evaluate a percentage phrase, returning the result and a restated sentence.
+    fn evaluate(input: &str) -> Option<(f64, String)> {
+        let number = |caps: &regex::Captures, name: &str| caps[name].parse::<f64>().ok();
+
+        if let Some(caps) = of_regex().captures(input) {
+            let (pct, base) = (number(&caps, "pct")?, number(&caps, "base")?);
+            let result = pct * base / 100.0;
+            let sentence = format!(
+                "{}% of {} is {}",
+                format_number(pct),
+                format_number(base),
+                format_number(result)
+            );
+            return Some((result, sentence));
+        }
+
+        if let Some(caps) = ratio_regex().captures(input) {
+            let (part, whole) = (number(&caps, "part")?, number(&caps, "whole")?);
+            if whole == 0.0 {
+                return None;
+            }
+            let result = part * 100.0 / whole;
+            let sentence = format!(
+                "{} is {}% of {}",
+                format_number(part),
+                format_number(result),
+                format_number(whole)
+            );
+            return Some((result, sentence));
+        }
+
+        if let Some(caps) = change_regex().captures(input) {
+            let (base, pct) = (number(&caps, "base")?, number(&caps, "pct")?);
+            let (result, verb) = if &caps["op"] == "+" {
+                (base + base * pct / 100.0, "increased")
+            } else {
+                (base - base * pct / 100.0, "decreased")
+            };
+            let sentence = format!(
+                "{} {} by {}% is {}",
+                format_number(base),
+                verb,
+                format_number(pct),
+                format_number(result)
+            );
+            return Some((result, sentence));
+        }
+
+        None
+    }
+}
+
+/// Format a result without float noise (36 rather than 36.00000000000001).
+fn format_number(n: f64) -> String {
+    let rounded = (n * 1e6).round() / 1e6;
+    if rounded == 0.0 {
+        // Avoid "-0"
+        return "0".to_string();
+    }
+    rounded.to_string()
+}
+
+impl Format for PercentFormat {
+    fn id(&self) -> &'static str {
+        "percent"
+    }
+
+    fn name(&self) -> &'static str {
+        "Percentage"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Math",
+            description: "Percentage phrases: X% of Y, X as a % of Y, Y ± X%",
+            examples: &["15% of 240", "30 as a % of 120", "240 + 15%"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some((result, sentence)) = Self::evaluate(input.trim()) else {
+            return vec![];
+        };
+        if !result.is_finite() {
+            return vec![];
+        }
+
+        vec![Interpretation {
+            value: CoreValue::Float(result),
+            source_format: "percent".to_string(),
+            confidence: 0.8,
+            description: sentence,
+            rich_display: vec![],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        // Like expr, the answer is only emitted when the input was a percentage phrase
+        let CoreValue::Float(result) = value else {
+            return vec![];
+        };
+
+        vec![Conversion {
+            value: CoreValue::Float(*result),
+            target_format: "result".to_string(),
+            display: format_number(*result),
+            priority: ConversionPriority::Primary,
+            kind: ConversionKind::Conversion,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["pct", "percentage"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(input: &str) -> (f64, String) {
+        let results = PercentFormat.parse(input);
+        assert_eq!(results.len(), 1, "expected {:?} to parse", input);
+        let CoreValue::Float(result) = results[0].value else {
+            panic!("Expected Float");
+        };
+        (result, results[0].description.clone())
+    }
+
+    #[test]
+    fn test_percent_of() {
+        assert_eq!(
+            evaluate("15% of 240"),
+            (36.0, "15% of 240 is 36".to_string())
+        );
+        assert_eq!(evaluate("12.5 % OF 80").0, 10.0);
+    }
+
+    #[test]
+    fn test_as_a_percent_of() {
+        assert_eq!(
+            evaluate("30 as a % of 120"),
+            (25.0, "30 is 25% of 120".to_string())
+        );
+        assert_eq!(evaluate("1 is what % of 3").1, "1 is 33.333333% of 3");
+        assert_eq!(evaluate("50 as a percentage of 200").0, 25.0);
+        assert!(PercentFormat.parse("5 as a % of 0").is_empty());
+    }
+
+    #[test]
+    fn test_increase_and_decrease() {
+        assert_eq!(
+            evaluate("240 + 15%"),
+            (276.0, "240 increased by 15% is 276".to_string())
+        );
+        assert_eq!(
+            evaluate("240 - 15%"),
+            (204.0, "240 decreased by 15% is 204".to_string())
+        );
+    }
+
+    #[test]
+    fn test_not_percent() {
+        assert!(PercentFormat.parse("15%").is_empty());
+        assert!(PercentFormat.parse("240 + 15").is_empty());
+        assert!(PercentFormat.parse("15% off everything").is_empty());
+    }
+
+    #[test]
+    fn test_result_conversion() {
+        let conversions = PercentFormat.source_conversions(&CoreValue::Float(36.0));
+        assert_eq!(conversions[0].target_format, "result");
+        assert_eq!(conversions[0].display, "36");
+    }
+}
@@ -3,9 +3,10 @@
 //! - Octal → Symbolic: `755` → `rwxr-xr-x`
 //! - Symbolic → Octal: `rwxr-xr-x` → `0755`
 //!
-//! Supports special bits (setuid, setgid, sticky).
+//! Supports special bits (setuid, setgid, sticky). Full `st_mode` integers
+//! (e.g. `33188` from `stat`) are recognized by their file-type bits.
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
     RichDisplay, RichDisplayOption,
@@ -180,6 +181,60 @@ fn to_symbolic(value: u16) -> String {
     result
 }
 
+/// Mask for the file-type bits of `st_mode` (S_IFMT).
+const S_IFMT: u32 = 0o170000;
+
+/// File types encoded in the S_IFMT bits of `st_mode`.
+const FILE_TYPES: &[(u32, &str)] = &[
+    (0o140000, "socket"),
+    (0o120000, "symbolic link"),
+    (0o100000, "regular file"),
+    (0o060000, "block device"),
+    (0o040000, "directory"),
+    (0o020000, "character device"),
+    (0o010000, "FIFO"),
+];
+
+/// Split a full `st_mode` value into its file type and permission bits.
+///
+/// Returns `None` unless the type bits name a known file type, so plain
+/// permission values (and most other numbers) are left alone. Special bits
+/// are only accepted where they mean something (setuid on files, sticky on
+/// directories).
+fn parse_full_mode(n: u32) -> Option<(&'static str, u16)> {
+    if n & !(S_IFMT | 0o7777) != 0 {
+        return None;
+    }
+    let file_type = FILE_TYPES
+        .iter()
+        .find(|(bits, _)| n & S_IFMT == *bits)
+        .map(|(_, name)| *name)?;
+    let perms = (n & 0o7777) as u16;
+
+    let special_ok = match file_type {
+        "regular file" => perms & 0o1000 == 0,
+        "directory" => perms & 0o4000 == 0,
+        _ => perms & 0o7000 == 0,
+    };
+    special_ok.then_some((file_type, perms))
+}
+
+/// Whether a permission value looks like something found on a real file.
+///
+/// Real file permissions almost always have:
+/// - At least read for owner (0o400)
+/// - Or are one of the common special cases (0o000, 0o111, etc.)
+///
+/// This avoids showing permission conversions for arbitrary numbers like 1024.
+fn is_common_permission(value: u16) -> bool {
+    let owner_perms = (value >> 6) & 0o7;
+    owner_perms >= 4  // Owner has at least read
+        || value == 0  // No permissions (rare but valid)
+        || value == 0o111  // Execute only (for directories)
+        || value == 0o222  // Write only (rare)
+        || value == 0o333 // Write+execute (rare)
+}
+
 /// Convert permission value to octal string with leading zero.
 fn to_octal(value: u16) -> String {
     if value > 0o777 {
@@ -294,6 +349,38 @@ fn build_rich_display(value: u16) -> RichDisplayOption {
     RichDisplayOption::new(RichDisplay::KeyValue { pairs })
 }
 
+/// Build the conversion describing a full `st_mode` value.
+fn full_mode_conversion(file_type: &str, perms: u16) -> Conversion {
+    let display = format!(
+        "{}, {} ({})",
+        file_type,
+        to_symbolic(perms),
+        to_octal(perms)
+    );
+
+    let mut rich_display = build_rich_display(perms);
+    if let RichDisplay::KeyValue { pairs } = &mut rich_display.preferred {
+        pairs.insert(0, ("type".to_string(), file_type.to_string()));
+    }
+
+    Conversion {
+        value: CoreValue::String(display.clone()),
+        target_format: "file-mode".to_string(),
+        display: display.clone(),
+        path: vec!["file-mode".to_string()],
+        steps: vec![ConversionStep {
+            format: "file-mode".to_string(),
+            value: CoreValue::String(display.clone()),
+            display,
+        }],
+        priority: ConversionPriority::Semantic,
+        kind: ConversionKind::Representation,
+        display_only: true,
+        rich_display: vec![rich_display],
+        ..Default::default()
+    }
+}
+
 // =============================================================================
 // Format Implementation
 // =============================================================================
@@ -370,26 +457,23 @@ impl Format for PermissionsFormat {
             return vec![];
         };
 
+        // Full st_mode values carry file-type bits above the permission range
+        if *int_val > 0o7777 {
+            return u32::try_from(*int_val)
+                .ok()
+                .and_then(parse_full_mode)
+                .filter(|(_, perms)| is_common_permission(*perms))
+                .map(|(file_type, perms)| vec![full_mode_conversion(file_type, perms)])
+                .unwrap_or_default();
+        }
+
         // Only valid for permission range (0-4095)
-        if *int_val < 0 || *int_val > 4095 {
+        if *int_val < 0 {
             return vec![];
         }
 
         let perm_value = *int_val as u16;
-
-        // Only show conversions for "realistic" permission values.
-        // Real file permissions almost always have:
-        // - At least read for owner (0o400)
-        // - Or are one of the common special cases (0o000, 0o111, etc.)
-        // This avoids showing permission conversions for arbitrary numbers like 1024.
-        let owner_perms = (perm_value >> 6) & 0o7;
-        let is_common_permission = owner_perms >= 4  // Owner has at least read
-            || perm_value == 0  // No permissions (rare but valid)
-            || perm_value == 0o111  // Execute only (for directories)
-            || perm_value == 0o222  // Write only (rare)
-            || perm_value == 0o333; // Write+execute (rare)
-
-        if !is_common_permission {
+        if !is_common_permission(perm_value) {
             return vec![];
         }
         let symbolic = to_symbolic(perm_value);
@@ -435,6 +519,10 @@ impl Format for PermissionsFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["perm", "chmod", "mode"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
@@ -562,6 +650,62 @@ mod tests {
         assert_eq!(octal.display, "0755");
     }
 
+    fn full_mode_display(mode: i128) -> Option<String> {
+        PermissionsFormat
+            .conversions(&CoreValue::Int {
+                value: mode,
+                original_bytes: None,
+            })
+            .into_iter()
+            .find(|c| c.target_format == "file-mode")
+            .map(|c| c.display)
+    }
+
+    #[test]
+    fn test_full_mode_regular_file() {
+        assert_eq!(parse_full_mode(33188), Some(("regular file", 0o644)));
+        assert_eq!(
+            full_mode_display(33188).as_deref(),
+            Some("regular file, rw-r--r-- (0644)")
+        );
+    }
+
+    #[test]
+    fn test_full_mode_directory() {
+        assert_eq!(
+            full_mode_display(16877).as_deref(),
+            Some("directory, rwxr-xr-x (0755)")
+        );
+    }
+
+    #[test]
+    fn test_full_mode_symlink() {
+        assert_eq!(
+            full_mode_display(0o120777).as_deref(),
+            Some("symbolic link, rwxrwxrwx (0777)")
+        );
+    }
+
+    #[test]
+    fn test_full_mode_setuid_binary() {
+        assert_eq!(
+            full_mode_display(0o104755).as_deref(),
+            Some("regular file, rwsr-xr-x (4755)")
+        );
+    }
+
+    #[test]
+    fn test_full_mode_requires_file_type() {
+        // 0o030000 is not a file type; bits above S_IFMT are never set
+        assert_eq!(parse_full_mode(0o030644), None);
+        assert_eq!(parse_full_mode(0o1100644), None);
+        // Sticky bit on a character device is noise, not a real mode
+        assert_eq!(parse_full_mode(0o021450), None);
+        assert_eq!(full_mode_display(100_000), None);
+        // Permission-range values keep the plain conversions
+        assert_eq!(full_mode_display(0o644), None);
+    }
+
     #[test]
     fn test_no_match() {
         let format = PermissionsFormat;
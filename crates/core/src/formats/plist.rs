@@ -151,11 +151,13 @@ impl Format for PlistFormat {
             display: format!("(decoded) {}", display),
             path: vec!["plist".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority: ConversionPriority::Structured,
             display_only: false,
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
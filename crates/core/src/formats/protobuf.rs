@@ -731,11 +731,13 @@ impl Format for ProtobufFormat {
             display,
             path: vec!["protobuf".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority,
             display_only: false,
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![RichDisplayOption::new(RichDisplay::PacketLayout {
                 segments,
                 compact,
@@ -0,0 +1,505 @@
+//! QR code payload format.
+//!
+//! Recognizes the structured text conventions QR codes carry and breaks
+//! them into fields:
+//! - `WIFI:S:MyNet;T:WPA;P:secret;;` → SSID, security, password
+//! - `mailto:`, `tel:`, `sms:` / `SMSTO:` → recipient and message
+//! - `geo:59.3293,18.0686` → coordinates (with all coordinate conversions)
+//! - `MECARD:` and `BEGIN:VCARD` contacts → name, phone, email, ...
+
+use percent_encoding::percent_decode_str;
+use serde_json::{Map, Value as JsonValue};
+
+use crate::format::{specificity, Format, FormatInfo};
+use crate::types::{CoreValue, Interpretation, RichDisplay, RichDisplayOption};
+
+pub struct QrPayloadFormat;
+
+/// A decoded payload: description, labelled fields and the value to convert.
+struct Payload {
+    description: String,
+    fields: Vec<(&'static str, String)>,
+    value: Option<CoreValue>,
+}
+
+impl Payload {
+    fn new(description: String, fields: Vec<(&'static str, String)>) -> Self {
+        Self {
+            description,
+            fields,
+            value: None,
+        }
+    }
+}
+
+/// Strip a case-insensitive scheme prefix ("WIFI:", "mailto:").
+fn strip_scheme<'a>(input: &'a str, scheme: &str) -> Option<&'a str> {
+    let prefix = input.get(..scheme.len())?;
+    prefix
+        .eq_ignore_ascii_case(scheme)
+        .then(|| &input[scheme.len()..])
+}
+
+/// Split `;`-separated fields, honoring backslash escapes (`\;`, `\:`, `\\`).
+fn split_escaped(s: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut current = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            }
+            ';' => fields.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        fields.push(current);
+    }
+    fields
+}
+
+/// Split `KEY:value` fields into pairs, keys uppercased.
+fn key_values(s: &str) -> Vec<(String, String)> {
+    split_escaped(s)
+        .into_iter()
+        .filter_map(|field| {
+            let (key, value) = field.split_once(':')?;
+            Some((key.trim().to_ascii_uppercase(), value.to_string()))
+        })
+        .collect()
+}
+
+/// Parse `key=value&...` query parameters, percent-decoding values.
+fn query_params(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            let value = percent_decode_str(&value.replace('+', " "))
+                .decode_utf8()
+                .ok()?
+                .into_owned();
+            Some((key.to_ascii_lowercase(), value))
+        })
+        .collect()
+}
+
+/// Whether a string looks like a phone number.
+fn is_phone_number(s: &str) -> bool {
+    let digits = s.chars().filter(|c| c.is_ascii_digit()).count();
+    (3..=20).contains(&digits)
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '+' | '-' | ' ' | '(' | ')' | '.'))
+}
+
+impl QrPayloadFormat {
+    /// `WIFI:T:WPA;S:MyNet;P:secret;H:true;;`
+    fn parse_wifi(input: &str) -> Option<Payload> {
+        let fields = key_values(strip_scheme(input, "WIFI:")?);
+        let get = |key: &str| {
+            fields
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.clone())
+        };
+
+        let ssid = get("S").filter(|s| !s.is_empty())?;
+        let security = match get("T").filter(|t| !t.is_empty()) {
+            Some(t) if t.eq_ignore_ascii_case("nopass") => "none".to_string(),
+            Some(t) => t,
+            None => "none".to_string(),
+        };
+        let hidden = get("H").is_some_and(|h| h.eq_ignore_ascii_case("true"));
+
+        let mut result = vec![("SSID", ssid.clone()), ("Security", security.clone())];
+        if let Some(password) = get("P").filter(|p| !p.is_empty()) {
+            result.push(("Password", password));
+        }
+        if hidden {
+            result.push(("Hidden", "yes".to_string()));
+        }
+
+        Some(Payload::new(
+            format!("Wi-Fi network {} ({})", ssid, security),
+            result,
+        ))
+    }
+
+    /// `mailto:a@example.com?subject=Hi&body=Hello`
+    fn parse_mailto(input: &str) -> Option<Payload> {
+        let rest = strip_scheme(input, "mailto:")?;
+        let (to, query) = rest.split_once('?').unwrap_or((rest, ""));
+        let to = percent_decode_str(to).decode_utf8().ok()?.into_owned();
+        if !to.contains('@') || to.contains(char::is_whitespace) {
+            return None;
+        }
+
+        let mut fields = vec![("To", to.clone())];
+        for (key, value) in query_params(query) {
+            let label = match key.as_str() {
+                "subject" => "Subject",
+                "body" => "Body",
+                "cc" => "Cc",
+                "bcc" => "Bcc",
+                _ => continue,
+            };
+            fields.push((label, value));
+        }
+
+        Some(Payload::new(format!("Email to {}", to), fields))
+    }
+
+    /// `tel:+46701234567`
+    fn parse_tel(input: &str) -> Option<Payload> {
+        let number = strip_scheme(input, "tel:")?.trim();
+        if !is_phone_number(number) {
+            return None;
+        }
+        Some(Payload::new(
+            format!("Phone number {}", number),
+            vec![("Number", number.to_string())],
+        ))
+    }
+
+    /// `SMSTO:+46701234567:Hello` or `sms:+46701234567?body=Hello`
+    fn parse_sms(input: &str) -> Option<Payload> {
+        let (number, message) = if let Some(rest) = strip_scheme(input, "SMSTO:") {
+            let (number, message) = rest.split_once(':').unwrap_or((rest, ""));
+            (number.to_string(), message.to_string())
+        } else {
+            let rest = strip_scheme(input, "sms:")?;
+            let (number, query) = rest.split_once('?').unwrap_or((rest, ""));
+            let message = query_params(query)
+                .into_iter()
+                .find(|(key, _)| key == "body")
+                .map(|(_, body)| body)
+                .unwrap_or_default();
+            (number.to_string(), message)
+        };
+        if !is_phone_number(&number) {
+            return None;
+        }
+
+        let mut fields = vec![("Number", number.clone())];
+        if !message.is_empty() {
+            fields.push(("Message", message));
+        }
+        Some(Payload::new(format!("Text message to {}", number), fields))
+    }
+
+    /// `geo:59.3293,18.0686` (an altitude and `;u=` / `?q=` parameters are ignored)
+    fn parse_geo(input: &str) -> Option<Payload> {
+        let rest = strip_scheme(input, "geo:")?;
+        let coords = rest.split(['?', ';']).next()?;
+        let mut parts = coords.split(',').map(|p| p.trim().parse::<f64>());
+        let lat = parts.next()?.ok()?;
+        let lon = parts.next()?.ok()?;
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return None;
+        }
+
+        let mut payload = Payload::new(
+            format!("Location {:.6}, {:.6}", lat, lon),
+            vec![
+                ("Latitude", lat.to_string()),
+                ("Longitude", lon.to_string()),
+            ],
+        );
+        payload.value = Some(CoreValue::Coordinates { lat, lon });
+        Some(payload)
+    }
+
+    /// `MECARD:N:Doe,John;TEL:+46701234567;EMAIL:john@example.com;;`
+    fn parse_mecard(input: &str) -> Option<Payload> {
+        let fields = key_values(strip_scheme(input, "MECARD:")?);
+        let contact = fields
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let label = match key.as_str() {
+                    // Names are "Last,First"
+                    "N" => {
+                        let name = match value.split_once(',') {
+                            Some((last, first)) => format!("{} {}", first, last),
+                            None => value,
+                        };
+                        return Some(("Name", name));
+                    }
+                    "TEL" => "Phone",
+                    "EMAIL" => "Email",
+                    "ORG" => "Organization",
+                    "URL" => "URL",
+                    "ADR" => "Address",
+                    "NOTE" => "Note",
+                    "BDAY" => "Birthday",
+                    _ => return None,
+                };
+                Some((label, value))
+            })
+            .collect();
+        Self::contact_payload(contact)
+    }
+
+    /// `BEGIN:VCARD ... END:VCARD`
+    fn parse_vcard(input: &str) -> Option<Payload> {
+        if !input
+            .get(..11)
+            .is_some_and(|p| p.eq_ignore_ascii_case("BEGIN:VCARD"))
+        {
+            return None;
+        }
+
+        // Unfold continuation lines (RFC 6350 §3.2)
+        let unfolded = input
+            .replace("\r\n", "\n")
+            .replace("\n ", "")
+            .replace("\n\t", "");
+
+        let mut contact = Vec::new();
+        for line in unfolded.lines() {
+            let Some((property, value)) = line.split_once(':') else {
+                continue;
+            };
+            // Drop parameters: "TEL;TYPE=CELL" → "TEL"
+            let name = property
+                .split(';')
+                .next()
+                .unwrap_or("")
+                .to_ascii_uppercase();
+            let value = value.replace("\\,", ",").replace("\\;", ";");
+            let label = match name.as_str() {
+                "FN" => "Name",
+                "TEL" => "Phone",
+                "EMAIL" => "Email",
+                "ORG" => "Organization",
+                "TITLE" => "Title",
+                "URL" => "URL",
+                "NOTE" => "Note",
+                "BDAY" => "Birthday",
+                // Structured address: PO box;extended;street;city;region;code;country
+                "ADR" => {
+                    let parts: Vec<_> = value
+                        .split(';')
+                        .map(str::trim)
+                        .filter(|p| !p.is_empty())
+                        .collect();
+                    contact.push(("Address", parts.join(", ")));
+                    continue;
+                }
+                _ => continue,
+            };
+            contact.push((label, value.trim().to_string()));
+        }
+        Self::contact_payload(contact)
+    }
+
+    /// Describe a contact by its name, or its first field.
+    fn contact_payload(fields: Vec<(&'static str, String)>) -> Option<Payload> {
+        let (_, first) = fields.first()?;
+        let name = fields
+            .iter()
+            .find(|(label, _)| *label == "Name")
+            .map_or(first, |(_, name)| name);
+        Some(Payload::new(format!("Contact {}", name), fields))
+    }
+
+    fn decode(input: &str) -> Option<Payload> {
+        Self::parse_wifi(input)
+            .or_else(|| Self::parse_mailto(input))
+            .or_else(|| Self::parse_tel(input))
+            .or_else(|| Self::parse_sms(input))
+            .or_else(|| Self::parse_geo(input))
+            .or_else(|| Self::parse_mecard(input))
+            .or_else(|| Self::parse_vcard(input))
+    }
+}
+
+impl Format for QrPayloadFormat {
+    fn id(&self) -> &'static str {
+        "qr-payload"
+    }
+
+    fn name(&self) -> &'static str {
+        "QR Code Payload"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Web",
+            description: "QR code text payloads: Wi-Fi, mailto, tel, sms, geo, MECARD and vCard",
+            examples: &[
+                "WIFI:S:MyNet;T:WPA;P:secret;;",
+                "geo:59.3293,18.0686",
+                "mailto:hello@example.com?subject=Hi",
+            ],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some(payload) = Self::decode(input.trim()) else {
+            return vec![];
+        };
+
+        // Structured payloads convert as JSON; geo hands over to the coordinate formats
+        let value = payload.value.unwrap_or_else(|| {
+            let mut map = Map::new();
+            for (label, value) in &payload.fields {
+                map.insert(label.to_lowercase(), JsonValue::String(value.clone()));
+            }
+            CoreValue::Json(JsonValue::Object(map))
+        });
+
+        let pairs = payload
+            .fields
+            .into_iter()
+            .map(|(label, value)| (label.to_string(), value))
+            .collect();
+
+        vec![Interpretation {
+            value,
+            source_format: "qr-payload".to_string(),
+            confidence: 0.9,
+            description: payload.description,
+            rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue { pairs })],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["qr", "wifi", "vcard", "mecard"]
+    }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(input: &str) -> Vec<(String, String)> {
+        let results = QrPayloadFormat.parse(input);
+        assert_eq!(results.len(), 1, "expected {:?} to parse", input);
+        match &results[0].rich_display[0].preferred {
+            RichDisplay::KeyValue { pairs } => pairs.clone(),
+            other => panic!("Expected KeyValue, got {:?}", other),
+        }
+    }
+
+    fn pair(label: &str, value: &str) -> (String, String) {
+        (label.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_wifi() {
+        let results = QrPayloadFormat.parse("WIFI:S:MyNet;T:WPA;P:secret;;");
+        assert_eq!(results[0].confidence, 0.9);
+        assert_eq!(results[0].description, "Wi-Fi network MyNet (WPA)");
+        assert_eq!(
+            fields("WIFI:S:MyNet;T:WPA;P:secret;;"),
+            [
+                pair("SSID", "MyNet"),
+                pair("Security", "WPA"),
+                pair("Password", "secret")
+            ]
+        );
+        let CoreValue::Json(json) = &results[0].value else {
+            panic!("Expected Json");
+        };
+        assert_eq!(json["ssid"], "MyNet");
+
+        // Escaped separators, hidden networks and open networks
+        assert_eq!(
+            fields(r"WIFI:T:nopass;S:Caf\;e\:1;H:true;;"),
+            [
+                pair("SSID", "Caf;e:1"),
+                pair("Security", "none"),
+                pair("Hidden", "yes")
+            ]
+        );
+        assert!(QrPayloadFormat.parse("WIFI:T:WPA;P:secret;;").is_empty());
+    }
+
+    #[test]
+    fn test_geo_produces_coordinates() {
+        let results = QrPayloadFormat.parse("geo:59.3293,18.0686");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(
+            results[0].value,
+            CoreValue::Coordinates { lat, lon } if lat == 59.3293 && lon == 18.0686
+        ));
+
+        // Altitude and parameters are ignored
+        assert!(matches!(
+            QrPayloadFormat.parse("geo:37.786971,-122.399677,12;u=35")[0].value,
+            CoreValue::Coordinates { lon, .. } if lon == -122.399677
+        ));
+        assert!(QrPayloadFormat.parse("geo:95.0,18.0").is_empty());
+    }
+
+    #[test]
+    fn test_mailto_tel_and_sms() {
+        assert_eq!(
+            fields("mailto:hello@example.com?subject=Hi%20there&body=See+you"),
+            [
+                pair("To", "hello@example.com"),
+                pair("Subject", "Hi there"),
+                pair("Body", "See you")
+            ]
+        );
+        assert_eq!(
+            fields("tel:+46 70 123 45 67"),
+            [pair("Number", "+46 70 123 45 67")]
+        );
+        assert_eq!(
+            fields("SMSTO:+46701234567:Running late"),
+            [
+                pair("Number", "+46701234567"),
+                pair("Message", "Running late")
+            ]
+        );
+        assert!(QrPayloadFormat.parse("tel:call me").is_empty());
+    }
+
+    #[test]
+    fn test_contacts() {
+        assert_eq!(
+            fields("MECARD:N:Doe,John;TEL:+46701234567;EMAIL:john@example.com;;"),
+            [
+                pair("Name", "John Doe"),
+                pair("Phone", "+46701234567"),
+                pair("Email", "john@example.com")
+            ]
+        );
+
+        let vcard = "BEGIN:VCARD\nVERSION:3.0\nFN:Jane Doe\nORG:Example AB\n\
+                     TEL;TYPE=CELL:+46 70 123 45 67\nADR:;;Main St 1;Stockholm;;111 22;Sweden\n\
+                     END:VCARD";
+        let results = QrPayloadFormat.parse(vcard);
+        assert_eq!(results[0].description, "Contact Jane Doe");
+        assert_eq!(
+            fields(vcard),
+            [
+                pair("Name", "Jane Doe"),
+                pair("Organization", "Example AB"),
+                pair("Phone", "+46 70 123 45 67"),
+                pair("Address", "Main St 1, Stockholm, 111 22, Sweden")
+            ]
+        );
+    }
+}
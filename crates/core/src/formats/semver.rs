@@ -0,0 +1,240 @@
+//! Semantic version format (SemVer 2.0.0).
+//!
+//! Parses versions with optional pre-release and build metadata:
+//! - `1.0.0`
+//! - `1.2.3-rc.1+build.5`
+//! - `v2.0.0` (common tag style)
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::format::{specificity, Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation, RichDisplay,
+    RichDisplayOption,
+};
+
+pub struct SemVerFormat;
+
+/// Official SemVer 2.0.0 pattern (semver.org), with an optional `v` prefix.
+fn semver_regex() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(
+            r"^(?P<v>v)?(?P<major>0|[1-9]\d*)\.(?P<minor>0|[1-9]\d*)\.(?P<patch>0|[1-9]\d*)(?:-(?P<pre>(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+(?P<build>[0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*))?$",
+        )
+        .unwrap()
+    })
+}
+
+/// A parsed semantic version.
+#[derive(Debug, PartialEq, Eq)]
+struct SemVer {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre_release: Option<String>,
+    build: Option<String>,
+}
+
+impl SemVer {
+    /// Canonical form without the `v` prefix.
+    fn normalized(&self) -> String {
+        let mut s = format!("{}.{}.{}", self.major, self.minor, self.patch);
+        if let Some(pre) = &self.pre_release {
+            s.push('-');
+            s.push_str(pre);
+        }
+        if let Some(build) = &self.build {
+            s.push('+');
+            s.push_str(build);
+        }
+        s
+    }
+}
+
+impl SemVerFormat {
+    /// Parse a version string. Returns the version and whether it had a `v` prefix.
+    fn parse_semver(input: &str) -> Option<(SemVer, bool)> {
+        let caps = semver_regex().captures(input.trim())?;
+        let version = SemVer {
+            major: caps["major"].parse().ok()?,
+            minor: caps["minor"].parse().ok()?,
+            patch: caps["patch"].parse().ok()?,
+            pre_release: caps.name("pre").map(|m| m.as_str().to_string()),
+            build: caps.name("build").map(|m| m.as_str().to_string()),
+        };
+        Some((version, caps.name("v").is_some()))
+    }
+
+    fn build_rich_display(version: &SemVer) -> RichDisplayOption {
+        let mut pairs = vec![
+            ("Major".to_string(), version.major.to_string()),
+            ("Minor".to_string(), version.minor.to_string()),
+            ("Patch".to_string(), version.patch.to_string()),
+        ];
+        if let Some(pre) = &version.pre_release {
+            pairs.push(("Pre-release".to_string(), pre.clone()));
+        }
+        if let Some(build) = &version.build {
+            pairs.push(("Build".to_string(), build.clone()));
+        }
+        RichDisplayOption::new(RichDisplay::KeyValue { pairs })
+    }
+}
+
+impl Format for SemVerFormat {
+    fn id(&self) -> &'static str {
+        "semver"
+    }
+
+    fn name(&self) -> &'static str {
+        "Semantic Version"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Identifiers",
+            description: "Semantic versions (SemVer 2.0) with pre-release and build metadata",
+            examples: &["1.2.3", "1.2.3-rc.1+build.5", "v2.0.0"],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let Some((version, has_v)) = Self::parse_semver(input) else {
+            return vec![];
+        };
+
+        let normalized = version.normalized();
+        vec![Interpretation {
+            value: CoreValue::String(normalized.clone()),
+            source_format: "semver".to_string(),
+            // Tag-style "v1.2.3" is a little less certain than the bare form
+            confidence: if has_v { 0.85 } else { 0.9 },
+            description: format!("SemVer {}", normalized),
+            rich_display: vec![Self::build_rich_display(&version)],
+        }]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::String(s) = value else {
+            return vec![];
+        };
+        let Some((version, _)) = Self::parse_semver(s) else {
+            return vec![];
+        };
+
+        let components = format!(
+            "major {}, minor {}, patch {}",
+            version.major, version.minor, version.patch
+        );
+        let mut conversions = vec![Conversion {
+            value: CoreValue::String(components.clone()),
+            target_format: "semver-components".to_string(),
+            display: components,
+            priority: ConversionPriority::Primary,
+            kind: ConversionKind::Representation,
+            display_only: true,
+            ..Default::default()
+        }];
+
+        if let Some(pre) = &version.pre_release {
+            // SemVer: pre-release versions have lower precedence than the release
+            let display = format!(
+                "pre-release ({}), precedes {}.{}.{}",
+                pre, version.major, version.minor, version.patch
+            );
+            conversions.push(Conversion {
+                value: CoreValue::String(display.clone()),
+                target_format: "semver-prerelease".to_string(),
+                display,
+                priority: ConversionPriority::Semantic,
+                kind: ConversionKind::Trait,
+                display_only: true,
+                ..Default::default()
+            });
+        }
+
+        conversions
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["version", "ver"]
+    }
+
+    fn specificity(&self) -> u32 {
+        specificity::IDENTIFIER
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_full_version() {
+        let results = SemVerFormat.parse("1.2.3-rc.1+build.5");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.9);
+
+        let RichDisplay::KeyValue { pairs } = &results[0].rich_display[0].preferred else {
+            panic!("Expected KeyValue");
+        };
+        let get = |key: &str| {
+            pairs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("Major"), Some("1"));
+        assert_eq!(get("Minor"), Some("2"));
+        assert_eq!(get("Patch"), Some("3"));
+        assert_eq!(get("Pre-release"), Some("rc.1"));
+        assert_eq!(get("Build"), Some("build.5"));
+
+        let conversions = SemVerFormat.source_conversions(&results[0].value);
+        assert_eq!(conversions[0].display, "major 1, minor 2, patch 3");
+        assert!(conversions
+            .iter()
+            .any(|c| c.kind == ConversionKind::Trait && c.display.starts_with("pre-release")));
+    }
+
+    #[test]
+    fn test_parse_release_version() {
+        let results = SemVerFormat.parse("1.0.0");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].description, "SemVer 1.0.0");
+
+        // No pre-release trait for a release
+        let conversions = SemVerFormat.source_conversions(&results[0].value);
+        assert!(conversions.iter().all(|c| c.kind != ConversionKind::Trait));
+    }
+
+    #[test]
+    fn test_v_prefix() {
+        let results = SemVerFormat.parse("v2.0.0");
+        assert_eq!(results.len(), 1);
+        assert!(matches!(&results[0].value, CoreValue::String(s) if s == "2.0.0"));
+    }
+
+    #[test]
+    fn test_reject_partial_versions() {
+        assert!(SemVerFormat.parse("v1").is_empty());
+        assert!(SemVerFormat.parse("1.2").is_empty());
+        assert!(SemVerFormat.parse("1.2.3.4").is_empty());
+        assert!(SemVerFormat.parse("01.2.3").is_empty()); // leading zero
+    }
+}
@@ -0,0 +1,170 @@
+//! Snowflake ID format.
+//!
+//! Discord and Twitter/X IDs are 64-bit snowflakes: the top 42 bits are
+//! milliseconds since a service-specific epoch. The same integer decodes to a
+//! different creation time under each epoch, and nothing in the ID says which
+//! service issued it, so both readings are shown.
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+};
+
+pub struct SnowflakeFormat;
+
+/// Service epochs in Unix milliseconds, as (conversion id, service, epoch).
+const EPOCHS: &[(&str, &str, i64)] = &[
+    // 2015-01-01T00:00:00Z
+    ("discord-snowflake", "Discord", 1_420_070_400_000),
+    // 2010-11-04T01:42:54.657Z
+    ("twitter-snowflake", "Twitter", 1_288_834_974_657),
+];
+
+/// Smallest value treated as a snowflake (17 digits). Snowflakes issued in
+/// practice are 17-19 digits; smaller integers are far more likely counts.
+const MIN_SNOWFLAKE: i128 = 10_000_000_000_000_000;
+
+/// Confidence for snowflake readings. Any large integer decodes to some
+/// timestamp, and the service is a guess.
+const SNOWFLAKE_CONFIDENCE: f32 = 0.6;
+
+impl SnowflakeFormat {
+    /// Creation time of a snowflake under the given epoch.
+    ///
+    /// Returns `None` outside the snowflake range or for times in the future.
+    fn creation_time(snowflake: i128, epoch_millis: i64) -> Option<DateTime<Utc>> {
+        if !(MIN_SNOWFLAKE..=i128::from(i64::MAX)).contains(&snowflake) {
+            return None;
+        }
+        let millis = i64::try_from(snowflake >> 22).ok()? + epoch_millis;
+        let created = Utc.timestamp_millis_opt(millis).single()?;
+        (created <= Utc::now()).then_some(created)
+    }
+}
+
+impl Format for SnowflakeFormat {
+    fn id(&self) -> &'static str {
+        "snowflake"
+    }
+
+    fn name(&self) -> &'static str {
+        "Snowflake ID"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Identifiers",
+            description: "Creation time of Discord and Twitter snowflake IDs",
+            examples: &[],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, _input: &str) -> Vec<Interpretation> {
+        // Snowflakes are plain integers; only offered as a conversion
+        vec![]
+    }
+
+    fn can_format(&self, _value: &CoreValue) -> bool {
+        false
+    }
+
+    fn format(&self, _value: &CoreValue) -> Option<String> {
+        None
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Int { value: int_val, .. } = value else {
+            return vec![];
+        };
+
+        EPOCHS
+            .iter()
+            .filter_map(|&(id, service, epoch)| {
+                let created = Self::creation_time(*int_val, epoch)?;
+                let display = format!("{} snowflake, created {}", service, created.to_rfc3339());
+                Some(Conversion {
+                    value: CoreValue::DateTime(created),
+                    target_format: id.to_string(),
+                    display: display.clone(),
+                    path: vec![id.to_string()],
+                    steps: vec![ConversionStep {
+                        format: id.to_string(),
+                        value: CoreValue::DateTime(created),
+                        display,
+                    }],
+                    confidence: SNOWFLAKE_CONFIDENCE,
+                    priority: ConversionPriority::Semantic,
+                    kind: ConversionKind::Conversion,
+                    display_only: true,
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["discord", "twitter"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snowflake_conversions(value: i128) -> Vec<Conversion> {
+        SnowflakeFormat.conversions(&CoreValue::Int {
+            value,
+            original_bytes: None,
+        })
+    }
+
+    #[test]
+    fn test_discord_snowflake() {
+        // Example from the Discord API reference
+        let conversions = snowflake_conversions(175_928_847_299_117_063);
+        let discord = conversions
+            .iter()
+            .find(|c| c.target_format == "discord-snowflake")
+            .unwrap();
+
+        assert_eq!(
+            discord.value,
+            CoreValue::DateTime(Utc.timestamp_millis_opt(1_462_015_105_796).unwrap())
+        );
+        assert_eq!(
+            discord.display,
+            "Discord snowflake, created 2016-04-30T11:18:25.796+00:00"
+        );
+        assert_eq!(discord.kind, ConversionKind::Conversion);
+        assert!(discord.confidence < 0.85);
+    }
+
+    #[test]
+    fn test_both_epochs_shown() {
+        let conversions = snowflake_conversions(175_928_847_299_117_063);
+        let targets: Vec<_> = conversions
+            .iter()
+            .map(|c| c.target_format.as_str())
+            .collect();
+        assert_eq!(targets, ["discord-snowflake", "twitter-snowflake"]);
+        assert_eq!(
+            conversions[1].display,
+            "Twitter snowflake, created 2012-03-03T13:01:20.453+00:00"
+        );
+    }
+
+    #[test]
+    fn test_rejects_implausible() {
+        // Too small to be a snowflake
+        assert!(snowflake_conversions(1_703_456_789).is_empty());
+        assert!(snowflake_conversions(-175_928_847_299_117_063).is_empty());
+        // Decodes to a time far in the future
+        assert!(snowflake_conversions(i128::from(i64::MAX)).is_empty());
+    }
+}
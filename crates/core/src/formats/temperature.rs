@@ -1,9 +1,11 @@
 //! Temperature format.
 //!
-//! Parses temperatures in Celsius, Fahrenheit, and Kelvin:
+//! Parses temperatures in Celsius, Fahrenheit, Kelvin, Rankine and Réaumur:
 //! - `72°F`, `72F`, `72 F`, `72 Fahrenheit`
 //! - `20°C`, `20C`, `20 C`, `20 Celsius`
 //! - `300K`, `300 K`, `300 Kelvin`
+//! - `491.67°R`, `491.67 Rankine` (a bare `R` is not a unit)
+//! - `80°Ré`, `80 Réaumur`
 //! - Negative: `-40°F`, `-40.5°C`
 //! - Decimal with locale heuristics: `4.28°F`, `4,28°F`
 
@@ -62,7 +64,8 @@ impl TemperatureFormat {
     }
 
     /// Parse temperature string like "72°F" or "-40.5 C".
-    /// Returns (value, unit) where unit is 'C', 'F', or 'K'.
+    /// Returns (value, unit) where unit is 'C', 'F', 'K', 'R' (Rankine) or
+    /// 'r' (Réaumur).
     fn parse_temperature(s: &str) -> Option<(f64, char)> {
         let s = s.trim();
 
@@ -84,6 +87,18 @@ impl TemperatureFormat {
             ("Kelvin", 'K'),
             ("K", 'K'),
             ("k", 'K'),
+            // Rankine and Réaumur need the degree sign or full name, so
+            // roman numerals and resistances aren't read as temperatures
+            ("rankine", 'R'),
+            ("Rankine", 'R'),
+            ("°Ra", 'R'),
+            ("°R", 'R'),
+            ("réaumur", 'r'),
+            ("Réaumur", 'r'),
+            ("reaumur", 'r'),
+            ("Reaumur", 'r'),
+            ("°Ré", 'r'),
+            ("°Re", 'r'),
         ];
 
         for (suffix, unit) in SUFFIXES {
@@ -109,6 +124,18 @@ impl TemperatureFormat {
     fn k_to_f(k: f64) -> f64 {
         (k - 273.15) * 9.0 / 5.0 + 32.0
     }
+    fn r_to_k(r: f64) -> f64 {
+        r * 5.0 / 9.0
+    }
+    fn k_to_r(k: f64) -> f64 {
+        k * 9.0 / 5.0
+    }
+    fn re_to_k(re: f64) -> f64 {
+        re * 5.0 / 4.0 + 273.15
+    }
+    fn k_to_re(k: f64) -> f64 {
+        (k - 273.15) * 4.0 / 5.0
+    }
 
     /// Convert input value to Kelvin based on unit.
     fn to_kelvin(value: f64, unit: char) -> f64 {
@@ -116,6 +143,8 @@ impl TemperatureFormat {
             'C' => Self::c_to_k(value),
             'F' => Self::f_to_k(value),
             'K' => value,
+            'R' => Self::r_to_k(value),
+            'r' => Self::re_to_k(value),
             _ => value,
         }
     }
@@ -144,8 +173,8 @@ impl Format for TemperatureFormat {
             id: self.id(),
             name: self.name(),
             category: "Numbers",
-            description: "Temperature (Celsius, Fahrenheit, Kelvin)",
-            examples: &["72°F", "20°C", "300K", "-40 Fahrenheit"],
+            description: "Temperature (Celsius, Fahrenheit, Kelvin, Rankine, Réaumur)",
+            examples: &["72°F", "20°C", "300K", "-40 Fahrenheit", "491.67°R"],
             aliases: self.aliases(),
             has_validation: false,
         }
@@ -168,6 +197,8 @@ impl Format for TemperatureFormat {
             'C' => "Celsius",
             'F' => "Fahrenheit",
             'K' => "Kelvin",
+            'R' => "Rankine",
+            'r' => "Réaumur",
             _ => "unknown",
         };
 
@@ -178,6 +209,8 @@ impl Format for TemperatureFormat {
                 'C' => "°C",
                 'F' => "°F",
                 'K' => " K",
+                'R' => "°R",
+                'r' => "°Ré",
                 _ => "",
             },
             unit_name
@@ -262,11 +295,52 @@ impl Format for TemperatureFormat {
             ..Default::default()
         });
 
+        // Rankine
+        let r_display = format!("{}°R", Self::format_value(Self::k_to_r(kelvin)));
+        conversions.push(Conversion {
+            value: CoreValue::Temperature(kelvin),
+            target_format: "rankine".to_string(),
+            display: r_display.clone(),
+            path: vec!["rankine".to_string()],
+            steps: vec![ConversionStep {
+                format: "rankine".to_string(),
+                value: CoreValue::Temperature(kelvin),
+                display: r_display,
+            }],
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Representation,
+            ..Default::default()
+        });
+
+        // Réaumur
+        let re_display = format!("{}°Ré", Self::format_value(Self::k_to_re(kelvin)));
+        conversions.push(Conversion {
+            value: CoreValue::Temperature(kelvin),
+            target_format: "reaumur".to_string(),
+            display: re_display.clone(),
+            path: vec!["reaumur".to_string()],
+            steps: vec![ConversionStep {
+                format: "reaumur".to_string(),
+                value: CoreValue::Temperature(kelvin),
+                display: re_display,
+            }],
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Representation,
+            ..Default::default()
+        });
+
         conversions
     }
 
     fn aliases(&self) -> &'static [&'static str] {
-        &["temp", "celsius", "fahrenheit", "kelvin"]
+        &[
+            "temp",
+            "celsius",
+            "fahrenheit",
+            "kelvin",
+            "rankine",
+            "reaumur",
+        ]
     }
 }
 
@@ -376,7 +450,7 @@ mod tests {
         let value = CoreValue::Temperature(273.15);
         let conversions = format.conversions(&value);
 
-        assert_eq!(conversions.len(), 3);
+        assert_eq!(conversions.len(), 5);
 
         let celsius = conversions.iter().find(|c| c.target_format == "celsius");
         assert!(celsius.is_some());
@@ -391,6 +465,46 @@ mod tests {
         assert_eq!(kelvin.unwrap().display, "273.15 K");
     }
 
+    #[test]
+    fn test_parse_rankine_and_reaumur() {
+        let k = parse_to_kelvin("491.67°R").unwrap();
+        assert!((k - 273.15).abs() < 0.01);
+        let k = parse_to_kelvin("671.67 Rankine").unwrap();
+        assert!((k - 373.15).abs() < 0.01);
+
+        let k = parse_to_kelvin("80°Ré").unwrap();
+        assert!((k - 373.15).abs() < 0.01);
+        let k = parse_to_kelvin("-8 Reaumur").unwrap();
+        assert!((k - 263.15).abs() < 0.01);
+
+        assert_eq!(
+            TemperatureFormat.parse("80°Ré")[0].description,
+            "80°Ré (Réaumur)"
+        );
+    }
+
+    #[test]
+    fn test_bare_r_is_not_rankine() {
+        assert!(parse_to_kelvin("491R").is_none());
+        assert!(parse_to_kelvin("XIV").is_none());
+        // Kelvin is unaffected
+        let k = parse_to_kelvin("273K").unwrap();
+        assert!((k - 273.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_conversions_rankine_and_reaumur() {
+        let conversions = TemperatureFormat.conversions(&CoreValue::Temperature(273.15));
+        let display = |id: &str| {
+            conversions
+                .iter()
+                .find(|c| c.target_format == id)
+                .map(|c| c.display.as_str())
+        };
+        assert_eq!(display("rankine"), Some("491.67°R"));
+        assert_eq!(display("reaumur"), Some("0°Ré"));
+    }
+
     #[test]
     fn test_format_value() {
         assert_eq!(TemperatureFormat::format_value(0.0), "0");
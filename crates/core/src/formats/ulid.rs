@@ -5,7 +5,7 @@
 
 use chrono::{DateTime, TimeZone, Utc};
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{CoreValue, Interpretation};
 
 pub struct UlidFormat;
@@ -177,6 +177,10 @@ impl Format for UlidFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &[]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
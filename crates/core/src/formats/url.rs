@@ -2,8 +2,14 @@
 
 use percent_encoding::{percent_decode_str, utf8_percent_encode, NON_ALPHANUMERIC};
 
-use crate::format::{Format, FormatInfo};
-use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::format::{specificity, Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
+};
+
+use super::CharFormat;
 
 pub struct UrlEncodingFormat;
 
@@ -214,18 +220,54 @@ impl Format for UrlEncodingFormat {
             display: encoded,
             path: vec!["url-encoded".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority: ConversionPriority::Encoding,
             display_only: true, // Terminal format - don't chain further
             kind: ConversionKind::Conversion,
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
 
+    fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        // A decoded multi-byte sequence that forms one character: name its codepoints
+        let CoreValue::String(s) = value else {
+            return vec![];
+        };
+        if s.is_ascii() || s.graphemes(true).count() != 1 {
+            return vec![];
+        }
+        let Some(character) = CharFormat.parse(s).into_iter().next() else {
+            return vec![];
+        };
+
+        let display = character.description;
+        vec![Conversion {
+            value: CoreValue::String(s.clone()),
+            target_format: "unicode-char".to_string(),
+            display: display.clone(),
+            path: vec!["unicode-char".to_string()],
+            steps: vec![ConversionStep {
+                format: "unicode-char".to_string(),
+                value: CoreValue::String(s.clone()),
+                display,
+            }],
+            priority: ConversionPriority::Semantic,
+            kind: ConversionKind::Trait,
+            display_only: true,
+            ..Default::default()
+        }]
+    }
+
     fn aliases(&self) -> &'static [&'static str] {
         &["url", "percent"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
@@ -258,6 +300,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_multibyte_utf8() {
+        let format = UrlEncodingFormat;
+
+        // 3-byte sequence reassembles into one character, not three
+        let results = format.parse("%E2%9C%93");
+        assert_eq!(results[0].value, CoreValue::String("✓".to_string()));
+        let traits = format.source_conversions(&results[0].value);
+        assert_eq!(traits[0].target_format, "unicode-char");
+        assert_eq!(traits[0].display, "U+2713 '✓'");
+        assert_eq!(traits[0].kind, ConversionKind::Trait);
+
+        // 4-byte emoji
+        let results = format.parse("%F0%9F%98%80");
+        assert_eq!(results[0].value, CoreValue::String("😀".to_string()));
+        let traits = format.source_conversions(&results[0].value);
+        assert_eq!(traits[0].display, "U+1F600 '😀'");
+
+        // Multi-codepoint grapheme: thumbs up with skin tone
+        let results = format.parse("%F0%9F%91%8D%F0%9F%8F%BD");
+        assert_eq!(results[0].value, CoreValue::String("👍🏽".to_string()));
+        let traits = format.source_conversions(&results[0].value);
+        assert_eq!(
+            traits[0].display,
+            "'👍🏽' = U+1F44D '👍' + U+1F3FD medium skin tone"
+        );
+
+        // Plain text and truncated sequences get no character trait
+        assert!(format
+            .source_conversions(&CoreValue::String("Hello World".to_string()))
+            .is_empty());
+        assert!(format.parse("%E2%9C").is_empty());
+    }
+
     #[test]
     fn test_format_to_url_encoded() {
         let format = UrlEncodingFormat;
@@ -6,7 +6,7 @@
 
 use url::Url;
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionStep, CoreValue, Interpretation,
     RichDisplay, RichDisplayOption,
@@ -340,7 +340,9 @@ impl Format for UrlParserFormat {
                 kind: ConversionKind::Representation,
                 display_only: true,
                 is_lossy: false,
+                confidence: 1.0,
                 hidden: false,
+                depth_limited: false,
                 rich_display: vec![RichDisplayOption::new(RichDisplay::KeyValue {
                     pairs: vec![
                         ("Cleaned URL".to_string(), url_str.clone()),
@@ -356,6 +358,10 @@ impl Format for UrlParserFormat {
     fn aliases(&self) -> &'static [&'static str] {
         &["url-parse", "link"]
     }
+
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
 }
 
 #[cfg(test)]
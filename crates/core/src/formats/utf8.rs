@@ -2,12 +2,229 @@
 //!
 //! Fallback format that interprets any input as plain text.
 //! Shows ASCII/UTF-8 properties and enables conversion to bytes for hashing.
+//! Non-ASCII text also gets Unicode checks: combining marks, words mixing
+//! scripts (a homoglyph spoofing signal, e.g. a Cyrillic "а" in "pаypal.com")
+//! and an NFC-normalized form when the input isn't already NFC.
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::{is_nfc, UnicodeNormalization};
+use unicode_script::{Script, UnicodeScript};
 
 use crate::format::{Format, FormatInfo};
 use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
 
+use super::css_colors::lookup_color;
+
 pub struct Utf8Format;
 
+/// Minimum number of words before guessing a language.
+const MIN_LANGUAGE_WORDS: usize = 3;
+
+/// Small embedded language model: frequent function words plus letters
+/// characteristic of each language. Stopwords are the strongest signal in
+/// short text; characteristic letters break ties between related languages.
+const LANGUAGES: &[(&str, &[&str], &[char])] = &[
+    (
+        "English",
+        &[
+            "the", "and", "is", "are", "of", "to", "in", "that", "it", "was", "for", "on", "with",
+            "this", "you", "not", "have", "be", "at", "from", "they", "we", "but", "or", "an",
+            "what", "there", "will", "would", "can", "has", "my", "your", "all",
+        ],
+        &[],
+    ),
+    (
+        "Swedish",
+        &[
+            "och", "är", "att", "det", "som", "en", "ett", "på", "av", "för", "med", "inte", "jag",
+            "du", "vi", "har", "till", "den", "om", "men", "var", "så", "kan", "från", "eller",
+            "hur", "vad", "mig", "ska", "här", "när", "också",
+        ],
+        &['å', 'ä', 'ö'],
+    ),
+    (
+        "German",
+        &[
+            "der", "die", "das", "und", "ist", "nicht", "ich", "sie", "es", "ein", "eine", "zu",
+            "den", "mit", "von", "auf", "für", "sich", "dem", "auch", "wir", "sind", "aber", "wie",
+            "noch", "nach", "wird", "bei", "oder", "haben",
+        ],
+        &['ß', 'ü', 'ä', 'ö'],
+    ),
+    (
+        "French",
+        &[
+            "le", "la", "les", "et", "est", "des", "une", "un", "du", "que", "qui", "dans", "pour",
+            "pas", "sur", "avec", "ce", "il", "elle", "nous", "vous", "je", "au", "sont", "mais",
+            "ou", "très", "aussi",
+        ],
+        &['é', 'è', 'ê', 'à', 'ç', 'ù', 'œ'],
+    ),
+    (
+        "Spanish",
+        &[
+            "el", "la", "los", "las", "y", "es", "de", "que", "en", "un", "una", "por", "con",
+            "para", "no", "se", "del", "al", "lo", "como", "pero", "más", "está", "son", "muy",
+            "también", "yo", "su",
+        ],
+        &['ñ', 'á', 'í', 'ó', 'ú', '¿', '¡'],
+    ),
+    (
+        "Dutch",
+        &[
+            "de", "het", "een", "en", "is", "van", "ik", "niet", "dat", "zijn", "op", "te", "met",
+            "voor", "je", "maar", "ook", "er", "wat", "naar", "bij", "nog", "wij", "kan", "hebben",
+        ],
+        &['ĳ'],
+    ),
+];
+
+/// Scripts that legitimately mix within one word (Japanese kanji with kana).
+const CJK_SCRIPTS: &[Script] = &[
+    Script::Han,
+    Script::Hiragana,
+    Script::Katakana,
+    Script::Hangul,
+    Script::Bopomofo,
+];
+
+impl Utf8Format {
+    /// Scripts mixed within a single word, in order of first appearance.
+    ///
+    /// Returns `None` when every word sticks to one script. Common and
+    /// inherited characters (digits, punctuation, combining marks) belong to
+    /// every script, and CJK scripts may mix freely.
+    fn mixed_scripts(text: &str) -> Option<Vec<Script>> {
+        let mut mixed: Vec<Script> = vec![];
+        for word in text.split_whitespace() {
+            let mut scripts: Vec<Script> = vec![];
+            for script in word.chars().map(|c| c.script()) {
+                if !matches!(script, Script::Common | Script::Inherited | Script::Unknown)
+                    && !scripts.contains(&script)
+                {
+                    scripts.push(script);
+                }
+            }
+            if scripts.len() > 1 && !scripts.iter().all(|s| CJK_SCRIPTS.contains(s)) {
+                for script in scripts {
+                    if !mixed.contains(&script) {
+                        mixed.push(script);
+                    }
+                }
+            }
+        }
+        (!mixed.is_empty()).then_some(mixed)
+    }
+
+    /// Unicode checks for non-ASCII text: combining marks, mixed scripts and NFC.
+    fn unicode_conversions(s: &str) -> Vec<Conversion> {
+        let mut conversions = vec![];
+
+        let combining = s.chars().filter(|c| is_combining_mark(*c)).count();
+        if combining > 0 {
+            conversions.push(Conversion {
+                value: CoreValue::Int {
+                    value: combining as i128,
+                    original_bytes: None,
+                },
+                target_format: "combining-marks".to_string(),
+                display: format!("contains combining marks ({})", combining),
+                path: vec!["combining-marks".to_string()],
+                is_lossy: false,
+                confidence: 1.0,
+                steps: vec![],
+                priority: ConversionPriority::Semantic,
+                display_only: true,
+                kind: ConversionKind::Trait,
+                hidden: false,
+                depth_limited: false,
+                rich_display: vec![],
+            });
+        }
+
+        if let Some(scripts) = Self::mixed_scripts(s) {
+            let names: Vec<_> = scripts.iter().map(|s| s.full_name()).collect();
+            conversions.push(Conversion {
+                value: CoreValue::String(names.join("+")),
+                target_format: "mixed-scripts".to_string(),
+                display: format!("mixed scripts ({})", names.join("+")),
+                path: vec!["mixed-scripts".to_string()],
+                is_lossy: false,
+                confidence: 1.0,
+                steps: vec![],
+                priority: ConversionPriority::Semantic,
+                display_only: true,
+                kind: ConversionKind::Trait,
+                hidden: false,
+                depth_limited: false,
+                rich_display: vec![],
+            });
+        }
+
+        if !is_nfc(s) {
+            let nfc: String = s.nfc().collect();
+            conversions.push(Conversion {
+                value: CoreValue::String(nfc.clone()),
+                target_format: "nfc".to_string(),
+                display: format!(
+                    "{} ({} → {} codepoints)",
+                    nfc,
+                    s.chars().count(),
+                    nfc.chars().count()
+                ),
+                path: vec!["nfc".to_string()],
+                is_lossy: false,
+                confidence: 1.0,
+                steps: vec![],
+                priority: ConversionPriority::Semantic,
+                display_only: true,
+                kind: ConversionKind::Conversion,
+                hidden: false,
+                depth_limited: false,
+                rich_display: vec![],
+            });
+        }
+
+        conversions
+    }
+
+    /// Guess the natural language of a text using stopword and letter hints.
+    ///
+    /// Returns `None` for short text, non-prose, or when no language clearly
+    /// wins. This is a heuristic and only reported as a low-key trait.
+    fn detect_language(text: &str) -> Option<&'static str> {
+        let lower = text.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty())
+            .collect();
+        if words.len() < MIN_LANGUAGE_WORDS {
+            return None;
+        }
+
+        let mut scores: Vec<(&'static str, usize, f64)> = LANGUAGES
+            .iter()
+            .map(|(name, stopwords, letters)| {
+                let hits = words.iter().filter(|w| stopwords.contains(w)).count();
+                let letter_bonus = if lower.chars().any(|c| letters.contains(&c)) {
+                    0.5
+                } else {
+                    0.0
+                };
+                (*name, hits, hits as f64 + letter_bonus)
+            })
+            .collect();
+        scores.sort_by(|a, b| b.2.total_cmp(&a.2));
+
+        let (best, hits, score) = scores[0];
+        let runner_up = scores[1].2;
+        // Need a couple of function words, a reasonable share of the text,
+        // and a clear lead over the next language
+        let enough_hits = hits >= 2 && hits * 5 >= words.len();
+        (enough_hits && score > runner_up).then_some(best)
+    }
+}
+
 impl Format for Utf8Format {
     fn id(&self) -> &'static str {
         "text"
@@ -46,10 +263,19 @@ impl Format for Utf8Format {
             format!("{} chars, {} bytes (UTF-8)", char_count, byte_count)
         };
 
+        // CSS color names ("red", "tan") are usually just words, so rank the
+        // text reading above the color-name one
+        let confidence =
+            if input.chars().all(|c| c.is_ascii_alphabetic()) && lookup_color(input).is_some() {
+                0.75
+            } else {
+                0.10 // Very low - fallback interpretation
+            };
+
         vec![Interpretation {
             value: CoreValue::String(input.to_string()),
             source_format: "text".to_string(),
-            confidence: 0.10, // Very low - fallback interpretation
+            confidence,
             description,
             rich_display: vec![],
         }]
@@ -78,11 +304,13 @@ impl Format for Utf8Format {
                         display: s,
                         path: vec!["utf8".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Encoding,
                         display_only: false,
                         kind: ConversionKind::default(),
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![],
                     }]
                 } else {
@@ -102,11 +330,13 @@ impl Format for Utf8Format {
                     display: format!("{} bytes", s.len()),
                     path: vec!["bytes".to_string()],
                     is_lossy: false,
+                    confidence: 1.0,
                     steps: vec![],
                     priority: ConversionPriority::Raw,
                     display_only: false,
                     kind: ConversionKind::Conversion,
                     hidden: true,
+                    depth_limited: false,
                     rich_display: vec![],
                 });
 
@@ -123,11 +353,13 @@ impl Format for Utf8Format {
                         display: ascii_dec,
                         path: vec!["ascii-decimal".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Encoding,
                         display_only: true,
                         kind: ConversionKind::Representation,
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![],
                     });
                     // Note: ascii-hex removed - utf8-bytes from CharFormat provides this
@@ -142,11 +374,13 @@ impl Format for Utf8Format {
                         display: "ASCII".to_string(),
                         path: vec!["is-ascii".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Semantic,
                         display_only: true,
                         kind: ConversionKind::Trait,
                         hidden: false,
+                        depth_limited: false,
                         rich_display: vec![],
                     });
                 } else {
@@ -162,11 +396,37 @@ impl Format for Utf8Format {
                         display: format!("UTF-8 ({} chars, {} bytes)", char_count, byte_count),
                         path: vec!["encoding".to_string()],
                         is_lossy: false,
+                        confidence: 1.0,
                         steps: vec![],
                         priority: ConversionPriority::Semantic,
                         display_only: true,
                         kind: ConversionKind::Trait,
                         hidden: false,
+                        depth_limited: false,
+                        rich_display: vec![],
+                    });
+                }
+
+                if !is_ascii {
+                    conversions.extend(Self::unicode_conversions(s));
+                }
+
+                // Natural language guess (low-key trait, heuristic)
+                if let Some(language) = Self::detect_language(s) {
+                    conversions.push(Conversion {
+                        value: CoreValue::String(language.to_string()),
+                        target_format: "language".to_string(),
+                        display: format!("likely {}", language),
+                        path: vec!["language".to_string()],
+                        is_lossy: false,
+                        // Stopword matching is a guess, not a classification
+                        confidence: 0.6,
+                        steps: vec![],
+                        priority: ConversionPriority::Semantic,
+                        display_only: true,
+                        kind: ConversionKind::Trait,
+                        hidden: false,
+                        depth_limited: false,
                         rich_display: vec![],
                     });
                 }
@@ -272,4 +532,73 @@ mod tests {
             .unwrap();
         assert!(encoding.display.contains("UTF-8"));
     }
+
+    fn language_of(text: &str) -> Option<String> {
+        Utf8Format
+            .conversions(&CoreValue::String(text.to_string()))
+            .into_iter()
+            .find(|c| c.target_format == "language")
+            .map(|c| c.display)
+    }
+
+    #[test]
+    fn test_language_english() {
+        assert_eq!(
+            language_of("The cat is sleeping on the sofa and it was happy").as_deref(),
+            Some("likely English")
+        );
+    }
+
+    #[test]
+    fn test_language_swedish() {
+        assert_eq!(
+            language_of("Jag har inte sett det här förut och det är konstigt").as_deref(),
+            Some("likely Swedish")
+        );
+    }
+
+    #[test]
+    fn test_language_requires_prose() {
+        assert_eq!(language_of("hello"), None);
+        assert_eq!(language_of("xyz qwv plmk zzt"), None);
+        assert_eq!(language_of("550e8400-e29b-41d4-a716-446655440000"), None);
+    }
+
+    fn conversion(text: &str, target: &str) -> Option<Conversion> {
+        Utf8Format
+            .conversions(&CoreValue::String(text.to_string()))
+            .into_iter()
+            .find(|c| c.target_format == target)
+    }
+
+    #[test]
+    fn test_mixed_scripts_homoglyph() {
+        // Cyrillic "а" (U+0430) in place of the Latin "a"
+        let mixed = conversion("p\u{0430}ypal.com", "mixed-scripts").unwrap();
+        assert_eq!(mixed.display, "mixed scripts (Latin+Cyrillic)");
+        assert_eq!(mixed.kind, ConversionKind::Trait);
+
+        assert!(conversion("paypal.com", "mixed-scripts").is_none());
+        // Separate words in different scripts are not spoofing
+        assert!(conversion("hello мир", "mixed-scripts").is_none());
+        // Kanji with kana is ordinary Japanese
+        assert!(conversion("食べる", "mixed-scripts").is_none());
+    }
+
+    #[test]
+    fn test_decomposed_normalizes_to_nfc() {
+        // "e" + COMBINING ACUTE ACCENT
+        let decomposed = "caf\u{0065}\u{0301}";
+        let nfc = conversion(decomposed, "nfc").unwrap();
+        assert_eq!(nfc.value, CoreValue::String("caf\u{00E9}".to_string()));
+        assert_eq!(nfc.display, "café (5 → 4 codepoints)");
+        assert_eq!(
+            conversion(decomposed, "combining-marks").unwrap().display,
+            "contains combining marks (1)"
+        );
+
+        // Already NFC
+        assert!(conversion("caf\u{00E9}", "nfc").is_none());
+        assert!(conversion("caf\u{00E9}", "combining-marks").is_none());
+    }
 }
@@ -2,7 +2,7 @@
 
 use uuid::Uuid;
 
-use crate::format::{Format, FormatInfo};
+use crate::format::{specificity, Format, FormatInfo};
 use crate::types::{Conversion, ConversionKind, ConversionPriority, CoreValue, Interpretation};
 
 pub struct UuidFormat;
@@ -95,11 +95,13 @@ impl Format for UuidFormat {
             display: uuid.to_string(),
             path: vec!["uuid".to_string()],
             is_lossy: false,
+            confidence: 1.0,
             steps: vec![],
             priority: ConversionPriority::Semantic,
             display_only: false,
             kind: ConversionKind::default(),
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }]
     }
@@ -108,6 +110,10 @@ impl Format for UuidFormat {
         &["guid"]
     }
 
+    fn specificity(&self) -> u32 {
+        specificity::HIGH
+    }
+
     fn validate(&self, input: &str) -> Option<String> {
         match Uuid::parse_str(input) {
             Ok(_) => None,
@@ -0,0 +1,441 @@
+//! XML format.
+//!
+//! Parses well-formed documents into an [`XmlElement`] tree and offers a
+//! re-indented rendering plus an XML → JSON conversion.
+//!
+//! Entities are never expanded beyond the predefined ones and character
+//! references: documents declaring entities in a DOCTYPE (billion laughs)
+//! are rejected, as are references to undeclared entities.
+
+use quick_xml::escape::{escape, partial_escape};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde_json::{Map, Value as JsonValue};
+
+use crate::format::{Format, FormatInfo};
+use crate::types::{
+    Conversion, ConversionPriority, ConversionStep, CoreValue, Interpretation, XmlElement, XmlNode,
+};
+
+pub struct XmlFormat;
+
+/// Deepest element nesting we accept.
+const MAX_DEPTH: usize = 256;
+
+/// Most nodes (elements, text and comments) we accept in one document.
+const MAX_NODES: usize = 100_000;
+
+impl XmlFormat {
+    /// Parse a complete document, returning the root element.
+    ///
+    /// Returns `None` for anything that isn't a single well-formed root
+    /// element, optionally preceded by a declaration, DOCTYPE, comments or
+    /// processing instructions.
+    fn parse_document(input: &str) -> Option<XmlElement> {
+        let mut reader = Reader::from_str(input);
+        reader.config_mut().trim_text(true);
+
+        let mut stack: Vec<XmlElement> = Vec::new();
+        let mut root = None;
+        let mut nodes = 0;
+
+        loop {
+            let event = reader.read_event().ok()?;
+
+            // Only comments and processing instructions may follow the root
+            if root.is_some() && !matches!(event, Event::Eof | Event::Comment(_) | Event::PI(_)) {
+                return None;
+            }
+            if matches!(
+                event,
+                Event::Start(_) | Event::Empty(_) | Event::Text(_) | Event::CData(_)
+            ) {
+                nodes += 1;
+                if nodes > MAX_NODES {
+                    return None;
+                }
+            }
+
+            match event {
+                Event::Decl(_) | Event::PI(_) => {}
+                Event::DocType(doctype) => {
+                    // Entity declarations are how billion laughs starts; don't expand them
+                    if !stack.is_empty() || doctype.windows(8).any(|w| w == b"<!ENTITY") {
+                        return None;
+                    }
+                }
+                Event::Start(start) => {
+                    if stack.len() >= MAX_DEPTH {
+                        return None;
+                    }
+                    stack.push(Self::element(&start)?);
+                }
+                Event::Empty(start) => {
+                    let element = Self::element(&start)?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(XmlNode::Element(element)),
+                        None => root = Some(element),
+                    }
+                }
+                Event::End(_) => {
+                    let element = stack.pop()?;
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(XmlNode::Element(element)),
+                        None => root = Some(element),
+                    }
+                }
+                Event::Text(text) => {
+                    // Fails on undeclared entities such as &lol;
+                    let text = text.unescape().ok()?.into_owned();
+                    stack.last_mut()?.children.push(XmlNode::Text(text));
+                }
+                Event::CData(cdata) => {
+                    let text = cdata.decode().ok()?.into_owned();
+                    stack.last_mut()?.children.push(XmlNode::Text(text));
+                }
+                Event::Comment(comment) => {
+                    if let Some(parent) = stack.last_mut() {
+                        let text = String::from_utf8_lossy(&comment).trim().to_string();
+                        parent.children.push(XmlNode::Comment(text));
+                    }
+                }
+                Event::Eof => break,
+            }
+        }
+
+        if !stack.is_empty() {
+            return None;
+        }
+        root
+    }
+
+    /// Build an (empty) element from a start tag.
+    fn element(start: &BytesStart) -> Option<XmlElement> {
+        let name = std::str::from_utf8(start.name().as_ref()).ok()?.to_string();
+        let mut attributes = Vec::new();
+        for attr in start.attributes() {
+            let attr = attr.ok()?;
+            let key = std::str::from_utf8(attr.key.as_ref()).ok()?.to_string();
+            let value = attr.unescape_value().ok()?.into_owned();
+            attributes.push((key, value));
+        }
+        Some(XmlElement {
+            name,
+            attributes,
+            children: vec![],
+        })
+    }
+
+    /// Count elements in a tree (for the description).
+    fn count_elements(element: &XmlElement) -> usize {
+        1 + element
+            .children
+            .iter()
+            .map(|child| match child {
+                XmlNode::Element(e) => Self::count_elements(e),
+                _ => 0,
+            })
+            .sum::<usize>()
+    }
+}
+
+impl XmlElement {
+    /// Render as indented XML, two spaces per level.
+    ///
+    /// Elements whose only content is text stay on one line.
+    pub fn to_pretty_string(&self) -> String {
+        let mut output = String::new();
+        write_element(self, 0, &mut output);
+        output
+    }
+
+    /// Convert to JSON using the common `@attr` / `#text` convention.
+    ///
+    /// Repeated child elements become arrays, and text-only elements become
+    /// plain strings. Comments are dropped. The result is wrapped in an
+    /// object keyed by the root element's name.
+    pub fn to_json(&self) -> JsonValue {
+        let mut root = Map::new();
+        root.insert(self.name.clone(), element_to_json(self));
+        JsonValue::Object(root)
+    }
+}
+
+fn write_element(element: &XmlElement, depth: usize, output: &mut String) {
+    let indent = "  ".repeat(depth);
+    output.push_str(&indent);
+    output.push('<');
+    output.push_str(&element.name);
+    for (key, value) in &element.attributes {
+        output.push_str(&format!(" {}=\"{}\"", key, escape(value)));
+    }
+
+    match element.children.as_slice() {
+        [] => output.push_str("/>"),
+        [XmlNode::Text(text)] => {
+            output.push('>');
+            output.push_str(&partial_escape(text));
+            output.push_str(&format!("</{}>", element.name));
+        }
+        children => {
+            output.push_str(">\n");
+            for child in children {
+                match child {
+                    XmlNode::Element(e) => write_element(e, depth + 1, output),
+                    XmlNode::Text(text) => {
+                        output.push_str(&indent);
+                        output.push_str("  ");
+                        output.push_str(&partial_escape(text));
+                    }
+                    XmlNode::Comment(comment) => {
+                        output.push_str(&indent);
+                        output.push_str(&format!("  <!-- {} -->", comment));
+                    }
+                }
+                output.push('\n');
+            }
+            output.push_str(&indent);
+            output.push_str(&format!("</{}>", element.name));
+        }
+    }
+}
+
+fn element_to_json(element: &XmlElement) -> JsonValue {
+    let mut map = Map::new();
+    for (key, value) in &element.attributes {
+        map.insert(format!("@{}", key), JsonValue::String(value.clone()));
+    }
+
+    let mut text = Vec::new();
+    for child in &element.children {
+        match child {
+            XmlNode::Element(e) => {
+                let value = element_to_json(e);
+                match map.get_mut(&e.name) {
+                    Some(JsonValue::Array(items)) => items.push(value),
+                    Some(existing) => *existing = JsonValue::Array(vec![existing.take(), value]),
+                    None => {
+                        map.insert(e.name.clone(), value);
+                    }
+                }
+            }
+            XmlNode::Text(t) => text.push(t.as_str()),
+            XmlNode::Comment(_) => {}
+        }
+    }
+
+    if !text.is_empty() {
+        let text = text.join(" ");
+        if map.is_empty() {
+            return JsonValue::String(text);
+        }
+        map.insert("#text".to_string(), JsonValue::String(text));
+    }
+
+    if map.is_empty() {
+        JsonValue::Null
+    } else {
+        JsonValue::Object(map)
+    }
+}
+
+impl Format for XmlFormat {
+    fn id(&self) -> &'static str {
+        "xml"
+    }
+
+    fn name(&self) -> &'static str {
+        "XML"
+    }
+
+    fn info(&self) -> FormatInfo {
+        FormatInfo {
+            id: self.id(),
+            name: self.name(),
+            category: "Data",
+            description: "Well-formed XML documents",
+            examples: &[
+                r#"<?xml version="1.0"?><note><to>Tove</to></note>"#,
+                r#"<book id="42"><title>Dune</title></book>"#,
+            ],
+            aliases: self.aliases(),
+            has_validation: false,
+        }
+    }
+
+    fn parse(&self, input: &str) -> Vec<Interpretation> {
+        let trimmed = input.trim();
+        if !trimmed.starts_with('<') || !trimmed.ends_with('>') {
+            return vec![];
+        }
+
+        let Some(root) = Self::parse_document(trimmed) else {
+            return vec![];
+        };
+
+        let elements = Self::count_elements(&root);
+        let description = format!(
+            "<{}> document, {} element{}",
+            root.name,
+            elements,
+            if elements == 1 { "" } else { "s" }
+        );
+
+        vec![Interpretation {
+            value: CoreValue::Xml(root),
+            source_format: "xml".to_string(),
+            confidence: 0.9,
+            description,
+            rich_display: vec![],
+        }]
+    }
+
+    fn can_format(&self, value: &CoreValue) -> bool {
+        matches!(value, CoreValue::Xml(_))
+    }
+
+    fn format(&self, value: &CoreValue) -> Option<String> {
+        match value {
+            CoreValue::Xml(root) => Some(root.to_pretty_string()),
+            _ => None,
+        }
+    }
+
+    fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+        let CoreValue::Xml(root) = value else {
+            return vec![];
+        };
+
+        let formatted = root.to_pretty_string();
+        let json = root.to_json();
+        let json_display = serde_json::to_string_pretty(&json).unwrap_or_default();
+
+        vec![
+            Conversion {
+                value: CoreValue::Xml(root.clone()),
+                target_format: "xml-formatted".to_string(),
+                display: formatted.clone(),
+                path: vec!["xml-formatted".to_string()],
+                steps: vec![ConversionStep {
+                    format: "xml-formatted".to_string(),
+                    value: CoreValue::Xml(root.clone()),
+                    display: formatted,
+                }],
+                priority: ConversionPriority::Structured,
+                display_only: true,
+                ..Default::default()
+            },
+            Conversion {
+                value: CoreValue::Json(json.clone()),
+                target_format: "json".to_string(),
+                display: json_display.clone(),
+                path: vec!["json".to_string()],
+                steps: vec![ConversionStep {
+                    format: "json".to_string(),
+                    value: CoreValue::Json(json),
+                    display: json_display,
+                }],
+                priority: ConversionPriority::Structured,
+                display_only: true,
+                ..Default::default()
+            },
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn root(input: &str) -> Option<XmlElement> {
+        match XmlFormat.parse(input).into_iter().next()?.value {
+            CoreValue::Xml(root) => Some(root),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_small_document() {
+        let input = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!-- greeting -->
+<note><to>Tove</to><from>Jani</from><body>Don't forget me!</body></note>"#;
+        let results = XmlFormat.parse(input);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].confidence, 0.9);
+        assert_eq!(results[0].description, "<note> document, 4 elements");
+
+        let CoreValue::Xml(root) = &results[0].value else {
+            panic!("expected Xml");
+        };
+        assert_eq!(
+            root.to_pretty_string(),
+            "<note>\n  <to>Tove</to>\n  <from>Jani</from>\n  <body>Don't forget me!</body>\n</note>"
+        );
+        assert_eq!(
+            root.to_json(),
+            json!({"note": {"to": "Tove", "from": "Jani", "body": "Don't forget me!"}})
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let root =
+            root(r#"<book id="42" lang='en'><title>A &amp; B</title><tag/><tag/></book>"#).unwrap();
+        assert_eq!(
+            root.attributes,
+            vec![
+                ("id".to_string(), "42".to_string()),
+                ("lang".to_string(), "en".to_string())
+            ]
+        );
+        assert_eq!(
+            root.to_pretty_string(),
+            "<book id=\"42\" lang=\"en\">\n  <title>A &amp; B</title>\n  <tag/>\n  <tag/>\n</book>"
+        );
+        assert_eq!(
+            root.to_json(),
+            json!({"book": {"@id": "42", "@lang": "en", "title": "A & B", "tag": [null, null]}})
+        );
+    }
+
+    #[test]
+    fn test_mixed_content_and_cdata() {
+        let root = root("<p>Hello <b>world</b><![CDATA[ <raw> ]]></p>").unwrap();
+        assert_eq!(root.children.len(), 3);
+        assert_eq!(root.children[2], XmlNode::Text(" <raw> ".to_string()));
+    }
+
+    #[test]
+    fn test_reject_entity_expansion() {
+        let billion_laughs = r#"<?xml version="1.0"?>
+<!DOCTYPE lolz [
+  <!ENTITY lol "lol">
+  <!ENTITY lol2 "&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;&lol;">
+]>
+<lolz>&lol2;</lolz>"#;
+        assert!(XmlFormat.parse(billion_laughs).is_empty());
+
+        // Undeclared entities aren't expanded either
+        assert!(XmlFormat.parse("<a>&lol;</a>").is_empty());
+    }
+
+    #[test]
+    fn test_reject_prose() {
+        assert!(XmlFormat.parse("a < b").is_empty());
+        assert!(XmlFormat.parse("<3 you>").is_empty());
+        assert!(XmlFormat.parse("<b>bold</b> and <i>italic</i>").is_empty());
+        assert!(XmlFormat.parse("<unclosed>").is_empty());
+        assert!(XmlFormat.parse("<a></b>").is_empty());
+    }
+
+    #[test]
+    fn test_reject_deep_nesting() {
+        let input = format!(
+            "{}{}",
+            "<a>".repeat(MAX_DEPTH + 1),
+            "</a>".repeat(MAX_DEPTH + 1)
+        );
+        assert!(XmlFormat.parse(&input).is_empty());
+    }
+}
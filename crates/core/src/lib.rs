@@ -41,8 +41,12 @@ pub mod convert;
 pub mod expr_context;
 pub mod format;
 pub mod formats;
+pub mod mime;
 pub mod plugin;
+pub mod time;
+pub mod tokenize;
 pub mod types;
+pub mod watch;
 
 /// Truncate a string to at most `max_chars` characters, appending "..." if truncated.
 ///
@@ -58,20 +62,65 @@ pub fn truncate_str(s: &str, max_chars: usize) -> String {
     }
 }
 
-pub use format::{Format, FormatInfo};
+/// Split a format filter into included and excluded format names.
+///
+/// Names prefixed with `!` or `-` exclude that format; all other names are
+/// included. With no included names, every format except the excluded ones
+/// is used.
+///
+/// # Examples
+///
+/// ```
+/// use formatorbit_core::split_format_filter;
+///
+/// let filter = vec!["hex".to_string(), "!text".to_string(), "-coords".to_string()];
+/// let (include, exclude) = split_format_filter(&filter);
+/// assert_eq!(include, vec!["hex"]);
+/// assert_eq!(exclude, vec!["text", "coords"]);
+/// ```
+#[must_use]
+pub fn split_format_filter(format_filter: &[String]) -> (Vec<&str>, Vec<&str>) {
+    let mut include = Vec::new();
+    let mut exclude = Vec::new();
+    for name in format_filter {
+        match name.strip_prefix('!').or_else(|| name.strip_prefix('-')) {
+            Some(excluded) => exclude.push(excluded),
+            None => include.push(name.as_str()),
+        }
+    }
+    (include, exclude)
+}
+
+/// Errors from checking a format filter (`--only`).
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum FormatFilterError {
+    /// No format has this id or alias.
+    #[error("unknown format '{0}'")]
+    UnknownFormat(String),
+
+    /// The same format is both included and excluded.
+    #[error("format '{0}' is both included and excluded")]
+    Conflict(String),
+}
+
+pub use format::{Format, FormatExplanation, FormatInfo};
 pub use plugin::{PluginError, PluginLoadReport, PluginRegistry};
+pub use tokenize::{tokenize, Token, TokenHint};
 pub use types::*;
 
 use formats::{
-    AngleFormat, ArchiveFormat, AreaFormat, AudioFormat, Base64Format, BinaryFormat,
-    BytesToIntFormat, CharFormat, CidrFormat, ColorFormat, ConstantsFormat, CoordsFormat,
-    CronFormat, CuidFormat, CurrencyFormat, DataSizeFormat, DateTimeFormat, DecimalFormat,
-    DurationFormat, EnergyFormat, EpochFormat, EscapeFormat, ExprFormat, FontFormat, GraphFormat,
-    HashFormat, HexFormat, HexdumpFormat, ImageFormat, IpAddrFormat, IsbnFormat, JsonFormat,
-    JwtFormat, LengthFormat, MacAddressFormat, MsgPackFormat, NanoIdFormat, NaturalDateFormat,
-    OctalFormat, OfficeFormat, PdfFormat, PermissionsFormat, PlistFormat, PressureFormat,
-    ProtobufFormat, SpeedFormat, TemperatureFormat, UlidFormat, UrlEncodingFormat, UrlParserFormat,
-    Utf8Format, UuidFormat, VideoFormat, VolumeFormat, WeightFormat,
+    AngleFormat, ArchiveFormat, AreaFormat, AudioFormat, Base32Format, Base64Format, BaseNFormat,
+    BinaryFormat, BytesToIntFormat, CharFormat, ChecksumFormat, CidrFormat, ColorFormat,
+    ConstantsFormat, CoordsFormat, CronFormat, CuidFormat, CurrencyFormat, CusipFormat,
+    DataSizeFormat, DataUriFormat, DateTimeFormat, DecimalFormat, DurationFormat, EnergyFormat,
+    EpochFormat, EscapeFormat, ExprFormat, FontFormat, FractionFormat, GraphFormat, HashFormat,
+    HexFormat, HexdumpFormat, HttpStatusFormat, IbanFormat, ImageFormat, IpAddrFormat, IsbnFormat,
+    IsinFormat, JsonFormat, JwkFormat, JwtFormat, LengthFormat, MacAddressFormat, MorseFormat,
+    MsgPackFormat, MusicNoteFormat, NanoIdFormat, NaturalDateFormat, OctalFormat, OfficeFormat,
+    PanFormat, PcapFormat, PdfFormat, PercentFormat, PermissionsFormat, PlistFormat,
+    PressureFormat, ProtobufFormat, QrPayloadFormat, SemVerFormat, SnowflakeFormat, SpeedFormat,
+    TemperatureFormat, UlidFormat, UrlEncodingFormat, UrlParserFormat, Utf8Format, UuidFormat,
+    VideoFormat, VolumeFormat, WeightFormat, XmlFormat,
 };
 
 /// Main entry point - a configured converter instance.
@@ -112,6 +161,47 @@ impl Formatorbit {
         }
     }
 
+    /// Create a converter with exactly the given formats, in order.
+    ///
+    /// Replaces the built-in list entirely. Order matters: interpretations
+    /// are sorted by confidence, and formats earlier in the list win ties.
+    /// The built-in list is sorted by [`Format::specificity`], putting JWT,
+    /// UUID, IP, ... first and broad fallbacks (text) last; follow the same
+    /// convention.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::formats::HexFormat;
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::with_formats(vec![Box::new(HexFormat)]);
+    /// let results = forb.interpret("0xDEADBEEF");
+    /// assert_eq!(results.len(), 1);
+    /// assert_eq!(results[0].source_format, "hex");
+    /// ```
+    #[must_use]
+    pub fn with_formats(formats: Vec<Box<dyn Format>>) -> Self {
+        Self {
+            formats,
+            config: None,
+            plugins: None,
+        }
+    }
+
+    /// Add a format after all existing ones of equal or higher specificity.
+    ///
+    /// The format takes part in interpretation, conversion and filtering like
+    /// a built-in one. At equal confidence it wins ties against less specific
+    /// formats and loses them against the rest (see [`Format::specificity`]);
+    /// use [`with_formats`](Self::with_formats) to control placement fully.
+    pub fn register_format(&mut self, format: Box<dyn Format>) {
+        let index = self
+            .formats
+            .partition_point(|f| f.specificity() >= format.specificity());
+        self.formats.insert(index, format);
+    }
+
     /// Create a new converter with plugins enabled.
     ///
     /// Loads plugins from `~/.config/forb/plugins/` and any additional
@@ -189,10 +279,13 @@ impl Formatorbit {
         self.config.as_ref()
     }
 
-    /// Create the list of built-in formats.
+    /// Create the list of built-in formats, sorted by specificity.
+    ///
+    /// The sort is stable, so within a specificity tier the order below
+    /// breaks confidence ties.
     fn create_format_list() -> Vec<Box<dyn Format>> {
-        vec![
-            // High-specificity formats first
+        let mut formats: Vec<Box<dyn Format>> = vec![
+            // High-specificity formats
             Box::new(JwtFormat),
             Box::new(UlidFormat),
             Box::new(UuidFormat),
@@ -207,9 +300,14 @@ impl Formatorbit {
             Box::new(PermissionsFormat),
             Box::new(UrlEncodingFormat),
             Box::new(UrlParserFormat),
+            Box::new(QrPayloadFormat),
             Box::new(CronFormat),
-            // Identifier formats (lower specificity)
+            // Identifier formats
+            Box::new(IbanFormat),
+            Box::new(IsinFormat),
+            Box::new(SemVerFormat),
             Box::new(IsbnFormat),
+            Box::new(CusipFormat),
             Box::new(CuidFormat),
             Box::new(NanoIdFormat),
             // Common formats
@@ -217,7 +315,10 @@ impl Formatorbit {
             Box::new(HexFormat),
             Box::new(BinaryFormat),
             Box::new(OctalFormat),
+            Box::new(DataUriFormat),
             Box::new(Base64Format),
+            Box::new(BaseNFormat),
+            Box::new(Base32Format),
             Box::new(EpochFormat),
             Box::new(DecimalFormat),
             Box::new(DataSizeFormat),
@@ -231,17 +332,27 @@ impl Formatorbit {
             Box::new(AngleFormat),
             Box::new(AreaFormat),
             Box::new(EnergyFormat),
+            Box::new(MusicNoteFormat),
             Box::new(CurrencyFormat),
+            Box::new(FractionFormat),
+            Box::new(PercentFormat),
             Box::new(ExprFormat),
             Box::new(EscapeFormat),
+            Box::new(MorseFormat),
             Box::new(DurationFormat),
             Box::new(DateTimeFormat),
             Box::new(JsonFormat),
+            Box::new(XmlFormat),
             Box::new(GraphFormat),
             Box::new(Utf8Format),
             // Conversion-only formats (don't parse strings directly)
             Box::new(BytesToIntFormat),
+            Box::new(ChecksumFormat),
             Box::new(HexdumpFormat),
+            Box::new(HttpStatusFormat),
+            Box::new(SnowflakeFormat),
+            Box::new(PanFormat),
+            Box::new(JwkFormat),
             Box::new(ImageFormat),
             Box::new(MsgPackFormat),
             Box::new(PlistFormat),
@@ -251,9 +362,12 @@ impl Formatorbit {
             Box::new(AudioFormat),
             Box::new(FontFormat),
             Box::new(OfficeFormat),
+            Box::new(PcapFormat),
             Box::new(PdfFormat),
             Box::new(VideoFormat),
-        ]
+        ];
+        formats.sort_by_key(|format| std::cmp::Reverse(format.specificity()));
+        formats
     }
 
     /// Parse input and return all possible interpretations.
@@ -274,37 +388,127 @@ impl Formatorbit {
     /// ```
     #[must_use]
     pub fn interpret(&self, input: &str) -> Vec<Interpretation> {
+        let mut results: Vec<Interpretation> = self
+            .interpretations_by_format(input)
+            .into_iter()
+            .flat_map(|(_, interpretations)| interpretations)
+            .collect();
+
+        // Sort by confidence, highest first
+        results.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        results
+    }
+
+    /// Every format's best confidence for the input, highest first.
+    ///
+    /// Keyed by format id (`epoch`, not the `epoch-seconds` reading it
+    /// produced), for diagnosing why one format beat another. Formats that
+    /// don't match are absent; [`explain`](Self::explain) gives their reasons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let scores = forb.interpret_scored("550e8400-e29b-41d4-a716-446655440000");
+    /// assert_eq!(scores[0].0, "uuid");
+    /// ```
+    #[must_use]
+    pub fn interpret_scored(&self, input: &str) -> Vec<(String, f32)> {
+        let mut scores: Vec<(String, f32)> = self
+            .interpretations_by_format(input)
+            .into_iter()
+            .filter_map(|(id, interpretations)| {
+                let best = interpretations
+                    .iter()
+                    .map(|interp| interp.confidence)
+                    .reduce(f32::max)?;
+                Some((id.to_string(), best))
+            })
+            .collect();
+
+        // Stable, so ties keep specificity order
+        scores.sort_by(|a, b| b.1.total_cmp(&a.1));
+        scores
+    }
+
+    /// Interpretations of the input from each unblocked format and plugin decoder.
+    fn interpretations_by_format(&self, input: &str) -> Vec<(&str, Vec<Interpretation>)> {
+        let is_blocked = |id: &str| {
+            self.config
+                .as_ref()
+                .is_some_and(|config| config.blocking.is_format_blocked(id))
+        };
         let mut results = Vec::new();
 
         // Built-in formats
         for format in &self.formats {
-            // Skip blocked formats
-            if let Some(ref config) = self.config {
-                if config.blocking.is_format_blocked(format.id()) {
-                    continue;
-                }
+            if !is_blocked(format.id()) {
+                results.push((format.id(), self.parse_format(format.as_ref(), input)));
             }
-            results.extend(format.parse(input));
         }
 
         // Plugin decoders
         if let Some(ref plugins) = self.plugins {
             for decoder in plugins.decoders() {
-                // Skip blocked plugins
-                if let Some(ref config) = self.config {
-                    if config.blocking.is_format_blocked(decoder.id()) {
-                        continue;
-                    }
+                if !is_blocked(decoder.id()) {
+                    results.push((
+                        decoder.id(),
+                        self.adjust_confidence(decoder.id(), decoder.parse(input)),
+                    ));
                 }
-                results.extend(decoder.parse(input));
             }
         }
 
-        // Sort by confidence, highest first
-        results.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
         results
     }
 
+    /// Parse input with one format, applying the config's format settings
+    /// and confidence override.
+    fn parse_format(&self, format: &dyn Format, input: &str) -> Vec<Interpretation> {
+        let interpretations = match self.config {
+            Some(ref config) => format.parse_with_config(input, config),
+            None => format.parse(input),
+        };
+        self.adjust_confidence(format.id(), interpretations)
+    }
+
+    /// Apply the configured confidence override for a format to its interpretations.
+    fn adjust_confidence(
+        &self,
+        format_id: &str,
+        mut interpretations: Vec<Interpretation>,
+    ) -> Vec<Interpretation> {
+        let Some(factor) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.confidence_override(format_id))
+        else {
+            return interpretations;
+        };
+        for interp in &mut interpretations {
+            interp.confidence = (interp.confidence * factor).clamp(0.0, 1.0);
+        }
+        interpretations
+    }
+
+    /// The single highest-confidence interpretation of the input, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let best = forb.interpret_best("550e8400-e29b-41d4-a716-446655440000").unwrap();
+    /// assert_eq!(best.source_format, "uuid");
+    /// ```
+    #[must_use]
+    pub fn interpret_best(&self, input: &str) -> Option<Interpretation> {
+        self.interpret(input).into_iter().next()
+    }
+
     /// Find all possible conversions from a value.
     #[must_use]
     pub fn convert(&self, value: &CoreValue) -> Vec<Conversion> {
@@ -333,6 +537,81 @@ impl Formatorbit {
         conversions
     }
 
+    /// Canonical representation of an interpretation in its own source format.
+    ///
+    /// [`convert_excluding`](Self::convert_excluding) skips the source format to
+    /// avoid hex→hex noise, but the normalized form is sometimes what you want
+    /// (e.g., `de:ad:be:ef` → `DEADBEEF`). Returns `None` if the source format
+    /// can't render its own value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let interp = forb.interpret_filtered("de:ad:be:ef", &["hex".into()]).remove(0);
+    /// let source = forb.source_representation(&interp).unwrap();
+    /// assert_eq!(source.display, "DEADBEEF");
+    /// ```
+    #[must_use]
+    pub fn source_representation(&self, interp: &Interpretation) -> Option<Conversion> {
+        let id = interp.source_format.as_str();
+        let format = self.formats.iter().find(|f| f.id() == id)?;
+
+        let display = if format.can_format(&interp.value) {
+            format.format(&interp.value)
+        } else {
+            None
+        };
+        let mut conv = match display {
+            Some(display) => Conversion::new(interp.value.clone(), id, display),
+            None => match self.config {
+                Some(ref config) => format.conversions_with_config(&interp.value, config),
+                None => format.conversions(&interp.value),
+            }
+            .into_iter()
+            .find(|c| c.target_format == id)?,
+        };
+
+        conv.path = vec![id.to_string(), id.to_string()];
+        conv.priority = ConversionPriority::Primary;
+        conv.kind = ConversionKind::Representation;
+        Some(conv)
+    }
+
+    /// Notations that could have produced a value: the inverse of [`convert`](Self::convert).
+    ///
+    /// A conversion is invertible when it only re-renders the value, i.e. its
+    /// result carries the original value unchanged (e.g., `dms` or `geohash`
+    /// for coordinates, `color-rgb` for colors). Transformations into other
+    /// values, such as hashes or unit-less numbers, are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::{CoreValue, Formatorbit};
+    ///
+    /// let forb = Formatorbit::new();
+    /// let sources = forb.reverse_interpret(&CoreValue::Coordinates { lat: 59.3293, lon: 18.0686 });
+    /// assert!(sources.contains(&"dms".to_string()));
+    /// ```
+    #[must_use]
+    pub fn reverse_interpret(&self, value: &CoreValue) -> Vec<String> {
+        let mut sources: Vec<String> = Vec::new();
+        for format in &self.formats {
+            for conv in format.conversions(value) {
+                if conv.kind != ConversionKind::Trait
+                    && conv.value == *value
+                    && !sources.contains(&conv.target_format)
+                {
+                    sources.push(conv.target_format);
+                }
+            }
+        }
+        sources
+    }
+
     /// Get trait conversions from plugins.
     #[cfg(feature = "python")]
     fn get_plugin_traits(
@@ -374,6 +653,7 @@ impl Formatorbit {
                     display: description.clone(),
                     path: vec![source_format.to_string(), trait_plugin.id().to_string()],
                     is_lossy: false,
+                    confidence: 1.0,
                     steps: vec![ConversionStep {
                         format: trait_plugin.id().to_string(),
                         value: value.clone(),
@@ -383,6 +663,7 @@ impl Formatorbit {
                     kind: ConversionKind::Trait,
                     display_only: true,
                     hidden: false,
+                    depth_limited: false,
                     rich_display: vec![],
                 });
             }
@@ -417,20 +698,104 @@ impl Formatorbit {
     /// ```
     #[must_use]
     pub fn convert_all(&self, input: &str) -> Vec<ConversionResult> {
-        self.interpret(input)
+        self.convert_all_iter(input).collect()
+    }
+
+    /// Lazy version of [`convert_all`](Self::convert_all).
+    ///
+    /// Interpretations are parsed and ranked up front, but the conversion
+    /// search for each one only runs when the iterator reaches it. Use this
+    /// with `.take(n)` or `.find(...)` to skip the BFS for results you don't need.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let uuid = forb
+    ///     .convert_all_iter("550e8400-e29b-41d4-a716-446655440000")
+    ///     .find(|r| r.interpretation.source_format == "uuid")
+    ///     .unwrap();
+    /// assert!(!uuid.conversions.is_empty());
+    /// ```
+    pub fn convert_all_iter<'a>(
+        &'a self,
+        input: &'a str,
+    ) -> impl Iterator<Item = ConversionResult> + 'a {
+        self.convert_interpretations(input, self.interpret(input))
+    }
+
+    /// Like [`convert_all`](Self::convert_all), but only for interpretations
+    /// with confidence above `min_confidence`.
+    ///
+    /// Low-confidence interpretations are dropped before the conversion search,
+    /// so noise like the plain-text fallback costs nothing. Passing `0.0` only
+    /// drops zero-confidence interpretations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let results = forb.convert_all_above("hello", 0.3);
+    /// assert!(results.iter().all(|r| r.interpretation.confidence > 0.3));
+    /// ```
+    #[must_use]
+    pub fn convert_all_above(&self, input: &str, min_confidence: f32) -> Vec<ConversionResult> {
+        let interpretations = self
+            .interpret(input)
             .into_iter()
-            .map(|interp| {
-                // Skip self-conversion (e.g., hex→hex)
-                let conversions = self.convert_excluding(&interp.value, &interp.source_format);
-                ConversionResult {
-                    input: input.to_string(),
-                    interpretation: interp,
-                    conversions,
-                }
-            })
+            .filter(|interp| interp.confidence > min_confidence)
+            .collect();
+        self.convert_interpretations(input, interpretations)
             .collect()
     }
 
+    /// Convert only the highest-confidence interpretation.
+    ///
+    /// Returns `None` when nothing reaches `min_confidence`. The conversion
+    /// search runs for the winner alone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let best = forb.convert_best("550e8400-e29b-41d4-a716-446655440000", 0.2).unwrap();
+    /// assert_eq!(best.interpretation.source_format, "uuid");
+    /// assert!(!best.conversions.is_empty());
+    /// ```
+    #[must_use]
+    pub fn convert_best(&self, input: &str, min_confidence: f32) -> Option<ConversionResult> {
+        let best = self
+            .interpret_best(input)
+            .filter(|interp| interp.confidence >= min_confidence)?;
+        self.convert_interpretations(input, vec![best]).next()
+    }
+
+    /// Run the conversion search for each interpretation, lazily.
+    fn convert_interpretations<'a>(
+        &'a self,
+        input: &'a str,
+        interpretations: Vec<Interpretation>,
+    ) -> impl Iterator<Item = ConversionResult> + 'a {
+        interpretations.into_iter().map(move |interp| {
+            // Skip self-conversion (e.g., hex→hex)
+            let mut conversions = self.convert_excluding(&interp.value, &interp.source_format);
+            if let Some(format) = self.formats.iter().find(|f| f.id() == interp.source_format) {
+                format.annotate_conversions(input, &interp, &mut conversions);
+            }
+            ConversionResult {
+                input: input.to_string(),
+                interpretation: interp,
+                conversions,
+            }
+        })
+    }
+
     /// Convert raw bytes and return all possible interpretations.
     ///
     /// This creates a single bytes interpretation and runs the conversion graph.
@@ -461,44 +826,37 @@ impl Formatorbit {
         self.convert_bytes_internal(data, format_filter)
     }
 
-    /// Internal: Convert raw bytes with optional format filter.
+    /// Interpret raw bytes with the formats that read binary data.
     ///
-    /// Creates interpretations directly from bytes:
-    /// 1. Try specialized binary formats (image, archive, etc.)
-    /// 2. Fall back to generic "bytes" interpretation
-    fn convert_bytes_internal(
+    /// Bytes go to each format's [`Format::parse_bytes`] as-is, without a
+    /// base64 round trip. Returns interpretations sorted by confidence
+    /// (highest first), or nothing if no binary format recognizes the data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let pdf = b"%PDF-1.7\n%%EOF\n";
+    /// let interps = forb.interpret_bytes(pdf);
+    /// assert_eq!(interps[0].source_format, "pdf");
+    /// ```
+    #[must_use]
+    pub fn interpret_bytes(&self, data: &[u8]) -> Vec<Interpretation> {
+        self.interpret_bytes_internal(data, &[])
+    }
+
+    /// Internal: Interpret raw bytes with optional format filter.
+    fn interpret_bytes_internal(
         &self,
         data: &[u8],
         format_filter: &[String],
-    ) -> Vec<ConversionResult> {
-        use base64::Engine;
-
-        // For specialized formats (image, archive, etc.), we need to pass
-        // the data as base64 since they expect string input.
-        // But we only create ONE interpretation to avoid duplicate processing.
-        let base64_input = base64::engine::general_purpose::STANDARD.encode(data);
-
+    ) -> Vec<Interpretation> {
+        let (include, exclude) = split_format_filter(format_filter);
         let mut interpretations = Vec::new();
-
-        // Try specialized binary formats that can parse base64-encoded data
-        let binary_formats = [
-            "image", "archive", "video", "audio", "font", "pdf", "office",
-        ];
-
         for format in &self.formats {
-            // If filter is active, check if format matches
-            if !format_filter.is_empty() {
-                let matches = format_filter.iter().any(|name| format.matches_name(name));
-                if !matches {
-                    continue;
-                }
-            }
-
-            // Only try formats that handle binary data
-            let is_binary_format = binary_formats
-                .iter()
-                .any(|&bf| format.id() == bf || format.aliases().contains(&bf));
-            if !is_binary_format {
+            if !Self::filter_allows(format.as_ref(), &include, &exclude) {
                 continue;
             }
 
@@ -509,9 +867,28 @@ impl Formatorbit {
                 }
             }
 
-            interpretations.extend(format.parse(&base64_input));
+            interpretations.extend(format.parse_bytes(data));
         }
 
+        // Sort by confidence, highest first
+        interpretations.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        interpretations
+    }
+
+    /// Internal: Convert raw bytes with optional format filter.
+    ///
+    /// Creates interpretations directly from bytes:
+    /// 1. Try specialized binary formats (image, archive, etc.)
+    /// 2. Fall back to generic "bytes" interpretation
+    fn convert_bytes_internal(
+        &self,
+        data: &[u8],
+        format_filter: &[String],
+    ) -> Vec<ConversionResult> {
+        use base64::Engine;
+
+        let mut interpretations = self.interpret_bytes_internal(data, format_filter);
+
         // If no specialized format matched, create a generic bytes interpretation
         if interpretations.is_empty() {
             interpretations.push(Interpretation {
@@ -523,8 +900,8 @@ impl Formatorbit {
             });
         }
 
-        // Sort by confidence, highest first
-        interpretations.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+        // Results echo the input as text, so binary input is shown as base64
+        let base64_input = base64::engine::general_purpose::STANDARD.encode(data);
 
         // Convert each interpretation
         interpretations
@@ -546,6 +923,15 @@ impl Formatorbit {
         self.formats.iter().map(|f| f.info()).collect()
     }
 
+    /// Get info about one format by id or alias (e.g. `hex`, `b64`).
+    #[must_use]
+    pub fn format_info(&self, name: &str) -> Option<FormatInfo> {
+        self.formats
+            .iter()
+            .find(|f| f.matches_name(name))
+            .map(|f| f.info())
+    }
+
     /// Get info about formats that have validation support.
     ///
     /// These formats provide detailed error messages when `--only` is used
@@ -559,21 +945,89 @@ impl Formatorbit {
             .collect()
     }
 
+    /// Explain, for every built-in format, whether it matched the input and why.
+    ///
+    /// Each entry carries the best confidence the format assigned (if any) and
+    /// its [`Format::explain`] reason, falling back to [`Format::validate`] for
+    /// rejected input. Useful when an input is detected as something unexpected.
+    #[must_use]
+    pub fn explain(&self, input: &str) -> Vec<FormatExplanation> {
+        self.formats
+            .iter()
+            .map(|format| {
+                let confidence = format
+                    .parse(input)
+                    .iter()
+                    .map(|interp| interp.confidence)
+                    .reduce(f32::max);
+                let reason = format.explain(input).or_else(|| {
+                    if confidence.is_none() {
+                        format.validate(input)
+                    } else {
+                        None
+                    }
+                });
+                FormatExplanation {
+                    format: format.id(),
+                    confidence,
+                    reason,
+                }
+            })
+            .collect()
+    }
+
+    /// Check whether a format passes a filter (see [`split_format_filter`]).
+    fn filter_allows(format: &dyn Format, include: &[&str], exclude: &[&str]) -> bool {
+        (include.is_empty() || include.iter().any(|name| format.matches_name(name)))
+            && !exclude.iter().any(|name| format.matches_name(name))
+    }
+
+    /// Check that every name in a format filter resolves to a format and
+    /// that no format is both included and excluded.
+    pub fn validate_format_filter(
+        &self,
+        format_filter: &[String],
+    ) -> Result<(), FormatFilterError> {
+        let (include, exclude) = split_format_filter(format_filter);
+        for name in include.iter().chain(&exclude) {
+            if !self.is_valid_format(name) {
+                return Err(FormatFilterError::UnknownFormat((*name).to_string()));
+            }
+        }
+
+        for format in &self.formats {
+            let included = include.iter().any(|name| format.matches_name(name));
+            if included && exclude.iter().any(|name| format.matches_name(name)) {
+                return Err(FormatFilterError::Conflict(format.id().to_string()));
+            }
+        }
+        Ok(())
+    }
+
     /// Parse input with only the specified formats (by id or alias).
-    /// If `format_filter` is empty, all formats are used.
+    /// If `format_filter` is empty, all formats are used. Names prefixed with
+    /// `!` or `-` exclude a format instead.
     #[must_use]
     pub fn interpret_filtered(&self, input: &str, format_filter: &[String]) -> Vec<Interpretation> {
         if format_filter.is_empty() {
             return self.interpret(input);
         }
 
+        let (include, exclude) = split_format_filter(format_filter);
         let mut results = Vec::new();
         for format in &self.formats {
-            // Check if this format matches any of the filter names
-            let matches = format_filter.iter().any(|name| format.matches_name(name));
-            if matches {
-                results.extend(format.parse(input));
+            if !Self::filter_allows(format.as_ref(), &include, &exclude) {
+                continue;
+            }
+            // Exclude-only filters narrow the default set, so blocked formats stay off
+            if include.is_empty() {
+                if let Some(ref config) = self.config {
+                    if config.blocking.is_format_blocked(format.id()) {
+                        continue;
+                    }
+                }
             }
+            results.extend(self.parse_format(format.as_ref(), input));
         }
         // Sort by confidence, highest first
         results.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
@@ -581,6 +1035,8 @@ impl Formatorbit {
     }
 
     /// Combined: interpret input (with filter) and find all conversions.
+    ///
+    /// The filter works as in [`Self::interpret_filtered`].
     #[must_use]
     pub fn convert_all_filtered(
         &self,
@@ -601,7 +1057,61 @@ impl Formatorbit {
             .collect()
     }
 
-    /// Validate input for a specific format and return an error message if invalid.
+    /// Convert input read as one format into another, when both ends are known.
+    ///
+    /// Interprets `input` only as `from` and returns the conversion whose
+    /// target is `to`, taking the shortest path when several lead there.
+    /// Both may be format ids or aliases; `to` may also name a conversion
+    /// target that isn't a format of its own (e.g. `epoch-millis`).
+    ///
+    /// Returns `None` if `input` isn't valid `from`, or no conversion reaches `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// let conversion = forb.convert_between("691E01B8", "hex", "base64").unwrap();
+    /// assert_eq!(conversion.display, "aR4BuA==");
+    /// ```
+    #[must_use]
+    pub fn convert_between(&self, input: &str, from: &str, to: &str) -> Option<Conversion> {
+        let target_id = self
+            .formats
+            .iter()
+            .find(|f| f.matches_name(to))
+            .map_or(to, |f| f.id());
+
+        self.convert_all_filtered(input, &[from.to_string()])
+            .into_iter()
+            .flat_map(|result| result.conversions)
+            .filter(|c| c.target_format == to || c.target_format == target_id)
+            .min_by_key(|c| c.path.len())
+    }
+
+    /// Peel encoding layers off an input (e.g., base64 → gzip → JSON).
+    ///
+    /// Decodes and decompresses repeatedly until the value is JSON, readable
+    /// text, or an image. Returns `None` if the input isn't wrapped in any
+    /// known layer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use formatorbit_core::Formatorbit;
+    ///
+    /// let forb = Formatorbit::new();
+    /// // base64 of hex of "Hello"
+    /// let result = forb.unwrap_layers("NDg2NTZjNmM2Zg==").unwrap();
+    /// assert_eq!(result.chain(), "base64 → hex → text");
+    /// ```
+    #[must_use]
+    pub fn unwrap_layers(&self, input: &str) -> Option<UnwrapResult> {
+        convert::unwrap_layers(&self.formats, input)
+    }
+
+    /// Validate input for a specific format and return an error message if invalid.
     ///
     /// This is useful when a user requests a specific format (e.g., `--only json`)
     /// and we want to explain why parsing failed.
@@ -616,6 +1126,19 @@ impl Formatorbit {
         None
     }
 
+    /// Check whether a format filter lets the format with this id through.
+    ///
+    /// Resolves aliases and `!`/`-` exclusions like [`Self::interpret_filtered`];
+    /// unknown ids are never allowed.
+    #[must_use]
+    pub fn format_filter_allows(&self, format_id: &str, format_filter: &[String]) -> bool {
+        let (include, exclude) = split_format_filter(format_filter);
+        self.formats
+            .iter()
+            .find(|f| f.id() == format_id)
+            .is_some_and(|format| Self::filter_allows(format.as_ref(), &include, &exclude))
+    }
+
     /// Check if a format name (id or alias) is valid.
     #[must_use]
     pub fn is_valid_format(&self, name: &str) -> bool {
@@ -639,6 +1162,119 @@ impl Default for Formatorbit {
 mod tests {
     use super::*;
 
+    type ParseFn = dyn Fn(&str) -> Vec<Interpretation> + Send + Sync;
+    type ConvertFn = dyn Fn(&CoreValue) -> Vec<Conversion> + Send + Sync;
+
+    /// Stand-in format for tests: parses and converts through closures,
+    /// and does nothing unless told to.
+    pub(crate) struct TestFormat {
+        id: &'static str,
+        specificity: u32,
+        parse: Box<ParseFn>,
+        conversions: Box<ConvertFn>,
+        source_conversions: Box<ConvertFn>,
+    }
+
+    impl TestFormat {
+        pub(crate) fn new(id: &'static str) -> Self {
+            Self {
+                id,
+                specificity: format::specificity::DEFAULT,
+                parse: Box::new(|_| vec![]),
+                conversions: Box::new(|_| vec![]),
+                source_conversions: Box::new(|_| vec![]),
+            }
+        }
+
+        pub(crate) fn parsing(
+            mut self,
+            parse: impl Fn(&str) -> Vec<Interpretation> + Send + Sync + 'static,
+        ) -> Self {
+            self.parse = Box::new(parse);
+            self
+        }
+
+        pub(crate) fn converting(
+            mut self,
+            conversions: impl Fn(&CoreValue) -> Vec<Conversion> + Send + Sync + 'static,
+        ) -> Self {
+            self.conversions = Box::new(conversions);
+            self
+        }
+
+        pub(crate) fn with_source_conversions(
+            mut self,
+            source_conversions: impl Fn(&CoreValue) -> Vec<Conversion> + Send + Sync + 'static,
+        ) -> Self {
+            self.source_conversions = Box::new(source_conversions);
+            self
+        }
+
+        pub(crate) fn with_specificity(mut self, specificity: u32) -> Self {
+            self.specificity = specificity;
+            self
+        }
+    }
+
+    impl Format for TestFormat {
+        fn id(&self) -> &'static str {
+            self.id
+        }
+
+        fn name(&self) -> &'static str {
+            self.id
+        }
+
+        fn parse(&self, input: &str) -> Vec<Interpretation> {
+            (self.parse)(input)
+        }
+
+        fn can_format(&self, _value: &CoreValue) -> bool {
+            false
+        }
+
+        fn format(&self, _value: &CoreValue) -> Option<String> {
+            None
+        }
+
+        fn conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+            (self.conversions)(value)
+        }
+
+        fn source_conversions(&self, value: &CoreValue) -> Vec<Conversion> {
+            (self.source_conversions)(value)
+        }
+
+        fn specificity(&self) -> u32 {
+            self.specificity
+        }
+    }
+
+    /// Reads any input as itself.
+    fn echo_format(id: &'static str) -> TestFormat {
+        TestFormat::new(id).parsing(move |input| {
+            vec![Interpretation::new(
+                CoreValue::String(input.to_string()),
+                id,
+                0.5,
+                "Echo",
+            )]
+        })
+    }
+
+    /// Reads text ending in !!! as the shouted word.
+    fn shout_format() -> TestFormat {
+        TestFormat::new("shout").parsing(|input| match input.strip_suffix("!!!") {
+            Some(word) => vec![Interpretation::new(
+                CoreValue::String(word.to_string()),
+                "shout",
+                0.99,
+                "Shouted word",
+            )],
+            None => vec![],
+        })
+    }
+
     /// Regression test: SHA-1 hash detection should appear in results
     #[test]
     fn test_sha1_hash_interpretation() {
@@ -687,4 +1323,566 @@ mod tests {
             "should have text interpretation"
         );
     }
+
+    #[test]
+    fn test_convert_all_iter_is_lazy() {
+        // Yields several interpretations and counts conversion searches
+        // (source_conversions runs exactly once per search)
+        let searches = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = searches.clone();
+        let probe = TestFormat::new("probe")
+            .parsing(|_| {
+                (0..3)
+                    .map(|i| {
+                        Interpretation::new(
+                            CoreValue::Int {
+                                value: i,
+                                original_bytes: None,
+                            },
+                            "probe",
+                            0.5,
+                            format!("probe {}", i),
+                        )
+                    })
+                    .collect()
+            })
+            .with_source_conversions(move |_| {
+                counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                vec![]
+            });
+        let forb = Formatorbit::with_formats(vec![Box::new(probe)]);
+
+        let first: Vec<_> = forb.convert_all_iter("anything").take(1).collect();
+        assert_eq!(first.len(), 1);
+        assert_eq!(searches.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        assert_eq!(forb.convert_all("anything").len(), 3);
+        assert_eq!(searches.load(std::sync::atomic::Ordering::SeqCst), 4);
+    }
+
+    /// Base64 that decodes to an image should surface the image metadata
+    #[test]
+    fn test_base64_png_surfaces_image() {
+        let forb = Formatorbit::new();
+        // 1x1 PNG
+        let results = forb.convert_all(
+            "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==",
+        );
+
+        let image = results
+            .iter()
+            .find(|r| r.interpretation.source_format == "image")
+            .expect("should have image interpretation");
+        assert!(image.interpretation.description.contains("PNG 1×1"));
+
+        let base64 = results
+            .iter()
+            .find(|r| r.interpretation.source_format == "base64")
+            .expect("should have base64 interpretation");
+        let info = base64
+            .conversions
+            .iter()
+            .find(|c| c.target_format == "image-info")
+            .expect("base64 bytes should convert to image-info");
+        assert!(info.display.contains("PNG 1×1"));
+    }
+
+    fn filter(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_filter_exclude_only() {
+        let forb = Formatorbit::new();
+        let all = forb.interpret("12345");
+        assert!(all.iter().any(|i| i.source_format == "text"));
+
+        let results = forb.interpret_filtered("12345", &filter(&["!text", "-decimal"]));
+        assert!(!results.is_empty());
+        assert!(results
+            .iter()
+            .all(|i| i.source_format != "text" && i.source_format != "decimal"));
+    }
+
+    #[test]
+    fn test_filter_mixed_include_exclude() {
+        let forb = Formatorbit::new();
+        let mixed = filter(&["hex", "b64", "!text"]);
+        assert_eq!(forb.validate_format_filter(&mixed), Ok(()));
+        let results = forb.interpret_filtered("deadbeef", &mixed);
+        let sources: Vec<&str> = results.iter().map(|i| i.source_format.as_str()).collect();
+        assert_eq!(sources, vec!["hex"]);
+    }
+
+    #[test]
+    fn test_format_filter_allows() {
+        let forb = Formatorbit::new();
+        assert!(forb.format_filter_allows("datasize", &[]));
+        assert!(forb.format_filter_allows("datasize", &filter(&["size"])));
+        assert!(forb.format_filter_allows("datasize", &filter(&["!hex"])));
+        assert!(!forb.format_filter_allows("datasize", &filter(&["-size"])));
+        assert!(!forb.format_filter_allows("datasize", &filter(&["hex"])));
+        assert!(!forb.format_filter_allows("nope", &[]));
+    }
+
+    #[test]
+    fn test_filter_include_and_exclude_same_format_conflicts() {
+        let forb = Formatorbit::new();
+        // Exclusions resolve aliases too: "b64" and "base64url" are base64
+        for excluded in ["!b64", "!base64url"] {
+            assert_eq!(
+                forb.validate_format_filter(&filter(&["hex", "b64", excluded])),
+                Err(FormatFilterError::Conflict("base64".to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_format_filter() {
+        let forb = Formatorbit::new();
+        assert_eq!(
+            forb.validate_format_filter(&filter(&["hex", "!text"])),
+            Ok(())
+        );
+        assert_eq!(
+            forb.validate_format_filter(&filter(&["!nope"])),
+            Err(FormatFilterError::UnknownFormat("nope".to_string()))
+        );
+        assert_eq!(
+            forb.validate_format_filter(&filter(&["b64", "!base64"])),
+            Err(FormatFilterError::Conflict("base64".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_format_info_by_id_and_alias() {
+        let forb = Formatorbit::new();
+        assert_eq!(forb.format_info("hex").unwrap().id, "hex");
+        assert_eq!(forb.format_info("b64").unwrap().id, "base64");
+        assert!(forb.format_info("nope").is_none());
+    }
+
+    #[test]
+    fn test_convert_all_above_drops_text_fallback() {
+        let forb = Formatorbit::new();
+        let has_text = |results: &[ConversionResult]| {
+            results
+                .iter()
+                .any(|r| r.interpretation.source_format == "text")
+        };
+
+        assert!(!has_text(&forb.convert_all_above("hello", 0.3)));
+        assert!(has_text(&forb.convert_all_above("hello", 0.0)));
+        assert_eq!(
+            forb.convert_all_above("hello", 0.0).len(),
+            forb.convert_all("hello").len()
+        );
+    }
+
+    #[test]
+    fn test_interpret_best() {
+        let forb = Formatorbit::new();
+        let uuid = "550e8400-e29b-41d4-a716-446655440000";
+
+        assert_eq!(forb.interpret_best(uuid).unwrap().source_format, "uuid");
+        let best = forb.convert_best(uuid, 0.2).unwrap();
+        assert_eq!(best.interpretation.source_format, "uuid");
+        // Nothing clears an impossible threshold
+        assert!(forb.convert_best(uuid, 1.1).is_none());
+
+        assert!(forb.interpret_best("").is_none());
+        assert!(forb.convert_best("", 0.0).is_none());
+    }
+
+    #[test]
+    fn test_scientific_notation_coexists_with_hex() {
+        let forb = Formatorbit::new();
+        let formats: Vec<_> = forb
+            .interpret("1e3")
+            .into_iter()
+            .map(|i| i.source_format)
+            .collect();
+        assert!(formats.contains(&"hex".to_string()), "{formats:?}");
+        assert!(formats.contains(&"decimal".to_string()), "{formats:?}");
+
+        let results = forb.convert_all("6.022e23");
+        assert_eq!(results[0].interpretation.source_format, "decimal");
+    }
+
+    #[test]
+    fn test_interpret_scored() {
+        let forb = Formatorbit::new();
+        let scores = forb.interpret_scored("550e8400-e29b-41d4-a716-446655440000");
+
+        assert_eq!(scores[0].0, "uuid");
+        assert!(scores.windows(2).all(|w| w[0].1 >= w[1].1));
+        // Each format appears once, with its best reading
+        let ids: std::collections::HashSet<_> = scores.iter().map(|(id, _)| id).collect();
+        assert_eq!(ids.len(), scores.len());
+        // Unrelated formats don't score
+        for unrelated in ["epoch", "color", "ip", "json"] {
+            assert!(
+                !scores.iter().any(|(id, c)| id == unrelated && *c > 0.0),
+                "{} scored: {:?}",
+                unrelated,
+                scores
+            );
+        }
+    }
+
+    #[test]
+    fn test_checksums_only_for_root_bytes() {
+        let results = Formatorbit::new().convert_all("550e8400-e29b-41d4-a716-446655440000");
+        let uuid = results
+            .iter()
+            .find(|r| r.interpretation.source_format == "uuid")
+            .unwrap();
+        let crc32: Vec<_> = uuid
+            .conversions
+            .iter()
+            .filter(|c| c.target_format == "checksum-crc32")
+            .collect();
+        // Only the UUID's 16 bytes, not its text reached via uuid → bytes
+        assert_eq!(crc32.len(), 1);
+        assert_eq!(crc32[0].path, ["uuid", "checksum-crc32"]);
+    }
+
+    #[test]
+    fn test_color_word_keeps_text_interpretation() {
+        let forb = Formatorbit::new();
+        let formats: Vec<_> = forb
+            .interpret("red")
+            .into_iter()
+            .map(|i| i.source_format)
+            .collect();
+        assert_eq!(formats.first().map(String::as_str), Some("text"));
+        assert!(formats.iter().any(|f| f == "color-name"));
+    }
+
+    #[test]
+    fn test_register_format() {
+        let mut forb = Formatorbit::new();
+        assert!(!forb.is_valid_format("shout"));
+
+        forb.register_format(Box::new(shout_format()));
+        assert!(forb.is_valid_format("shout"));
+
+        let results = forb.interpret("HEY!!!");
+        assert_eq!(results[0].source_format, "shout");
+        assert!(forb
+            .interpret_filtered("HEY!!!", &filter(&["shout"]))
+            .iter()
+            .all(|i| i.source_format == "shout"));
+    }
+
+    #[test]
+    fn test_with_formats_replaces_builtins() {
+        let forb = Formatorbit::with_formats(vec![Box::new(shout_format())]);
+        assert_eq!(forb.format_ids(), vec!["shout"]);
+        assert!(forb.interpret("0xDEADBEEF").is_empty());
+    }
+
+    #[test]
+    fn test_specificity_breaks_confidence_ties() {
+        let mut forb = Formatorbit::with_formats(vec![]);
+        forb.register_format(Box::new(
+            echo_format("broad").with_specificity(format::specificity::DEFAULT),
+        ));
+        forb.register_format(Box::new(
+            echo_format("narrow").with_specificity(format::specificity::HIGH),
+        ));
+        forb.register_format(Box::new(
+            echo_format("broad2").with_specificity(format::specificity::DEFAULT),
+        ));
+        assert_eq!(forb.format_ids(), vec!["narrow", "broad", "broad2"]);
+
+        let results = forb.interpret("anything");
+        assert_eq!(results[0].source_format, "narrow");
+        assert_eq!(results[0].confidence, results[1].confidence);
+    }
+
+    #[test]
+    fn test_builtin_formats_sorted_by_specificity() {
+        let forb = Formatorbit::new();
+        assert!(forb
+            .formats
+            .windows(2)
+            .all(|pair| pair[0].specificity() >= pair[1].specificity()));
+        assert_eq!(forb.formats[0].id(), "jwt");
+    }
+
+    #[test]
+    fn test_convert_between() {
+        let forb = Formatorbit::new();
+
+        let conversion = forb.convert_between("Hello", "text", "base64").unwrap();
+        assert_eq!(conversion.display, "SGVsbG8=");
+        assert_eq!(conversion.target_format, "base64");
+
+        // Aliases on both ends
+        let conversion = forb.convert_between("Hello", "str", "b64").unwrap();
+        assert_eq!(conversion.display, "SGVsbG8=");
+
+        // Shortest path wins
+        let conversion = forb
+            .convert_between("1703456789", "decimal", "hex-int")
+            .unwrap();
+        assert_eq!(conversion.path, ["decimal", "hex-int"]);
+
+        assert!(forb.convert_between("Hello", "uuid", "base64").is_none());
+        assert!(forb.convert_between("Hello", "text", "nope").is_none());
+    }
+
+    #[test]
+    fn test_reverse_interpret_coordinates() {
+        let forb = Formatorbit::new();
+        let sources = forb.reverse_interpret(&CoreValue::Coordinates {
+            lat: 59.3293,
+            lon: 18.0686,
+        });
+        for expected in ["dd", "dms", "geohash"] {
+            assert!(
+                sources.contains(&expected.to_string()),
+                "expected {} in {:?}",
+                expected,
+                sources
+            );
+        }
+        assert!(!sources.iter().any(|s| s.starts_with("sha")));
+    }
+
+    #[test]
+    fn test_explain_epoch_input() {
+        let forb = Formatorbit::new();
+        let explanations = forb.explain("1704067200");
+        assert_eq!(explanations.len(), forb.formats.len());
+        let find = |id: &str| explanations.iter().find(|e| e.format == id).unwrap();
+
+        let coords = find("coords");
+        assert_eq!(coords.confidence, None);
+        let reason = coords.reason.as_deref().unwrap();
+        assert!(reason.contains("geohash"), "{}", reason);
+        assert!(reason.contains("plain number"), "{}", reason);
+
+        let epoch = find("epoch");
+        assert!(epoch.confidence.is_some());
+        assert!(epoch.reason.as_deref().unwrap().contains("2024-01-01"));
+
+        let isbn = find("isbn");
+        assert_eq!(isbn.confidence, Some(0.40));
+        assert!(isbn.reason.as_deref().unwrap().contains("epoch range"));
+    }
+
+    #[test]
+    fn test_max_depth_limits_conversions() {
+        // base64 → bytes → utf8 → hex → ... chains deeply
+        let input = "SGVsbG8sIFdvcmxkIQ==";
+        let unbounded = Formatorbit::new().convert_all_filtered(input, &filter(&["base64"]));
+
+        let config = ConversionConfig {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+        let shallow =
+            Formatorbit::with_config(config).convert_all_filtered(input, &filter(&["base64"]));
+
+        assert!(shallow[0].conversions.len() < unbounded[0].conversions.len());
+        assert!(shallow[0].conversions.iter().all(|c| c.path.len() <= 2));
+        assert!(shallow[0].conversions.iter().any(|c| c.depth_limited));
+        assert!(!unbounded[0].conversions.iter().any(|c| c.depth_limited));
+    }
+
+    #[test]
+    fn test_display_timezone_adds_local_time() {
+        let config = ConversionConfig {
+            display_timezone: Some("America/New_York".to_string()),
+            ..Default::default()
+        };
+        let forb = Formatorbit::with_config(config);
+
+        let results = forb.convert_all_filtered("1703456789", &filter(&["epoch"]));
+        let local = results[0]
+            .conversions
+            .iter()
+            .find(|c| c.target_format == "timezone")
+            .expect("timezone conversion");
+        // 2023-12-24T22:26:29Z is 17:26:29 EST (UTC-5)
+        assert_eq!(
+            local.display,
+            "2023-12-24T17:26:29-05:00 (America/New_York)"
+        );
+
+        // Without a configured zone there's no extra line
+        let results = Formatorbit::new().convert_all_filtered("1703456789", &filter(&["epoch"]));
+        assert!(!results[0]
+            .conversions
+            .iter()
+            .any(|c| c.target_format == "timezone"));
+    }
+
+    #[test]
+    fn test_custom_rates_used_for_currency() {
+        let config = ConversionConfig {
+            custom_rates: std::collections::HashMap::from([("EUR".to_string(), 1.25)]),
+            ..Default::default()
+        };
+        let forb = Formatorbit::with_config(config);
+
+        let results = forb.convert_all_filtered("100 USD", &filter(&["currency"]));
+        let eur = results[0]
+            .conversions
+            .iter()
+            .find(|c| c.target_format == "eur")
+            .expect("EUR conversion from pinned rate");
+        let CoreValue::Currency { amount, .. } = eur.value else {
+            panic!("Expected currency value");
+        };
+        assert!((amount - 80.0).abs() < 1e-9, "got {}", amount);
+
+        // Pinned rates belong to that converter, not the process
+        let results = Formatorbit::new().convert_all_filtered("100 USD", &filter(&["currency"]));
+        assert!(!results[0].conversions.iter().any(|c| {
+            matches!(c.value, CoreValue::Currency { amount, .. } if (amount - 80.0).abs() < 1e-9)
+        }));
+    }
+
+    #[test]
+    fn test_color_alpha_order_config() {
+        let forb = Formatorbit::with_config(ConversionConfig {
+            color_alpha_order: ColorAlphaOrder::ArgbFirst,
+            ..Default::default()
+        });
+        let best = forb.interpret_best("#80FF0000").unwrap();
+        assert_eq!(best.source_format, "color-hex");
+        assert_eq!(best.value, CoreValue::Bytes(vec![255, 0, 0, 128]));
+    }
+
+    #[test]
+    fn test_terse_relative_time_config() {
+        let terse = Formatorbit::with_config(ConversionConfig {
+            relative_time_style: RelativeTimeStyle::Terse,
+            ..Default::default()
+        });
+
+        // 2023-12-24, years before any clock this test runs on
+        let results = terse.convert_all_filtered("1703456789", &filter(&["epoch"]));
+        let description = &results[0].interpretation.description;
+        assert!(description.ends_with("y ago)"), "got {}", description);
+        let relative = results[0]
+            .conversions
+            .iter()
+            .find(|c| c.target_format == "relative-time")
+            .unwrap();
+        assert!(
+            relative.display.ends_with("y ago"),
+            "got {}",
+            relative.display
+        );
+
+        // The style belongs to the converter, so others stay verbose
+        let results = Formatorbit::new().convert_all_filtered("1703456789", &filter(&["epoch"]));
+        let description = &results[0].interpretation.description;
+        assert!(description.ends_with("years ago)"), "got {}", description);
+    }
+
+    #[test]
+    fn test_output_radix_adds_base_n() {
+        let forb = Formatorbit::with_config(ConversionConfig {
+            output_radix: Some(36),
+            ..Default::default()
+        });
+
+        let results = forb.convert_all_filtered("255", &filter(&["decimal"]));
+        let base36 = results[0]
+            .conversions
+            .iter()
+            .find(|c| c.target_format == "base36")
+            .expect("base36 conversion");
+        assert_eq!(base36.display, "73");
+        assert_eq!(base36.path, vec!["decimal", "base36"]);
+
+        let results = forb.convert_all_filtered("base36:zz", &filter(&["base-n"]));
+        assert_eq!(
+            results[0].interpretation.value,
+            CoreValue::Int {
+                value: 1295,
+                original_bytes: None
+            }
+        );
+
+        // Bases with a dedicated format don't get a duplicate line
+        let forb16 = Formatorbit::with_config(ConversionConfig {
+            output_radix: Some(16),
+            ..Default::default()
+        });
+        let results = forb16.convert_all_filtered("255", &filter(&["decimal"]));
+        assert!(!results[0]
+            .conversions
+            .iter()
+            .any(|c| c.target_format == "base16"));
+
+        // Without a configured radix there's no extra line
+        let results = Formatorbit::new().convert_all_filtered("255", &filter(&["decimal"]));
+        assert!(!results[0]
+            .conversions
+            .iter()
+            .any(|c| c.target_format == "base36"));
+    }
+
+    #[test]
+    fn test_confidence_overrides_reorder_results() {
+        let input = "1703456789";
+        let default_top = Formatorbit::new().interpret(input)[0].source_format.clone();
+        assert_ne!(default_top, "hex");
+
+        let config = ConversionConfig {
+            confidence_overrides: std::collections::HashMap::from([
+                ("hex".to_string(), 2.0),
+                ("nanoid".to_string(), 0.0),
+            ]),
+            ..Default::default()
+        };
+        let results = Formatorbit::with_config(config).interpret(input);
+        assert_eq!(results[0].source_format, "hex");
+        assert_eq!(results[0].confidence, 1.0);
+        let nanoid = results.iter().find(|r| r.source_format == "nanoid");
+        assert!(nanoid.is_none_or(|r| r.confidence == 0.0));
+    }
+
+    #[test]
+    fn test_interpret_bytes_passes_raw_png_to_image() {
+        let mut png = Vec::new();
+        image::RgbImage::from_pixel(4, 2, image::Rgb([255, 0, 0]))
+            .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+
+        let forb = Formatorbit::new();
+        let interps = forb.interpret_bytes(&png);
+        assert_eq!(interps.len(), 1);
+        assert_eq!(interps[0].source_format, "image");
+        assert!(interps[0].description.starts_with("PNG 4×2"));
+        assert!(matches!(&interps[0].value, CoreValue::Bytes(bytes) if *bytes == png));
+
+        // Only binary formats take part; unrecognized data yields nothing
+        assert!(forb.interpret_bytes(b"hello").is_empty());
+    }
+
+    #[test]
+    fn test_sha1_gets_hash_note_and_git_abbreviation() {
+        let results = Formatorbit::new().convert_all("da39a3ee5e6b4b0d3255bfef95601890afd80709");
+        let hash = results
+            .iter()
+            .find(|r| r.interpretation.source_format == "hash")
+            .unwrap();
+
+        assert!(hash.interpretation.description.contains("SHA-1"));
+        assert!(hash
+            .conversions
+            .iter()
+            .any(|c| c.target_format == "git-short" && c.display == "da39a3e"));
+        assert!(hash
+            .conversions
+            .iter()
+            .any(|c| c.target_format == "git-object" && c.kind == ConversionKind::Trait));
+    }
 }
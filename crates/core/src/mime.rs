@@ -0,0 +1,154 @@
+//! MIME type detection from magic bytes.
+//!
+//! Identifies common file types by their leading signature. Used to build
+//! `data:` URIs and to label binary data without relying on file extensions.
+//! Images, audio, fonts and archives are recognized by the same detectors
+//! their formats use.
+
+use crate::formats::{ArchiveFormat, AudioFormat, FontFormat, ImageFormat};
+
+/// MIME types for the names returned by the format detectors.
+const DETECTED_MIME: &[(&str, &str)] = &[
+    // ImageFormat
+    ("JPEG", "image/jpeg"),
+    ("PNG", "image/png"),
+    ("GIF", "image/gif"),
+    ("WebP", "image/webp"),
+    ("BMP", "image/bmp"),
+    ("TIFF", "image/tiff"),
+    ("ICO", "image/x-icon"),
+    ("HEIC", "image/heic"),
+    ("AVIF", "image/avif"),
+    // AudioFormat
+    ("MP3", "audio/mpeg"),
+    ("FLAC", "audio/flac"),
+    ("WAV", "audio/wav"),
+    ("OGG", "audio/ogg"),
+    ("AAC", "audio/aac"),
+    // FontFormat
+    ("TrueType", "font/ttf"),
+    ("OpenType", "font/otf"),
+    ("WOFF", "font/woff"),
+    ("WOFF2", "font/woff2"),
+    // ArchiveFormat
+    ("ZIP", "application/zip"),
+    ("GZIP", "application/gzip"),
+    ("TAR", "application/x-tar"),
+];
+
+/// Detect the MIME type of binary data from its magic bytes.
+///
+/// Returns `None` for unrecognized data. Short or ambiguous signatures are
+/// only matched when enough bytes are present to avoid false positives.
+///
+/// # Examples
+///
+/// ```
+/// use formatorbit_core::mime::detect_mime;
+///
+/// assert_eq!(detect_mime(b"%PDF-1.7\n..."), Some("application/pdf"));
+/// assert_eq!(detect_mime(b"hello"), None);
+/// ```
+#[must_use]
+pub fn detect_mime(data: &[u8]) -> Option<&'static str> {
+    // Formats with a detector of their own
+    let detected = ImageFormat::detect_format(data)
+        .or_else(|| AudioFormat::detect_audio_format(data))
+        .or_else(|| FontFormat::detect_font_type(data))
+        .or_else(|| ArchiveFormat::detect_archive_type(data));
+    if let Some(name) = detected {
+        return DETECTED_MIME
+            .iter()
+            .find(|(detected, _)| *detected == name)
+            .map(|(_, mime)| *mime);
+    }
+
+    // RIFF containers (WebP and WAV are detected above)
+    if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"AVI " {
+        return Some("video/x-msvideo");
+    }
+
+    // ISO base media (ftyp box; HEIC and AVIF are detected above)
+    if data.len() >= 12 && &data[4..8] == b"ftyp" {
+        return Some(match &data[8..12] {
+            b"M4A " => "audio/mp4",
+            b"qt  " => "video/quicktime",
+            _ => "video/mp4",
+        });
+    }
+
+    if data.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        return Some("video/webm");
+    }
+
+    // Documents and other archives
+    if data.starts_with(b"%PDF-") {
+        return Some("application/pdf");
+    }
+    if data.starts_with(&[0x37, 0x7A, 0xBC, 0xAF, 0x27, 0x1C]) {
+        return Some("application/x-7z-compressed");
+    }
+    if data.starts_with(b"BZh") && data.len() >= 10 {
+        return Some("application/x-bzip2");
+    }
+    if data.starts_with(&[0xFD, b'7', b'z', b'X', b'Z', 0x00]) {
+        return Some("application/x-xz");
+    }
+    if data.starts_with(b"\0asm") && data.len() >= 8 {
+        return Some("application/wasm");
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_png() {
+        let png = [
+            0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0x0D,
+        ];
+        assert_eq!(detect_mime(&png), Some("image/png"));
+    }
+
+    #[test]
+    fn test_detect_riff_variants() {
+        assert_eq!(detect_mime(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+        assert_eq!(detect_mime(b"RIFF\0\0\0\0WAVEfmt "), Some("audio/wav"));
+    }
+
+    #[test]
+    fn test_detectors_are_shared() {
+        assert_eq!(
+            detect_mime(b"RIFF\0\0\0\0AVI LIST"),
+            Some("video/x-msvideo")
+        );
+        assert_eq!(detect_mime(b"\0\0\0\x18ftypheic"), Some("image/heic"));
+        assert_eq!(detect_mime(b"\0\0\0\x18ftypisom"), Some("video/mp4"));
+        assert_eq!(
+            detect_mime(&[0xFF, 0xFB, 0x90, 0x64, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Some("audio/mpeg")
+        );
+    }
+
+    #[test]
+    fn test_loose_signatures_not_detected() {
+        // Bare TTF, ICO and MP3 signatures without a plausible header
+        let mut ttf = [0u8; 16];
+        ttf[1] = 0x01;
+        assert_eq!(detect_mime(&ttf), None);
+        let mut ico = [0u8; 24];
+        ico[2] = 0x01;
+        assert_eq!(detect_mime(&ico), None);
+        assert_eq!(detect_mime(&[0xFF, 0xFB, 0xFF, 0xFF, 0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_text_not_detected() {
+        assert_eq!(detect_mime(b"BMW is a car brand, not a bitmap"), None);
+        assert_eq!(detect_mime(b"hello world"), None);
+        assert_eq!(detect_mime(&[]), None);
+    }
+}
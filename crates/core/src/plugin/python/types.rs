@@ -321,6 +321,10 @@ pub fn core_value_to_py(py: Python<'_>, value: &CoreValue) -> PyResult<PyObject>
         CoreValue::String(s) => Ok(PyString::new(py, s).into()),
         CoreValue::Int { value, .. } => Ok(value.into_pyobject(py)?.into_any().unbind()),
         CoreValue::Float(f) => Ok(f.into_pyobject(py)?.into_any().unbind()),
+        CoreValue::Rational { num, den } => {
+            let fraction = py.import("fractions")?.getattr("Fraction")?;
+            Ok(fraction.call1((*num, *den))?.unbind())
+        }
         CoreValue::Bool(b) => Ok((*b).into_pyobject(py)?.to_owned().into_any().unbind()),
         CoreValue::DateTime(dt) => {
             // Convert to ISO string for simplicity
@@ -352,6 +356,7 @@ pub fn core_value_to_py(py: Python<'_>, value: &CoreValue) -> PyResult<PyObject>
             // Convert to string representation for now
             Ok(PyString::new(py, "[protobuf data]").into())
         }
+        CoreValue::Xml(root) => Ok(PyString::new(py, &root.to_pretty_string()).into()),
     }
 }
 
@@ -0,0 +1,159 @@
+//! Relative time formatting shared by the date and time formats.
+//!
+//! Renders the distance between two instants as "3 days ago" / "in 2 hours",
+//! or tersely as "3d ago" / "in 2h". Formats pass the style from
+//! [`ConversionConfig::relative_time_style`](crate::types::ConversionConfig::relative_time_style)
+//! through `parse_with_config` and `conversions_with_config`.
+
+use chrono::{DateTime, Utc};
+
+use crate::types::RelativeTimeStyle;
+
+/// Units from smallest to largest, as (seconds, singular, plural, terse suffix).
+const UNITS: &[(i64, &str, &str, &str)] = &[
+    (1, "second", "seconds", "s"),
+    (60, "minute", "minutes", "m"),
+    (3_600, "hour", "hours", "h"),
+    (86_400, "day", "days", "d"),
+    (604_800, "week", "weeks", "w"),
+    (2_592_000, "month", "months", "mo"),
+    (31_536_000, "year", "years", "y"),
+];
+
+/// Format `then` relative to `now`, rounded down to the largest whole unit.
+///
+/// Verbose: "3 weeks ago", "in 1 day". Terse: "3w ago", "in 1d".
+#[must_use]
+pub fn format_relative(
+    now: DateTime<Utc>,
+    then: DateTime<Utc>,
+    style: RelativeTimeStyle,
+) -> String {
+    let secs = then.signed_duration_since(now).num_seconds();
+    if secs == 0 {
+        return "now".to_string();
+    }
+
+    let abs_secs = secs.abs();
+    let &(unit_secs, singular, plural, suffix) = UNITS
+        .iter()
+        .rev()
+        .find(|(unit_secs, ..)| abs_secs >= *unit_secs)
+        .unwrap_or(&UNITS[0]);
+    let value = abs_secs / unit_secs;
+
+    let amount = match style {
+        RelativeTimeStyle::Verbose => {
+            format!("{} {}", value, if value == 1 { singular } else { plural })
+        }
+        RelativeTimeStyle::Terse => format!("{}{}", value, suffix),
+    };
+
+    if secs < 0 {
+        format!("{} ago", amount)
+    } else {
+        format!("in {}", amount)
+    }
+}
+
+/// Like [`format_relative`], but keeps the leftover minutes under a day.
+///
+/// "in 8h 44m" rather than "in 8 hours", for schedules where the minutes
+/// matter (cron next runs). Other distances read as in [`format_relative`].
+#[must_use]
+pub fn format_relative_precise(
+    now: DateTime<Utc>,
+    then: DateTime<Utc>,
+    style: RelativeTimeStyle,
+) -> String {
+    let secs = then.signed_duration_since(now).num_seconds();
+    let abs_secs = secs.abs();
+    let (hours, mins) = (abs_secs / 3_600, abs_secs % 3_600 / 60);
+    if !(1..24).contains(&hours) || mins == 0 {
+        return format_relative(now, then, style);
+    }
+
+    if secs < 0 {
+        format!("{}h {}m ago", hours, mins)
+    } else {
+        format!("in {}h {}m", hours, mins)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn relative(offset: Duration, style: RelativeTimeStyle) -> String {
+        let now = Utc::now();
+        format_relative(now, now + offset, style)
+    }
+
+    #[test]
+    fn test_verbose() {
+        let verbose = |offset| relative(offset, RelativeTimeStyle::Verbose);
+        assert_eq!(verbose(Duration::zero()), "now");
+        assert_eq!(verbose(Duration::seconds(-1)), "1 second ago");
+        assert_eq!(verbose(Duration::minutes(5)), "in 5 minutes");
+        assert_eq!(verbose(Duration::hours(-1)), "1 hour ago");
+        assert_eq!(verbose(Duration::days(2)), "in 2 days");
+        assert_eq!(verbose(Duration::weeks(-3)), "3 weeks ago");
+        assert_eq!(verbose(Duration::days(65)), "in 2 months");
+        assert_eq!(verbose(Duration::days(-800)), "2 years ago");
+    }
+
+    #[test]
+    fn test_terse() {
+        let terse = |offset| relative(offset, RelativeTimeStyle::Terse);
+        assert_eq!(terse(Duration::zero()), "now");
+        assert_eq!(terse(Duration::seconds(-45)), "45s ago");
+        assert_eq!(terse(Duration::minutes(5)), "in 5m");
+        assert_eq!(terse(Duration::hours(-23)), "23h ago");
+        assert_eq!(terse(Duration::days(2)), "in 2d");
+        assert_eq!(terse(Duration::weeks(-3)), "3w ago");
+        assert_eq!(terse(Duration::days(65)), "in 2mo");
+        assert_eq!(terse(Duration::days(-800)), "2y ago");
+    }
+
+    #[test]
+    fn test_forward_and_backward_offsets() {
+        let now = Utc::now();
+        for (offset, amount) in [
+            (Duration::seconds(30), "30 seconds"),
+            (Duration::hours(2), "2 hours"),
+            (Duration::days(1), "1 day"),
+            (Duration::weeks(2), "2 weeks"),
+        ] {
+            let styled = |then| format_relative(now, then, RelativeTimeStyle::Verbose);
+            assert_eq!(styled(now + offset), format!("in {}", amount));
+            assert_eq!(styled(now - offset), format!("{} ago", amount));
+        }
+    }
+
+    #[test]
+    fn test_precise_keeps_minutes_under_a_day() {
+        let now = Utc::now();
+        for style in [RelativeTimeStyle::Verbose, RelativeTimeStyle::Terse] {
+            let precise = |offset| format_relative_precise(now, now + offset, style);
+            assert_eq!(
+                precise(Duration::hours(8) + Duration::minutes(44)),
+                "in 8h 44m"
+            );
+            assert_eq!(
+                precise(-(Duration::hours(2) + Duration::minutes(30))),
+                "2h 30m ago"
+            );
+        }
+
+        // Whole hours and other ranges read as in format_relative
+        let precise =
+            |offset| format_relative_precise(now, now + offset, RelativeTimeStyle::Verbose);
+        assert_eq!(precise(Duration::hours(2)), "in 2 hours");
+        assert_eq!(precise(Duration::minutes(5)), "in 5 minutes");
+        assert_eq!(
+            precise(Duration::days(2) + Duration::minutes(30)),
+            "in 2 days"
+        );
+    }
+}
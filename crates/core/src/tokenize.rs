@@ -0,0 +1,757 @@
+//! Tokenizer for annotating log lines.
+//!
+//! Extracts value-like tokens from free-form text with position tracking and
+//! smart grouping: hex-like sequences ("69 1E 01 B8"), dates ("Jan 15 10:30")
+//! and quoted substrings each become a single token, and wrapping punctuation
+//! is trimmed. Pipe mode in the CLI is built on this; embedders can use it to
+//! annotate their own logs.
+
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthStr;
+
+/// A token extracted from a line with its position information.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Token {
+    /// The token text (may be normalized, e.g., grouped hex bytes)
+    pub text: String,
+    /// Byte offset in the original line (start)
+    pub start: usize,
+    /// Byte offset in the original line (end, exclusive)
+    pub end: usize,
+    /// Display column (0-indexed, accounting for unicode width)
+    pub display_col: usize,
+    /// What the surrounding tokens say this token is, if anything
+    pub hint: Option<TokenHint>,
+}
+
+/// Meaning of a token inferred from its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TokenHint {
+    /// A size in bytes (e.g., the size column of `ls -l`)
+    FileSize,
+}
+
+/// Tokens of a line worth interpreting, at most `max_tokens` of them.
+///
+/// Splits the line (see [`split_tokens`]) and keeps the tokens that look like
+/// values rather than words (see [`is_interesting_candidate`]), in line order.
+///
+/// # Examples
+///
+/// ```
+/// use formatorbit_core::tokenize;
+///
+/// let tokens = tokenize("user 42 sent 69 1E 01 B8 at 1703456789", 10);
+/// let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+/// assert_eq!(texts, ["42", "69 1E 01 B8", "1703456789"]);
+/// assert_eq!(&"user 42"[tokens[0].start..tokens[0].end], "42");
+/// ```
+#[must_use]
+pub fn tokenize(line: &str, max_tokens: usize) -> Vec<Token> {
+    split_tokens(line)
+        .into_iter()
+        .filter(|t| is_interesting_candidate(&t.text))
+        .take(max_tokens)
+        .collect()
+}
+
+/// Split a line into tokens with smart quote, hex and date grouping.
+///
+/// Splits on whitespace but groups adjacent tokens that form one value
+/// (e.g., "69 1E 01 B8", "Jan 15 10:30" or a quoted "two words" becomes a
+/// single token), trims wrapping punctuation (`(192.168.1.1),` → `192.168.1.1`)
+/// and marks the size column of `ls -l` lines. A `KEY=value` line (as in `.env`
+/// files) yields just its value.
+#[must_use]
+pub fn split_tokens(line: &str) -> Vec<Token> {
+    if let Some(value) = assignment_value(line) {
+        return vec![value];
+    }
+
+    let raw_tokens = extract_raw_tokens(line);
+    let quote_grouped = apply_quote_grouping(&raw_tokens, line);
+    let trimmed = trim_punctuation(&quote_grouped, line);
+    let date_grouped = apply_date_grouping(&trimmed, line);
+    let mut tokens = apply_hex_grouping(&date_grouped, line);
+    mark_listing_size(&mut tokens);
+    tokens
+}
+
+/// A token covering `line[start..end]`.
+fn token_at(line: &str, start: usize, end: usize) -> Token {
+    Token {
+        text: line[start..end].to_string(),
+        start,
+        end,
+        display_col: UnicodeWidthStr::width(&line[..start]),
+        hint: None,
+    }
+}
+
+/// Punctuation that ends a clause after a value ("at 1703456789, from").
+const TRAILING_PUNCTUATION: &[char] = &[',', ';', '.', '!', '?'];
+
+/// Bracket and quote pairs that wrap a value ("(192.168.1.1)", "'abc'").
+const WRAPPING_PAIRS: &[(char, char)] =
+    &[('(', ')'), ('[', ']'), ('<', '>'), ('"', '"'), ('\'', '\'')];
+
+/// Merge a quoted substring spanning several tokens into one, without the quotes.
+///
+/// `id "two words" done` yields `two words`; trailing punctuation after the
+/// closing quote (`"two words",`) is allowed.
+fn apply_quote_grouping(tokens: &[Token], original_line: &str) -> Vec<Token> {
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let text = &tokens[i].text;
+        let quote = text.chars().next().filter(|c| matches!(c, '"' | '\''));
+        // A token with its own closing quote ("abc") is trimmed later instead
+        if let Some(quote) = quote.filter(|q| !text[1..].contains(*q)) {
+            let closing = (i + 1..tokens.len()).find(|&j| tokens[j].text.contains(quote));
+            if let Some(j) = closing {
+                let closing_text = tokens[j].text.trim_end_matches(TRAILING_PUNCTUATION);
+                let start = tokens[i].start + 1;
+                let end = tokens[j].start + closing_text.len().saturating_sub(1);
+                if closing_text.ends_with(quote)
+                    && closing_text.matches(quote).count() == 1
+                    && start < end
+                {
+                    result.push(token_at(original_line, start, end));
+                    i = j + 1;
+                    continue;
+                }
+            }
+        }
+
+        result.push(tokens[i].clone());
+        i += 1;
+    }
+
+    result
+}
+
+/// Trim trailing clause punctuation and wrapping brackets or quotes from tokens.
+///
+/// Wrappers are only removed in matching pairs and never around a comma-separated
+/// list, so `[1,2,3]` keeps its brackets. Tokens that are all punctuation are dropped.
+fn trim_punctuation(tokens: &[Token], original_line: &str) -> Vec<Token> {
+    tokens
+        .iter()
+        .filter_map(|token| {
+            let (mut start, mut end) = (token.start, token.end);
+            loop {
+                let text = &original_line[start..end];
+                let without_trailing = text.trim_end_matches(TRAILING_PUNCTUATION);
+                if without_trailing.len() < text.len() {
+                    end = start + without_trailing.len();
+                    continue;
+                }
+
+                let mut chars = text.chars();
+                let (Some(first), Some(last)) = (chars.next(), chars.next_back()) else {
+                    break;
+                };
+                let inner = &text[first.len_utf8()..text.len() - last.len_utf8()];
+                if WRAPPING_PAIRS.contains(&(first, last)) && !inner.contains(',') {
+                    start += first.len_utf8();
+                    end -= last.len_utf8();
+                    continue;
+                }
+                break;
+            }
+
+            if start >= end {
+                None
+            } else if (start, end) == (token.start, token.end) {
+                Some(token.clone())
+            } else {
+                Some(token_at(original_line, start, end))
+            }
+        })
+        .collect()
+}
+
+/// If the whole line is a `KEY=value` assignment, the token for its value.
+///
+/// Accepts `.env` lines (with an optional leading `export`) and `key = value`
+/// config lines. Surrounding quotes are left out of the token, so quoted values
+/// may contain spaces; unquoted ones may not, which keeps logfmt lines
+/// (`level=info id=42`) tokenized as usual.
+fn assignment_value(line: &str) -> Option<Token> {
+    let mut rest = line.trim_start();
+    if let Some(after_export) = rest.strip_prefix("export ") {
+        rest = after_export.trim_start();
+    }
+
+    let eq = rest.find('=')?;
+    let key = rest[..eq].trim_end();
+    let is_key = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+    if !is_key {
+        return None;
+    }
+
+    let raw_value = &rest[eq + 1..];
+    let value = raw_value.trim();
+    let mut start = line.len() - raw_value.trim_start().len();
+    let mut end = start + value.len();
+
+    let bytes = value.as_bytes();
+    let quoted =
+        bytes.len() >= 2 && matches!(bytes[0], b'"' | b'\'') && bytes[bytes.len() - 1] == bytes[0];
+    if quoted {
+        start += 1;
+        end -= 1;
+    } else if value.contains(char::is_whitespace) {
+        return None;
+    }
+    if start >= end {
+        return None;
+    }
+
+    Some(Token {
+        text: line[start..end].to_string(),
+        start,
+        end,
+        display_col: UnicodeWidthStr::width(&line[..start]),
+        hint: None,
+    })
+}
+
+/// Extract individual whitespace-separated tokens with positions.
+fn extract_raw_tokens(line: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut display_col = 0;
+    let mut byte_offset = 0;
+    let mut in_token = false;
+    let mut token_start_byte = 0;
+    let mut token_start_col = 0;
+
+    for (i, c) in line.char_indices() {
+        let c_width = UnicodeWidthStr::width(c.to_string().as_str());
+
+        if c.is_whitespace() {
+            if in_token {
+                // End of token
+                let text = &line[token_start_byte..i];
+                tokens.push(Token {
+                    text: text.to_string(),
+                    start: token_start_byte,
+                    end: i,
+                    display_col: token_start_col,
+                    hint: None,
+                });
+                in_token = false;
+            }
+        } else if !in_token {
+            // Start of new token
+            in_token = true;
+            token_start_byte = i;
+            token_start_col = display_col;
+        }
+
+        display_col += c_width;
+        byte_offset = i + c.len_utf8();
+    }
+
+    // Handle final token
+    if in_token {
+        let text = &line[token_start_byte..byte_offset];
+        tokens.push(Token {
+            text: text.to_string(),
+            start: token_start_byte,
+            end: byte_offset,
+            display_col: token_start_col,
+            hint: None,
+        });
+    }
+
+    tokens
+}
+
+/// Check if a token looks like a hex byte (1-2 hex digits).
+fn looks_like_hex_byte(s: &str) -> bool {
+    let len = s.len();
+    (len == 1 || len == 2) && s.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Check if a token looks like a space-separated hex dump (e.g., "87 A3 69 6E 74 01").
+fn looks_like_hex_dump(s: &str) -> bool {
+    // Must have at least one space to be a hex dump
+    if !s.contains(' ') {
+        return false;
+    }
+
+    // Split on spaces and verify each part is a hex byte
+    let parts: Vec<&str> = s.split(' ').collect();
+
+    // Need at least 2 parts
+    if parts.len() < 2 {
+        return false;
+    }
+
+    // All parts must be hex bytes
+    parts.iter().all(|p| looks_like_hex_byte(p))
+}
+
+/// Try to group adjacent tokens that look like hex bytes.
+fn apply_hex_grouping(tokens: &[Token], original_line: &str) -> Vec<Token> {
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        // Check if this starts a potential hex sequence
+        if looks_like_hex_byte(&tokens[i].text) {
+            // Try to extend the sequence
+            let mut end = i + 1;
+            while end < tokens.len() && looks_like_hex_byte(&tokens[end].text) {
+                end += 1;
+            }
+
+            if end > i + 1 {
+                // We have a sequence of 2+ hex-like tokens - group them
+                let start_token = &tokens[i];
+                let end_token = &tokens[end - 1];
+
+                // Combine the text with spaces preserved from original
+                let combined = &original_line[start_token.start..end_token.end];
+
+                result.push(Token {
+                    text: combined.to_string(),
+                    start: start_token.start,
+                    end: end_token.end,
+                    display_col: start_token.display_col,
+                    hint: None,
+                });
+
+                i = end;
+                continue;
+            }
+        }
+
+        // Not part of a group, add as-is
+        result.push(tokens[i].clone());
+        i += 1;
+    }
+
+    result
+}
+
+/// Check if a token is a month name ("Jan" or "January").
+fn looks_like_month(s: &str) -> bool {
+    const MONTHS: [&str; 12] = [
+        "january",
+        "february",
+        "march",
+        "april",
+        "may",
+        "june",
+        "july",
+        "august",
+        "september",
+        "october",
+        "november",
+        "december",
+    ];
+    MONTHS
+        .iter()
+        .any(|m| m.eq_ignore_ascii_case(s) || m[..3].eq_ignore_ascii_case(s))
+}
+
+/// Check if a token is a day of the month (1-31).
+fn looks_like_day(s: &str) -> bool {
+    s.len() <= 2 && s.parse::<u8>().is_ok_and(|d| (1..=31).contains(&d))
+}
+
+/// Check if a token is a time ("10:30", "10:30:00") or a year ("2023").
+fn looks_like_time_or_year(s: &str) -> bool {
+    let is_time = {
+        let parts: Vec<&str> = s.split(':').collect();
+        (2..=3).contains(&parts.len())
+            && parts
+                .iter()
+                .all(|p| p.len() == 2 && p.bytes().all(|b| b.is_ascii_digit()))
+    };
+    let is_year = s.len() == 4 && s.bytes().all(|b| b.is_ascii_digit());
+    is_time || is_year
+}
+
+/// Group "Jan 15 10:30" and "Jan 15 2023" (as printed by `ls -l` and syslog).
+fn apply_date_grouping(tokens: &[Token], original_line: &str) -> Vec<Token> {
+    let mut result = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let is_date = tokens.len() - i >= 3
+            && looks_like_month(&tokens[i].text)
+            && looks_like_day(&tokens[i + 1].text)
+            && looks_like_time_or_year(&tokens[i + 2].text);
+
+        if is_date {
+            let (start_token, end_token) = (&tokens[i], &tokens[i + 2]);
+            result.push(Token {
+                text: original_line[start_token.start..end_token.end].to_string(),
+                start: start_token.start,
+                end: end_token.end,
+                display_col: start_token.display_col,
+                hint: None,
+            });
+            i += 3;
+            continue;
+        }
+
+        result.push(tokens[i].clone());
+        i += 1;
+    }
+
+    result
+}
+
+/// Check if a token is an `ls -l` mode string (e.g., "-rw-r--r--", "drwxr-xr-x@").
+fn looks_like_file_mode(s: &str) -> bool {
+    let s = s.trim_end_matches(['.', '@', '+']);
+    let bytes = s.as_bytes();
+    bytes.len() == 10
+        && b"-dlcbpsD".contains(&bytes[0])
+        && bytes[1..].iter().all(|b| b"rwxsStT-".contains(b))
+}
+
+/// In an `ls -l` line, mark the size column (the number right before the date).
+fn mark_listing_size(tokens: &mut [Token]) {
+    if !tokens
+        .first()
+        .is_some_and(|t| looks_like_file_mode(&t.text))
+    {
+        return;
+    }
+
+    // The date is the first grouped token starting with a month name
+    let Some(date_index) = tokens.iter().position(|t| {
+        t.text
+            .split_whitespace()
+            .next()
+            .is_some_and(looks_like_month)
+            && t.text.contains(' ')
+    }) else {
+        return;
+    };
+
+    if date_index >= 2 {
+        let size = &mut tokens[date_index - 1];
+        if size.text.bytes().all(|b| b.is_ascii_digit()) {
+            size.hint = Some(TokenHint::FileSize);
+        }
+    }
+}
+
+/// Quick check if a token is worth analyzing.
+/// Returns false for common words that are unlikely to be interesting.
+#[must_use]
+pub fn is_interesting_candidate(token: &str) -> bool {
+    let len = token.len();
+
+    // Skip very short tokens
+    if len < 2 {
+        return false;
+    }
+
+    // Check if it looks like space-separated hex bytes (e.g., "87 A3 69 6E 74 01")
+    // These can be quite long, so we check this before the length limit.
+    if looks_like_hex_dump(token) {
+        return true;
+    }
+
+    // Skip very long tokens (unlikely to be a single value)
+    if len > 128 {
+        return false;
+    }
+
+    // Skip common log words
+    let lower = token.to_lowercase();
+    if matches!(
+        lower.as_str(),
+        "the"
+            | "and"
+            | "for"
+            | "from"
+            | "with"
+            | "that"
+            | "this"
+            | "are"
+            | "was"
+            | "were"
+            | "been"
+            | "have"
+            | "has"
+            | "had"
+            | "not"
+            | "but"
+            | "what"
+            | "all"
+            | "when"
+            | "user"
+            | "logged"
+            | "error"
+            | "warning"
+            | "warn"
+            | "info"
+            | "debug"
+            | "trace"
+            | "received"
+            | "sent"
+            | "payload"
+            | "request"
+            | "response"
+            | "status"
+            | "message"
+            | "data"
+            | "value"
+            | "null"
+            | "true"
+            | "false"
+            | "none"
+    ) {
+        return false;
+    }
+
+    // Check for patterns that are likely interpretable
+    let has_digits = token.chars().any(|c| c.is_ascii_digit());
+    let has_format_marker = token.starts_with("0x")
+        || token.starts_with('#')
+        || token.contains('-')
+        || token.contains(':')
+        || token.contains('.');
+
+    // Check if it looks hex-like (letters a-f plus digits)
+    let is_hex_like = (4..=64).contains(&len) && token.chars().all(|c| c.is_ascii_hexdigit());
+
+    has_digits || has_format_marker || is_hex_like
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_simple() {
+        let tokens = split_tokens("hello world");
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].text, "hello");
+        assert_eq!(tokens[1].text, "world");
+    }
+
+    #[test]
+    fn test_tokenize_positions() {
+        // Use non-hex tokens to avoid grouping
+        let tokens = split_tokens("hi there");
+        assert_eq!(tokens[0].start, 0);
+        assert_eq!(tokens[0].end, 2);
+        assert_eq!(tokens[0].display_col, 0);
+        assert_eq!(tokens[1].start, 3);
+        assert_eq!(tokens[1].end, 8);
+        assert_eq!(tokens[1].display_col, 3);
+    }
+
+    #[test]
+    fn test_hex_grouping() {
+        let tokens = split_tokens("payload: 69 1E 01 B8 end");
+        assert_eq!(tokens.len(), 3);
+        assert_eq!(tokens[0].text, "payload:");
+        assert_eq!(tokens[1].text, "69 1E 01 B8");
+        assert_eq!(tokens[2].text, "end");
+    }
+
+    #[test]
+    fn test_hex_grouping_preserves_position() {
+        let tokens = split_tokens("data: AB CD EF");
+        assert_eq!(tokens.len(), 2);
+        let hex_token = &tokens[1];
+        assert_eq!(hex_token.text, "AB CD EF");
+        assert_eq!(hex_token.start, 6);
+        assert_eq!(hex_token.end, 14);
+    }
+
+    #[test]
+    fn test_single_hex_not_grouped() {
+        let tokens = split_tokens("value: FF");
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[1].text, "FF");
+    }
+
+    #[test]
+    fn test_date_grouping() {
+        let tokens = split_tokens("sshd[42]: Jan 15 10:30:00 accepted");
+        assert_eq!(tokens[1].text, "Jan 15 10:30:00");
+
+        // Double-spaced day, as ls pads it
+        let tokens = split_tokens("Mar  5  2023 notes.txt");
+        assert_eq!(tokens[0].text, "Mar  5  2023");
+        assert_eq!(tokens[1].text, "notes.txt");
+
+        // Not a date without the time or year
+        let tokens = split_tokens("Jan 15 meeting");
+        assert_eq!(tokens.len(), 3);
+    }
+
+    #[test]
+    fn test_ls_long_line() {
+        let tokens = split_tokens("-rw-r--r--  1 user group 4096 Jan 15 10:30 file.txt");
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "-rw-r--r--",
+                "1",
+                "user",
+                "group",
+                "4096",
+                "Jan 15 10:30",
+                "file.txt"
+            ]
+        );
+        assert_eq!(tokens[4].hint, Some(TokenHint::FileSize));
+        assert!(tokens.iter().filter(|t| t.hint.is_some()).count() == 1);
+
+        // Only ls lines get the hint
+        let tokens = split_tokens("sent 4096 Jan 15 10:30");
+        assert!(tokens.iter().all(|t| t.hint.is_none()));
+    }
+
+    #[test]
+    fn test_assignment_value() {
+        let tokens = split_tokens("TIMEOUT=30000");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].text, "30000");
+        assert_eq!((tokens[0].start, tokens[0].end), (8, 13));
+        assert_eq!(tokens[0].display_col, 8);
+
+        // Quotes are stripped, and quoted values can contain spaces
+        let tokens = split_tokens("export GREETING=\"69 1E 01 B8\"");
+        assert_eq!(tokens[0].text, "69 1E 01 B8");
+        assert_eq!(tokens[0].start, 17);
+
+        let tokens = split_tokens("server.port = '8080'");
+        assert_eq!(tokens[0].text, "8080");
+
+        // Not assignments: logfmt, comments, empty values
+        assert_eq!(split_tokens("level=info id=42").len(), 2);
+        assert_eq!(split_tokens("# TIMEOUT=30000").len(), 2);
+        assert_eq!(split_tokens("EMPTY=\"\"")[0].text, "EMPTY=\"\"");
+    }
+
+    #[test]
+    fn test_is_interesting() {
+        assert!(is_interesting_candidate(
+            "550e8400-e29b-41d4-a716-446655440000"
+        ));
+        assert!(is_interesting_candidate("192.168.1.1"));
+        assert!(is_interesting_candidate("0x691E01B8"));
+        assert!(is_interesting_candidate("#FF5733"));
+        assert!(is_interesting_candidate("1763574200"));
+        assert!(is_interesting_candidate("691E01B8"));
+
+        assert!(!is_interesting_candidate("the"));
+        assert!(!is_interesting_candidate("error"));
+        assert!(!is_interesting_candidate("a")); // too short
+    }
+
+    #[test]
+    fn test_mixed_line() {
+        let tokens = split_tokens("[2024-01-15] User abc123 sent 69 1E 01 B8");
+        // Should have: [2024-01-15], User, abc123, sent, "69 1E 01 B8"
+        assert_eq!(tokens.len(), 5);
+        assert_eq!(tokens[4].text, "69 1E 01 B8");
+    }
+
+    #[test]
+    fn test_is_interesting_long_hex_dump() {
+        // Long hex dumps should be interesting even if > 128 chars
+        let long_hex = "87 A3 69 6E 74 01 A5 66 6C 6F 61 74 CB 3F E0 00 00 00 00 00 00 A7 62 6F 6F 6C 65 61 6E C3 A4 6E 75 6C 6C C0 A6 73 74 72 69 6E 67 A7 66 6F 6F 20 62 61 72";
+        assert!(long_hex.len() > 128); // Verify it's actually long
+        assert!(is_interesting_candidate(long_hex));
+    }
+
+    #[test]
+    fn test_looks_like_hex_dump() {
+        assert!(looks_like_hex_dump("87 A3 69"));
+        assert!(looks_like_hex_dump("FF 00"));
+        assert!(!looks_like_hex_dump("87A369")); // No spaces
+        assert!(!looks_like_hex_dump("hello world")); // Not hex
+        assert!(!looks_like_hex_dump("87")); // Single byte
+    }
+
+    #[test]
+    fn test_mixed_punctuation() {
+        let line = "at 1703456789, from (192.168.1.1); see <admin@example.com>. [::1]!";
+        let tokens = split_tokens(line);
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "at",
+                "1703456789",
+                "from",
+                "192.168.1.1",
+                "see",
+                "admin@example.com",
+                "::1"
+            ]
+        );
+        // Positions point at the trimmed value in the line
+        assert_eq!(&line[tokens[3].start..tokens[3].end], "192.168.1.1");
+        assert_eq!(tokens[3].display_col, 21);
+
+        // Lists keep their brackets, and bare punctuation is dropped
+        let texts: Vec<_> = split_tokens("got [1,2,3], then ... done")
+            .into_iter()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(texts, ["got", "[1,2,3]", "then", "done"]);
+    }
+
+    #[test]
+    fn test_quoted_substrings() {
+        let line = r#"msg "69 1E 01 B8", id '550e8400-e29b-41d4-a716-446655440000' name="x""#;
+        let tokens = split_tokens(line);
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(
+            texts,
+            [
+                "msg",
+                "69 1E 01 B8",
+                "id",
+                "550e8400-e29b-41d4-a716-446655440000",
+                "name=\"x\""
+            ]
+        );
+        assert_eq!((tokens[1].start, tokens[1].end), (5, 16));
+
+        // Spaces inside quotes don't split the value
+        let texts: Vec<_> = split_tokens(r#"said "hello there" twice"#)
+            .into_iter()
+            .map(|t| t.text)
+            .collect();
+        assert_eq!(texts, ["said", "hello there", "twice"]);
+
+        // Apostrophes and unterminated quotes are left alone
+        assert_eq!(split_tokens("it's 'twas")[1].text, "'twas");
+        assert_eq!(split_tokens(r#""open quote"#)[0].text, "\"open");
+    }
+
+    #[test]
+    fn test_tokenize_keeps_interesting_tokens() {
+        let tokens = tokenize("the user logged in at 1703456789 from 192.168.1.1", 10);
+        let texts: Vec<_> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["1703456789", "192.168.1.1"]);
+
+        // Capped at max_tokens, in line order
+        assert_eq!(tokenize("1 22 333 4444 55555", 2).len(), 2);
+        assert_eq!(tokenize("22 333 4444", 2)[1].text, "333");
+    }
+}
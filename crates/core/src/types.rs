@@ -246,6 +246,14 @@ pub enum CoreValue {
         original_bytes: Option<Vec<u8>>,
     },
     Float(f64),
+    /// Exact fraction `num/den` in lowest terms, with a positive denominator.
+    ///
+    /// Produced by expressions whose operands are all integers or fractions,
+    /// so `1/3 * 3` is exactly 1 rather than a float near it.
+    Rational {
+        num: i64,
+        den: i64,
+    },
     Bool(bool),
     DateTime(DateTime<Utc>),
     Json(JsonValue),
@@ -260,6 +268,11 @@ pub enum CoreValue {
     /// This allows UI apps to render protobuf with field number annotations
     /// and wire type information.
     Protobuf(Vec<ProtoField>),
+    /// Parsed XML document (root element).
+    ///
+    /// Kept as a tree rather than converted to Json so element order, attributes
+    /// and mixed content survive, and UI apps can render it as XML.
+    Xml(XmlElement),
 
     // =========================================================================
     // Unit-specific value types
@@ -326,6 +339,28 @@ pub enum ProtoValue {
     Message(Vec<ProtoField>),
 }
 
+/// A parsed XML element.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XmlElement {
+    /// Qualified tag name (e.g., "book" or "dc:title").
+    pub name: String,
+    /// Attributes in document order, with entities unescaped.
+    pub attributes: Vec<(String, String)>,
+    /// Child nodes in document order.
+    pub children: Vec<XmlNode>,
+}
+
+/// A node in an XML element's content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum XmlNode {
+    /// Nested element.
+    Element(XmlElement),
+    /// Text content (CDATA sections included), with entities unescaped.
+    Text(String),
+    /// Comment content.
+    Comment(String),
+}
+
 /// A segment within a binary packet layout.
 ///
 /// Used for byte-level visualization of binary formats like protobuf and msgpack.
@@ -359,10 +394,12 @@ impl CoreValue {
             Self::String(_) => "string",
             Self::Int { .. } => "int",
             Self::Float(_) => "float",
+            Self::Rational { .. } => "rational",
             Self::Bool(_) => "bool",
             Self::DateTime(_) => "datetime",
             Self::Json(_) => "json",
             Self::Protobuf(_) => "protobuf",
+            Self::Xml(_) => "xml",
             Self::Length(_) => "length",
             Self::Weight(_) => "weight",
             Self::Volume(_) => "volume",
@@ -656,6 +693,28 @@ impl BlockingConfig {
     }
 }
 
+/// Channel order assumed for 8-digit hex colors like `#80FF0000`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum ColorAlphaOrder {
+    /// `#RRGGBBAA`, as in CSS.
+    #[default]
+    RgbaFirst,
+    /// `#AARRGGBB`, as in Android.
+    ArgbFirst,
+}
+
+/// How relative times like "3 days ago" are worded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RelativeTimeStyle {
+    /// "3 days ago", "in 2 weeks".
+    #[default]
+    Verbose,
+    /// "3d ago", "in 2w", for compact displays.
+    Terse,
+}
+
 /// Combined user configuration for conversion behavior.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConversionConfig {
@@ -676,6 +735,58 @@ pub struct ConversionConfig {
     /// Default: 0.7 (70%). Set to 1.0 to disable reinterpretation.
     #[serde(default = "default_reinterpret_threshold")]
     pub reinterpret_threshold: f32,
+
+    /// Pinned exchange rates: currency code -> value of one unit in USD.
+    ///
+    /// Consulted before the cached ECB rates, e.g. `{"EUR": 1.08}` makes
+    /// currency conversions reproducible without network access.
+    #[serde(default)]
+    pub custom_rates: std::collections::HashMap<String, f64>,
+
+    /// Never access the network (e.g., for live currency rates).
+    ///
+    /// Currency conversions use pinned and disk-cached rates only.
+    #[serde(default)]
+    pub offline: bool,
+
+    /// Maximum number of conversion steps to follow from the input.
+    ///
+    /// `Some(1)` returns only direct conversions. Caps latency on inputs whose
+    /// conversion graph explodes. Default: `None` (the built-in limit of 5).
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// IANA timezone (e.g., `Europe/Stockholm`) to also render datetimes in.
+    ///
+    /// When set, every datetime result gains a `timezone` conversion showing
+    /// the local time in that zone. Unknown zone names are ignored; use
+    /// [`DateTimeFormat::parse_timezone`](crate::formats::DateTimeFormat::parse_timezone)
+    /// to validate them first.
+    #[serde(default)]
+    pub display_timezone: Option<String>,
+
+    /// Confidence multipliers by format id, e.g. `{"hex": 1.5, "text": 0.5}`.
+    ///
+    /// Applied to every interpretation a format produces before results are
+    /// ranked. Adjusted confidences are clamped to 0.0-1.0.
+    #[serde(default)]
+    pub confidence_overrides: std::collections::HashMap<String, f32>,
+
+    /// Channel order for 8-digit hex colors (`#RRGGBBAA` or `#AARRGGBB`).
+    #[serde(default)]
+    pub color_alpha_order: ColorAlphaOrder,
+
+    /// Radix (2-36) to also render integers in.
+    ///
+    /// When set, every integer result gains a `base<N>` conversion, e.g.
+    /// `base36` showing 255 as `73`. Radixes outside 2-36 are ignored, as are
+    /// 2, 8 and 16, which the binary, octal and hex formats already show.
+    #[serde(default)]
+    pub output_radix: Option<u32>,
+
+    /// Wording of relative times ("3 days ago" or "3d ago").
+    #[serde(default)]
+    pub relative_time_style: RelativeTimeStyle,
 }
 
 fn default_reinterpret_threshold() -> f32 {
@@ -686,7 +797,25 @@ impl ConversionConfig {
     /// Check if this config has any customizations.
     #[must_use]
     pub fn is_customized(&self) -> bool {
-        self.priority.is_customized() || self.blocking.is_customized()
+        self.priority.is_customized()
+            || self.blocking.is_customized()
+            || !self.custom_rates.is_empty()
+            || self.offline
+            || self.max_depth.is_some()
+            || self.display_timezone.is_some()
+            || !self.confidence_overrides.is_empty()
+            || self.color_alpha_order != ColorAlphaOrder::default()
+            || self.output_radix.is_some()
+            || self.relative_time_style != RelativeTimeStyle::default()
+    }
+
+    /// Get the confidence multiplier configured for a format, if any.
+    #[must_use]
+    pub fn confidence_override(&self, format_id: &str) -> Option<f32> {
+        self.confidence_overrides
+            .iter()
+            .find(|(id, _)| id.eq_ignore_ascii_case(format_id))
+            .map(|(_, factor)| *factor)
     }
 
     /// Get the reinterpret threshold, with default fallback.
@@ -722,7 +851,7 @@ pub struct ConversionStep {
 ///    appropriate UI components. Do NOT also show `display` - it would be redundant.
 ///
 /// 2. **If `rich_display` is empty**: Fall back to showing `display` as plain text.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Conversion {
     /// The converted value.
     pub value: CoreValue,
@@ -743,6 +872,12 @@ pub struct Conversion {
     pub steps: Vec<ConversionStep>,
     #[serde(default)]
     pub is_lossy: bool,
+    /// How reliable this conversion is (0.0 to 1.0).
+    ///
+    /// Exact representations are 1.0. Lossy or heuristic conversions score lower,
+    /// and multi-step conversions inherit the confidence of every step.
+    #[serde(default = "default_conversion_confidence")]
+    pub confidence: f32,
     /// Priority for sorting results (lower = shown first)
     #[serde(default)]
     pub priority: ConversionPriority,
@@ -769,6 +904,11 @@ pub struct Conversion {
     /// - `bytes` from text - enables hashes/hex/base64 but "15 bytes" is shown by utf8-bytes
     #[serde(skip)]
     pub hidden: bool,
+    /// If true, the search stopped at this conversion because of
+    /// [`ConversionConfig::max_depth`], so conversions reachable from it
+    /// were not explored.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub depth_limited: bool,
     /// Rich display hints for UI rendering.
     ///
     /// Multiple display options can be provided, each with a preferred
@@ -791,15 +931,30 @@ impl Conversion {
             path: vec![],
             steps: vec![],
             is_lossy: false,
+            confidence: 1.0,
             priority: ConversionPriority::default(),
             kind: ConversionKind::default(),
             display_only: false,
             hidden: false,
+            depth_limited: false,
             rich_display: vec![],
         }
     }
 }
 
+impl Default for Conversion {
+    fn default() -> Self {
+        Self::new(CoreValue::default(), String::new(), String::new())
+    }
+}
+
+fn default_conversion_confidence() -> f32 {
+    1.0
+}
+
+/// Confidence given to lossy conversions that don't set their own.
+pub const LOSSY_CONVERSION_CONFIDENCE: f32 = 0.8;
+
 /// Complete result for an input.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConversionResult {
@@ -807,3 +962,30 @@ pub struct ConversionResult {
     pub interpretation: Interpretation,
     pub conversions: Vec<Conversion>,
 }
+
+/// Encoding layers peeled off an input by [`crate::Formatorbit::unwrap_layers`].
+///
+/// For `base64(gzip(json))` this has `layers: ["base64", "gzip"]` and
+/// `terminal: "json"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnwrapResult {
+    /// Layers that were decoded, outermost first.
+    pub layers: Vec<String>,
+    /// What the innermost value was identified as: "json", "text", or "image".
+    pub terminal: String,
+    /// The fully decoded value.
+    pub value: CoreValue,
+}
+
+impl UnwrapResult {
+    /// The full chain as a display string, e.g. "base64 → gzip → json".
+    #[must_use]
+    pub fn chain(&self) -> String {
+        self.layers
+            .iter()
+            .chain(std::iter::once(&self.terminal))
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" → ")
+    }
+}
@@ -0,0 +1,80 @@
+//! Change detection for watch mode.
+//!
+//! A watcher polls a source (e.g. the system clipboard) and only wants to
+//! evaluate values it hasn't just seen. [`ClipboardDiff`] tracks the last
+//! value; [`watch_events`] turns a stream of snapshots into the inputs to
+//! evaluate.
+
+/// Tracks the last clipboard value and reports when a new one should be evaluated.
+#[derive(Debug, Default)]
+pub struct ClipboardDiff {
+    last: Option<String>,
+}
+
+impl ClipboardDiff {
+    /// Feed a clipboard snapshot, returning the input to evaluate if it changed.
+    ///
+    /// Failed reads (`None`) and empty clipboards are skipped without
+    /// forgetting the last value, so copying the same text again after
+    /// clearing the clipboard doesn't re-run the evaluation.
+    pub fn update(&mut self, snapshot: Option<&str>) -> Option<String> {
+        let text = snapshot?.trim();
+        if text.is_empty() || self.last.as_deref() == Some(text) {
+            return None;
+        }
+        self.last = Some(text.to_string());
+        self.last.clone()
+    }
+}
+
+/// The inputs watch mode evaluates for a stream of clipboard snapshots.
+///
+/// # Examples
+///
+/// ```
+/// use formatorbit_core::watch::watch_events;
+///
+/// let snapshots = [Some("691E01B8".to_string()), None, Some("691E01B8\n".to_string())];
+/// assert_eq!(watch_events(snapshots).collect::<Vec<_>>(), ["691E01B8"]);
+/// ```
+pub fn watch_events<I>(snapshots: I) -> impl Iterator<Item = String>
+where
+    I: IntoIterator<Item = Option<String>>,
+{
+    let mut diff = ClipboardDiff::default();
+    snapshots
+        .into_iter()
+        .filter_map(move |snapshot| diff.update(snapshot.as_deref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshots(texts: &[Option<&str>]) -> Vec<Option<String>> {
+        texts.iter().map(|text| text.map(String::from)).collect()
+    }
+
+    #[test]
+    fn test_watch_events_skip_repeats_and_empty() {
+        let events: Vec<_> = watch_events(snapshots(&[
+            Some("691E01B8"),
+            Some("691E01B8\n"),
+            None,
+            Some(""),
+            Some("691E01B8"),
+            Some("  550e8400-e29b-41d4-a716-446655440000  "),
+            Some("691E01B8"),
+        ]))
+        .collect();
+        assert_eq!(
+            events,
+            [
+                "691E01B8",
+                "550e8400-e29b-41d4-a716-446655440000",
+                "691E01B8"
+            ]
+        );
+        assert_eq!(watch_events(snapshots(&[None, Some("   ")])).count(), 0);
+    }
+}
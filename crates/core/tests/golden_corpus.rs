@@ -173,6 +173,24 @@ const BASE64_CASES: &[GoldenCase] = &[
         "base64",
         "JWT header-like",
     ),
+    // URL-safe alphabet without padding (base64url, JWT segments)
+    GoldenCase::top(
+        "SflKxwRJSMeKKF2QT4fwpMeJf36POk6yJV_adQssw5c",
+        "base64",
+        "JWT signature (base64url, unpadded)",
+    ),
+    GoldenCase::top("PDw_Pz4-", "base64", "base64url with - and _"),
+    // data: URIs wrap base64 but should be recognized as such
+    GoldenCase::top(
+        "data:text/plain;base64,SGVsbG8=",
+        "data-uri",
+        "base64 data URI",
+    ),
+    GoldenCase::top(
+        "data:,Hello%20World",
+        "data-uri",
+        "percent-encoded data URI",
+    ),
     // Should NOT match pure hex as base64
     // (these should be hex, not base64)
 ];
@@ -277,6 +295,19 @@ const ISBN_CASES: &[GoldenCase] = &[
     GoldenCase::top("080442957X", "isbn-10", "ISBN-10 with X checkdigit 2"),
 ];
 
+// =============================================================================
+// Golden Corpus: IBANs
+// =============================================================================
+
+const IBAN_CASES: &[GoldenCase] = &[
+    GoldenCase::top("GB82WEST12345698765432", "iban", "UK IBAN, electronic form"),
+    GoldenCase::top(
+        "DE89 3704 0044 0532 0130 00",
+        "iban",
+        "German IBAN, print form",
+    ),
+];
+
 // =============================================================================
 // Golden Corpus: JSON
 // =============================================================================
@@ -520,6 +551,15 @@ const ADVERSARIAL_CASES: &[GoldenCase] = &[
 // Test Runner
 // =============================================================================
 
+/// Every format's best confidence for the input, for diagnosing failures.
+fn format_scores(forb: &Formatorbit, input: &str) -> String {
+    forb.interpret_scored(input)
+        .iter()
+        .map(|(id, confidence)| format!("{}={:.0}%", id, confidence * 100.0))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn run_golden_tests(cases: &[GoldenCase], category: &str) {
     let forb = Formatorbit::new();
     let mut failures = Vec::new();
@@ -578,7 +618,7 @@ fn run_golden_tests(cases: &[GoldenCase], category: &str) {
                     let top = &results[0];
                     if top.interpretation.source_format != case.expected_format {
                         failures.push(format!(
-                            "[{}] '{}' ({}): Expected {} to be top, but {} ({:.0}%) is top. {} is at {:.0}%",
+                            "[{}] '{}' ({}): Expected {} to be top, but {} ({:.0}%) is top. {} is at {:.0}%\n  Scores: {}",
                             category,
                             case.input,
                             case.description,
@@ -586,7 +626,8 @@ fn run_golden_tests(cases: &[GoldenCase], category: &str) {
                             top.interpretation.source_format,
                             top.interpretation.confidence * 100.0,
                             case.expected_format,
-                            confidence * 100.0
+                            confidence * 100.0,
+                            format_scores(&forb, case.input)
                         ));
                     }
                 }
@@ -652,6 +693,11 @@ fn test_golden_isbn() {
     run_golden_tests(ISBN_CASES, "ISBN");
 }
 
+#[test]
+fn test_golden_iban() {
+    run_golden_tests(IBAN_CASES, "IBAN");
+}
+
 #[test]
 fn test_golden_json() {
     run_golden_tests(JSON_CASES, "JSON");
@@ -742,6 +788,7 @@ fn test_golden_corpus_summary() {
         ("Colors", COLOR_CASES),
         ("DateTime", DATETIME_CASES),
         ("ISBN", ISBN_CASES),
+        ("IBAN", IBAN_CASES),
         ("JSON", JSON_CASES),
         ("Text", TEXT_CASES),
         ("Numbers", NUMBER_CASES),
@@ -197,6 +197,19 @@ fn test_snapshot_datasize() {
 // Duration Tests
 // =============================================================================
 
+/// Redact any string that contains a timestamp (displays and from-now values)
+fn redact_timestamp(
+    value: insta::internals::Content,
+    _path: insta::internals::ContentPath<'_>,
+) -> insta::internals::Content {
+    if let Some(s) = value.as_str() {
+        if s.contains("T") && s.contains("Z") {
+            return insta::internals::Content::String("[dynamic timestamp]".into());
+        }
+    }
+    value
+}
+
 #[test]
 fn test_snapshot_duration() {
     let forb = Formatorbit::new();
@@ -208,15 +221,10 @@ fn test_snapshot_duration() {
         ".description" => "[description with dynamic timestamp]"
     });
     assert_json_snapshot!("duration_conversions", &result.conversions, {
-        "[].display" => insta::dynamic_redaction(|value, _path| {
-            // Redact any display that contains a timestamp
-            if let Some(s) = value.as_str() {
-                if s.contains("T") && s.contains("Z") {
-                    return insta::internals::Content::String("[dynamic timestamp]".into());
-                }
-            }
-            value.clone()
-        })
+        "[].display" => insta::dynamic_redaction(redact_timestamp),
+        "[].value.value" => insta::dynamic_redaction(redact_timestamp),
+        "[].steps[].display" => insta::dynamic_redaction(redact_timestamp),
+        "[].steps[].value.value" => insta::dynamic_redaction(redact_timestamp),
     });
 }
 
@@ -23,6 +23,9 @@ pub enum FfiError {
     FileError { message: String },
 }
 
+/// Maximum tokens interpreted per line by [`annotate_line`] (as in `forb --tee`).
+const ANNOTATE_MAX_TOKENS: usize = 50;
+
 static INSTANCE: OnceLock<Formatorbit> = OnceLock::new();
 
 fn get_instance() -> &'static Formatorbit {
@@ -41,14 +44,34 @@ pub fn version() -> String {
 
 /// Get information about all supported formats as JSON.
 ///
-/// Returns a JSON array of format info objects. Using JSON here since
-/// FormatInfo contains static str references which UniFFI cannot handle.
+/// Returns a JSON array of format info objects.
+///
+/// Deprecated: use [`formats`], which returns typed records instead of JSON.
+/// Kept for existing callers.
 #[uniffi::export]
 pub fn list_formats() -> String {
     let infos = get_instance().format_infos();
     serde_json::to_string(&infos).unwrap_or_else(|_| "[]".to_string())
 }
 
+/// Get information about all supported formats.
+#[uniffi::export]
+pub fn formats() -> Vec<FfiFormatInfo> {
+    get_instance()
+        .format_infos()
+        .into_iter()
+        .map(Into::into)
+        .collect()
+}
+
+/// Get information about one format by id or alias.
+///
+/// Returns `None` if no format has that name.
+#[uniffi::export]
+pub fn format_info(name: String) -> Option<FfiFormatInfo> {
+    get_instance().format_info(&name).map(Into::into)
+}
+
 /// Convert input and return all possible interpretations and conversions.
 ///
 /// Returns all possible interpretations sorted by confidence (highest first).
@@ -59,6 +82,23 @@ pub fn convert_all(input: String) -> Vec<FfiConversionResult> {
     results.into_iter().map(Into::into).collect()
 }
 
+/// Convert input and return all interpretations as a JSON string.
+///
+/// The same results as [`convert_all`], serialized with the core library's
+/// JSON representation (as printed by `forb --json`), for hosts that want JSON
+/// rather than typed records. `pretty` selects indented output over a single
+/// compact line.
+#[uniffi::export]
+pub fn convert_all_json(input: String, pretty: bool) -> String {
+    let results = get_instance().convert_all(&input);
+    let json = if pretty {
+        serde_json::to_string_pretty(&results)
+    } else {
+        serde_json::to_string(&results)
+    };
+    json.unwrap_or_else(|_| "[]".to_string())
+}
+
 /// Convert input using only specific formats.
 ///
 /// `formats` is a list of format IDs or aliases (e.g., ["hex", "uuid", "ts"]).
@@ -71,14 +111,10 @@ pub fn convert_filtered(input: String, formats: Vec<String>) -> Vec<FfiConversio
 
 /// Convert input and return only the highest-confidence result.
 ///
-/// Returns None if no interpretation found with confidence > 0.2.
+/// Returns None if no interpretation found with confidence of at least 0.2.
 #[uniffi::export]
 pub fn convert_first(input: String) -> Option<FfiConversionResult> {
-    let results = get_instance().convert_all(&input);
-    results
-        .into_iter()
-        .find(|r| r.interpretation.confidence > 0.2)
-        .map(Into::into)
+    get_instance().convert_best(&input, 0.2).map(Into::into)
 }
 
 /// Convert input, forcing interpretation as a specific format.
@@ -94,10 +130,65 @@ pub fn convert_from(input: String, from_format: String) -> Vec<FfiConversionResu
     results.into_iter().map(Into::into).collect()
 }
 
+/// Convert input read as one format into another.
+///
+/// Returns the shortest conversion from `from_format` to `to_format`, or None
+/// if the input isn't valid `from_format` or nothing converts to `to_format`.
+#[uniffi::export]
+pub fn convert_between(
+    input: String,
+    from_format: String,
+    to_format: String,
+) -> Option<FfiConversion> {
+    get_instance()
+        .convert_between(&input, &from_format, &to_format)
+        .map(Into::into)
+}
+
+/// Find the interesting tokens in a line of text, for highlighting log lines.
+///
+/// Each value-like token (UUIDs, timestamps, hex, IPs, ...) is interpreted,
+/// and annotated with its best interpretation if that reaches `threshold`
+/// confidence. `formats` limits interpretation to the given format IDs or
+/// aliases (empty = all formats). Annotations are in line order; spans are
+/// byte offsets into `line`.
+#[uniffi::export]
+pub fn annotate_line(line: String, threshold: f32, formats: Vec<String>) -> Vec<FfiAnnotation> {
+    let forb = get_instance();
+    formatorbit_core::tokenize(&line, ANNOTATE_MAX_TOKENS)
+        .into_iter()
+        .filter_map(|token| {
+            // Results are sorted best first, as the CLI shows them
+            let best = forb
+                .convert_all_filtered(&token.text, &formats)
+                .into_iter()
+                .next()
+                .filter(|r| r.interpretation.confidence >= threshold)?;
+            let top_conversion = best
+                .conversions
+                .into_iter()
+                .find(|c| !c.hidden)
+                .map(Into::into);
+            Some(FfiAnnotation {
+                start: token.start as u64,
+                end: token.end as u64,
+                text: token.text,
+                format: best.interpretation.source_format,
+                confidence: best.interpretation.confidence,
+                description: best.interpretation.description,
+                top_conversion,
+            })
+        })
+        .collect()
+}
+
 /// Convert raw bytes and return all possible interpretations.
 ///
-/// This is useful for binary data like images, archives, etc.
-/// The bytes are base64-encoded internally for format detection.
+/// This is useful for binary data like images, archives, etc. Callers pass
+/// the bytes as-is; there is no need to base64-encode them first. The
+/// binary metadata formats (image, pdf, archive, audio, font, office, video)
+/// are tried, falling back to a generic bytes interpretation that still
+/// converts to integers, hexdump, etc.
 #[uniffi::export]
 pub fn convert_bytes(data: Vec<u8>) -> Vec<FfiConversionResult> {
     let results = get_instance().convert_bytes(&data);
@@ -271,6 +362,23 @@ mod tests {
         assert!(formats.contains("uuid"));
     }
 
+    #[test]
+    fn test_formats() {
+        let formats = formats();
+        assert!(!formats.is_empty());
+
+        let hex = formats.iter().find(|f| f.id == "hex").expect("hex format");
+        assert!(hex.aliases.contains(&"x".to_string()));
+        assert!(!hex.examples.is_empty());
+    }
+
+    #[test]
+    fn test_format_info() {
+        assert_eq!(format_info("hex".to_string()).unwrap().id, "hex");
+        assert_eq!(format_info("b64".to_string()).unwrap().id, "base64");
+        assert!(format_info("nope".to_string()).is_none());
+    }
+
     #[test]
     fn test_convert_all() {
         let results = convert_all("691E01B8".to_string());
@@ -282,6 +390,57 @@ mod tests {
         assert!(has_hex);
     }
 
+    #[test]
+    fn test_convert_all_json() {
+        let compact = convert_all_json("691E01B8".to_string(), false);
+        assert!(!compact.contains('\n'));
+
+        let parsed: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let results = parsed.as_array().unwrap();
+        assert!(!results.is_empty());
+        assert!(compact.contains("\"source_format\""));
+        assert!(results
+            .iter()
+            .any(|r| r["interpretation"]["source_format"] == "hex"));
+
+        // Relative-time displays can tick between calls, so compare the interpretations
+        let pretty = convert_all_json("691E01B8".to_string(), true);
+        assert!(pretty.contains('\n'));
+        let pretty: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        let interpretations = |v: &serde_json::Value| -> Vec<serde_json::Value> {
+            v.as_array()
+                .unwrap()
+                .iter()
+                .map(|r| r["interpretation"].clone())
+                .collect()
+        };
+        assert_eq!(interpretations(&pretty), interpretations(&parsed));
+    }
+
+    #[test]
+    fn test_annotate_line() {
+        let line = "user 550e8400-e29b-41d4-a716-446655440000 logged in at 1703456789";
+        let annotations = annotate_line(line.to_string(), 0.8, vec![]);
+        assert_eq!(annotations.len(), 2);
+
+        let uuid = &annotations[0];
+        assert_eq!(uuid.format, "uuid");
+        assert_eq!((uuid.start, uuid.end), (5, 41));
+        assert_eq!(
+            &line[uuid.start as usize..uuid.end as usize],
+            "550e8400-e29b-41d4-a716-446655440000"
+        );
+
+        let epoch = &annotations[1];
+        assert_eq!(epoch.format, "epoch-seconds");
+        assert_eq!((epoch.start, epoch.end), (55, 65));
+        assert!(epoch.top_conversion.is_some());
+
+        // A format filter drops tokens it can't read
+        let annotations = annotate_line(line.to_string(), 0.8, vec!["uuid".to_string()]);
+        assert_eq!(annotations.len(), 1);
+    }
+
     #[test]
     fn test_convert_filtered() {
         let results = convert_filtered("691E01B8".to_string(), vec!["hex".to_string()]);
@@ -298,6 +457,20 @@ mod tests {
         assert!(result.is_some());
     }
 
+    #[test]
+    fn test_convert_between() {
+        let conversion = convert_between(
+            "Hello".to_string(),
+            "text".to_string(),
+            "base64".to_string(),
+        )
+        .expect("text converts to base64");
+        assert_eq!(conversion.display, "SGVsbG8=");
+        assert!(
+            convert_between("Hello".to_string(), "uuid".to_string(), "hex".to_string()).is_none()
+        );
+    }
+
     #[test]
     fn test_convert_bytes() {
         // PNG magic bytes
@@ -315,6 +488,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_convert_bytes_png_image() {
+        // Complete 1x1 PNG
+        let png = vec![
+            0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48,
+            0x44, 0x52, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00,
+            0x00, 0x1F, 0x15, 0xC4, 0x89, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x44, 0x41, 0x54, 0x78,
+            0xDA, 0x63, 0x64, 0xF8, 0xCF, 0x50, 0x0F, 0x00, 0x03, 0x86, 0x01, 0x80, 0x5A, 0x34,
+            0x7D, 0x6B, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E, 0x44, 0xAE, 0x42, 0x60, 0x82,
+        ];
+        let results = convert_bytes(png);
+        let image = results
+            .iter()
+            .find(|r| r.interpretation.source_format == "image")
+            .expect("PNG bytes should have an image interpretation");
+        assert!(image.interpretation.description.contains("PNG 1×1"));
+    }
+
     #[test]
     fn test_convert_bytes_from() {
         // When forcing a specific format, it should use that format
@@ -4,6 +4,7 @@
 //! They avoid problematic patterns like DateTime<Utc>, JsonValue, and complex
 //! nested enums that UniFFI cannot handle.
 
+use formatorbit_core::format::FormatInfo;
 use formatorbit_core::types::{
     Conversion, ConversionKind, ConversionPriority, ConversionResult, ConversionStep,
     Interpretation, PacketSegment, RichDisplay, RichDisplayOption, TreeNode,
@@ -311,6 +312,7 @@ pub struct FfiConversion {
     pub path: Vec<String>,
     pub steps: Vec<FfiConversionStep>,
     pub is_lossy: bool,
+    pub confidence: f32,
     pub priority: FfiConversionPriority,
     pub kind: FfiConversionKind,
     pub rich_display: Vec<FfiRichDisplayOption>,
@@ -324,6 +326,7 @@ impl From<Conversion> for FfiConversion {
             path: c.path,
             steps: c.steps.into_iter().map(Into::into).collect(),
             is_lossy: c.is_lossy,
+            confidence: c.confidence,
             priority: c.priority.into(),
             kind: c.kind.into(),
             rich_display: c.rich_display.into_iter().map(Into::into).collect(),
@@ -351,3 +354,51 @@ impl From<ConversionResult> for FfiConversionResult {
         }
     }
 }
+
+// ============================================================================
+// Annotation (log line highlighting)
+// ============================================================================
+
+/// An interesting token found in a line by `annotate_line`.
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct FfiAnnotation {
+    /// Byte offset of the token in the line (start)
+    pub start: u64,
+    /// Byte offset of the token in the line (end, exclusive)
+    pub end: u64,
+    /// The token text
+    pub text: String,
+    /// Format of the best interpretation (e.g., "uuid", "epoch-seconds")
+    pub format: String,
+    pub confidence: f32,
+    pub description: String,
+    /// The highest-priority conversion, if any
+    pub top_conversion: Option<FfiConversion>,
+}
+
+// ============================================================================
+// FormatInfo
+// ============================================================================
+
+#[derive(uniffi::Record, Debug, Clone)]
+pub struct FfiFormatInfo {
+    pub id: String,
+    pub name: String,
+    pub category: String,
+    pub description: String,
+    pub examples: Vec<String>,
+    pub aliases: Vec<String>,
+}
+
+impl From<FormatInfo> for FfiFormatInfo {
+    fn from(f: FormatInfo) -> Self {
+        Self {
+            id: f.id.to_string(),
+            name: f.name.to_string(),
+            category: f.category.to_string(),
+            description: f.description.to_string(),
+            examples: f.examples.iter().map(|s| s.to_string()).collect(),
+            aliases: f.aliases.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}